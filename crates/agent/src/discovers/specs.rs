@@ -159,6 +159,7 @@ pub fn merge_capture(
                 disable: discovered_binding.disable,
                 resource: models::RawValue::from_value(&resource),
                 backfill: 0,
+                rate_limit: None,
             });
             filtered_bindings.push(discovered_binding);
         }
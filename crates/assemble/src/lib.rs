@@ -287,6 +287,8 @@ pub fn shard_template(
         read_channel_size,
         ring_buffer_size,
         log_level,
+        rate_limit,
+        max_combiner_bytes,
     } = shard;
 
     // We hard-code that recovery logs always have prefix "recovery".
@@ -343,6 +345,16 @@ pub fn shard_template(
         },
     ];
 
+    if let Some(max_combiner_bytes) = max_combiner_bytes {
+        labels.push(broker::Label {
+            name: labels::MAX_COMBINER_BYTES.to_string(),
+            value: max_combiner_bytes.to_string(),
+        });
+    }
+    if let Some(rate_limit) = rate_limit {
+        labels.extend(rate_limit_labels(rate_limit, None));
+    }
+
     // Only add a hostname if the task actually exposes any ports.
     if !ports.is_empty() {
         labels.push(broker::Label {
@@ -396,6 +408,53 @@ pub fn shard_template(
     }
 }
 
+/// Add rate limit labels for the bindings of a capture task to its `shard` template.
+/// `bindings` are the rate limits of each built binding, in binding index order.
+pub fn binding_rate_limit_labels<'a>(
+    shard: &mut consumer::ShardSpec,
+    bindings: impl Iterator<Item = Option<&'a models::RateLimit>>,
+) {
+    let labels = &mut shard.labels.get_or_insert_with(Default::default).labels;
+
+    for (index, rate_limit) in bindings.enumerate() {
+        if let Some(rate_limit) = rate_limit {
+            labels.extend(rate_limit_labels(rate_limit, Some(index)));
+        }
+    }
+    // Labels must be in lexicographic order.
+    labels.sort_by(|l, r| l.name.cmp(&r.name));
+}
+
+fn rate_limit_labels(
+    models::RateLimit {
+        docs_per_second,
+        bytes_per_second,
+    }: &models::RateLimit,
+    binding_index: Option<usize>,
+) -> Vec<broker::Label> {
+    // Task-level limits use the bare label, while binding limits
+    // use the label prefix suffixed by the binding index.
+    let name = |task: &str, prefix: &str| match binding_index {
+        None => task.to_string(),
+        Some(index) => format!("{prefix}{index}"),
+    };
+    let mut labels = Vec::new();
+
+    if let Some(docs_per_second) = docs_per_second {
+        labels.push(broker::Label {
+            name: name(labels::RATE_LIMIT_DOCS, labels::RATE_LIMIT_DOCS_PREFIX),
+            value: docs_per_second.to_string(),
+        });
+    }
+    if let Some(bytes_per_second) = bytes_per_second {
+        labels.push(broker::Label {
+            name: name(labels::RATE_LIMIT_BYTES, labels::RATE_LIMIT_BYTES_PREFIX),
+            value: bytes_per_second.to_string(),
+        });
+    }
+    labels
+}
+
 /// This function supplies a domain name label that identifies _all_ shards for a given task.
 /// To do this, we just hash the task name and convert it to a hexidecimal string.
 /// It's a bit janky, but the only idea I've liked better is pet-names, which we
@@ -682,6 +741,55 @@ mod test {
         assert_eq!(&out, "prefix%2Fhttp%3A%2F%2Fuser%3Apassword%40foo.bar.example.com%3A9000%2Fhooks%2F%2F%2Fbaz%3Ftype=critical%26test=true.v42");
     }
 
+    #[test]
+    fn test_rate_limit_labels() {
+        let shard = models::ShardTemplate {
+            rate_limit: Some(models::RateLimit {
+                docs_per_second: Some(100),
+                bytes_per_second: None,
+            }),
+            max_combiner_bytes: Some(1 << 20),
+            ..Default::default()
+        };
+        let mut spec = shard_template(
+            "a-build",
+            "acmeCo/task",
+            labels::TASK_TYPE_CAPTURE,
+            &shard,
+            false,
+            &[],
+        );
+        let binding_limit = models::RateLimit {
+            docs_per_second: None,
+            bytes_per_second: Some(4096),
+        };
+        binding_rate_limit_labels(&mut spec, [None, Some(&binding_limit)].into_iter());
+
+        let labels: Vec<_> = spec
+            .labels
+            .unwrap()
+            .labels
+            .into_iter()
+            .map(|l| (l.name, l.value))
+            .collect();
+
+        assert_eq!(
+            labels,
+            [
+                ("app.gazette.dev/managed-by", "estuary.dev/flow"),
+                ("estuary.dev/build", "a-build"),
+                ("estuary.dev/log-level", "info"),
+                ("estuary.dev/max-combiner-bytes", "1048576"),
+                ("estuary.dev/rate-limit-bytes/1", "4096"),
+                ("estuary.dev/rate-limit-docs", "100"),
+                ("estuary.dev/task-name", "acmeCo/task"),
+                ("estuary.dev/task-type", "capture"),
+            ]
+            .map(|(n, v)| (n.to_string(), v.to_string()))
+            .to_vec()
+        );
+    }
+
     #[test]
     fn journal_selector_percent_encodes_values() {
         let mut include = BTreeMap::new();
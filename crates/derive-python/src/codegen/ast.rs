@@ -0,0 +1,252 @@
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AST {
+    Comment { body: String, of: Box<AST> },
+    Never,
+    Any,
+    Bool,
+    None,
+    Int,
+    Float,
+    Str,
+    Literal { value: Value },
+    List { of: Box<AST> },
+    Dict { of: Box<AST> },
+    TypedDict { properties: Vec<ASTProperty> },
+    Union { variants: Vec<AST> },
+    Anchor(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ASTProperty {
+    pub field: String,
+    pub value: AST,
+    pub is_required: bool,
+}
+
+/// Context accumulates the named type definitions of a generated Python module.
+///
+/// Python TypedDicts must be named, so object types which are nested within
+/// another type are hoisted into definitions of their own, with names derived
+/// from their location. Definitions are written in dependency order.
+pub struct Context {
+    defs: Vec<String>,
+    names: BTreeSet<String>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self {
+            defs: Vec::new(),
+            names: BTreeSet::new(),
+        }
+    }
+
+    /// Reserve `name`, so that it's not used for a hoisted definition.
+    pub fn reserve(&mut self, name: &str) {
+        self.names.insert(name.to_string());
+    }
+
+    /// Define `name` as the type of `ast`, preceded by a `comment`.
+    /// If `ast` is an object then it's defined as a TypedDict class,
+    /// and otherwise as a type alias.
+    pub fn define(&mut self, name: &str, comment: &str, ast: &AST) {
+        self.reserve(name);
+
+        let mut comments = Vec::new();
+        if !comment.is_empty() {
+            comments.push(comment.to_string());
+        }
+        let ast = peel_comments(ast, &mut comments);
+
+        if let AST::TypedDict { properties } = ast {
+            self.define_typed_dict(name, &comments, properties);
+        } else {
+            let expr = self.expr(ast, name);
+
+            let mut w = String::new();
+            push_comments(&mut w, "", &comments);
+            w.push_str(name);
+            w.push_str(": typing.TypeAlias = ");
+            w.push_str(&quote(&expr));
+            w.push('\n');
+            self.defs.push(w);
+        }
+    }
+
+    /// Render all definitions of the Context.
+    pub fn render(&self, into: &mut String) {
+        for def in &self.defs {
+            into.push_str("\n\n");
+            into.push_str(def);
+        }
+    }
+
+    // Render `ast` as a type expression.
+    // `hint` is the name of hoisted definitions of `ast`.
+    fn expr(&mut self, ast: &AST, hint: &str) -> String {
+        match ast {
+            AST::Comment { of, .. } => self.expr(of, hint),
+            AST::Never => "typing.Never".to_string(),
+            AST::Any => "typing.Any".to_string(),
+            AST::Bool => "bool".to_string(),
+            AST::None => "None".to_string(),
+            AST::Int => "int".to_string(),
+            AST::Float => "float".to_string(),
+            AST::Str => "str".to_string(),
+            AST::Literal { value } => format!("typing.Literal[{}]", literal(value)),
+            AST::List { of } => format!("list[{}]", self.expr(of, &format!("{hint}Item"))),
+            AST::Dict { of } => format!("dict[str, {}]", self.expr(of, &format!("{hint}Value"))),
+            AST::TypedDict { properties } => {
+                let name = self.unique_name(hint);
+                self.define_typed_dict(&name, &[], properties);
+                name
+            }
+            AST::Union { variants } => self.union_expr(variants, hint),
+            AST::Anchor(anchor) => anchor.clone(),
+        }
+    }
+
+    fn union_expr(&mut self, variants: &[AST], hint: &str) -> String {
+        // Literals are collected into a single typing.Literal,
+        // which is placed where its first variant appears.
+        let mut literals = Vec::new();
+        let mut parts = Vec::new();
+
+        for variant in variants {
+            match variant {
+                AST::Literal { value } => {
+                    if literals.is_empty() {
+                        parts.push(None);
+                    }
+                    literals.push(literal(value));
+                }
+                variant => {
+                    let expr = self.expr(variant, hint);
+                    if !parts.contains(&Some(expr.clone())) {
+                        parts.push(Some(expr));
+                    }
+                }
+            }
+        }
+
+        parts
+            .into_iter()
+            .map(|part| match part {
+                Some(expr) => expr,
+                None => format!("typing.Literal[{}]", literals.join(", ")),
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    fn define_typed_dict(&mut self, name: &str, comments: &[String], properties: &[ASTProperty]) {
+        // Render fields before the definition is pushed,
+        // so that hoisted definitions of fields come first.
+        let mut fields = Vec::new();
+
+        for prop in properties {
+            let mut comments = Vec::new();
+            let value = peel_comments(&prop.value, &mut comments);
+            let hint = format!("{name}{}", super::camel_case(&prop.field, true));
+
+            let mut expr = self.expr(value, &hint);
+            if !prop.is_required {
+                expr = format!("typing.NotRequired[{expr}]");
+            }
+            fields.push((&prop.field, comments, expr));
+        }
+
+        let mut w = String::new();
+        push_comments(&mut w, "", comments);
+
+        if fields
+            .iter()
+            .all(|(field, _, _)| PY_IDENTIFIER_RE.is_match(field) && !is_keyword(field))
+        {
+            w.push_str(&format!("class {name}(typing.TypedDict):\n"));
+
+            for (field, comments, expr) in fields {
+                push_comments(&mut w, "    ", &comments);
+                w.push_str(&format!("    {field}: {expr}\n"));
+            }
+        } else {
+            // Fields which aren't identifiers require the functional syntax.
+            w.push_str(&format!("{name} = typing.TypedDict({}, {{\n", quote(name)));
+
+            for (field, comments, expr) in fields {
+                push_comments(&mut w, "    ", &comments);
+                w.push_str(&format!("    {}: {},\n", quote(field), quote(&expr)));
+            }
+            w.push_str("})\n");
+        }
+
+        self.defs.push(w);
+    }
+
+    fn unique_name(&mut self, hint: &str) -> String {
+        let mut name = hint.to_string();
+
+        for n in 2.. {
+            if !self.names.contains(&name) {
+                break;
+            }
+            name = format!("{hint}{n}");
+        }
+        self.reserve(&name);
+
+        name
+    }
+}
+
+// Peel Comments from `ast`, collecting their bodies into `comments`.
+fn peel_comments<'a>(mut ast: &'a AST, comments: &mut Vec<String>) -> &'a AST {
+    while let AST::Comment { body, of } = ast {
+        comments.push(body.clone());
+        ast = of;
+    }
+    ast
+}
+
+fn push_comments(w: &mut String, indent: &str, comments: &[String]) {
+    for line in comments.iter().flat_map(|c| c.lines()) {
+        w.push_str(indent);
+        w.push_str(format!("# {line}").trim_end());
+        w.push('\n');
+    }
+}
+
+// Python Literal arguments. JSON numbers and strings are also valid Python literals.
+fn literal(value: &Value) -> String {
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        value => value.to_string(),
+    }
+}
+
+// Quote `s` as a Python string literal. JSON string escapes are also valid Python escapes.
+fn quote(s: &str) -> String {
+    Value::String(s.to_string()).to_string()
+}
+
+pub fn is_keyword(s: &str) -> bool {
+    PY_KEYWORDS.binary_search(&s).is_ok()
+}
+
+lazy_static::lazy_static! {
+    // The set of allowed characters in a bare Python identifier.
+    static ref PY_IDENTIFIER_RE: Regex = Regex::new(r"^[\pL_][\pL\pN_]*$").unwrap();
+}
+
+// Reserved keywords of Python, which cannot be used as identifiers.
+const PY_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
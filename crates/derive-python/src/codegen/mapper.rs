@@ -0,0 +1,165 @@
+use super::ast::{ASTProperty, AST};
+use schemalate::typegen::{self, Field, Type};
+use std::collections::BTreeMap;
+
+/// Mapper maps the Shapes of a JSON schema bundle into Python ASTs,
+/// through the language-neutral Types of the shared typegen::Mapper.
+pub struct Mapper(typegen::Mapper);
+
+impl Mapper {
+    pub fn new(bundle: &str, anchor_prefix: &str) -> Self {
+        // We don't verify index references, as validation is handled
+        // elsewhere and this is a best-effort attempt.
+        Self(typegen::Mapper::new(bundle, anchor_prefix).unwrap())
+    }
+
+    // Map the schema having |url| into an abstract syntax tree.
+    pub fn map(&self, url: &url::Url) -> AST {
+        let (doc, type_) = self.0.map(url);
+        with_doc(doc, type_to_ast(&type_))
+    }
+
+    pub fn root(&self) -> &url::Url {
+        self.0.root()
+    }
+
+    pub fn anchors(&self) -> &BTreeMap<url::Url, String> {
+        self.0.anchors()
+    }
+}
+
+fn type_to_ast(type_: &Type) -> AST {
+    match type_ {
+        Type::Any => AST::Any,
+        Type::Never => AST::Never,
+        Type::Null => AST::None,
+        Type::Bool => AST::Bool,
+        Type::Int => AST::Int,
+        Type::Float => AST::Float,
+        Type::Str => AST::Str,
+        Type::Enum(values) => AST::Union {
+            variants: values.iter().map(enum_to_ast).collect(),
+        },
+        Type::Array(item) => AST::List {
+            of: Box::new(type_to_ast(item)),
+        },
+        Type::Map(value) => AST::Dict {
+            of: Box::new(type_to_ast(value)),
+        },
+        Type::Object(fields) => AST::TypedDict {
+            properties: fields
+                .iter()
+                .map(
+                    |Field {
+                         name,
+                         doc,
+                         type_,
+                         required,
+                     }| ASTProperty {
+                        field: name.clone(),
+                        value: with_doc(doc.clone(), type_to_ast(type_)),
+                        is_required: *required,
+                    },
+                )
+                .collect(),
+        },
+        Type::Union(variants) => AST::Union {
+            variants: variants.iter().map(type_to_ast).collect(),
+        },
+        // Named types are hoisted by ast::Context, rather than by typegen.
+        Type::Ref(name) | Type::Named(name) => AST::Anchor(name.clone()),
+    }
+}
+
+// Wrap `ast` with a Comment of its `doc`, if any.
+fn with_doc(doc: Option<String>, ast: AST) -> AST {
+    match doc {
+        Some(body) => AST::Comment {
+            body,
+            of: Box::new(ast),
+        },
+        None => ast,
+    }
+}
+
+// Map an enum variant into an AST. Python Literals may only be
+// strings, integers, booleans, or None, and other values map to their type.
+fn enum_to_ast(value: &serde_json::Value) -> AST {
+    use serde_json::Value;
+
+    match value {
+        Value::Number(n) if n.is_f64() => AST::Float,
+        Value::Array(_) => AST::List {
+            of: Box::new(AST::Any),
+        },
+        Value::Object(_) => AST::Dict {
+            of: Box::new(AST::Any),
+        },
+        value => AST::Literal {
+            value: value.clone(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::super::ast::Context;
+    use super::Mapper;
+    use std::fmt::Write;
+
+    #[test]
+    fn schema_generation() {
+        let fixture = serde_yaml::from_slice(include_bytes!("mapper_test.yaml")).unwrap();
+        let mut sources = sources::scenarios::evaluate_fixtures(Default::default(), &fixture);
+        sources::inline_sources(&mut sources);
+
+        let tables::Sources {
+            collections,
+            errors,
+            ..
+        } = sources;
+
+        if !errors.is_empty() {
+            panic!("unexpected errors: {errors:?}");
+        }
+        let mut w = String::new();
+
+        for collection in collections.iter() {
+            let m = Mapper::new(collection.spec.schema.as_ref().unwrap().get(), "Doc");
+            writeln!(
+                &mut w,
+                "Schema for {name} with CURI {curi} with anchors:",
+                name = collection.collection.as_str(),
+                curi = m.root(),
+            )
+            .unwrap();
+            render(&m, &mut w);
+
+            let m = Mapper::new(collection.spec.schema.as_ref().unwrap().get(), "");
+            writeln!(
+                &mut w,
+                "Schema for {name} with CURI {curi} without anchors:",
+                name = collection.collection.as_str(),
+                curi = m.root(),
+            )
+            .unwrap();
+            render(&m, &mut w);
+        }
+
+        insta::assert_display_snapshot!(w);
+    }
+
+    fn render(m: &Mapper, w: &mut String) {
+        let mut ctx = Context::new();
+        for anchor in m.anchors().values() {
+            ctx.reserve(&format!("Doc{anchor}"));
+        }
+        ctx.define("Doc", "", &m.map(m.root()));
+
+        let mut out = String::new();
+        ctx.render(&mut out);
+        w.push_str(out.trim_start());
+        w.push_str("\n");
+    }
+}
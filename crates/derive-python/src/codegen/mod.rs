@@ -0,0 +1,333 @@
+use itertools::Itertools;
+use proto_flow::flow;
+use std::fmt::Write;
+
+mod ast;
+mod mapper;
+
+use super::LambdaConfig;
+use ast::Context;
+use mapper::Mapper;
+
+/// Segments of the generated types module of the named collection,
+/// which are valid Python identifiers.
+pub fn types_module(collection: &str) -> Vec<String> {
+    collection
+        .split('/')
+        .map(|segment| {
+            let mut segment = segment
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect::<String>();
+
+            if segment.starts_with(|c: char| c.is_numeric()) {
+                segment.insert(0, '_');
+            } else if ast::is_keyword(&segment) {
+                segment.push('_');
+            }
+            segment
+        })
+        .collect()
+}
+
+pub fn types_py(
+    collection: &flow::CollectionSpec,
+    transforms: &[(&str, &flow::CollectionSpec, LambdaConfig)],
+) -> String {
+    let mut ctx = Context::new();
+
+    let (w_mapper, r_mapper) = collection_mappers(collection, "Document");
+
+    // Reserve names of generated types, so they're not used by hoisted definitions.
+    reserve_anchors(&mut ctx, &w_mapper, r_mapper.as_ref(), "Document");
+    for (name, collection, _config) in transforms {
+        let source_name = format!("Source{}", camel_case(name, true));
+        let (w_mapper, r_mapper) = collection_mappers(collection, &source_name);
+
+        reserve_anchors(&mut ctx, &w_mapper, r_mapper.as_ref(), &source_name);
+        ctx.reserve(&format!("Read{}", camel_case(name, true)));
+    }
+    for name in [
+        "Open",
+        "StartCommit",
+        "StartedCommit",
+        "State",
+        "IDerivation",
+    ] {
+        ctx.reserve(name);
+    }
+
+    // Generate Document* types.
+    ctx.define(
+        "Document",
+        &format!(
+            "Generated for published documents of derived collection {}.",
+            &collection.name
+        ),
+        &w_mapper.map(w_mapper.root()),
+    );
+    generate_anchors(&mut ctx, &w_mapper, r_mapper.as_ref(), "Document");
+
+    // Generate Source{name} and Read{name} types for each transform.
+    for (name, collection, _config) in transforms {
+        let source_name = format!("Source{}", camel_case(name, true));
+        let (w_mapper, r_mapper) = collection_mappers(collection, &source_name);
+        let source_mapper = r_mapper.as_ref().unwrap_or(&w_mapper);
+
+        ctx.define(
+            &source_name,
+            &format!(
+                "Generated for read documents of sourced collection {}.",
+                &collection.name
+            ),
+            &source_mapper.map(source_mapper.root()),
+        );
+        generate_anchors(&mut ctx, &w_mapper, r_mapper.as_ref(), &source_name);
+    }
+
+    let mut w = String::with_capacity(4096);
+
+    write!(
+        w,
+        r#"# Generated types of derivation {name}.
+from __future__ import annotations
+
+import abc
+import typing
+"#,
+        name = &collection.name,
+    )
+    .unwrap();
+
+    ctx.render(&mut w);
+
+    for (name, _, _) in transforms {
+        write!(
+            w,
+            r#"
+
+# A read document of transform {name}.
+class Read{camel}(typing.TypedDict):
+    doc: Source{camel}
+"#,
+            camel = camel_case(name, true),
+        )
+        .unwrap();
+    }
+
+    // Generate protocol types and the IDerivation abstract class.
+    w.push_str(
+        r#"
+
+# Request.Open message, passed to the IDerivation constructor.
+class Open(typing.TypedDict):
+    state: typing.Any
+
+
+# Request.StartCommit message.
+class StartCommit(typing.TypedDict):
+    runtimeCheckpoint: typing.Any
+
+
+# An update of the connector state.
+class State(typing.TypedDict):
+    updated: typing.Any
+    mergePatch: bool
+
+
+# Response.StartedCommit message, which may include a connector state update.
+class StartedCommit(typing.TypedDict):
+    state: typing.NotRequired[State]
+
+
+class IDerivation(abc.ABC):
+    # Construct a new Derivation instance from a Request.Open message,
+    # which includes the last committed connector `state`.
+    def __init__(self, open: Open):
+        pass
+
+    # flush returns any remaining documents to be published.
+    def flush(self) -> list[Document]:
+        return []
+
+    # reset is called only when running catalog tests, and must reset any internal state.
+    def reset(self) -> None:
+        pass
+
+    # start_commit is notified of a runtime commit in progress, and returns an optional
+    # connector state update to be committed.
+    def start_commit(self, start_commit: StartCommit) -> StartedCommit:
+        return {}
+"#,
+    );
+
+    for (name, _, _) in transforms {
+        write!(
+            w,
+            r#"
+    @abc.abstractmethod
+    def {method_name}(self, read: Read{camel}) -> list[Document]:
+        raise NotImplementedError()
+"#,
+            method_name = snake_case(name),
+            camel = camel_case(name, true),
+        )
+        .unwrap();
+    }
+
+    w
+}
+
+pub fn main_py(transforms: &[(&str, &flow::CollectionSpec, LambdaConfig)]) -> String {
+    let w = include_str!("main.py.template").to_string();
+
+    let transforms = transforms
+        .iter()
+        .map(|(name, _, _)| format!("    derivation.{},", snake_case(name)))
+        .join("\n");
+
+    let w = w.replace("TRANSFORMS", &transforms);
+
+    w
+}
+
+pub fn check_py(collection: &flow::CollectionSpec) -> String {
+    include_str!("check.py.template")
+        .replace("TYPES_MODULE", &types_module(&collection.name).join("."))
+}
+
+pub fn stub_py(
+    collection: &flow::CollectionSpec,
+    transforms: &[(&str, &flow::CollectionSpec, LambdaConfig)],
+) -> String {
+    let mut w = String::with_capacity(4096);
+
+    let transforms = transforms
+        .iter()
+        .map(|(name, _, _)| {
+            let method_name = snake_case(name);
+            let read_name = format!("Read{}", camel_case(name, true));
+            (method_name, read_name)
+        })
+        .collect::<Vec<_>>();
+
+    let transform_reads = transforms.iter().map(|(_, read_name)| read_name).join(", ");
+
+    writeln!(
+        w,
+        "from flow_generated.python.{module} import IDerivation, Document, {transform_reads}",
+        module = types_module(&collection.name).join("."),
+    )
+    .unwrap();
+
+    write!(
+        w,
+        r#"
+
+# Implementation for derivation {name}.
+class Derivation(IDerivation):
+"#,
+        name = &collection.name,
+    )
+    .unwrap();
+
+    for (index, (method_name, read_name)) in transforms.iter().enumerate() {
+        if index != 0 {
+            w.push('\n');
+        }
+        writeln!(
+            w,
+            "    def {method_name}(self, read: {read_name}) -> list[Document]:"
+        )
+        .unwrap();
+        w.push_str("        raise NotImplementedError(\"Not implemented\")\n");
+    }
+
+    w
+}
+
+fn reserve_anchors(ctx: &mut Context, w_mapper: &Mapper, r_mapper: Option<&Mapper>, prefix: &str) {
+    let anchor_mapper = r_mapper.unwrap_or(w_mapper);
+
+    ctx.reserve(prefix);
+    for anchor_name in anchor_mapper.anchors().values() {
+        ctx.reserve(&format!("{prefix}{anchor_name}"));
+    }
+}
+
+fn generate_anchors(ctx: &mut Context, w_mapper: &Mapper, r_mapper: Option<&Mapper>, prefix: &str) {
+    let anchor_mapper = r_mapper.unwrap_or(w_mapper);
+
+    for (anchor_url, anchor_name) in anchor_mapper.anchors().iter() {
+        ctx.define(
+            &format!("{prefix}{anchor_name}"),
+            &format!(
+                "Generated for schema $anchor {}.",
+                anchor_url.fragment().unwrap()
+            ),
+            &anchor_mapper.map(anchor_url),
+        );
+    }
+}
+
+fn collection_mappers(c: &flow::CollectionSpec, anchor_prefix: &str) -> (Mapper, Option<Mapper>) {
+    // We extract anchors from just one schema:
+    // * The write schema, if there is no read schema.
+    // * Otherwise the read schema and not the write schema.
+    if c.read_schema_json.is_empty() {
+        (Mapper::new(&c.write_schema_json, anchor_prefix), None)
+    } else {
+        (
+            Mapper::new(&c.write_schema_json, ""),
+            Some(Mapper::new(&c.read_schema_json, anchor_prefix)),
+        )
+    }
+}
+
+fn camel_case(name: &str, mut upper: bool) -> String {
+    let mut w = String::new();
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            upper = true
+        } else if upper {
+            w.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            w.push(c);
+        }
+    }
+    w
+}
+
+fn snake_case(name: &str) -> String {
+    let mut w = String::new();
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !w.is_empty() && !w.ends_with('_') {
+                w.push('_');
+            }
+            prev_lower = false;
+        } else if c.is_uppercase() {
+            if prev_lower {
+                w.push('_');
+            }
+            w.extend(c.to_lowercase());
+            prev_lower = false;
+        } else {
+            w.push(c);
+            prev_lower = c.is_lowercase() || c.is_numeric();
+        }
+    }
+    let mut w = w.trim_end_matches('_').to_string();
+
+    // Method names may not begin with a digit, or be a reserved keyword.
+    if w.is_empty() || w.starts_with(|c: char| c.is_numeric()) {
+        w.insert_str(0, "transform_");
+    } else if ast::is_keyword(&w) {
+        w.push('_');
+    }
+    w
+}
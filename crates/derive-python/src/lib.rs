@@ -0,0 +1,304 @@
+use anyhow::Context;
+use proto_flow::{derive, flow};
+use std::io::{BufRead, Write};
+use std::{collections::BTreeMap, process::Stdio};
+
+mod codegen;
+
+pub fn run() -> anyhow::Result<()> {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    let mut bin = std::io::BufReader::new(stdin);
+    let mut line = String::new();
+
+    // Handle Spec and Validate requests, breaking upon an Open.
+    let open = loop {
+        if bin.read_line(&mut line)? == 0 {
+            return Ok(()); // Clean EOF.
+        };
+        let request: proto_flow::derive::Request = serde_json::from_str(&line)?;
+        line.clear();
+
+        if request.spec.is_some() {
+            stdout.write_all(
+                &serde_json::to_vec(&derive::Response {
+                    spec: Some(derive::response::Spec {
+                        protocol: 3032023,
+                        config_schema_json: "{}".to_string(),
+                        resource_config_schema_json: "{}".to_string(),
+                        documentation_url: "https://docs.estuary.dev".to_string(),
+                        oauth2: None,
+                    }),
+                    ..Default::default()
+                })
+                .unwrap(),
+            )?;
+        } else if let Some(request) = request.validate {
+            stdout.write_all(
+                &serde_json::to_vec(&derive::Response {
+                    validated: Some(validate(request)?),
+                    ..Default::default()
+                })
+                .unwrap(),
+            )?;
+        } else if request.open.is_some() {
+            break request;
+        } else {
+            anyhow::bail!("unexpected request {request:?}")
+        }
+        stdout.write_all("\n".as_bytes())?;
+        stdout.flush()?;
+    };
+
+    let collection = open.open.as_ref().unwrap().collection.as_ref().unwrap();
+    let derivation = collection.derivation.as_ref().unwrap();
+
+    let config = serde_json::from_str::<Config>(&derivation.config_json).unwrap();
+    let transforms = derivation
+        .transforms
+        .iter()
+        .map(|transform| {
+            let flow::collection_spec::derivation::Transform {
+                lambda_config_json,
+                collection,
+                name,
+                ..
+            } = transform;
+
+            let lambda = if lambda_config_json == "null" {
+                LambdaConfig { read_only: false }
+            } else {
+                serde_json::from_str::<LambdaConfig>(&lambda_config_json).unwrap()
+            };
+
+            (name.as_str(), collection.as_ref().unwrap(), lambda)
+        })
+        .collect::<Vec<_>>();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let temp_dir = temp_dir.path();
+
+    write_python_dir(
+        temp_dir,
+        &collection.name,
+        &codegen::types_py(&collection, &transforms),
+        &config.module,
+    )?;
+    std::fs::write(temp_dir.join(MAIN_NAME), codegen::main_py(&transforms))?;
+
+    let mut child = std::process::Command::new(PYTHON)
+        .stdin(Stdio::piped())
+        .current_dir(temp_dir)
+        .arg(MAIN_NAME)
+        .spawn()
+        .context("failed to run `python3`")?;
+
+    // Forward `open` and the remainder of stdin to `python3`.
+    let mut child_stdin = child.stdin.take().unwrap();
+    let _ = std::thread::spawn(move || {
+        let _ = child_stdin.write_all(&serde_json::to_vec(&open).unwrap());
+        let _ = child_stdin.write_all("\n".as_bytes());
+        let _ = std::io::copy(&mut bin.buffer(), &mut child_stdin);
+        let _ = std::io::copy(&mut bin.into_inner(), &mut child_stdin);
+    });
+
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("python3 failed with status {status:?}");
+    }
+
+    Ok(())
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    module: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LambdaConfig {
+    read_only: bool,
+}
+
+fn validate(validate: derive::request::Validate) -> anyhow::Result<derive::response::Validated> {
+    let derive::request::Validate {
+        connector_type: _,
+        collection,
+        config_json,
+        transforms,
+        shuffle_key_types: _,
+        project_root,
+        import_map,
+    } = &validate;
+
+    let collection = collection.as_ref().unwrap();
+
+    let config = serde_json::from_str::<Config>(&config_json)
+        .with_context(|| format!("invalid derivation configuration: {config_json}"))?;
+
+    let transforms = transforms
+        .iter()
+        .map(|transform| {
+            let derive::request::validate::Transform {
+                lambda_config_json,
+                collection,
+                name,
+                shuffle_lambda_config_json,
+            } = transform;
+
+            let lambda = if lambda_config_json == "null" {
+                LambdaConfig { read_only: false }
+            } else {
+                serde_json::from_str::<LambdaConfig>(&lambda_config_json)
+                    .with_context(|| format!("invalid lambda configuration for transform {name}"))?
+            };
+
+            if !shuffle_lambda_config_json.is_empty() {
+                anyhow::bail!("computed shuffles are not supported yet");
+            }
+
+            Ok((name.as_str(), collection.as_ref().unwrap(), lambda))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let transforms_response = transforms
+        .iter()
+        .map(
+            |(_, _, LambdaConfig { read_only })| derive::response::validated::Transform {
+                read_only: *read_only,
+            },
+        )
+        .collect();
+
+    let types_path = types_path(&collection.name);
+    let types_url = format!("{project_root}/{types_path}");
+    let types_content = codegen::types_py(&collection, &transforms);
+
+    let mut generated_files: Vec<(String, String)> =
+        vec![(types_url.clone(), types_content.clone())];
+
+    // Do we need to generate a module stub?
+    if !config.module.chars().any(char::is_whitespace) {
+        generated_files.push((
+            config.module.clone(),
+            codegen::stub_py(&collection, &transforms),
+        ));
+
+        // There's no further validation we can do.
+        return Ok(derive::response::Validated {
+            transforms: transforms_response,
+            generated_files: generated_files.into_iter().collect(),
+        });
+    }
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let temp_dir = temp_dir.path();
+
+    write_python_dir(temp_dir, &collection.name, &types_content, &config.module)?;
+    std::fs::write(temp_dir.join(CHECK_NAME), codegen::check_py(&collection))?;
+
+    let module_path = temp_dir.join(MODULE_NAME).display().to_string();
+    let temp_types_path = temp_dir.join(&types_path).display().to_string();
+
+    // Verify the module imports, and defines a complete Derivation class.
+    let output = std::process::Command::new(PYTHON)
+        .current_dir(temp_dir)
+        .arg(CHECK_NAME)
+        .output()
+        .context("failed to run `python3`. Is Python 3.11 or later installed?")?;
+
+    let mut diagnostics = if output.status.success() {
+        String::new()
+    } else {
+        String::from_utf8_lossy(&output.stderr).to_string()
+    };
+
+    // Type-check the module, if mypy is available.
+    if diagnostics.is_empty() && has_mypy() {
+        let output = std::process::Command::new(PYTHON)
+            .current_dir(temp_dir)
+            .args(["-m", "mypy", "--no-error-summary", "--no-color-output"])
+            .arg(&module_path)
+            .output()
+            .context("failed to run mypy")?;
+
+        if !output.status.success() {
+            diagnostics = String::from_utf8_lossy(&output.stdout).to_string();
+        }
+    }
+
+    if !diagnostics.is_empty() {
+        anyhow::bail!(rewrite_diagnostics(
+            diagnostics,
+            &module_path,
+            &temp_types_path,
+            &types_url,
+            &import_map,
+        ));
+    }
+
+    Ok(derive::response::Validated {
+        transforms: transforms_response,
+        generated_files: generated_files.into_iter().collect(),
+    })
+}
+
+// Path of the generated types module of the named collection,
+// relative to the project root.
+fn types_path(collection: &str) -> String {
+    format!(
+        "{GENERATED_PREFIX}/{}.py",
+        codegen::types_module(collection).join("/")
+    )
+}
+
+// Write the files of a derivation into `temp_dir`, for use by `python3`.
+fn write_python_dir(
+    temp_dir: &std::path::Path,
+    collection: &str,
+    types_py: &str,
+    module_py: &str,
+) -> anyhow::Result<()> {
+    let types_path = temp_dir.join(types_path(collection));
+
+    std::fs::create_dir_all(types_path.parent().unwrap())?;
+    std::fs::write(types_path, types_py)?;
+    std::fs::write(temp_dir.join(MODULE_NAME), module_py)?;
+
+    Ok(())
+}
+
+fn has_mypy() -> bool {
+    let status = std::process::Command::new(PYTHON)
+        .args(["-m", "mypy", "--version"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    matches!(status, Ok(status) if status.success())
+}
+
+// Re-write paths of the module and generated types within `diagnostics`
+// to their user-facing URLs.
+fn rewrite_diagnostics(
+    mut diagnostics: String,
+    module_path: &str,
+    types_path: &str,
+    types_url: &str,
+    import_map: &BTreeMap<String, String>,
+) -> String {
+    tracing::info!(?import_map, ?types_url, "re-writing diagnostics");
+
+    if let Some(import) = import_map.get("/using/python/module") {
+        diagnostics = diagnostics.replace(module_path, import);
+    }
+    diagnostics.replace(types_path, types_url)
+}
+
+const CHECK_NAME: &str = "check.py";
+const GENERATED_PREFIX: &str = "flow_generated/python";
+const MAIN_NAME: &str = "main.py";
+const MODULE_NAME: &str = "module.py";
+const PYTHON: &str = "python3";
@@ -3,7 +3,7 @@ use anyhow::Context;
 use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt};
 use prost::Message;
-use proto_flow::runtime::{derive_request_ext, derive_response_ext, DeriveRequestExt};
+use proto_flow::runtime::{derive_request_ext, DeriveRequestExt};
 use proto_flow::{
     derive::{request, response, Request, Response},
     flow, RuntimeCheckpoint,
@@ -65,8 +65,8 @@ where
                 internal,
                 ..
             }) => {
-                let sqlite_uri: String;
-                (sqlite_uri, migrations, transforms) =
+                let (sqlite_uri, snapshot): (String, Option<dbutil::Snapshot>);
+                (sqlite_uri, snapshot, migrations, transforms) =
                     parse_open(open, internal).map_err(anyhow_to_status)?;
 
                 // Drop to close an open Database.
@@ -74,19 +74,17 @@ where
                 std::mem::drop(maybe_handle);
 
                 let (handle, runtime_checkpoint) =
-                    Handle::new(&sqlite_uri, &migrations, &transforms).map_err(anyhow_to_status)?;
+                    Handle::new(&sqlite_uri, snapshot, &migrations, &transforms)
+                        .map_err(anyhow_to_status)?;
 
-                // Send Opened extended with our recovered runtime checkpoint.
+                // Send Opened with our recovered runtime checkpoint.
                 let _ = response_tx
                     .send(Ok(Response {
-                        opened: Some(response::Opened {}),
-                        ..Default::default()
-                    }
-                    .with_internal(|internal| {
-                        internal.opened = Some(derive_response_ext::Opened {
+                        opened: Some(response::Opened {
                             runtime_checkpoint: Some(runtime_checkpoint),
-                        });
-                    })))
+                        }),
+                        ..Default::default()
+                    }))
                     .await;
 
                 maybe_handle = Some(handle);
@@ -114,15 +112,20 @@ where
                     .await;
             }
             Some(Request {
-                start_commit: Some(request::StartCommit { runtime_checkpoint }),
+                start_commit:
+                    Some(request::StartCommit {
+                        runtime_checkpoint,
+                        runtime_checkpoint_delta: _,
+                    }),
                 ..
             }) => {
                 let handle = maybe_handle
-                    .as_ref()
+                    .as_mut()
                     .ok_or_else(|| tonic::Status::invalid_argument("StartCommit without Open"))?;
 
                 let started_commit =
-                    do_commit(handle.conn, runtime_checkpoint).map_err(anyhow_to_status)?;
+                    do_commit(handle.conn, handle.snapshot.as_mut(), runtime_checkpoint)
+                        .map_err(anyhow_to_status)?;
 
                 // Send StartedCommit to runtime.
                 let _ = response_tx
@@ -138,7 +141,8 @@ where
             }) => {
                 // Replace with a new :memory: database with the same configuration.
                 let (db, _runtime_checkpoint) =
-                    Handle::new(":memory:", &migrations, &transforms).map_err(anyhow_to_status)?;
+                    Handle::new(":memory:", None, &migrations, &transforms)
+                        .map_err(anyhow_to_status)?;
                 maybe_handle = Some(db);
             }
             Some(malformed) => Err(tonic::Status::invalid_argument(format!(
@@ -151,10 +155,15 @@ where
 fn parse_open(
     open: request::Open,
     internal: bytes::Bytes,
-) -> anyhow::Result<(String, Vec<String>, Vec<Transform>)> {
+) -> anyhow::Result<(
+    String,
+    Option<dbutil::Snapshot>,
+    Vec<String>,
+    Vec<Transform>,
+)> {
     let request::Open {
         collection,
-        range: _,
+        range,
         state_json: _,
         version: _,
     } = open;
@@ -186,6 +195,20 @@ fn parse_open(
     let config: Config = serde_json::from_str(config_json)
         .with_context(|| format!("failed to parse SQLite configuration: {config_json}"))?;
 
+    // Each shard writes a distinct snapshot file, named by its key and r-clock range.
+    let snapshot = config
+        .snapshot
+        .map(|snapshot| {
+            Ok::<_, anyhow::Error>(dbutil::Snapshot {
+                pages_per_step: snapshot.pages_per_step()?,
+                directory: snapshot.directory.into(),
+                range: range.unwrap_or_default(),
+                interval: snapshot.interval,
+                last_written: None,
+            })
+        })
+        .transpose()?;
+
     let transforms: Vec<Transform> = transforms
         .into_iter()
         .map(|transform| {
@@ -217,7 +240,7 @@ fn parse_open(
         })
         .collect::<Result<_, anyhow::Error>>()?;
 
-    Ok((sqlite_uri, config.migrations, transforms))
+    Ok((sqlite_uri, snapshot, config.migrations, transforms))
 }
 
 fn do_read<'db>(
@@ -265,12 +288,13 @@ fn do_read<'db>(
 
 fn do_commit(
     conn: &rusqlite::Connection,
+    snapshot: Option<&mut dbutil::Snapshot>,
     runtime_checkpoint: Option<RuntimeCheckpoint>,
 ) -> anyhow::Result<response::StartedCommit> {
     if let Some(runtime_checkpoint) = runtime_checkpoint {
         let () = dbutil::update_checkpoint(conn, runtime_checkpoint)?;
     }
-    dbutil::commit_and_begin(conn)?;
+    dbutil::commit_and_begin(conn, snapshot)?;
 
     Ok(response::StartedCommit { state: None })
 }
@@ -278,20 +302,23 @@ fn do_commit(
 struct Handle {
     conn: &'static rusqlite::Connection,
     transforms: Vec<(String, Vec<Lambda<'static>>)>,
+    snapshot: Option<dbutil::Snapshot>,
 }
 
 impl Handle {
     fn new(
         sqlite_uri: &str,
+        snapshot: Option<dbutil::Snapshot>,
         migrations: &[String],
         transforms: &[Transform],
     ) -> anyhow::Result<(Handle, RuntimeCheckpoint)> {
-        let (conn, runtime_checkpoint) = dbutil::open(sqlite_uri, &migrations)?;
+        let (conn, runtime_checkpoint) = dbutil::open(sqlite_uri, &migrations, snapshot.as_ref())?;
 
         // Place into Self so it's covered by our Drop implementation.
         let mut db = Self {
             conn: Box::leak(Box::new(conn)),
             transforms: Vec::new(),
+            snapshot,
         };
         db.transforms = dbutil::build_transforms(&db.conn, &transforms)?;
 
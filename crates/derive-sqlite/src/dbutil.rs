@@ -1,14 +1,118 @@
 use super::{is_url_to_generate, Error, Lambda, Transform};
 use anyhow::Context;
 use prost::Message;
-use proto_flow::RuntimeCheckpoint;
+use proto_flow::{flow, RuntimeCheckpoint};
 use rusqlite::Connection;
 
-pub fn open(uri: &str, migrations: &[String]) -> anyhow::Result<(Connection, RuntimeCheckpoint)> {
-    let conn = Connection::open(uri)?;
+/// Snapshot of a database into a file of a directory, which is typically on a
+/// recovery-log-backed or object-store-backed file system. Snapshots are written
+/// at commit boundaries, at most once per `interval`, and are restored into a
+/// database which hasn't been bootstrapped.
+///
+/// Each snapshot is a full copy of the database: every page is re-copied
+/// rather than only those changed since the prior snapshot, so the cost of a
+/// snapshot grows with the size of the database and not its rate of change.
+///
+/// Snapshot files are named by the key and r-clock range of their shard.
+/// A shard which has no snapshot of its own range, as happens after it's split
+/// from a parent, restores from the narrowest snapshot whose range covers its own.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// Directory of snapshot database files.
+    pub directory: std::path::PathBuf,
+    /// Key and r-clock range of the shard.
+    pub range: flow::RangeSpec,
+    /// Number of pages copied in each step of a snapshot.
+    pub pages_per_step: i32,
+    /// Minimum interval between written snapshots.
+    pub interval: std::time::Duration,
+    /// Instant at which a snapshot was last written.
+    pub last_written: Option<std::time::Instant>,
+}
+
+impl Snapshot {
+    /// Path of the snapshot file of this shard's range.
+    pub fn path(&self) -> std::path::PathBuf {
+        let flow::RangeSpec {
+            key_begin,
+            key_end,
+            r_clock_begin,
+            r_clock_end,
+        } = self.range;
+
+        self.directory.join(format!(
+            "{key_begin:08x}-{key_end:08x}-{r_clock_begin:08x}-{r_clock_end:08x}.sqlite"
+        ))
+    }
+
+    // Locate the snapshot file to restore, which is the narrowest existing
+    // snapshot having a range that covers this shard's range.
+    fn restore_path(&self) -> anyhow::Result<Option<std::path::PathBuf>> {
+        let entries = match std::fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!("failed to list SQLite snapshots of {:?}", self.directory)
+                })
+            }
+        };
+        let mut narrowest: Option<(u64, std::path::PathBuf)> = None;
+
+        for entry in entries {
+            let entry = entry.context("failed to list SQLite snapshots")?;
+
+            let Some(range) = entry.file_name().to_str().and_then(parse_snapshot_name) else {
+                continue;
+            };
+            if range.key_begin > self.range.key_begin
+                || range.key_end < self.range.key_end
+                || range.r_clock_begin > self.range.r_clock_begin
+                || range.r_clock_end < self.range.r_clock_end
+            {
+                continue; // Doesn't cover our range.
+            }
+            let width = (range.key_end - range.key_begin) as u64
+                + (range.r_clock_end - range.r_clock_begin) as u64;
+
+            if !matches!(&narrowest, Some((w, _)) if *w <= width) {
+                narrowest = Some((width, entry.path()));
+            }
+        }
+        Ok(narrowest.map(|(_, path)| path))
+    }
+}
+
+// Parse the range of a snapshot file name, as produced by Snapshot::path().
+fn parse_snapshot_name(name: &str) -> Option<flow::RangeSpec> {
+    let mut it = name
+        .strip_suffix(".sqlite")?
+        .split('-')
+        .map(|part| u32::from_str_radix(part, 16).ok());
+
+    let range = flow::RangeSpec {
+        key_begin: it.next()??,
+        key_end: it.next()??,
+        r_clock_begin: it.next()??,
+        r_clock_end: it.next()??,
+    };
+    it.next().is_none().then_some(range)
+}
+
+pub fn open(
+    uri: &str,
+    migrations: &[String],
+    snapshot: Option<&Snapshot>,
+) -> anyhow::Result<(Connection, RuntimeCheckpoint)> {
+    let mut conn = Connection::open(uri)?;
+    super::udf::register(&conn).context("failed to register user-defined functions")?;
+    super::document::register(&conn).context("failed to register flow_document")?;
 
     // TODO(johnny): Lock it down.
 
+    if let Some(snapshot) = snapshot {
+        let () = restore_snapshot(&mut conn, snapshot)?;
+    }
     let () = set_optimal_journal_mode(&conn)?;
     run_script(&conn, BOOTSTRAP, "bootstrap").context("failed to bootstrap the database")?;
     apply_migrations(&conn, migrations)?;
@@ -126,15 +230,82 @@ pub fn update_checkpoint(conn: &Connection, checkpoint: RuntimeCheckpoint) -> an
     Ok(())
 }
 
-pub fn commit_and_begin(conn: &Connection) -> anyhow::Result<()> {
-    conn.execute_batch(
-        r#"
-                COMMIT;
-                BEGIN EXCLUSIVE;
-                "#,
+pub fn commit_and_begin(conn: &Connection, snapshot: Option<&mut Snapshot>) -> anyhow::Result<()> {
+    conn.execute_batch("COMMIT;")
+        .context("failed to commit transaction")?;
+
+    // Snapshot at the commit boundary, so that the snapshot reflects exactly
+    // the checkpoint of the committed transaction. A restored snapshot which
+    // is older than the latest commit resumes from its older checkpoint.
+    if let Some(snapshot) = snapshot {
+        if !matches!(snapshot.last_written, Some(last) if last.elapsed() < snapshot.interval) {
+            let () = write_snapshot(conn, snapshot)?;
+            snapshot.last_written = Some(std::time::Instant::now());
+        }
+    }
+
+    conn.execute_batch("BEGIN EXCLUSIVE;")
+        .context("failed to begin transaction")?;
+
+    Ok(())
+}
+
+// Restore `snapshot` into `conn`, if the snapshot exists and the database of `conn`
+// has not yet been bootstrapped. A bootstrapped database is more recent than
+// any snapshot, which it was either restored from or has since written.
+fn restore_snapshot(conn: &mut Connection, snapshot: &Snapshot) -> anyhow::Result<()> {
+    let Some(path) = snapshot.restore_path()? else {
+        tracing::debug!(path=?snapshot.path(), "SQLite snapshot does not exist (will be created)");
+        return Ok(());
+    };
+
+    let bootstrapped: bool = conn
+        .query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_schema WHERE name = 'gazette_checkpoint'",
+            [],
+            |row| row.get(0),
+        )
+        .context("failed to query whether the database is bootstrapped")?;
+
+    if bootstrapped {
+        tracing::debug!(
+            ?path,
+            "not restoring SQLite snapshot into a bootstrapped database"
+        );
+        return Ok(());
+    }
+
+    conn.restore(
+        rusqlite::DatabaseName::Main,
+        &path,
+        None::<fn(rusqlite::backup::Progress)>,
     )
-    .context("failed to commit transaction")?;
+    .with_context(|| format!("failed to restore SQLite snapshot {path:?}"))?;
 
+    tracing::info!(?path, "restored SQLite database from snapshot");
+    Ok(())
+}
+
+// Write a full copy of the committed database of `conn` into `snapshot`.
+// Pages are copied `pages_per_step` at a time, within a transaction of the
+// snapshot database. An interrupted snapshot is rolled back by SQLite,
+// leaving the previous snapshot intact.
+fn write_snapshot(conn: &Connection, snapshot: &Snapshot) -> anyhow::Result<()> {
+    let path = snapshot.path();
+    let mut dst = Connection::open(&path)
+        .with_context(|| format!("failed to open SQLite snapshot {path:?}"))?;
+
+    let progress = {
+        let step = rusqlite::backup::Backup::new(conn, &mut dst)
+            .context("failed to start SQLite snapshot")?;
+
+        step.run_to_completion(snapshot.pages_per_step, std::time::Duration::ZERO, None)
+            .with_context(|| format!("failed to write SQLite snapshot {path:?}"))?;
+
+        step.progress()
+    };
+
+    tracing::debug!(?path, pages = progress.pagecount, "wrote SQLite snapshot");
     Ok(())
 }
 
@@ -272,7 +443,7 @@ mod test {
             .to_string(),
         ];
 
-        let (conn, _checkpoint) = open(tmp.path().to_str().unwrap(), &migrations).unwrap();
+        let (conn, _checkpoint) = open(tmp.path().to_str().unwrap(), &migrations, None).unwrap();
 
         update_checkpoint(
             &conn,
@@ -294,7 +465,7 @@ mod test {
             .unwrap();
 
         // We can close and then re-open with an added migration.
-        commit_and_begin(&conn).unwrap();
+        commit_and_begin(&conn, None).unwrap();
         std::mem::drop(conn);
 
         migrations.push(
@@ -305,7 +476,7 @@ mod test {
             .to_string(),
         );
 
-        let (conn, checkpoint) = open(tmp.path().to_str().unwrap(), &migrations).unwrap();
+        let (conn, checkpoint) = open(tmp.path().to_str().unwrap(), &migrations, None).unwrap();
 
         assert_eq!(
             checkpoint.sources.len(),
@@ -330,6 +501,167 @@ mod test {
         insta::assert_display_snapshot!(fixture_content, @r###"[{"id":4,"value":"hello"},{"id":5,"value":"updated"},{"thing":"hi","other":32},{"thing":"there","other":32},{"thing":"bye","other":42}]"###);
     }
 
+    #[test]
+    fn snapshot_and_restore() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut snapshot = Snapshot {
+            directory: tmp.path().to_owned(),
+            range: flow::RangeSpec {
+                key_begin: 0,
+                key_end: u32::MAX,
+                r_clock_begin: 0,
+                r_clock_end: u32::MAX,
+            },
+            pages_per_step: 2,
+            interval: std::time::Duration::from_secs(3600),
+            last_written: None,
+        };
+        let migrations = vec![r#"
+            CREATE TABLE one ( id INTEGER PRIMARY KEY NOT NULL, value TEXT );
+        "#
+        .to_string()];
+
+        // Open a :memory: database, which has no snapshot to restore.
+        let (conn, checkpoint) = open(":memory:", &migrations, Some(&snapshot)).unwrap();
+        assert_eq!(checkpoint, RuntimeCheckpoint::default());
+
+        // Write enough rows to span multiple snapshot steps.
+        for id in 0..1000 {
+            conn.execute(
+                "INSERT INTO one (id, value) VALUES (?, ?);",
+                rusqlite::params![id, "x".repeat(100)],
+            )
+            .unwrap();
+        }
+        update_checkpoint(
+            &conn,
+            RuntimeCheckpoint {
+                sources: [(
+                    "a/journal".to_string(),
+                    runtime_checkpoint::Source {
+                        read_through: 456,
+                        ..Default::default()
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        commit_and_begin(&conn, Some(&mut snapshot)).unwrap();
+        assert!(snapshot
+            .path()
+            .ends_with("00000000-ffffffff-00000000-ffffffff.sqlite"));
+
+        // A commit within the snapshot interval doesn't write a snapshot.
+        conn.execute("DELETE FROM one WHERE id < 500;", []).unwrap();
+        commit_and_begin(&conn, Some(&mut snapshot)).unwrap();
+
+        // Uncommitted changes are not part of the snapshot.
+        conn.execute("DELETE FROM one;", []).unwrap();
+        std::mem::drop(conn);
+
+        // A new :memory: database is restored from the snapshot.
+        let (conn, checkpoint) = open(":memory:", &migrations, Some(&snapshot)).unwrap();
+        assert_eq!(checkpoint.sources["a/journal"].read_through, 456);
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM one", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1000);
+
+        // A shard split from the snapshotted range restores from its parent's snapshot.
+        let mut child = Snapshot {
+            range: flow::RangeSpec {
+                key_begin: 0x80000000,
+                ..snapshot.range
+            },
+            last_written: None,
+            ..snapshot.clone()
+        };
+        let (conn, checkpoint) = open(":memory:", &migrations, Some(&child)).unwrap();
+        assert_eq!(checkpoint.sources["a/journal"].read_through, 456);
+
+        // Once written, the child's own snapshot is preferred.
+        conn.execute("DELETE FROM one;", []).unwrap();
+        commit_and_begin(&conn, Some(&mut child)).unwrap();
+        std::mem::drop(conn);
+
+        let (conn, _checkpoint) = open(":memory:", &migrations, Some(&child)).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM one", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+
+        // A database which is already bootstrapped is not restored.
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let (conn, _checkpoint) = open(file.path().to_str().unwrap(), &[], None).unwrap();
+        commit_and_begin(&conn, None).unwrap();
+        std::mem::drop(conn);
+
+        let (_conn, checkpoint) =
+            open(file.path().to_str().unwrap(), &migrations, Some(&snapshot)).unwrap();
+        assert_eq!(checkpoint, RuntimeCheckpoint::default());
+    }
+
+    #[test]
+    fn snapshot_names() {
+        let range = flow::RangeSpec {
+            key_begin: 0x10,
+            key_end: 0x2000,
+            r_clock_begin: 0,
+            r_clock_end: u32::MAX,
+        };
+        assert_eq!(
+            parse_snapshot_name("00000010-00002000-00000000-ffffffff.sqlite"),
+            Some(range)
+        );
+        assert_eq!(
+            parse_snapshot_name("00000010-00002000-00000000.sqlite"),
+            None
+        );
+        assert_eq!(
+            parse_snapshot_name("00000010-00002000-00000000-ffffffff-1.sqlite"),
+            None
+        );
+        assert_eq!(
+            parse_snapshot_name("00000010-00002000-00000000-ffffffff.db"),
+            None
+        );
+    }
+
+    #[test]
+    fn snapshot_config_pages_per_step() {
+        let parse = |config: serde_json::Value| {
+            serde_json::from_value::<crate::SnapshotConfig>(config)
+                .unwrap()
+                .pages_per_step()
+                .map_err(|err| err.to_string())
+        };
+
+        assert_eq!(
+            parse(serde_json::json!({"directory": "/snapshots"})),
+            Ok(1024)
+        );
+        assert_eq!(
+            parse(serde_json::json!({"directory": "/snapshots", "pagesPerStep": 2147483647})),
+            Ok(i32::MAX)
+        );
+        insta::assert_debug_snapshot!((
+            parse(serde_json::json!({"directory": "/snapshots", "pagesPerStep": 0})),
+            parse(serde_json::json!({"directory": "/snapshots", "pagesPerStep": 2147483648u64})),
+        ), @r###"
+        (
+            Err(
+                "snapshot pagesPerStep must be between 1 and 2147483647, not 0",
+            ),
+            Err(
+                "snapshot pagesPerStep must be between 1 and 2147483647, not 2147483648",
+            ),
+        )
+        "###);
+    }
+
     #[test]
     fn mapping_sql_blocks_to_statements() {
         let statements = sql_block_to_statements(
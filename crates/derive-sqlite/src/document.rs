@@ -0,0 +1,263 @@
+use rusqlite::types::Value;
+use rusqlite::vtab::{
+    eponymous_only_module, Context, IndexConstraintOp, IndexInfo, VTab, VTabConnection, VTabCursor,
+    Values,
+};
+use rusqlite::{ffi, Connection, Error, Result};
+use std::cell::RefCell;
+use std::os::raw::c_int;
+
+thread_local! {
+    // Source document of the lambda which is currently being invoked on this thread.
+    static CURRENT: RefCell<Option<Source>> = RefCell::new(None);
+}
+
+/// Serializes the source document of an invoked lambda into a JSON value.
+pub type Serialize<'d> = Box<dyn Fn() -> serde_json::Result<serde_json::Value> + 'd>;
+
+// Source document of an invoked lambda. It's serialized only once `flow_document`
+// is actually read, as most lambdas never read it.
+enum Source {
+    // Pointer to the Serialize owned by the `Current` guard of this thread.
+    Pending(*const (dyn Fn() -> serde_json::Result<serde_json::Value> + 'static)),
+    Serialized(serde_json::Value),
+}
+
+/// Set the current source document of this thread, which is exposed to lambdas
+/// through the `flow_document` table-valued function until the returned
+/// guard is dropped. `serialize` is called at most once, and only if the
+/// document is read.
+pub fn set_current<'d>(serialize: Serialize<'d>) -> Current<'d> {
+    let ptr: *const (dyn Fn() -> serde_json::Result<serde_json::Value> + 'd) = &*serialize;
+    // SAFETY: The lifetime of `serialize` is erased only while it's stored
+    // in CURRENT, and the returned guard (which owns `serialize`) clears
+    // CURRENT before `serialize` is dropped.
+    let ptr: *const (dyn Fn() -> serde_json::Result<serde_json::Value> + 'static) =
+        unsafe { std::mem::transmute(ptr) };
+
+    CURRENT.with(|current| *current.borrow_mut() = Some(Source::Pending(ptr)));
+    Current {
+        _serialize: serialize,
+    }
+}
+
+/// Current is a guard which clears the source document of this thread when dropped.
+pub struct Current<'d> {
+    _serialize: Serialize<'d>,
+}
+
+impl Drop for Current<'_> {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = None);
+    }
+}
+
+/// Register the `flow_document` table-valued function with the Connection.
+///
+/// `flow_document(ptr)` returns the children of the JSON pointer `ptr` within
+/// the source document of the invoked lambda, with one row for each item of an
+/// array or property of an object. If `ptr` locates a scalar, a single row is
+/// returned, and if it doesn't exist then no rows are returned.
+/// `ptr` defaults to the document root.
+///
+/// Each row has columns:
+///  * `key`: The INTEGER array index or TEXT object property of the child, or NULL for a scalar.
+///  * `value`: The child value. Arrays and objects are JSON-encoded TEXT.
+///  * `type`: One of 'null', 'boolean', 'integer', 'number', 'string', 'array', or 'object'.
+///  * `ptr`: The JSON pointer of the child within the source document.
+pub fn register(conn: &Connection) -> Result<()> {
+    conn.create_module(
+        "flow_document",
+        eponymous_only_module::<DocumentTab>(),
+        None,
+    )
+}
+
+// Column indices of the `flow_document` table.
+const COLUMN_KEY: c_int = 0;
+const COLUMN_VALUE: c_int = 1;
+const COLUMN_TYPE: c_int = 2;
+const COLUMN_PTR: c_int = 3;
+const COLUMN_ROOT: c_int = 4;
+
+#[repr(C)]
+struct DocumentTab {
+    base: ffi::sqlite3_vtab,
+}
+
+unsafe impl<'vtab> VTab<'vtab> for DocumentTab {
+    type Aux = ();
+    type Cursor = DocumentCursor;
+
+    fn connect(
+        _db: &mut VTabConnection,
+        _aux: Option<&()>,
+        _args: &[&[u8]],
+    ) -> Result<(String, Self)> {
+        Ok((
+            "CREATE TABLE x(key, value, type, ptr, root HIDDEN)".to_string(),
+            DocumentTab {
+                base: ffi::sqlite3_vtab::default(),
+            },
+        ))
+    }
+
+    fn best_index(&self, info: &mut IndexInfo) -> Result<()> {
+        let mut root = None;
+
+        for (index, constraint) in info.constraints().enumerate() {
+            if constraint.column() != COLUMN_ROOT {
+                continue;
+            } else if !constraint.is_usable()
+                || constraint.operator() != IndexConstraintOp::SQLITE_INDEX_CONSTRAINT_EQ
+            {
+                // Refuse plans which can't pass the `root` argument through to filter().
+                return Err(Error::SqliteFailure(
+                    ffi::Error::new(ffi::SQLITE_CONSTRAINT),
+                    None,
+                ));
+            }
+            root = Some(index);
+        }
+
+        if let Some(index) = root {
+            let mut usage = info.constraint_usage(index);
+            usage.set_argv_index(1);
+            usage.set_omit(true);
+            info.set_idx_num(1);
+        }
+        info.set_estimated_cost(1.0);
+
+        Ok(())
+    }
+
+    fn open(&mut self) -> Result<DocumentCursor> {
+        Ok(DocumentCursor {
+            base: ffi::sqlite3_vtab_cursor::default(),
+            root: String::new(),
+            rows: Vec::new(),
+            row_id: 0,
+        })
+    }
+}
+
+struct Row {
+    key: Value,
+    value: Value,
+    type_: &'static str,
+    ptr: String,
+}
+
+#[repr(C)]
+struct DocumentCursor {
+    base: ffi::sqlite3_vtab_cursor,
+    root: String,
+    rows: Vec<Row>,
+    row_id: usize,
+}
+
+unsafe impl VTabCursor for DocumentCursor {
+    fn filter(&mut self, idx_num: c_int, _idx_str: Option<&str>, args: &Values<'_>) -> Result<()> {
+        self.root = if idx_num == 1 {
+            args.get::<Option<String>>(0)?.unwrap_or_default()
+        } else {
+            String::new()
+        };
+        self.row_id = 0;
+
+        self.rows = CURRENT.with(|current| {
+            let mut current = current.borrow_mut();
+
+            // Serialize the source document upon its first read.
+            if let Some(Source::Pending(serialize)) = current.as_ref() {
+                // SAFETY: A Pending source is valid for as long as it's in CURRENT.
+                let doc = unsafe { (**serialize)() }.map_err(|err| {
+                    Error::ModuleError(format!("failed to serialize the source document: {err}"))
+                })?;
+                *current = Some(Source::Serialized(doc));
+            }
+
+            match current.as_ref() {
+                Some(Source::Serialized(doc)) => Ok(build_rows(&self.root, doc)),
+                _ => Err(Error::ModuleError(
+                    "flow_document() may only be used within a transform lambda".to_string(),
+                )),
+            }
+        })?;
+
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        self.row_id += 1;
+        Ok(())
+    }
+
+    fn eof(&self) -> bool {
+        self.row_id >= self.rows.len()
+    }
+
+    fn column(&self, ctx: &mut Context, i: c_int) -> Result<()> {
+        let row = &self.rows[self.row_id];
+
+        match i {
+            COLUMN_KEY => ctx.set_result(&row.key),
+            COLUMN_VALUE => ctx.set_result(&row.value),
+            COLUMN_TYPE => ctx.set_result(&row.type_),
+            COLUMN_PTR => ctx.set_result(&row.ptr),
+            COLUMN_ROOT => ctx.set_result(&self.root),
+            _ => Err(Error::ModuleError(format!("invalid column {i}"))),
+        }
+    }
+
+    fn rowid(&self) -> Result<i64> {
+        Ok(self.row_id as i64)
+    }
+}
+
+fn build_rows(root: &str, doc: &serde_json::Value) -> Vec<Row> {
+    use serde_json::Value as JSON;
+
+    let Some(node) = doc::Pointer::from_str(root).query(doc) else {
+        return Vec::new();
+    };
+    // Child pointers are built by escaping and appending tokens to the root.
+    let child = |key: Value, token: &str, value: &serde_json::Value| Row {
+        key,
+        value: super::udf::json_to_sql(value),
+        type_: type_name(value),
+        ptr: format!("{root}/{}", token.replace('~', "~0").replace('/', "~1")),
+    };
+
+    match node {
+        JSON::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| child(Value::Integer(index as i64), &index.to_string(), item))
+            .collect(),
+        JSON::Object(props) => props
+            .iter()
+            .map(|(prop, value)| child(Value::Text(prop.clone()), prop, value))
+            .collect(),
+        scalar => vec![Row {
+            key: Value::Null,
+            value: super::udf::json_to_sql(scalar),
+            type_: type_name(scalar),
+            ptr: root.to_string(),
+        }],
+    }
+}
+
+fn type_name(value: &serde_json::Value) -> &'static str {
+    use serde_json::Value as JSON;
+
+    match value {
+        JSON::Null => "null",
+        JSON::Bool(_) => "boolean",
+        JSON::Number(n) if n.is_f64() => "number",
+        JSON::Number(_) => "integer",
+        JSON::String(_) => "string",
+        JSON::Array(_) => "array",
+        JSON::Object(_) => "object",
+    }
+}
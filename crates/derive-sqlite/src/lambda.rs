@@ -18,10 +18,23 @@ use super::{Error, Param};
 /// with "json_object", as is typically the case with SQLite's JSON functions,
 /// then this column is directly mapped into the returned output document.
 /// This can be used to implement lambdas with dynamic top-level properties.
+///
+/// Output columns named as a JSON pointer, such as `"/key/id"`, place their
+/// value at that location of the output document instead of as a top-level
+/// property. This is used to explicitly set (possibly nested) collection keys,
+/// and to publish deletions through a `'d' AS "/_meta/op"` column.
+/// Pointed columns having a NULL value are omitted from the output document.
+///
+/// Each returned row is published as a separate document, so a lambda may
+/// publish zero, one, or many documents for each source document. The
+/// `flow_document` table-valued function exposes the source document
+/// to the statement, which is helpful for unnesting its arrays and objects.
 pub struct Lambda<'db> {
     stmt: rusqlite::Statement<'db>,
     bindings: Vec<(String, Param)>,
     outputs: Vec<(String, Option<usize>)>,
+    // Output column pointers, for columns named as a JSON pointer.
+    pointers: Vec<Option<doc::Pointer>>,
 }
 
 impl<'db> Lambda<'db> {
@@ -84,23 +97,43 @@ impl<'db> Lambda<'db> {
             }
         }
 
+        let pointers = outputs
+            .iter()
+            .map(|(name, _binding)| {
+                if name.starts_with('/') {
+                    Some(doc::Pointer::from_str(name))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
         Ok(Self {
             stmt,
             bindings,
             outputs,
+            pointers,
         })
     }
 
-    pub fn invoke<'s, N: doc::AsNode>(
+    pub fn invoke<'s, 'd: 's, N: doc::AsNode>(
         &'s mut self,
-        document: &N,
+        document: &'d N,
     ) -> Result<impl Iterator<Item = rusqlite::Result<serde_json::Value>> + 's, Error> {
         let Self {
             stmt,
             bindings,
             outputs,
+            pointers,
         } = self;
 
+        // Expose the document to `flow_document`, which may be used indirectly
+        // through a view or trigger, for as long as the returned rows are alive.
+        // It's serialized only if `flow_document` is actually read.
+        let current = super::document::set_current(Box::new(move || {
+            serde_json::to_value(&doc::SerPolicy::default().on(document))
+        }));
+
         for (index, (encoding, param)) in bindings.iter().enumerate() {
             bind_parameter(stmt, index, param, document).map_err(|err| Error::BindingError {
                 encoding: encoding.clone(),
@@ -109,12 +142,15 @@ impl<'db> Lambda<'db> {
             })?;
         }
 
-        let result = self
+        let rows = self
             .stmt
             .raw_query()
-            .mapped(|row| Ok(row_to_json(bindings, outputs, row)));
+            .mapped(|row| row_to_json(bindings, outputs, pointers, row));
 
-        Ok(result)
+        Ok(Invocation {
+            rows,
+            _current: current,
+        })
     }
 
     pub fn invoke_vec<'s, N: doc::AsNode>(
@@ -133,6 +169,21 @@ impl<'db> Lambda<'db> {
     }
 }
 
+// Invocation is an iterator over the rows of an invoked Lambda,
+// which clears the current `flow_document` when dropped.
+struct Invocation<'d, I> {
+    rows: I,
+    _current: super::document::Current<'d>,
+}
+
+impl<I: Iterator> Iterator for Invocation<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next()
+    }
+}
+
 fn bind_parameter<N: doc::AsNode>(
     stmt: &mut rusqlite::Statement<'_>,
     index: usize,
@@ -184,13 +235,10 @@ fn bind_parameter_node<N: doc::AsNode>(
         Node::Float(f) => stmt.raw_bind_parameter(index + 1, f),
         Node::NegInt(s) => stmt.raw_bind_parameter(index + 1, s),
         Node::PosInt(u) => stmt.raw_bind_parameter(index + 1, u),
-        Node::Array(_) => stmt.raw_bind_parameter(
-            index + 1,
-            &serde_json::to_string(&doc::SerPolicy::default().on(node)).unwrap(),
-        ),
-        Node::Object(_) => stmt.raw_bind_parameter(
+        Node::Array(_) | Node::Object(_) => stmt.raw_bind_parameter(
             index + 1,
-            &serde_json::to_string(&doc::SerPolicy::default().on(node)).unwrap(),
+            &serde_json::to_string(&doc::SerPolicy::default().on(node))
+                .map_err(|err| rusqlite::Error::ToSqlConversionFailure(Box::new(err)))?,
         ),
     }
 }
@@ -198,28 +246,51 @@ fn bind_parameter_node<N: doc::AsNode>(
 fn row_to_json(
     bindings: &[(String, Param)],
     columns: &[(String, Option<usize>)],
+    pointers: &[Option<doc::Pointer>],
     row: &rusqlite::Row<'_>,
-) -> serde_json::Value {
+) -> rusqlite::Result<serde_json::Value> {
     if columns.is_empty() {
         // SELECT json_object(...) from ...
-        convert_value_ref(row.get_ref(0).unwrap())
-    } else {
-        // SELECT 1 as foo, 'two' as bar from ...
-        serde_json::Value::Object(
-            columns
-                .iter()
-                .enumerate()
-                .map(|(index, (name, binding))| {
-                    (
-                        binding
-                            .map(|b| bindings[b].1.projection.field.clone())
-                            .unwrap_or_else(|| name.clone()),
-                        convert_value_ref(row.get_ref(index).unwrap()),
-                    )
-                })
-                .collect(),
-        )
+        return Ok(convert_value_ref(row.get_ref(0).unwrap()));
+    }
+
+    // SELECT 1 as foo, 'two' as bar from ...
+    let mut doc = serde_json::Value::Object(
+        columns
+            .iter()
+            .zip(pointers.iter())
+            .enumerate()
+            .filter(|(_index, (_column, ptr))| ptr.is_none())
+            .map(|(index, ((name, binding), _ptr))| {
+                (
+                    binding
+                        .map(|b| bindings[b].1.projection.field.clone())
+                        .unwrap_or_else(|| name.clone()),
+                    convert_value_ref(row.get_ref(index).unwrap()),
+                )
+            })
+            .collect(),
+    );
+
+    // SELECT 'd' as "/_meta/op", $id as "/key/id" from ...
+    // Pointed locations are applied after top-level properties, and take precedence.
+    for (index, ((name, _binding), ptr)) in columns.iter().zip(pointers.iter()).enumerate() {
+        let Some(ptr) = ptr else { continue };
+
+        let value = convert_value_ref(row.get_ref(index).unwrap());
+        if value.is_null() {
+            continue;
+        }
+
+        let Some(location) = ptr.create_value(&mut doc) else {
+            return Err(rusqlite::Error::InvalidColumnName(format!(
+                "output column {name:?} cannot be placed in the published document {doc}"
+            )));
+        };
+        *location = value;
     }
+
+    Ok(doc)
 }
 
 fn convert_value_ref(value: rusqlite::types::ValueRef<'_>) -> serde_json::Value {
@@ -454,4 +525,70 @@ mod test {
 
         insta::assert_json_snapshot!(output);
     }
+
+    #[test]
+    fn test_pointer_outputs_and_flow_document() {
+        let db = rusqlite::Connection::open_in_memory().unwrap();
+        super::super::document::register(&db).unwrap();
+
+        let fixture = json!({
+            "id": "order-1",
+            "items": [{"sku": "a", "qty": 2}, {"sku": "b", "qty": 0}],
+            "note": {"a/b": "hi"},
+        });
+        let params = &[test_param("id", "/id", false, false, false)];
+
+        // Publish a document for each item, where zero-quantity items are deletions.
+        let mut lambda = Lambda::new(
+            &db,
+            r#"
+            select
+              $id as "/key/id",
+              i.key as "/key/line",
+              json_extract(i.value, '$.sku') as sku,
+              case when json_extract(i.value, '$.qty') = 0 then 'd' end as "/_meta/op"
+            from flow_document('/items') as i;
+            "#,
+            params,
+        )
+        .unwrap();
+
+        let mut output = vec![lambda.invoke_vec(&fixture).unwrap()];
+
+        // Enumerate the document root, and also a nested object and scalar.
+        for root in ["", "/note", "/id", "/missing"] {
+            let mut lambda = Lambda::new(
+                &db,
+                &format!("select key, value, type, ptr from flow_document('{root}');"),
+                params,
+            )
+            .unwrap();
+
+            output.push(lambda.invoke_vec(&fixture).unwrap());
+        }
+
+        insta::assert_json_snapshot!(output);
+
+        // The document is also available to views which use `flow_document`.
+        db.execute_batch("create view items as select * from flow_document('/items');")
+            .unwrap();
+        let mut lambda = Lambda::new(&db, "select count(*) as n from items;", params).unwrap();
+        assert_eq!(lambda.invoke_vec(&fixture).unwrap(), vec![json!({"n": 2})]);
+
+        // Once an invocation is finished, the document is no longer available.
+        let err = db
+            .query_row("select count(*) from flow_document();", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("within a transform lambda"),
+            "{err}"
+        );
+
+        // A pointed column may not be nested under a scalar column.
+        let mut lambda = Lambda::new(&db, r#"select 1 as a, 2 as "/a/b";"#, params).unwrap();
+        let err = lambda.invoke_vec(&fixture).unwrap_err();
+        assert!(err.to_string().contains(r#"output column "/a/b""#), "{err}");
+    }
 }
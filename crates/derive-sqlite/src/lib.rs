@@ -31,8 +31,10 @@ pub enum Error {
 
 mod connector;
 mod dbutil;
+mod document;
 mod lambda;
 mod param;
+mod udf;
 mod validate;
 
 pub use connector::connector;
@@ -50,6 +52,42 @@ fn is_url_to_generate(statement: &str) -> bool {
 pub struct Config {
     #[serde(default)]
     migrations: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    snapshot: Option<SnapshotConfig>,
+}
+
+// Configuration of database snapshots, which persist the database across shard assignments.
+// Each snapshot is a full copy of the database, not an incremental delta.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotConfig {
+    directory: String,
+    #[serde(default = "SnapshotConfig::default_pages_per_step")]
+    pages_per_step: u64,
+    #[serde(default = "SnapshotConfig::default_interval", with = "humantime_serde")]
+    interval: std::time::Duration,
+}
+
+impl SnapshotConfig {
+    fn default_pages_per_step() -> u64 {
+        1024
+    }
+    fn default_interval() -> std::time::Duration {
+        std::time::Duration::from_secs(300)
+    }
+
+    // Validated number of pages copied in each step of a snapshot.
+    // A step of zero pages would never complete, and SQLite takes an i32.
+    fn pages_per_step(&self) -> anyhow::Result<i32> {
+        match i32::try_from(self.pages_per_step) {
+            Ok(pages) if pages >= 1 => Ok(pages),
+            _ => anyhow::bail!(
+                "snapshot pagesPerStep must be between 1 and {}, not {}",
+                i32::MAX,
+                self.pages_per_step
+            ),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,472 @@
+use rusqlite::functions::{Aggregate, Context, FunctionFlags};
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{Connection, Error, Result};
+use sha2::Digest;
+
+/// Register the library of user-defined functions with the Connection.
+///
+/// Scalar functions:
+///  * `regex_match(pattern, text)` is 1 if `text` matches `pattern`, else 0.
+///  * `regex_extract(pattern, text [, group])` is the `group` (default 0) of the first match, or NULL.
+///  * `regex_replace(pattern, text, replacement)` replaces all matches, where `$1` or `${name}` refer to groups.
+///  * `sha256(value)` is the lower-case hex SHA-256 digest of a TEXT or BLOB.
+///  * `xxhash(value [, seed])` is the XXH3 64-bit hash of a TEXT or BLOB, as an INTEGER.
+///  * `uuid_v4()` is a random UUID.
+///  * `uuid_v5(namespace, name)` is a name-based UUID, where `namespace` is a UUID or one of 'dns', 'url', 'oid', or 'x500'.
+///  * `rfc3339_to_unix(timestamp)` is the REAL seconds since the Unix epoch of an RFC 3339 timestamp.
+///  * `rfc3339_from_unix(seconds [, offset])` is the RFC 3339 timestamp of Unix `seconds`, in `offset` (default 'Z').
+///  * `rfc3339_to_offset(timestamp, offset)` is `timestamp` as of UTC `offset`, such as '-05:00'.
+///  * `rfc3339_add(timestamp, seconds)` is `timestamp` plus (possibly negative and fractional) `seconds`.
+///  * `json_pointer(document, pointer)` is the value of JSON `document` at JSON `pointer`, or NULL.
+///
+/// Aggregate functions:
+///  * `sha256_agg(value)` is the SHA-256 digest of all aggregated values, in order.
+///  * `xxhash_agg(value)` is the XXH3 64-bit hash of all aggregated values, in order.
+///
+/// All functions return NULL if their first argument is NULL.
+pub fn register(conn: &Connection) -> Result<()> {
+    let deterministic = FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC;
+
+    conn.create_scalar_function("regex_match", 2, deterministic, |ctx| {
+        let Some(text) = text_arg(ctx, 1)? else {
+            return Ok(None);
+        };
+        Ok(Some(regex_arg(ctx, 0)?.is_match(&text)))
+    })?;
+    for n_arg in [2, 3] {
+        conn.create_scalar_function("regex_extract", n_arg, deterministic, |ctx| {
+            let Some(text) = text_arg(ctx, 1)? else {
+                return Ok(None);
+            };
+            let group: i64 = if ctx.len() == 3 { ctx.get(2)? } else { 0 };
+            let re = regex_arg(ctx, 0)?;
+
+            Ok(re
+                .captures(&text)
+                .and_then(|captures| captures.get(group as usize))
+                .map(|m| m.as_str().to_string()))
+        })?;
+    }
+    conn.create_scalar_function("regex_replace", 3, deterministic, |ctx| {
+        let Some(text) = text_arg(ctx, 1)? else {
+            return Ok(None);
+        };
+        let replacement: String = ctx.get(2)?;
+        let re = regex_arg(ctx, 0)?;
+
+        Ok(Some(
+            re.replace_all(&text, replacement.as_str()).into_owned(),
+        ))
+    })?;
+
+    conn.create_scalar_function("sha256", 1, deterministic, |ctx| {
+        Ok(bytes_arg(ctx, 0)?.map(|b| hex::encode(sha2::Sha256::digest(b))))
+    })?;
+    for n_arg in [1, 2] {
+        conn.create_scalar_function("xxhash", n_arg, deterministic, |ctx| {
+            let seed: i64 = if ctx.len() == 2 { ctx.get(1)? } else { 0 };
+            Ok(bytes_arg(ctx, 0)?
+                .map(|b| xxhash_rust::xxh3::xxh3_64_with_seed(&b, seed as u64) as i64))
+        })?;
+    }
+
+    conn.create_scalar_function("uuid_v4", 0, FunctionFlags::SQLITE_UTF8, |_ctx| {
+        Ok(uuid::Uuid::new_v4().to_string())
+    })?;
+    conn.create_scalar_function("uuid_v5", 2, deterministic, |ctx| {
+        let Some(namespace) = text_arg(ctx, 0)? else {
+            return Ok(None);
+        };
+        let namespace = match namespace.as_str() {
+            "dns" => uuid::Uuid::NAMESPACE_DNS,
+            "url" => uuid::Uuid::NAMESPACE_URL,
+            "oid" => uuid::Uuid::NAMESPACE_OID,
+            "x500" => uuid::Uuid::NAMESPACE_X500,
+            namespace => uuid::Uuid::parse_str(namespace).map_err(user_err)?,
+        };
+        let name = bytes_arg(ctx, 1)?.unwrap_or_default();
+
+        Ok(Some(uuid::Uuid::new_v5(&namespace, &name).to_string()))
+    })?;
+
+    conn.create_scalar_function("rfc3339_to_unix", 1, deterministic, |ctx| {
+        let Some(ts) = timestamp_arg(ctx, 0)? else {
+            return Ok(None);
+        };
+        Ok(Some(
+            ts.unix_timestamp() as f64 + ts.nanosecond() as f64 / 1e9,
+        ))
+    })?;
+    for n_arg in [1, 2] {
+        conn.create_scalar_function("rfc3339_from_unix", n_arg, deterministic, |ctx| {
+            let Some(seconds) = ctx.get::<Option<f64>>(0)? else {
+                return Ok(None);
+            };
+            let offset = if ctx.len() == 2 {
+                offset_arg(ctx, 1)?
+            } else {
+                time::UtcOffset::UTC
+            };
+            // Convert whole and fractional seconds separately, to not lose precision.
+            let (whole, fract) = (seconds.floor(), seconds - seconds.floor());
+            let ts = time::OffsetDateTime::from_unix_timestamp(whole as i64).map_err(user_err)?
+                + time::Duration::nanoseconds((fract * 1e9).round() as i64);
+
+            format_timestamp(ts.to_offset(offset)).map(Some)
+        })?;
+    }
+    conn.create_scalar_function("rfc3339_to_offset", 2, deterministic, |ctx| {
+        let Some(ts) = timestamp_arg(ctx, 0)? else {
+            return Ok(None);
+        };
+        format_timestamp(ts.to_offset(offset_arg(ctx, 1)?)).map(Some)
+    })?;
+    conn.create_scalar_function("rfc3339_add", 2, deterministic, |ctx| {
+        let Some(ts) = timestamp_arg(ctx, 0)? else {
+            return Ok(None);
+        };
+        let seconds: f64 = ctx.get(1)?;
+
+        // Duration::seconds_f64 panics if `seconds` is not finite or overflows.
+        if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+            return Err(user_err(format!("{seconds} seconds is out of range")));
+        }
+        let ts = ts
+            .checked_add(time::Duration::seconds_f64(seconds))
+            .ok_or_else(|| user_err("timestamp is out of range"))?;
+
+        format_timestamp(ts).map(Some)
+    })?;
+
+    conn.create_scalar_function("json_pointer", 2, deterministic, |ctx| {
+        let Some(doc) = bytes_arg(ctx, 0)? else {
+            return Ok(Value::Null);
+        };
+        let doc: serde_json::Value = serde_json::from_slice(&doc).map_err(user_err)?;
+        let ptr = doc::Pointer::from_str(&ctx.get::<String>(1)?);
+
+        Ok(match ptr.query(&doc) {
+            Some(value) => json_to_sql(value),
+            None => Value::Null,
+        })
+    })?;
+
+    conn.create_aggregate_function("sha256_agg", 1, deterministic, Sha256Agg)?;
+    conn.create_aggregate_function("xxhash_agg", 1, deterministic, XxhashAgg)?;
+
+    Ok(())
+}
+
+struct Sha256Agg;
+
+impl Aggregate<sha2::Sha256, Option<String>> for Sha256Agg {
+    fn init(&self, _ctx: &mut Context<'_>) -> Result<sha2::Sha256> {
+        Ok(sha2::Sha256::new())
+    }
+
+    fn step(&self, ctx: &mut Context<'_>, hasher: &mut sha2::Sha256) -> Result<()> {
+        if let Some(b) = bytes_arg(ctx, 0)? {
+            hasher.update(b);
+        }
+        Ok(())
+    }
+
+    fn finalize(
+        &self,
+        _ctx: &mut Context<'_>,
+        hasher: Option<sha2::Sha256>,
+    ) -> Result<Option<String>> {
+        Ok(hasher.map(|hasher| hex::encode(hasher.finalize())))
+    }
+}
+
+struct XxhashAgg;
+
+impl Aggregate<xxhash_rust::xxh3::Xxh3, Option<i64>> for XxhashAgg {
+    fn init(&self, _ctx: &mut Context<'_>) -> Result<xxhash_rust::xxh3::Xxh3> {
+        Ok(xxhash_rust::xxh3::Xxh3::new())
+    }
+
+    fn step(&self, ctx: &mut Context<'_>, hasher: &mut xxhash_rust::xxh3::Xxh3) -> Result<()> {
+        if let Some(b) = bytes_arg(ctx, 0)? {
+            hasher.update(&b);
+        }
+        Ok(())
+    }
+
+    fn finalize(
+        &self,
+        _ctx: &mut Context<'_>,
+        hasher: Option<xxhash_rust::xxh3::Xxh3>,
+    ) -> Result<Option<i64>> {
+        Ok(hasher.map(|hasher| hasher.digest() as i64))
+    }
+}
+
+fn user_err<E: ToString>(err: E) -> Error {
+    Error::UserFunctionError(err.to_string().into())
+}
+
+// Compiled regular expressions are cached by SQLite as auxiliary data
+// of the (typically constant) pattern argument.
+fn regex_arg(ctx: &Context<'_>, index: usize) -> Result<std::sync::Arc<regex::Regex>> {
+    ctx.get_or_create_aux(index as std::os::raw::c_int, |pattern| -> Result<_> {
+        regex::Regex::new(pattern.as_str().map_err(user_err)?).map_err(user_err)
+    })
+}
+
+fn text_arg(ctx: &Context<'_>, index: usize) -> Result<Option<String>> {
+    ctx.get(index)
+}
+
+// Map a TEXT, BLOB, or numeric argument into its bytes.
+fn bytes_arg(ctx: &Context<'_>, index: usize) -> Result<Option<Vec<u8>>> {
+    Ok(match ctx.get_raw(index) {
+        ValueRef::Null => None,
+        ValueRef::Text(b) | ValueRef::Blob(b) => Some(b.to_vec()),
+        ValueRef::Integer(i) => Some(i.to_string().into_bytes()),
+        ValueRef::Real(f) => Some(f.to_string().into_bytes()),
+    })
+}
+
+fn timestamp_arg(ctx: &Context<'_>, index: usize) -> Result<Option<time::OffsetDateTime>> {
+    let Some(ts) = text_arg(ctx, index)? else {
+        return Ok(None);
+    };
+    time::OffsetDateTime::parse(&ts, &time::format_description::well_known::Rfc3339)
+        .map(Some)
+        .map_err(|err| user_err(format!("invalid RFC 3339 timestamp {ts:?}: {err}")))
+}
+
+fn offset_arg(ctx: &Context<'_>, index: usize) -> Result<time::UtcOffset> {
+    let offset: String = ctx.get(index)?;
+
+    if offset == "Z" || offset == "z" {
+        return Ok(time::UtcOffset::UTC);
+    }
+    time::UtcOffset::parse(
+        &offset,
+        time::macros::format_description!("[offset_hour sign:mandatory]:[offset_minute]"),
+    )
+    .map_err(|err| user_err(format!("invalid UTC offset {offset:?}: {err}")))
+}
+
+fn format_timestamp(ts: time::OffsetDateTime) -> Result<String> {
+    ts.format(&time::format_description::well_known::Rfc3339)
+        .map_err(user_err)
+}
+
+pub fn json_to_sql(value: &serde_json::Value) -> Value {
+    use serde_json::Value as JSON;
+
+    match value {
+        JSON::Null => Value::Null,
+        JSON::Bool(b) => Value::Integer(*b as i64),
+        JSON::Number(n) => match (n.as_i64(), n.as_f64()) {
+            (Some(i), _) => Value::Integer(i),
+            (None, Some(f)) => Value::Real(f),
+            (None, None) => Value::Text(n.to_string()),
+        },
+        JSON::String(s) => Value::Text(s.clone()),
+        JSON::Array(_) | JSON::Object(_) => Value::Text(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Query a single row, mapping its columns into a JSON array.
+    fn query(conn: &Connection, sql: &str) -> serde_json::Value {
+        use serde_json::json;
+
+        conn.query_row(sql, [], |row| {
+            let count = row.as_ref().column_count();
+            Ok((0..count)
+                .map(|i| match row.get_ref(i).unwrap() {
+                    ValueRef::Null => json!(null),
+                    ValueRef::Integer(i) => json!(i),
+                    ValueRef::Real(f) => json!(f),
+                    ValueRef::Text(s) => json!(std::str::from_utf8(s).unwrap()),
+                    ValueRef::Blob(b) => json!(b),
+                })
+                .collect())
+        })
+        .unwrap()
+    }
+
+    fn conn() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        register(&conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_regex_functions() {
+        let conn = conn();
+
+        insta::assert_json_snapshot!(query(&conn, r#"SELECT
+            regex_match('^a+b$', 'aaab'),
+            regex_match('^a+b$', 'aaac'),
+            regex_match('^a+b$', NULL),
+            regex_extract('(\d+)-(\d+)', 'order 12-34'),
+            regex_extract('(\d+)-(\d+)', 'order 12-34', 2),
+            regex_extract('(\d+)-(\d+)', 'no match'),
+            regex_replace('(?P<y>\d{4})-(?P<m>\d{2})', 'on 2023-08', '${m}/${y}')
+        "#), @r###"
+        [
+          1,
+          0,
+          null,
+          "12-34",
+          "34",
+          null,
+          "on 08/2023"
+        ]
+        "###);
+
+        let err = conn
+            .query_row("SELECT regex_match('(', 'text')", [], |row| {
+                row.get::<_, bool>(0)
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("regex parse error"), "{err}");
+    }
+
+    #[test]
+    fn test_hash_functions() {
+        let conn = conn();
+
+        insta::assert_json_snapshot!(query(&conn, r#"SELECT
+            sha256('hello'),
+            sha256(CAST('hello' AS BLOB)),
+            sha256(NULL),
+            xxhash(''),
+            xxhash('hello') = xxhash(CAST('hello' AS BLOB)),
+            xxhash('hello') != xxhash('hello', 42),
+            xxhash(NULL)
+        "#), @r###"
+        [
+          "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+          "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+          null,
+          3244421341483603138,
+          1,
+          1,
+          null
+        ]
+        "###);
+
+        // Aggregates hash the concatenation of their values.
+        insta::assert_json_snapshot!(query(&conn, r#"
+            WITH parts(p) AS (VALUES ('hel'), ('lo'))
+            SELECT sha256_agg(p), xxhash_agg(p) = xxhash('hello') FROM parts
+        "#), @r###"
+        [
+          "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+          1
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_uuid_functions() {
+        let conn = conn();
+
+        insta::assert_json_snapshot!(query(&conn, r#"SELECT
+            uuid_v5('dns', 'example.com'),
+            uuid_v5('6ba7b811-9dad-11d1-80b4-00c04fd430c8', 'https://example.com'),
+            length(uuid_v4()),
+            uuid_v4() != uuid_v4()
+        "#), @r###"
+        [
+          "cfbff0d1-9375-5685-968c-48ce8b15ae17",
+          "4fd35a71-71ef-5a55-a9d9-aa75c889a6d0",
+          36,
+          1
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_time_functions() {
+        let conn = conn();
+
+        insta::assert_json_snapshot!(query(&conn, r#"SELECT
+            rfc3339_to_unix('2023-08-01T12:30:00.5Z'),
+            rfc3339_to_unix('2023-08-01T08:30:00-04:00'),
+            rfc3339_from_unix(1690893000),
+            rfc3339_from_unix(1690893000.25, '+05:30'),
+            rfc3339_to_offset('2023-08-01T12:30:00Z', '-07:00'),
+            rfc3339_add('2023-08-01T23:30:00+02:00', 3600),
+            rfc3339_add('2023-08-01T00:00:00Z', -0.5)
+        "#), @r###"
+        [
+          1690893000.5,
+          1690893000.0,
+          "2023-08-01T12:30:00Z",
+          "2023-08-01T18:00:00.25+05:30",
+          "2023-08-01T05:30:00-07:00",
+          "2023-08-02T00:30:00+02:00",
+          "2023-07-31T23:59:59.5Z"
+        ]
+        "###);
+
+        let err = conn
+            .query_row("SELECT rfc3339_to_unix('yesterday')", [], |row| {
+                row.get::<_, f64>(0)
+            })
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("invalid RFC 3339 timestamp"),
+            "{err}"
+        );
+
+        // Non-finite or overflowing durations are errors, rather than panics.
+        for (seconds, expect) in [
+            ("9e999", "inf seconds is out of range"),
+            ("-9e999", "-inf seconds is out of range"),
+            ("1e300", "seconds is out of range"),
+            ("1e15", "timestamp is out of range"),
+        ] {
+            let err = conn
+                .query_row(
+                    &format!("SELECT rfc3339_add('2023-08-01T00:00:00Z', {seconds})"),
+                    [],
+                    |row| row.get::<_, String>(0),
+                )
+                .unwrap_err();
+            assert!(err.to_string().contains(expect), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        let conn = conn();
+
+        insta::assert_json_snapshot!(query(&conn, r#"
+            WITH d(doc) AS (VALUES ('{"a":{"b":[1,"two",{"c~d":true}],"e/f":3.5},"n":null}'))
+            SELECT
+                json_pointer(doc, '/a/b/0'),
+                json_pointer(doc, '/a/b/1'),
+                json_pointer(doc, '/a/b/2/c~0d'),
+                json_pointer(doc, '/a/e~1f'),
+                json_pointer(doc, '/a/b'),
+                json_pointer(doc, '/n'),
+                json_pointer(doc, '/missing'),
+                json_pointer(NULL, '/a')
+            FROM d
+        "#), @r###"
+        [
+          1,
+          "two",
+          1,
+          3.5,
+          "[1,\"two\",{\"c~d\":true}]",
+          null,
+          null,
+          null
+        ]
+        "###);
+    }
+}
@@ -21,6 +21,10 @@ pub fn parse_validate(
     let config: Config = serde_json::from_str(&config_json)
         .with_context(|| format!("failed to parse SQLite configuration: {config_json}"))?;
 
+    if let Some(snapshot) = &config.snapshot {
+        snapshot.pages_per_step()?;
+    }
+
     let transforms: Vec<Transform> = transforms
         .into_iter()
         .map(|transform| {
@@ -59,7 +63,7 @@ pub fn do_validate(
     migrations: &[String],
     transforms: &[Transform],
 ) -> anyhow::Result<response::Validated> {
-    let (conn, _checkpoint) = dbutil::open(":memory:", migrations)?;
+    let (conn, _checkpoint) = dbutil::open(":memory:", migrations, None)?;
     let transform_stacks = dbutil::build_transforms(&conn, &transforms)?;
 
     let mut generated_files: Vec<(String, String)> = Vec::new();
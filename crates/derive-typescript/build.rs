@@ -0,0 +1,45 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=TYPESCRIPT_DIR");
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    // The TypeScript compiler which is embedded for type-checking and
+    // transpiling derivations without a `deno` installation is vendored into
+    // ./typescript by ./vendor-typescript.sh. $TYPESCRIPT_DIR may override it
+    // with another unpacked `typescript` npm package.
+    let package = match std::env::var("TYPESCRIPT_DIR") {
+        Ok(dir) => PathBuf::from(dir),
+        Err(_) => manifest_dir.join("typescript"),
+    };
+    let lib = package.join("lib");
+    println!("cargo:rerun-if-changed={}", lib.display());
+
+    if !lib.join("typescript.js").exists() {
+        panic!("{lib:?} is missing typescript.js (run ./vendor-typescript.sh)");
+    }
+
+    let mut libs: Vec<String> = std::fs::read_dir(&lib)
+        .unwrap_or_else(|err| panic!("failed to read {lib:?}: {err}"))
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("lib.") && name.ends_with(".d.ts"))
+        .collect();
+    libs.sort();
+
+    let mut w = String::new();
+    writeln!(
+        w,
+        "pub const TYPESCRIPT_JS: &str = include_str!({:?});",
+        lib.join("typescript.js")
+    )
+    .unwrap();
+    writeln!(w, "pub const LIBS: &[(&str, &str)] = &[").unwrap();
+    for name in libs {
+        writeln!(w, "    ({name:?}, include_str!({:?})),", lib.join(&name)).unwrap();
+    }
+    writeln!(w, "];").unwrap();
+
+    std::fs::write(out_dir.join("typescript.rs"), w).unwrap();
+}
@@ -1,85 +1,49 @@
 use super::ast::{ASTProperty, ASTTuple, AST};
-use doc::shape::{ArrayShape, ObjShape, Provenance, Shape};
-use json::schema::{types, Keyword};
+use doc::shape::{ArrayShape, ObjShape, Shape};
+use json::schema::types;
 use regex::Regex;
+use schemalate::typegen;
 use std::collections::BTreeMap;
 
-pub struct Mapper {
-    pub top_level: BTreeMap<url::Url, String>,
-    validator: doc::Validator,
-    anchor_prefix: String,
-}
+/// Mapper maps the Shapes of a JSON schema bundle into TypeScript ASTs.
+/// Named schemas, and references to them, are resolved by the shared
+/// language-neutral typegen::Mapper, while TypeScript's richer tuples and
+/// index signatures are mapped here.
+pub struct Mapper(typegen::Mapper);
 
 impl Mapper {
     pub fn new(bundle: &str, anchor_prefix: &str) -> Self {
-        let schema = doc::validation::build_bundle(bundle).unwrap();
-        let validator = doc::validation::Validator::new(schema).unwrap();
-
-        let mut top_level = BTreeMap::new();
-
-        if !anchor_prefix.is_empty() {
-            let mut stack = vec![&validator.schemas()[0]];
-            while let Some(schema) = stack.pop() {
-                for kw in &schema.kw {
-                    match kw {
-                        Keyword::Anchor(anchor_uri) => {
-                            // Does this anchor meet our definition of a named schema?
-                            if let Some((_, anchor)) = anchor_uri
-                                .as_str()
-                                .split_once('#')
-                                .filter(|(_, s)| NAMED_SCHEMA_RE.is_match(s))
-                            {
-                                top_level.insert(anchor_uri.clone(), anchor.to_owned());
-                            }
-                        }
-                        Keyword::Application(_, child) => {
-                            stack.push(child);
-                        }
-                        _ => (),
-                    }
-                }
-            }
-        }
-
         // We don't verify index references, as validation is handled
         // elsewhere and this is a best-effort attempt.
-
-        Mapper {
-            validator,
-            top_level,
-            anchor_prefix: anchor_prefix.to_string(),
-        }
+        Self(typegen::Mapper::new(bundle, anchor_prefix).unwrap())
     }
 
     // Map the schema having |url| into an abstract syntax tree.
     pub fn map(&self, url: &url::Url) -> AST {
-        let index = self.validator.schema_index();
-        let shape = match index.fetch(url) {
-            Some(schema) => Shape::infer(schema, index),
-            None => Shape::anything(),
-        };
-        self.to_ast(&shape)
+        self.to_ast(&self.0.shape(url))
     }
 
     pub fn root(&self) -> &url::Url {
-        &self.validator.schemas()[0].curi
+        self.0.root()
+    }
+
+    pub fn anchors(&self) -> &BTreeMap<url::Url, String> {
+        self.0.anchors()
     }
 
     fn to_ast(&self, shape: &Shape) -> AST {
-        if let Provenance::Reference(uri) = &shape.provenance {
-            if let Some(anchor) = self.top_level.get(uri) {
-                let mut ast = AST::Anchor(format!("{}{anchor}", &self.anchor_prefix));
-
-                // Wrap with a `title` keyword comment, but not `description`.
-                if let Some(title) = &shape.title {
-                    ast = AST::Comment {
-                        body: title.to_string(),
-                        of: Box::new(ast),
-                    };
-                }
+        if let Some(anchor) = self.0.anchor_ref(shape) {
+            let mut ast = AST::Anchor(anchor);
 
-                return ast;
+            // Wrap with a `title` keyword comment, but not `description`.
+            if let Some(title) = &shape.title {
+                ast = AST::Comment {
+                    body: title.to_string(),
+                    of: Box::new(ast),
+                };
             }
+
+            return ast;
         }
 
         let mut ast = self.to_ast_inner(shape);
@@ -249,13 +213,6 @@ impl Mapper {
 lazy_static::lazy_static! {
     // The set of allowed characters in a bare TypeScript variable name.
     static ref TS_VARIABLE_RE : Regex = Regex::new(r"^\pL[\pL\pN_]*$").unwrap();
-    // The set of allowed characters in a schema `$anchor` is quite limited,
-    // by Sec 8.2.3.
-    //
-    // To identify named schemas, we further restrict to anchors which start
-    // with a capital letter and include only '_' as punctuation.
-    // See: https://json-schema.org/draft/2019-09/json-schema-core.html#anchor
-    static ref NAMED_SCHEMA_RE: regex::Regex = regex::Regex::new("^[A-Z][\\w_]+$").unwrap();
 }
 
 #[cfg(test)]
@@ -169,7 +169,7 @@ export class Derivation extends IDerivation {{
 fn generate_anchors(w: &mut String, w_mapper: &Mapper, r_mapper: Option<&Mapper>, prefix: &str) {
     let anchor_mapper = r_mapper.unwrap_or(w_mapper);
 
-    for (anchor_url, anchor_name) in anchor_mapper.top_level.iter() {
+    for (anchor_url, anchor_name) in anchor_mapper.anchors().iter() {
         write!(
             w,
             r#"
@@ -0,0 +1,5 @@
+// Deno globals which are available within the embedded TypeScript engine.
+declare namespace Deno {
+    const stdin: unknown;
+    const stdout: unknown;
+}
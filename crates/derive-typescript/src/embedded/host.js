@@ -0,0 +1,208 @@
+// Host glue of the embedded TypeScript engine.
+//
+// The Rust host defines the global functions:
+//  * __flow_read_line(): string | null, which reads a line of input (or null at EOF).
+//  * __flow_write(data: string), which writes to the output.
+//  * __flow_flush(), which flushes the output.
+//  * __flow_log(line: string), which writes a line to stderr.
+//  * __flow_fail(error: string), which records a fatal error of the derivation.
+//
+// The TypeScript compiler is evaluated before this script,
+// and is available as the global `ts`.
+"use strict";
+
+// Resolve an import `specifier` of the module `referrer`,
+// using `imports` to map bare and URL specifiers.
+function __flow_resolve(specifier, referrer, imports) {
+    if (specifier in imports) {
+        return imports[specifier];
+    } else if (!specifier.startsWith("./") && !specifier.startsWith("../")) {
+        return specifier;
+    }
+    const parts = referrer.split("/").slice(0, -1);
+
+    for (const part of specifier.split("/")) {
+        if (part === "..") {
+            parts.pop();
+        } else if (part !== ".") {
+            parts.push(part);
+        }
+    }
+    return parts.join("/");
+}
+
+// Type-check the `root` module, returning formatted diagnostics
+// or an empty string if there are none.
+function __flow_check(filesJson, importsJson, root) {
+    const files = JSON.parse(filesJson);
+    const imports = JSON.parse(importsJson);
+
+    const options = {
+        target: ts.ScriptTarget.ES2022,
+        module: ts.ModuleKind.ES2022,
+        lib: ["lib.es2022.d.ts", "lib.dom.d.ts", "lib.dom.iterable.d.ts"],
+        strict: true,
+        noEmit: true,
+        allowImportingTsExtensions: true,
+        types: [],
+    };
+    const host = {
+        getSourceFile: (name, languageVersion) =>
+            name in files ? ts.createSourceFile(name, files[name], languageVersion) : undefined,
+        getDefaultLibFileName: () => "/lib/lib.d.ts",
+        getDefaultLibLocation: () => "/lib",
+        writeFile: () => { },
+        getCurrentDirectory: () => "/",
+        getCanonicalFileName: (name) => name,
+        useCaseSensitiveFileNames: () => true,
+        getNewLine: () => "\n",
+        fileExists: (name) => name in files,
+        readFile: (name) => files[name],
+        resolveModuleNames: (specifiers, referrer) => specifiers.map((specifier) => {
+            const name = __flow_resolve(specifier, referrer, imports);
+
+            if (name in files) {
+                return { resolvedFileName: name, extension: ts.extensionFromPath(name) };
+            }
+            return undefined;
+        }),
+    };
+
+    // Ambient declarations (other than standard libraries) are also roots.
+    const ambient = Object.keys(files).filter((name) => name.endsWith(".d.ts") && !name.startsWith("/lib/"));
+
+    const program = ts.createProgram([root, ...ambient], options, host);
+
+    return ts.getPreEmitDiagnostics(program).map((diagnostic) => {
+        const category = ts.DiagnosticCategory[diagnostic.category].toLowerCase();
+        const message = ts.flattenDiagnosticMessageText(diagnostic.messageText, "\n");
+
+        if (!diagnostic.file) {
+            return `${category} TS${diagnostic.code}: ${message}`;
+        }
+        const { line, character } = diagnostic.file.getLineAndCharacterOfPosition(diagnostic.start);
+        return `${diagnostic.file.fileName}:${line + 1}:${character + 1} - ${category} TS${diagnostic.code}: ${message}`;
+    }).join("\n");
+}
+
+// Transpile and run the `root` module, resolving when it completes.
+function __flow_run(filesJson, importsJson, root) {
+    const files = JSON.parse(filesJson);
+    const imports = JSON.parse(importsJson);
+    const modules = {};
+
+    const load = (name) => {
+        if (name in modules) {
+            return modules[name].exports;
+        } else if (!(name in files)) {
+            throw new Error(`module ${name} is not available within the embedded TypeScript engine`);
+        }
+        const module = { exports: {} };
+        modules[name] = module;
+
+        const code = ts.transpileModule(files[name], {
+            compilerOptions: {
+                target: ts.ScriptTarget.ES2020,
+                module: ts.ModuleKind.CommonJS,
+            },
+            fileName: name,
+        }).outputText;
+
+        // Modules are wrapped in an async function to support top-level await.
+        const fn = new Function("exports", "require", "module", `return (async () => {\n${code}\n})();`);
+        module.done = fn(module.exports, (specifier) => load(__flow_resolve(specifier, name, imports)), module);
+
+        return module.exports;
+    };
+
+    load(root);
+    modules[root].done.catch((err) => __flow_fail(__flow_format(err)));
+}
+
+function __flow_format(value) {
+    if (typeof value === "string") {
+        return value;
+    } else if (value instanceof Error) {
+        return `${value}\n${value.stack || ""}`;
+    }
+    try {
+        return JSON.stringify(value);
+    } catch (_) {
+        return String(value);
+    }
+}
+
+// Deno globals used by main.ts. Reads and writes are implemented by the
+// embedded replacements of the Deno standard library modules.
+globalThis.Deno = { stdin: {}, stdout: {} };
+
+globalThis.console = {
+    log: (...args) => __flow_log(args.map(__flow_format).join(" ")),
+};
+globalThis.console.error = globalThis.console.log;
+globalThis.console.warn = globalThis.console.log;
+globalThis.console.info = globalThis.console.log;
+globalThis.console.debug = globalThis.console.log;
+
+// UTF-8 encoding and decoding, which QuickJS doesn't provide.
+globalThis.TextEncoder = class TextEncoder {
+    get encoding() {
+        return "utf-8";
+    }
+
+    encode(input = "") {
+        const out = [];
+
+        for (const char of input) {
+            const c = char.codePointAt(0);
+
+            if (c < 0x80) {
+                out.push(c);
+            } else if (c < 0x800) {
+                out.push(0xc0 | (c >> 6), 0x80 | (c & 0x3f));
+            } else if (c < 0x10000) {
+                out.push(0xe0 | (c >> 12), 0x80 | ((c >> 6) & 0x3f), 0x80 | (c & 0x3f));
+            } else {
+                out.push(
+                    0xf0 | (c >> 18),
+                    0x80 | ((c >> 12) & 0x3f),
+                    0x80 | ((c >> 6) & 0x3f),
+                    0x80 | (c & 0x3f),
+                );
+            }
+        }
+        return new Uint8Array(out);
+    }
+};
+
+globalThis.TextDecoder = class TextDecoder {
+    get encoding() {
+        return "utf-8";
+    }
+
+    decode(input = new Uint8Array()) {
+        const bytes = input instanceof Uint8Array ? input : new Uint8Array(input.buffer || input);
+        let out = "";
+
+        for (let i = 0; i < bytes.length;) {
+            const b = bytes[i];
+            let c, n;
+
+            if (b < 0x80) {
+                [c, n] = [b, 1];
+            } else if (b < 0xe0) {
+                [c, n] = [b & 0x1f, 2];
+            } else if (b < 0xf0) {
+                [c, n] = [b & 0x0f, 3];
+            } else {
+                [c, n] = [b & 0x07, 4];
+            }
+            for (let j = 1; j < n; j++) {
+                c = (c << 6) | (bytes[i + j] & 0x3f);
+            }
+            out += String.fromCodePoint(c);
+            i += n;
+        }
+        return out;
+    }
+};
@@ -0,0 +1,305 @@
+use rquickjs::{CatchResultExt, Context, Function, Runtime};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+
+// TypeScript compiler and standard library declarations, as vendored
+// by vendor-typescript.sh and embedded by build.rs.
+mod typescript {
+    include!(concat!(env!("OUT_DIR"), "/typescript.rs"));
+}
+
+/// Sources of a TypeScript derivation, keyed on their path within the embedded engine.
+pub struct Sources {
+    files: BTreeMap<String, String>,
+    imports: BTreeMap<String, String>,
+}
+
+// Paths of derivation sources within the embedded engine.
+// These are distinctive, so that they may be re-written in diagnostics.
+pub const MAIN_PATH: &str = "/__flow__/main.ts";
+pub const MODULE_PATH: &str = "/__flow__/module.ts";
+pub const TYPES_PATH: &str = "/__flow__/types.ts";
+
+impl Sources {
+    pub fn new(collection: &str, types_ts: String, module_ts: String, main_ts: String) -> Self {
+        let files = [
+            (MAIN_PATH, main_ts),
+            (MODULE_PATH, module_ts),
+            (TYPES_PATH, types_ts),
+            ("/__flow__/deno.d.ts", include_str!("deno.d.ts").to_string()),
+            ("/__flow__/std/io.ts", include_str!("std_io.ts").to_string()),
+            (
+                "/__flow__/std/buf_writer.ts",
+                include_str!("std_buf_writer.ts").to_string(),
+            ),
+        ];
+        // Map imports of generated types, and of the Deno standard library used by main.ts.
+        let imports = [
+            (format!("flow/{collection}.ts"), TYPES_PATH),
+            (
+                "https://deno.land/std@0.178.0/io/mod.ts".to_string(),
+                "/__flow__/std/io.ts",
+            ),
+            (
+                "https://deno.land/std@0.178.0/io/buf_writer.ts".to_string(),
+                "/__flow__/std/buf_writer.ts",
+            ),
+        ];
+
+        Self {
+            files: files
+                .into_iter()
+                .map(|(path, content)| (path.to_string(), content))
+                .collect(),
+            imports: imports
+                .into_iter()
+                .map(|(specifier, path)| (specifier, path.to_string()))
+                .collect(),
+        }
+    }
+}
+
+/// Type-check the derivation Sources, returning formatted diagnostics
+/// or an empty string if there are none.
+pub fn check(sources: &Sources) -> anyhow::Result<String> {
+    let mut files = sources.files.clone();
+    for (name, content) in typescript::LIBS {
+        files.insert(format!("/lib/{name}"), content.to_string());
+    }
+
+    let (_runtime, context) = new_engine()?;
+
+    context.with(|ctx| {
+        let check: Function = ctx.globals().get("__flow_check")?;
+
+        check
+            .call::<_, String>((
+                serde_json::to_string(&files).unwrap(),
+                serde_json::to_string(&sources.imports).unwrap(),
+                MAIN_PATH,
+            ))
+            .catch(&ctx)
+            .map_err(|err| anyhow::anyhow!("failed to type-check derivation: {err}"))
+    })
+}
+
+/// Run the derivation Sources, reading protocol requests from `input`
+/// and writing responses to `output`, until `input` reaches EOF.
+pub fn run(
+    sources: &Sources,
+    input: impl BufRead + 'static,
+    output: impl Write + 'static,
+) -> anyhow::Result<()> {
+    let (runtime, context) = new_engine()?;
+
+    let input = Rc::new(RefCell::new(input));
+    let output = Rc::new(RefCell::new(output));
+    let failure: Rc<RefCell<Option<String>>> = Default::default();
+
+    context.with(|ctx| {
+        let globals = ctx.globals();
+
+        globals.set(
+            "__flow_read_line",
+            Function::new(ctx.clone(), move || -> rquickjs::Result<Option<String>> {
+                let mut line = String::new();
+                if input.borrow_mut().read_line(&mut line)? == 0 {
+                    return Ok(None); // EOF.
+                }
+                Ok(Some(line.trim_end_matches(['\n', '\r']).to_string()))
+            })?,
+        )?;
+
+        let output_clone = output.clone();
+        globals.set(
+            "__flow_write",
+            Function::new(ctx.clone(), move |data: String| -> rquickjs::Result<()> {
+                Ok(output_clone.borrow_mut().write_all(data.as_bytes())?)
+            })?,
+        )?;
+        globals.set(
+            "__flow_flush",
+            Function::new(ctx.clone(), move || -> rquickjs::Result<()> {
+                Ok(output.borrow_mut().flush()?)
+            })?,
+        )?;
+
+        let failure = failure.clone();
+        globals.set(
+            "__flow_fail",
+            Function::new(ctx.clone(), move |err: String| {
+                failure.borrow_mut().get_or_insert(err);
+            })?,
+        )?;
+
+        let run: Function = globals.get("__flow_run")?;
+        run.call::<_, ()>((
+            serde_json::to_string(&sources.files).unwrap(),
+            serde_json::to_string(&sources.imports).unwrap(),
+            MAIN_PATH,
+        ))
+        .catch(&ctx)
+        .map_err(|err| anyhow::anyhow!("failed to start derivation: {err}"))
+    })?;
+
+    // Input is read synchronously, so the derivation runs to completion
+    // as we drive its pending jobs.
+    while runtime.is_job_pending() {
+        if runtime.execute_pending_job().is_err() {
+            anyhow::bail!("failed to execute a pending job of the derivation");
+        }
+        if failure.borrow().is_some() {
+            break;
+        }
+    }
+
+    if let Some(err) = failure.take() {
+        anyhow::bail!("derivation failed: {err}");
+    }
+    Ok(())
+}
+
+// Build a Runtime and Context having the TypeScript compiler and our host glue.
+fn new_engine() -> anyhow::Result<(Runtime, Context)> {
+    let runtime = Runtime::new()?;
+    // The TypeScript compiler is deeply recursive.
+    runtime.set_max_stack_size(64 << 20);
+    let context = Context::full(&runtime)?;
+
+    context.with(|ctx| {
+        let globals = ctx.globals();
+
+        globals.set(
+            "__flow_log",
+            Function::new(ctx.clone(), |line: String| {
+                tracing::info!(target: "derivation", "{line}");
+            })?,
+        )?;
+
+        ctx.eval::<(), _>(typescript::TYPESCRIPT_JS)
+            .catch(&ctx)
+            .map_err(|err| anyhow::anyhow!("failed to load the TypeScript compiler: {err}"))?;
+        ctx.eval::<(), _>(include_str!("host.js"))
+            .catch(&ctx)
+            .map_err(|err| anyhow::anyhow!("failed to load the embedded host: {err}"))?;
+
+        Ok::<(), anyhow::Error>(())
+    })?;
+
+    Ok((runtime, context))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TYPES_TS: &str = r#"
+export type Document = { id: string, count: number };
+export abstract class IDerivation {
+    constructor(_open: { state: unknown }) { }
+    async flush(): Promise<Document[]> { return []; }
+    async reset() { }
+    startCommit(_startCommit: { runtimeCheckpoint: unknown }): { state?: { updated: unknown, mergePatch: boolean } } {
+        return {};
+    }
+    abstract fromThings(read: { doc: { id: string } }): Document[];
+}
+"#;
+
+    const MODULE_TS: &str = r#"
+import { IDerivation, Document } from 'flow/acmeCo/counts.ts';
+
+export class Derivation extends IDerivation {
+    count = 0;
+
+    fromThings(read: { doc: { id: string } }): Document[] {
+        this.count += 1;
+        return [{ id: read.doc.id, count: this.count }];
+    }
+
+    async flush(): Promise<Document[]> {
+        await Promise.resolve();
+        return [{ id: "flushed", count: this.count }];
+    }
+}
+"#;
+
+    fn sources(module_ts: &str) -> Sources {
+        let main_ts = include_str!("../codegen/main.ts.template").replace(
+            "TRANSFORMS",
+            "    derivation.fromThings.bind(derivation) as Lambda,",
+        );
+        Sources::new(
+            "acmeCo/counts",
+            TYPES_TS.to_string(),
+            module_ts.to_string(),
+            main_ts,
+        )
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(&sources(MODULE_TS)).unwrap(), "");
+
+        let diagnostics = check(&sources(&MODULE_TS.replace("id: read.doc.id", "id: 42"))).unwrap();
+        assert!(diagnostics.contains(MODULE_PATH), "{diagnostics}");
+        assert!(diagnostics.contains("TS2322"), "{diagnostics}");
+    }
+
+    #[test]
+    fn test_run() {
+        #[derive(Clone, Default)]
+        struct Output(Rc<RefCell<Vec<u8>>>);
+
+        impl Write for Output {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let input = [
+            r#"{"open":{"state":{}}}"#,
+            r#"{"read":{"doc":{"id":"a"}}}"#,
+            r#"{"read":{"doc":{"id":"b"}}}"#,
+            r#"{"flush":{}}"#,
+            r#"{"startCommit":{"runtimeCheckpoint":{}}}"#,
+        ]
+        .join("\n");
+
+        let output = Output::default();
+        run(
+            &sources(MODULE_TS),
+            std::io::Cursor::new(input.clone()),
+            output.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output.0.take()).unwrap(),
+            [
+                r#"{"opened":{}}"#,
+                r#"{"published":{"doc":{"id":"a","count":1}}}"#,
+                r#"{"published":{"doc":{"id":"b","count":2}}}"#,
+                r#"{"published":{"doc":{"id":"flushed","count":2}}}"#,
+                r#"{"flushed":{}}"#,
+                r#"{"startedCommit":{}}"#,
+                "",
+            ]
+            .join("\n")
+        );
+
+        // Errors thrown by the derivation fail the run.
+        let err = run(
+            &sources(&MODULE_TS.replace("this.count += 1;", "throw new Error('whoops');")),
+            std::io::Cursor::new(input),
+            Output::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("whoops"), "{err}");
+    }
+}
@@ -0,0 +1,14 @@
+// Embedded replacement of https://deno.land/std/io/mod.ts,
+// which reads lines of input from the host.
+declare function __flow_read_line(): string | null;
+
+// deno-lint-ignore require-await
+export async function* readLines(_reader: unknown): AsyncIterableIterator<string> {
+    while (true) {
+        const line = __flow_read_line();
+        if (line === null) {
+            return;
+        }
+        yield line;
+    }
+}
@@ -1,10 +1,53 @@
 use anyhow::Context;
 use proto_flow::{derive, flow};
 use serde_json::json;
-use std::io::{BufRead, Write};
+use std::io::{BufRead, Read, Write};
 use std::{collections::BTreeMap, process::Stdio};
 
 mod codegen;
+mod embedded;
+
+/// Engine which type-checks and runs TypeScript derivations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Engine {
+    /// Run derivations with a `deno` subprocess.
+    Deno,
+    /// Run derivations in-process, using an embedded JavaScript engine.
+    Embedded,
+}
+
+impl Engine {
+    /// Select an Engine from $FLOW_TYPESCRIPT_ENGINE, which may be "deno" or "embedded".
+    /// If it's not set, then Deno is used if it's installed and otherwise the embedded engine.
+    pub fn from_env() -> anyhow::Result<Self> {
+        match std::env::var("FLOW_TYPESCRIPT_ENGINE").as_deref() {
+            Ok("deno") => Ok(Self::Deno),
+            Ok("embedded") => Ok(Self::Embedded),
+            Ok(other) => anyhow::bail!(
+                "invalid FLOW_TYPESCRIPT_ENGINE {other:?} (expected \"deno\" or \"embedded\")"
+            ),
+            Err(_) => {
+                let deno = std::process::Command::new("deno")
+                    .arg("--version")
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .status();
+
+                match deno {
+                    Ok(status) if status.success() => Ok(Self::Deno),
+                    Ok(status) => {
+                        tracing::warn!(%status, "`deno --version` failed; falling back to the embedded TypeScript engine");
+                        Ok(Self::Embedded)
+                    }
+                    Err(err) => {
+                        tracing::info!(%err, "deno is not available; falling back to the embedded TypeScript engine");
+                        Ok(Self::Embedded)
+                    }
+                }
+            }
+        }
+    }
+}
 
 pub fn run() -> anyhow::Result<()> {
     let stdin = std::io::stdin();
@@ -74,20 +117,27 @@ pub fn run() -> anyhow::Result<()> {
         })
         .collect::<Vec<_>>();
 
+    let types_ts = codegen::types_ts(&collection, &transforms);
+    let main_ts = codegen::main_ts(&transforms);
+
+    if Engine::from_env()? == Engine::Embedded {
+        let sources = embedded::Sources::new(&collection.name, types_ts, config.module, main_ts);
+
+        // Forward `open` and the remainder of stdin to the derivation.
+        let input = std::io::Cursor::new(format!("{}\n", serde_json::to_string(&open)?));
+        return embedded::run(&sources, input.chain(bin), stdout);
+    }
+
     let temp_dir = tempfile::TempDir::new().unwrap();
     let temp_dir = temp_dir.path();
 
-    std::fs::write(
-        temp_dir.join(TYPES_NAME),
-        codegen::types_ts(&collection, &transforms),
+    write_deno_dir(
+        temp_dir,
+        &collection.name,
+        &types_ts,
+        &config.module,
+        &main_ts,
     )?;
-    std::fs::write(
-        temp_dir.join("deno.json"),
-        json!({"imports": {format!("flow/{}.ts", collection.name): format!("./{TYPES_NAME}")}})
-            .to_string(),
-    )?;
-    std::fs::write(temp_dir.join(MODULE_NAME), config.module)?;
-    std::fs::write(temp_dir.join(MAIN_NAME), codegen::main_ts(&transforms))?;
 
     let mut child = std::process::Command::new("deno")
         .stdin(Stdio::piped())
@@ -202,31 +252,59 @@ fn validate(validate: derive::request::Validate) -> anyhow::Result<derive::respo
         });
     }
 
-    let temp_dir = tempfile::TempDir::new().unwrap();
-    let temp_dir = temp_dir.path();
+    let main_ts = codegen::main_ts(&transforms);
 
-    std::fs::write(temp_dir.join(TYPES_NAME), generated_files[0].1.as_bytes())?;
-    std::fs::write(
-        temp_dir.join("deno.json"),
-        json!({"imports": {format!("flow/{}.ts", collection.name): format!("./{TYPES_NAME}")}})
-            .to_string(),
-    )?;
-    std::fs::write(temp_dir.join(MODULE_NAME), config.module)?;
-    std::fs::write(temp_dir.join(MAIN_NAME), codegen::main_ts(&transforms))?;
+    let diagnostics = match Engine::from_env()? {
+        Engine::Deno => {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            let temp_dir = temp_dir.path();
 
-    let output = std::process::Command::new("deno")
-        .current_dir(temp_dir)
-        .args(["check", MAIN_NAME])
-        .output()
-        .expect("The Deno runtime is a prerequisite for TypeScript but could not be found. Please install Deno from https://deno.com");
-
-    if !output.status.success() {
-        anyhow::bail!(rewrite_deno_stderr(
-            output.stderr,
-            temp_dir,
-            &types_url,
-            &import_map
-        ));
+            write_deno_dir(
+                temp_dir,
+                &collection.name,
+                &types_content,
+                &config.module,
+                &main_ts,
+            )?;
+
+            let output = std::process::Command::new("deno")
+                .current_dir(temp_dir)
+                .args(["check", MAIN_NAME])
+                .output()
+                .context("failed to run `deno check`. Install Deno from https://deno.com, or set FLOW_TYPESCRIPT_ENGINE=embedded")?;
+
+            if output.status.success() {
+                String::new()
+            } else {
+                rewrite_diagnostics(
+                    String::from_utf8_lossy(&output.stderr).to_string(),
+                    url::Url::from_file_path(temp_dir.join(MODULE_NAME))
+                        .unwrap()
+                        .as_str(),
+                    url::Url::from_file_path(temp_dir.join(TYPES_NAME))
+                        .unwrap()
+                        .as_str(),
+                    &types_url,
+                    &import_map,
+                )
+            }
+        }
+        Engine::Embedded => {
+            let sources =
+                embedded::Sources::new(&collection.name, types_content, config.module, main_ts);
+
+            rewrite_diagnostics(
+                embedded::check(&sources)?,
+                embedded::MODULE_PATH,
+                embedded::TYPES_PATH,
+                &types_url,
+                &import_map,
+            )
+        }
+    };
+
+    if !diagnostics.is_empty() {
+        anyhow::bail!(diagnostics);
     }
 
     Ok(derive::response::Validated {
@@ -235,33 +313,41 @@ fn validate(validate: derive::request::Validate) -> anyhow::Result<derive::respo
     })
 }
 
-fn rewrite_deno_stderr(
-    stderr: Vec<u8>,
+// Write the files of a derivation into `temp_dir`, for use by `deno`.
+fn write_deno_dir(
     temp_dir: &std::path::Path,
+    collection: &str,
+    types_ts: &str,
+    module_ts: &str,
+    main_ts: &str,
+) -> anyhow::Result<()> {
+    std::fs::write(temp_dir.join(TYPES_NAME), types_ts)?;
+    std::fs::write(
+        temp_dir.join(DENO_NAME),
+        json!({"imports": {format!("flow/{collection}.ts"): format!("./{TYPES_NAME}")}})
+            .to_string(),
+    )?;
+    std::fs::write(temp_dir.join(MODULE_NAME), module_ts)?;
+    std::fs::write(temp_dir.join(MAIN_NAME), main_ts)?;
+
+    Ok(())
+}
+
+// Re-write paths of the module and generated types within `diagnostics`
+// to their user-facing URLs.
+fn rewrite_diagnostics(
+    mut diagnostics: String,
+    module_path: &str,
+    types_path: &str,
     types_url: &str,
     import_map: &BTreeMap<String, String>,
 ) -> String {
-    tracing::info!(?import_map, ?types_url, "re-writing deno stderr");
-
-    let mut stderr = String::from_utf8(stderr).unwrap();
+    tracing::info!(?import_map, ?types_url, "re-writing diagnostics");
 
     if let Some(import) = import_map.get("/using/typescript/module") {
-        stderr = stderr.replace(
-            url::Url::from_file_path(temp_dir.join(MODULE_NAME))
-                .unwrap()
-                .as_str(),
-            import,
-        );
+        diagnostics = diagnostics.replace(module_path, import);
     }
-
-    stderr = stderr.replace(
-        url::Url::from_file_path(temp_dir.join(TYPES_NAME))
-            .unwrap()
-            .as_str(),
-        types_url,
-    );
-
-    stderr
+    diagnostics.replace(types_path, types_url)
 }
 
 const DENO_NAME: &str = "deno.json";
@@ -0,0 +1,33 @@
+#!/bin/bash
+# Vendor the pinned TypeScript compiler and its lib files into ./typescript,
+# from which they're embedded by build.rs. Re-run after changing VERSION.
+set -o errexit
+set -o pipefail
+set -o nounset
+
+VERSION=5.2.2
+# Subresource integrity of the VERSION tarball, as published by the npm
+# registry (`npm view typescript@5.2.2 dist.integrity`).
+INTEGRITY=sha512-mI4WrpHsbCIcwT9cF4FZvr80QUeKvsUsUvKDoR+X/7XHQH98xYD8YHZg7ANtz2GtZt/CBq2QJ0thkGJMHfqc1w==
+
+cd "$(dirname "$0")"
+TMP="$(mktemp -d)"
+trap 'rm -rf "${TMP}"' EXIT
+
+curl --fail --silent --show-error --location \
+    --output "${TMP}/typescript.tgz" \
+    "https://registry.npmjs.org/typescript/-/typescript-${VERSION}.tgz"
+
+ACTUAL="sha512-$(openssl dgst -sha512 -binary "${TMP}/typescript.tgz" | openssl base64 -A)"
+if [[ "${ACTUAL}" != "${INTEGRITY}" ]]; then
+    echo "typescript-${VERSION}.tgz has integrity ${ACTUAL} but expected ${INTEGRITY}" >&2
+    exit 1
+fi
+
+tar -xzf "${TMP}/typescript.tgz" -C "${TMP}"
+
+rm -rf typescript
+mkdir -p typescript/lib
+cp "${TMP}/package/LICENSE.txt" "${TMP}/package/ThirdPartyNoticeText.txt" typescript/
+cp "${TMP}/package/lib/typescript.js" "${TMP}"/package/lib/lib.*.d.ts typescript/lib/
+echo "${VERSION}" > typescript/VERSION
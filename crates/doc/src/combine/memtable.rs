@@ -278,6 +278,7 @@ impl MemTable {
     /// If the MemTable is empty this is a no-op.
     /// `chunk_target_size` is the target size of a serialized chunk of the spilled segment.
     /// In practice, values like 256KB are reasonable.
+    /// Chunks are compressed and encrypted per the SpillOptions of the MemTable's Spec.
     pub fn spill<F: io::Read + io::Write + io::Seek>(
         self,
         writer: &mut SpillWriter<F>,
@@ -315,7 +316,7 @@ impl MemTable {
             }
         }
 
-        let bytes = writer.write_segment(&sorted, chunk_target_size)?;
+        let bytes = writer.write_segment(&sorted, chunk_target_size, &spec.spill)?;
         tracing::debug!(
             %bytes,
             entries=%sorted.len(),
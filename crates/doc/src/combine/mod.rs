@@ -20,10 +20,12 @@ pub enum Error {
 
 /// Specification of how Combine operations are to be done
 /// over one or more bindings.
+#[derive(Clone)]
 pub struct Spec {
     is_full: Vec<bool>,
     keys: Arc<[Box<[Extractor]>]>,
     validators: Vec<(Validator, Option<url::Url>)>,
+    spill: SpillOptions,
 }
 
 impl Spec {
@@ -47,6 +49,7 @@ impl Spec {
             is_full: vec![full],
             keys: vec![key].into(),
             validators: vec![(validator, schema)],
+            spill: SpillOptions::default(),
         }
     }
 
@@ -70,8 +73,23 @@ impl Spec {
             is_full: full,
             keys: keys.into(),
             validators,
+            spill: SpillOptions::default(),
         }
     }
+
+    /// Use the given SpillCodec to compress chunks of spill files.
+    pub fn with_spill_codec(mut self, codec: SpillCodec) -> Self {
+        self.spill.codec = codec;
+        self
+    }
+
+    /// Use the given SpillCipher to encrypt chunks of spill files.
+    /// Typically this is `SpillCipher::ephemeral_aes256gcm()`, so that spilled
+    /// documents are encrypted at rest under a key known only to this Spec.
+    pub fn with_spill_cipher(mut self, cipher: SpillCipher) -> Self {
+        self.spill.cipher = cipher;
+        self
+    }
 }
 
 /// Meta is metadata about an entry: its binding index and flags.
@@ -89,7 +107,10 @@ pub mod memtable;
 pub use memtable::{MemDrainer, MemTable};
 
 pub mod spill;
-pub use spill::{SpillDrainer, SpillWriter};
+pub use spill::{SpillCipher, SpillCodec, SpillDrainer, SpillOptions, SpillWriter};
+
+pub mod parallel;
+pub use parallel::ParallelDrainer;
 
 /// Accumulator is a MemTable paired with a File-backed SpillWriter.
 /// As the caller utilizes the MemTable the Accumulator will transparently
@@ -160,9 +181,39 @@ impl Accumulator {
             })
         }
     }
+
+    /// Map this combine Accumulator into a Drainer which, if a spill occurred,
+    /// merges and reduces spilled segments using up to `threads` threads.
+    /// Documents are drained in the same order as with `into_drainer()`.
+    pub fn into_parallel_drainer(self, threads: usize) -> Result<Drainer, Error> {
+        let Self {
+            memtable: Some(memtable),
+            mut spill,
+        } = self
+        else {
+            unreachable!("memtable must be Some");
+        };
+
+        if spill.segment_ranges().is_empty() {
+            let (spill, _ranges) = spill.into_parts();
+
+            Ok(Drainer::Mem {
+                spill,
+                drainer: memtable.try_into_drainer()?,
+            })
+        } else {
+            // Spill the final MemTable segment.
+            let spec = memtable.spill(&mut spill, CHUNK_TARGET_SIZE)?;
+            let (spill, ranges) = spill.into_parts();
+
+            Ok(Drainer::Parallel {
+                drainer: ParallelDrainer::new(spec, spill, &ranges, threads)?,
+            })
+        }
+    }
 }
 
-/// Drainer drains from either a wrapped MemTable or SpillDrainer.
+/// Drainer drains from either a wrapped MemTable, SpillDrainer, or ParallelDrainer.
 pub enum Drainer {
     Mem {
         spill: std::fs::File,
@@ -171,6 +222,9 @@ pub enum Drainer {
     Spill {
         drainer: SpillDrainer<std::fs::File>,
     },
+    Parallel {
+        drainer: ParallelDrainer,
+    },
 }
 
 /// DrainedDoc is a document drained from a Drainer.
@@ -186,6 +240,7 @@ impl Iterator for Drainer {
         match self {
             Self::Mem { drainer, .. } => drainer.next(),
             Self::Spill { drainer } => drainer.next(),
+            Self::Parallel { drainer } => drainer.next(),
         }
     }
 }
@@ -196,6 +251,7 @@ impl Drainer {
         match self {
             Self::Mem { drainer, .. } => drainer.drain_next(),
             Self::Spill { drainer } => drainer.drain_next(),
+            Self::Parallel { drainer } => drainer.drain_next(),
         }
     }
 
@@ -213,6 +269,14 @@ impl Drainer {
                 spill.seek(io::SeekFrom::Start(0))?; // Reset to start.
                 spill.set_len(0)?; // Release allocated size to OS.
 
+                Ok(Accumulator::new(spec, spill)?)
+            }
+            Drainer::Parallel { drainer } => {
+                let (spec, mut spill) = drainer.into_parts();
+
+                spill.seek(io::SeekFrom::Start(0))?; // Reset to start.
+                spill.set_len(0)?; // Release allocated size to OS.
+
                 Ok(Accumulator::new(spec, spill)?)
             }
         }
@@ -357,6 +421,9 @@ fn _assert_accumulator_is_send(t: Accumulator) {
 fn _assert_spill_drainer_is_send(t: SpillDrainer<std::fs::File>) {
     _assert_send(t)
 }
+fn _assert_parallel_drainer_is_send(t: ParallelDrainer) {
+    _assert_send(t)
+}
 fn _assert_mem_drainer_is_send(t: MemDrainer) {
     _assert_send(t)
 }
@@ -0,0 +1,600 @@
+use super::spill::{chunk_fences, SpillDrainer};
+use super::{DrainedDoc, Error, Spec};
+use crate::{AsNode, Extractor, SerPolicy};
+use std::ops::Range;
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::{cmp, io};
+
+/// ParallelDrainer drains documents across all segments of a spill file,
+/// using multiple threads. The spilled key space is divided into ordered
+/// partitions which are merged and reduced concurrently, and drained entries
+/// are yielded in the same ascending (binding, key) order as a SpillDrainer.
+pub struct ParallelDrainer {
+    current: usize, // Index of the partition being read.
+    receivers: Vec<mpsc::Receiver<Drained>>,
+    shared: Arc<Shared>,
+    // Spec and spill file, which are taken by into_parts().
+    parts: Option<(Spec, Arc<std::fs::File>)>,
+    workers: Vec<std::thread::JoinHandle<()>>,
+}
+
+// Fence is an owned (binding, key) at which the spilled key space is partitioned.
+// Its key is held as a document having only the key locations of the
+// binding, so that it's ordered by Extractor::compare_key like spilled entries.
+#[derive(Debug, Clone)]
+pub(super) struct Fence {
+    binding: usize,
+    key: serde_json::Value,
+}
+
+// Partition is a contiguous range of the spilled key space.
+struct Partition {
+    lower: Option<Fence>,    // Inclusive lower bound.
+    upper: Option<Fence>,    // Exclusive upper bound.
+    ranges: Vec<Range<u64>>, // Segment ranges, each starting at the chunk which may hold `lower`.
+}
+
+// Shared state of a ParallelDrainer and its workers.
+struct Shared {
+    state: Mutex<State>,
+    wake: Condvar,
+}
+
+struct State {
+    cancelled: bool, // Workers should exit.
+    consumed: usize, // Index of the partition being read.
+    next: usize,     // Index of the next partition to be claimed by a worker.
+    senders: Vec<Option<mpsc::SyncSender<Drained>>>,
+}
+
+// Drained is sent from a worker for each drained document of a partition,
+// followed by Ok(None) when the partition is complete.
+type Drained = Result<Option<DrainedDoc>, Error>;
+
+impl ParallelDrainer {
+    /// Build a new ParallelDrainer which drains the given segment ranges
+    /// previously written to the spill file, using up to `threads` threads.
+    pub fn new(
+        spec: Spec,
+        spill: std::fs::File,
+        ranges: &[Range<u64>],
+        threads: usize,
+    ) -> Result<Self, io::Error> {
+        let threads = threads.max(1);
+        let spill = Arc::new(spill);
+
+        let partitions = plan_partitions(&spec, &spill, ranges, threads)?;
+        let threads = threads.min(partitions.len());
+        let partitions: Arc<[Partition]> = partitions.into();
+
+        let (senders, receivers): (Vec<_>, Vec<_>) = partitions
+            .iter()
+            .map(|_| {
+                let (tx, rx) = mpsc::sync_channel(DRAINED_CHANNEL_CAPACITY);
+                (Some(tx), rx)
+            })
+            .unzip();
+
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                cancelled: false,
+                consumed: 0,
+                next: 0,
+                senders,
+            }),
+            wake: Condvar::new(),
+        });
+
+        let workers = (0..threads)
+            .map(|index| {
+                let (spec, spill) = (spec.clone(), spill.clone());
+                let (partitions, shared) = (partitions.clone(), shared.clone());
+                let inflight = threads * INFLIGHT_PARTITIONS_PER_THREAD;
+
+                std::thread::Builder::new()
+                    .name(format!("combine-drain-{index}"))
+                    .spawn(move || drain_partitions(spec, spill, partitions, shared, inflight))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        tracing::debug!(
+            partitions = receivers.len(),
+            segments = ranges.len(),
+            %threads,
+            "began parallel drain of spill file"
+        );
+
+        Ok(Self {
+            current: 0,
+            receivers,
+            shared,
+            parts: Some((spec, spill)),
+            workers,
+        })
+    }
+
+    pub fn drain_next(&mut self) -> Result<Option<DrainedDoc>, Error> {
+        while let Some(rx) = self.receivers.get(self.current) {
+            match rx.recv() {
+                Ok(Ok(Some(doc))) => return Ok(Some(doc)),
+                Ok(Ok(None)) => {
+                    // Partition is complete. Step to the next one, which may
+                    // allow a waiting worker to claim a further partition.
+                    self.current += 1;
+                    self.shared.state.lock().unwrap().consumed = self.current;
+                    self.shared.wake.notify_all();
+                }
+                Ok(Err(err)) => {
+                    self.current = self.receivers.len();
+                    return Err(err);
+                }
+                Err(mpsc::RecvError) => {
+                    self.current = self.receivers.len();
+                    return Err(Error::SpillIO(io::Error::new(
+                        io::ErrorKind::Other,
+                        "parallel drain worker exited without completing its partition",
+                    )));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Stop all workers and destructure the ParallelDrainer into its Spec and spill file.
+    /// Any un-drained documents are dropped.
+    pub fn into_parts(mut self) -> (Spec, std::fs::File) {
+        // Dropping receivers fails the sends of workers blocked on a full channel.
+        self.receivers.clear();
+        self.cancel();
+
+        for worker in std::mem::take(&mut self.workers) {
+            if let Err(panic) = worker.join() {
+                std::panic::resume_unwind(panic);
+            }
+        }
+        let (spec, spill) = self.parts.take().unwrap();
+        let spill = Arc::try_unwrap(spill).expect("workers have exited");
+
+        (spec, spill)
+    }
+
+    // Signal workers to exit, without waiting for them to do so.
+    fn cancel(&self) {
+        self.shared.state.lock().unwrap().cancelled = true;
+        self.shared.wake.notify_all();
+    }
+}
+
+impl Drop for ParallelDrainer {
+    // Workers which are waiting to claim a partition exit upon being woken.
+    // Workers which are draining a partition exit upon their next send,
+    // which fails once `receivers` are dropped.
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+impl Iterator for ParallelDrainer {
+    type Item = Result<DrainedDoc, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain_next().transpose()
+    }
+}
+
+impl Fence {
+    // Build a Fence from the key of a document of the given binding.
+    pub(super) fn new<N: AsNode>(keys: &[Box<[Extractor]>], binding: usize, doc: &N) -> Self {
+        let mut key = serde_json::Value::Null;
+
+        // Copy each key location which exists in `doc`. Locations which don't
+        // exist are also absent from `key`, and are extracted as their default.
+        for ex in keys[binding].iter() {
+            let Some(node) = ex.ptr().query(doc) else {
+                continue;
+            };
+            if let Some(value) = ex.ptr().create_value(&mut key) {
+                *value = serde_json::to_value(SerPolicy::default().on(node)).unwrap();
+            }
+        }
+
+        Self { binding, key }
+    }
+
+    // Compare this Fence with the (binding, key) of a document.
+    pub(super) fn cmp_doc<N: AsNode>(
+        &self,
+        keys: &[Box<[Extractor]>],
+        binding: usize,
+        doc: &N,
+    ) -> cmp::Ordering {
+        self.binding
+            .cmp(&binding)
+            .then_with(|| Extractor::compare_key(&keys[binding], &self.key, doc))
+    }
+
+    fn cmp(&self, keys: &[Box<[Extractor]>], other: &Self) -> cmp::Ordering {
+        self.binding
+            .cmp(&other.binding)
+            .then_with(|| Extractor::compare_key(&keys[self.binding], &self.key, &other.key))
+    }
+}
+
+// Divide the key space of the spill file into ordered Partitions,
+// using the Fences of every spilled chunk as a sample of its distribution.
+fn plan_partitions(
+    spec: &Spec,
+    spill: &Arc<std::fs::File>,
+    ranges: &[Range<u64>],
+    threads: usize,
+) -> Result<Vec<Partition>, io::Error> {
+    // Read chunk Fences of each segment, spreading segments across threads.
+    let per_thread = (ranges.len() + threads - 1) / threads;
+
+    let fences: Vec<Vec<(u64, Fence)>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = ranges
+            .chunks(per_thread.max(1))
+            .map(|ranges| {
+                scope.spawn(|| {
+                    let mut r = SpillReader::new(spill.clone());
+
+                    ranges
+                        .iter()
+                        .map(|range| {
+                            chunk_fences(
+                                spec.keys.clone(),
+                                spec.spill.clone(),
+                                &mut r,
+                                range.clone(),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Result<Vec<_>, _>>()
+            .map(|groups| groups.into_iter().flatten().collect())
+    })?;
+
+    let mut sorted: Vec<&Fence> = fences.iter().flatten().map(|(_, fence)| fence).collect();
+    sorted.sort_by(|l, r| l.cmp(&spec.keys, r));
+
+    let chunks = sorted.len();
+    let count = (chunks / CHUNKS_PER_PARTITION)
+        .max(threads * MIN_PARTITIONS_PER_THREAD)
+        .min(chunks)
+        .max(1);
+
+    // Select partition bounds at evenly-spaced quantiles of chunk Fences.
+    let mut bounds: Vec<&Fence> = Vec::new();
+    for index in 1..count {
+        let fence = sorted[index * chunks / count];
+
+        if fence.cmp(&spec.keys, sorted[0]).is_gt()
+            && bounds
+                .last()
+                .map_or(true, |last| last.cmp(&spec.keys, fence).is_lt())
+        {
+            bounds.push(fence);
+        }
+    }
+
+    let mut partitions = Vec::with_capacity(bounds.len() + 1);
+    for index in 0..=bounds.len() {
+        let lower = index.checked_sub(1).map(|i| bounds[i]);
+        let upper = bounds.get(index).copied();
+        let mut part_ranges = Vec::new();
+
+        for (range, fences) in ranges.iter().zip(fences.iter()) {
+            // Begin at the last chunk having a Fence that's less than `lower`,
+            // as it may contain entries which are equal to `lower`.
+            let begin = match lower {
+                None => 0,
+                Some(lower) => fences
+                    .partition_point(|(_, fence)| fence.cmp(&spec.keys, lower).is_lt())
+                    .saturating_sub(1),
+            };
+            let Some((offset, fence)) = fences.get(begin) else {
+                continue; // Segment is empty.
+            };
+
+            // If the beginning chunk is already beyond `upper`, then so is the remainder.
+            if matches!(upper, Some(upper) if fence.cmp(&spec.keys, upper).is_ge()) {
+                continue;
+            }
+            part_ranges.push(*offset..range.end);
+        }
+
+        partitions.push(Partition {
+            lower: lower.cloned(),
+            upper: upper.cloned(),
+            ranges: part_ranges,
+        });
+    }
+
+    Ok(partitions)
+}
+
+// Drain Partitions of the spill file as they're claimed by this worker,
+// sending drained documents of each Partition to its channel.
+fn drain_partitions(
+    mut spec: Spec,
+    spill: Arc<std::fs::File>,
+    partitions: Arc<[Partition]>,
+    shared: Arc<Shared>,
+    inflight: usize,
+) {
+    loop {
+        // Claim the next Partition, waiting while we're too far ahead of the reader.
+        let (index, tx) = {
+            let mut state = shared.state.lock().unwrap();
+            loop {
+                if state.cancelled || state.next == partitions.len() {
+                    return;
+                } else if state.next < state.consumed + inflight {
+                    break;
+                }
+                state = shared.wake.wait(state).unwrap();
+            }
+            let index = state.next;
+            state.next += 1;
+            (index, state.senders[index].take().unwrap())
+        };
+        let Partition {
+            lower,
+            upper,
+            ranges,
+        } = &partitions[index];
+
+        let mut drainer = match SpillDrainer::new_partition(
+            spec,
+            SpillReader::new(spill.clone()),
+            ranges,
+            lower.as_ref(),
+            upper.clone(),
+        ) {
+            Ok(drainer) => drainer,
+            Err(err) => {
+                _ = tx.send(Err(err.into()));
+                return cancel(&shared);
+            }
+        };
+
+        let ok = loop {
+            let next = drainer.drain_next();
+            let (done, failed) = (matches!(next, Ok(None)), next.is_err());
+
+            if tx.send(next).is_err() {
+                break false; // Reader has gone away.
+            } else if done || failed {
+                break !failed;
+            }
+        };
+
+        if !ok {
+            return cancel(&shared);
+        }
+        (spec, _) = drainer.into_parts();
+    }
+}
+
+// Cancel a ParallelDrainer after a failure, waking all workers to exit.
+// Unclaimed partitions are dropped, which is observed if they're read.
+fn cancel(shared: &Shared) {
+    let mut state = shared.state.lock().unwrap();
+    state.cancelled = true;
+    state.senders.clear();
+    shared.wake.notify_all();
+}
+
+// SpillReader is an independent reader of a shared spill File.
+// It uses positional reads, so that many SpillReaders may read concurrently.
+struct SpillReader {
+    file: Arc<std::fs::File>,
+    offset: u64,
+}
+
+impl SpillReader {
+    fn new(file: Arc<std::fs::File>) -> Self {
+        Self { file, offset: 0 }
+    }
+}
+
+impl io::Read for SpillReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        #[cfg(unix)]
+        let n = std::os::unix::fs::FileExt::read_at(&*self.file, buf, self.offset)?;
+        #[cfg(windows)]
+        let n = std::os::windows::fs::FileExt::seek_read(&*self.file, buf, self.offset)?;
+
+        self.offset += n as u64;
+        Ok(n)
+    }
+}
+
+impl io::Seek for SpillReader {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let (base, delta) = match pos {
+            io::SeekFrom::Start(offset) => (offset, 0),
+            io::SeekFrom::Current(delta) => (self.offset, delta),
+            io::SeekFrom::End(delta) => (self.file.metadata()?.len(), delta),
+        };
+        let Some(offset) = (base as i64).checked_add(delta).filter(|o| *o >= 0) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ));
+        };
+        self.offset = offset as u64;
+        Ok(self.offset)
+    }
+}
+
+// Target number of spilled chunks (~16MB of raw documents) within each partition.
+// Smaller partitions bound the memory of partitions drained ahead of the reader.
+const CHUNKS_PER_PARTITION: usize = 64;
+// Minimum number of partitions per thread, so that work is balanced across threads.
+const MIN_PARTITIONS_PER_THREAD: usize = 4;
+// Maximum number of partitions per thread which may be drained ahead of the reader.
+const INFLIGHT_PARTITIONS_PER_THREAD: usize = 2;
+// Maximum number of drained documents of a partition which may be buffered
+// ahead of the reader, after which its worker blocks until they're read.
+const DRAINED_CHANNEL_CAPACITY: usize = 1024;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::combine::{MemTable, SpillWriter};
+    use crate::{validation::build_schema, HeapNode, Validator};
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_parallel_drain_matches_sequential() {
+        for is_full in [true, false] {
+            let (spill, ranges, spec) = spill_fixture(is_full);
+            let expect = drain_all(SpillDrainer::new(spec.clone(), spill, &ranges).unwrap());
+
+            for threads in [1, 2, 3, 8] {
+                let (spill, ranges, spec) = spill_fixture(is_full);
+                let drainer = ParallelDrainer::new(spec, spill, &ranges, threads).unwrap();
+
+                // Many partitions were planned from the fixture's many small chunks.
+                assert!(drainer.receivers.len() >= 4 * threads.min(2));
+
+                let actual = drain_all(drainer);
+                assert_eq!(actual.len(), expect.len());
+                assert!(actual == expect, "is_full {is_full} threads {threads}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_drain_into_parts_stops_workers() {
+        let (spill, ranges, spec) = spill_fixture(true);
+        let mut drainer = ParallelDrainer::new(spec, spill, &ranges, 4).unwrap();
+
+        // Drain a handful of documents, and then stop.
+        for _ in 0..10 {
+            drainer.drain_next().unwrap().unwrap();
+        }
+        let (_spec, spill) = drainer.into_parts();
+        assert!(spill.metadata().unwrap().len() != 0);
+    }
+
+    #[test]
+    fn test_parallel_drain_drop_stops_workers() {
+        let (spill, ranges, spec) = spill_fixture(true);
+        let mut drainer = ParallelDrainer::new(spec, spill, &ranges, 4).unwrap();
+        drainer.drain_next().unwrap().unwrap();
+
+        let shared = drainer.shared.clone();
+        std::mem::drop(drainer);
+
+        // Workers exit and release their references to shared state.
+        let start = std::time::Instant::now();
+        while Arc::strong_count(&shared) != 1 {
+            assert!(start.elapsed() < std::time::Duration::from_secs(10));
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        assert!(shared.state.lock().unwrap().cancelled);
+    }
+
+    #[test]
+    fn test_fence_ordering() {
+        let keys: Arc<[Box<[Extractor]>]> = vec![
+            vec![Extractor::new("/k", &SerPolicy::default())].into(),
+            vec![
+                Extractor::new("/a", &SerPolicy::default()),
+                Extractor::with_default("/b", &SerPolicy::default(), json!("def")),
+            ]
+            .into(),
+        ]
+        .into();
+
+        let fence = Fence::new(&keys, 1, &json!({"a": 10, "z": true}));
+        assert_eq!(fence.key, json!({"a": 10}));
+
+        for (binding, doc, expect) in [
+            (0, json!({"k": 99}), cmp::Ordering::Greater),
+            (1, json!({"a": 9.5, "b": "zzz"}), cmp::Ordering::Greater),
+            (1, json!({"a": 10, "b": "abc"}), cmp::Ordering::Greater),
+            (1, json!({"a": 10.0}), cmp::Ordering::Equal),
+            (1, json!({"a": 10, "b": "def"}), cmp::Ordering::Equal),
+            (1, json!({"a": 10, "b": "xyz"}), cmp::Ordering::Less),
+            (1, json!({"a": 11}), cmp::Ordering::Less),
+        ] {
+            assert_eq!(fence.cmp_doc(&keys, binding, &doc), expect, "{doc}");
+            assert_eq!(
+                fence.cmp(&keys, &Fence::new(&keys, binding, &doc)),
+                expect,
+                "{doc}"
+            );
+        }
+    }
+
+    // Build a spill file having many segments and many small chunks.
+    fn spill_fixture(is_full: bool) -> (std::fs::File, Vec<Range<u64>>, Spec) {
+        let spec = Spec::with_bindings(
+            std::iter::repeat_with(|| {
+                let schema = build_schema(
+                    url::Url::parse("http://example/schema").unwrap(),
+                    &json!({
+                        "properties": {
+                            "key": { "type": "integer" },
+                            "v": {
+                                "type": "array",
+                                "reduce": { "strategy": "append" }
+                            }
+                        },
+                        "reduce": { "strategy": "merge" }
+                    }),
+                )
+                .unwrap();
+
+                (
+                    is_full,
+                    vec![Extractor::new("/key", &SerPolicy::default())],
+                    None,
+                    Validator::new(schema).unwrap(),
+                )
+            })
+            .take(2),
+        );
+
+        let mut spill = SpillWriter::new(tempfile::tempfile().unwrap()).unwrap();
+        let mut memtable = MemTable::new(spec);
+
+        for segment in 0..7u64 {
+            for n in 0..300u64 {
+                let key = (n * 7919 + segment * 104729) % 500;
+                let doc = json!({"key": key, "v": [format!("{segment}-{n}")]});
+                let doc = HeapNode::from_node(&doc, memtable.alloc());
+                memtable.add((key % 2) as u32, doc, n % 50 == 0).unwrap();
+            }
+            let spec = memtable.spill(&mut spill, 256).unwrap();
+            memtable = MemTable::new(spec);
+        }
+        let spec = memtable.try_into_drainer().unwrap().into_spec();
+        let (spill, ranges) = spill.into_parts();
+
+        (spill, ranges, spec)
+    }
+
+    fn drain_all<I: Iterator<Item = Result<DrainedDoc, Error>>>(
+        it: I,
+    ) -> Vec<(usize, Value, bool)> {
+        it.map(|doc| {
+            let doc = doc.unwrap();
+            (
+                doc.meta.binding(),
+                serde_json::to_value(SerPolicy::default().on_owned(&doc.root)).unwrap(),
+                doc.meta.front(),
+            )
+        })
+        .collect()
+    }
+}
@@ -1,3 +1,4 @@
+use super::parallel::Fence;
 use super::{bump_mem_used, reduce, DrainedDoc, Error, HeapEntry, Meta, Spec, BUMP_THRESHOLD};
 use crate::owned::OwnedArchivedNode;
 use crate::{Extractor, HeapNode, LazyNode, OwnedHeapNode, OwnedNode};
@@ -34,12 +35,13 @@ impl<F: io::Read + io::Write + io::Seek> SpillWriter<F> {
     /// Write a segment to the spill file. The segment array documents must
     /// already be in sorted key order. Documents will be grouped into chunks
     /// of the given size, and are then written in-order to the spill file.
-    /// Each chunks is compressed using LZ4.
+    /// Each chunk is compressed and (optionally) encrypted per `options`.
     /// The written size of the segment is returned.
     pub fn write_segment(
         &mut self,
         entries: &[HeapEntry<'_>],
         chunk_target_size: usize,
+        options: &SpillOptions,
     ) -> Result<u64, io::Error> {
         if entries.is_empty() {
             return Ok(0);
@@ -48,7 +50,7 @@ impl<F: io::Read + io::Write + io::Seek> SpillWriter<F> {
         let begin = self.spill.seek(io::SeekFrom::Current(0))?;
 
         let mut last_chunk_index = 0;
-        let mut lz4_buf = Vec::new();
+        let mut chunk_buf = Vec::new();
         let mut raw_buf = rkyv::AlignedVec::with_capacity(2 * chunk_target_size);
         let mut rkyv_scratch = Default::default();
 
@@ -85,40 +87,19 @@ impl<F: io::Read + io::Write + io::Seek> SpillWriter<F> {
                 continue;
             }
             // We have a complete chunk. Next we compress and write it to the spill file.
-
-            // Prepare `lz4_buf` to hold the compressed result, reserving leading bytes for a chunk header.
-            lz4_buf.reserve(8 + lz4::block::compress_bound(raw_buf.len())?);
-            unsafe { lz4_buf.set_len(lz4_buf.capacity()) };
-
-            // Compress the raw buffer, reserving the header.
-            let n = lz4::block::compress_to_buffer(
-                &raw_buf,
-                Some(lz4::block::CompressionMode::DEFAULT),
-                false,
-                &mut lz4_buf[8..],
-            )?;
-            // Safety: lz4 will not write beyond our given slice.
-            unsafe { lz4_buf.set_len(8 + n) };
-
-            // Update the header with the raw and lz4'd chunk lengths, then send to writer.
-            let lz4_len = u32::to_ne_bytes(lz4_buf.len() as u32 - 8);
-            let raw_len = u32::to_ne_bytes(raw_buf.len() as u32);
-            lz4_buf[0..4].copy_from_slice(&lz4_len);
-            lz4_buf[4..8].copy_from_slice(&raw_len);
-
-            self.spill.write_all(&lz4_buf)?;
+            options.seal_chunk(&raw_buf, &mut chunk_buf)?;
+            self.spill.write_all(&chunk_buf)?;
 
             tracing::trace!(
                 chunk_docs = %(1 + index - last_chunk_index),
                 bytes_per_doc = (raw_buf.len() / (1 + index - last_chunk_index)),
                 raw_len = %raw_buf.len(),
-                lz4_len = %lz4_buf.len(),
+                chunk_len = %chunk_buf.len(),
                 remaining = %(entries.len() - (1 + index)),
                 "wrote chunk",
             );
 
             last_chunk_index = index;
-            lz4_buf.clear();
             raw_buf.clear();
         }
 
@@ -139,6 +120,189 @@ impl<F: io::Read + io::Write + io::Seek> SpillWriter<F> {
     }
 }
 
+/// SpillCodec is the compression codec applied to each chunk of a spill file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SpillCodec {
+    /// LZ4 block compression. It's very fast and is the default.
+    #[default]
+    Lz4,
+    /// Zstandard compression at the given level, where zero is zstd's default.
+    /// It trades CPU for smaller spill files, which can win on I/O-bound hosts.
+    Zstd { level: i32 },
+}
+
+/// SpillCipher is the encryption applied to each chunk of a spill file.
+#[derive(Default, Clone)]
+pub enum SpillCipher {
+    /// Chunks are written in plaintext.
+    #[default]
+    None,
+    /// Chunks are sealed with AES-256-GCM under the given key.
+    /// Each chunk has a random nonce, and its header is authenticated.
+    Aes256Gcm { key: Arc<[u8; 32]> },
+}
+
+impl SpillCipher {
+    /// Build an AES-256-GCM SpillCipher having a random, ephemeral key.
+    /// The key lives only in memory: once it's dropped, spilled chunks
+    /// cannot be recovered by anyone.
+    pub fn ephemeral_aes256gcm() -> Result<Self, io::Error> {
+        let mut key = [0; 32];
+        openssl::rand::rand_bytes(&mut key)?;
+
+        Ok(Self::Aes256Gcm { key: Arc::new(key) })
+    }
+}
+
+impl std::fmt::Debug for SpillCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print key material.
+        match self {
+            Self::None => f.write_str("None"),
+            Self::Aes256Gcm { .. } => f.write_str("Aes256Gcm"),
+        }
+    }
+}
+
+/// SpillOptions configure how chunks of a spill file are encoded.
+/// Chunks must be read with the same options they were written with.
+#[derive(Debug, Default, Clone)]
+pub struct SpillOptions {
+    pub codec: SpillCodec,
+    pub cipher: SpillCipher,
+}
+
+// Length of the AES-GCM nonce which prefixes a sealed chunk.
+const NONCE_LEN: usize = 12;
+// Length of the AES-GCM authentication tag which suffixes a sealed chunk.
+const TAG_LEN: usize = 16;
+
+impl SpillOptions {
+    // Compress and encrypt the `raw` chunk into `out`, which is cleared first.
+    // The written chunk has an 8-byte header of its stored and raw lengths,
+    // followed by `stored` bytes. If encrypted, `stored` is [nonce, ciphertext, tag].
+    fn seal_chunk(&self, raw: &[u8], out: &mut Vec<u8>) -> Result<(), io::Error> {
+        out.clear();
+
+        // Reserve leading bytes for the chunk header and a possible nonce.
+        let offset = match self.cipher {
+            SpillCipher::None => 8,
+            SpillCipher::Aes256Gcm { .. } => 8 + NONCE_LEN,
+        };
+
+        let bound = match self.codec {
+            SpillCodec::Lz4 => lz4::block::compress_bound(raw.len())?,
+            SpillCodec::Zstd { .. } => zstd::zstd_safe::compress_bound(raw.len()),
+        };
+        out.resize(offset + bound, 0);
+
+        let n = match self.codec {
+            SpillCodec::Lz4 => lz4::block::compress_to_buffer(
+                raw,
+                Some(lz4::block::CompressionMode::DEFAULT),
+                false,
+                &mut out[offset..],
+            )?,
+            SpillCodec::Zstd { level } => {
+                zstd::bulk::compress_to_buffer(raw, &mut out[offset..], level)?
+            }
+        };
+        out.truncate(offset + n);
+
+        if let SpillCipher::Aes256Gcm { key } = &self.cipher {
+            let stored_len = NONCE_LEN + n + TAG_LEN;
+            out[0..4].copy_from_slice(&u32::to_ne_bytes(stored_len as u32));
+            out[4..8].copy_from_slice(&u32::to_ne_bytes(raw.len() as u32));
+
+            let (header, rest) = out.split_at_mut(8);
+            let (nonce, plaintext) = rest.split_at_mut(NONCE_LEN);
+            openssl::rand::rand_bytes(nonce)?;
+
+            let mut tag = [0; TAG_LEN];
+            let ciphertext = openssl::symm::encrypt_aead(
+                openssl::symm::Cipher::aes_256_gcm(),
+                key.as_ref(),
+                Some(nonce),
+                header,
+                plaintext,
+                &mut tag,
+            )?;
+            plaintext.copy_from_slice(&ciphertext);
+            out.extend_from_slice(&tag);
+        } else {
+            out[0..4].copy_from_slice(&u32::to_ne_bytes(n as u32));
+            out[4..8].copy_from_slice(&u32::to_ne_bytes(raw.len() as u32));
+        }
+
+        Ok(())
+    }
+
+    // Decrypt and decompress a `stored` chunk having the given `header`,
+    // which is expected to decode into `raw_len` bytes.
+    fn open_chunk(
+        &self,
+        header: &[u8; 8],
+        stored: &[u8],
+        raw_len: usize,
+    ) -> Result<rkyv::AlignedVec, io::Error> {
+        let plaintext;
+        let compressed = match &self.cipher {
+            SpillCipher::None => stored,
+            SpillCipher::Aes256Gcm { key } => {
+                if stored.len() < NONCE_LEN + TAG_LEN {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        format!(
+                            "corrupt segment: sealed chunk is smaller than its nonce and tag: {}",
+                            stored.len()
+                        ),
+                    ));
+                }
+                let (nonce, rest) = stored.split_at(NONCE_LEN);
+                let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+
+                plaintext = openssl::symm::decrypt_aead(
+                    openssl::symm::Cipher::aes_256_gcm(),
+                    key.as_ref(),
+                    Some(nonce),
+                    header,
+                    ciphertext,
+                    tag,
+                )
+                .map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("corrupt segment: failed to authenticate chunk: {err}"),
+                    )
+                })?;
+
+                &plaintext
+            }
+        };
+
+        // Allocate and decompress into `raw_buf`.
+        // Safety: we're immediately decompressing into allocated memory, overwriting its uninitialized content.
+        let mut raw_buf = rkyv::AlignedVec::with_capacity(raw_len);
+        unsafe { raw_buf.set_len(raw_len) }
+
+        let decompressed_bytes = match self.codec {
+            SpillCodec::Lz4 => {
+                lz4::block::decompress_to_buffer(compressed, Some(raw_len as i32), &mut raw_buf)?
+            }
+            SpillCodec::Zstd { .. } => zstd::bulk::decompress_to_buffer(compressed, &mut raw_buf)?,
+        };
+
+        if decompressed_bytes != raw_buf.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("corrupt segment: decompressed chunk bytes don't match the length encoded in the chunk header: {decompressed_bytes} vs {}", raw_buf.len()),
+            ));
+        }
+
+        Ok(raw_buf)
+    }
+}
+
 // Entry is a parsed document entry of a spill file.
 struct Entry {
     meta: Meta,
@@ -187,6 +351,7 @@ struct Segment {
     head: Entry,                   // Next Entry of Segment.
     keys: Arc<[Box<[Extractor]>]>, // Keys for comparing Entries across Segments.
     next: Range<u64>,              // Next chunk of this Segment.
+    options: SpillOptions,         // Options with which chunks were written.
     tail: bytes::Bytes,            // Remainder of the current chunk.
 }
 
@@ -194,6 +359,7 @@ impl Segment {
     /// Build a new Segment covering the given range of the spill file.
     fn new<R: io::Read + io::Seek>(
         keys: Arc<[Box<[Extractor]>]>,
+        options: SpillOptions,
         r: &mut R,
         range: Range<u64>,
     ) -> Result<Self, io::Error> {
@@ -204,39 +370,25 @@ impl Segment {
         r.seek(io::SeekFrom::Start(range.start))?;
         r.read_exact(&mut header)?;
 
-        let lz4_len = u32::from_ne_bytes(header[0..4].try_into().unwrap()) as u64;
-        let raw_len = u32::from_ne_bytes(header[4..8].try_into().unwrap()) as u64;
+        let stored_len = u32::from_ne_bytes(header[0..4].try_into().unwrap()) as u64;
+        let raw_len = u32::from_ne_bytes(header[4..8].try_into().unwrap()) as usize;
 
         // Compute implied next chunk range and ensure it remains valid.
-        let next = range.start + 8 + lz4_len..range.end;
+        let next = range.start + 8 + stored_len..range.end;
         if next.start > next.end {
             return Err(io::Error::new(
                 io::ErrorKind::Other,
-                format!("read header len {lz4_len} which is outside of region {next:?}"),
+                format!("read header len {stored_len} which is outside of region {next:?}"),
             ));
         }
 
-        // Allocate and read compressed chunk into `lz4_buf`.
+        // Allocate and read the stored chunk into `stored_buf`.
         // Safety: we're immediately reading into allocated memory, overwriting its uninitialized content.
-        let mut lz4_buf = Vec::with_capacity(lz4_len as usize);
-        unsafe { lz4_buf.set_len(lz4_len as usize) }
-        r.read_exact(&mut lz4_buf)?;
-
-        // Allocate and decompress into `raw_buf`.
-        // Safety: we're immediately decompressing into allocated memory, overwriting its uninitialized content.
-        let mut raw_buf = rkyv::AlignedVec::with_capacity(raw_len as usize);
-        unsafe { raw_buf.set_len(raw_len as usize) }
-
-        let decompressed_bytes =
-            lz4::block::decompress_to_buffer(&lz4_buf, Some(raw_len as i32), &mut raw_buf)?;
-
-        if decompressed_bytes != raw_buf.len() {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!("corrupt segment: decompressed chunk bytes don't match the length encoded in the chunk header: {decompressed_bytes} vs {}", raw_buf.len()),
-            ));
-        }
+        let mut stored_buf = Vec::with_capacity(stored_len as usize);
+        unsafe { stored_buf.set_len(stored_len as usize) }
+        r.read_exact(&mut stored_buf)?;
 
+        let raw_buf = options.open_chunk(&header, &stored_buf, raw_len)?;
         let chunk: bytes::Bytes = raw_buf.into_vec().into();
         let (head, tail) = Entry::parse(chunk)?;
 
@@ -244,6 +396,7 @@ impl Segment {
             head,
             keys,
             next,
+            options,
             tail,
         })
     }
@@ -258,6 +411,7 @@ impl Segment {
             head: popped,
             keys,
             next,
+            options,
             tail,
         } = self;
 
@@ -270,11 +424,12 @@ impl Segment {
                     head,
                     keys,
                     next,
+                    options,
                     tail,
                 }),
             ))
         } else if !next.is_empty() {
-            Ok((popped, Some(Self::new(keys, r, next)?)))
+            Ok((popped, Some(Self::new(keys, options, r, next)?)))
         } else {
             Ok((popped, None))
         }
@@ -318,6 +473,7 @@ pub struct SpillDrainer<F: io::Read + io::Seek> {
     in_group: bool,
     spec: Spec,
     spill: F,
+    upper: Option<Fence>, // Exclusive upper bound of drained entries.
 }
 
 // Safety: SpillDrainer is safe to Send because it wraps Bump with Arc,
@@ -333,7 +489,7 @@ impl<F: io::Read + io::Seek> SpillDrainer<F> {
         // Pop `segment`'s next Entry, and then re-heap it.
         let (entry, segment) = segment.pop_head(&mut self.spill)?;
         if let Some(segment) = segment {
-            self.heap.push(cmp::Reverse(segment));
+            push_segment(&mut self.heap, self.upper.as_ref(), segment);
         }
 
         let Entry { mut meta, root } = entry;
@@ -383,7 +539,7 @@ impl<F: io::Read + io::Seek> SpillDrainer<F> {
                     let segment = self.heap.pop().unwrap().0;
                     let (_discard, segment) = segment.pop_head(&mut self.spill)?;
                     if let Some(segment) = segment {
-                        self.heap.push(cmp::Reverse(segment));
+                        push_segment(&mut self.heap, self.upper.as_ref(), segment);
                     }
                 }
                 Err(reduce::Error::NotAssociative) => {
@@ -445,12 +601,47 @@ impl<F: io::Read + io::Seek> Iterator for SpillDrainer<F> {
 impl<F: io::Read + io::Seek> SpillDrainer<F> {
     /// Build a new SpillDrainer which drains the given segment ranges previously
     /// written to the spill file.
-    pub fn new(spec: Spec, mut spill: F, ranges: &[Range<u64>]) -> Result<Self, std::io::Error> {
+    pub fn new(spec: Spec, spill: F, ranges: &[Range<u64>]) -> Result<Self, std::io::Error> {
+        Self::new_partition(spec, spill, ranges, None, None)
+    }
+
+    // Build a new SpillDrainer which drains only entries within the partition
+    // [lower, upper) of the spilled key space. Each of `ranges` begins at a
+    // chunk of a segment, and leading entries which are below `lower` are skipped.
+    pub(super) fn new_partition(
+        spec: Spec,
+        mut spill: F,
+        ranges: &[Range<u64>],
+        lower: Option<&Fence>,
+        upper: Option<Fence>,
+    ) -> Result<Self, std::io::Error> {
         let mut heap = BinaryHeap::with_capacity(ranges.len());
 
         for range in ranges {
-            let segment = Segment::new(spec.keys.clone(), &mut spill, range.clone())?;
-            heap.push(cmp::Reverse(segment));
+            let mut segment = Some(Segment::new(
+                spec.keys.clone(),
+                spec.spill.clone(),
+                &mut spill,
+                range.clone(),
+            )?);
+
+            if let Some(lower) = lower {
+                while let Some(next) = segment.take() {
+                    if lower
+                        .cmp_doc(&next.keys, next.head.meta.binding(), next.head.root.get())
+                        .is_gt()
+                    {
+                        segment = next.pop_head(&mut spill)?.1;
+                    } else {
+                        segment = Some(next);
+                        break;
+                    }
+                }
+            }
+
+            if let Some(segment) = segment {
+                push_segment(&mut heap, upper.as_ref(), segment);
+            }
         }
 
         Ok(Self {
@@ -459,6 +650,7 @@ impl<F: io::Read + io::Seek> SpillDrainer<F> {
             in_group: false,
             spec,
             spill,
+            upper,
         })
     }
 
@@ -469,11 +661,54 @@ impl<F: io::Read + io::Seek> SpillDrainer<F> {
             in_group: _,
             spec,
             spill,
+            upper: _,
         } = self;
         (spec, spill)
     }
 }
 
+// Push `segment` onto `heap`, unless its head is at or beyond the `upper` Fence.
+fn push_segment(
+    heap: &mut BinaryHeap<cmp::Reverse<Segment>>,
+    upper: Option<&Fence>,
+    segment: Segment,
+) {
+    match upper {
+        Some(upper)
+            if upper
+                .cmp_doc(
+                    &segment.keys,
+                    segment.head.meta.binding(),
+                    segment.head.root.get(),
+                )
+                .is_le() => {}
+        _ => heap.push(cmp::Reverse(segment)),
+    }
+}
+
+// Read the Fence of each chunk of a segment, returning its offsets and Fences.
+// Chunk Fences are the (binding, key) of their first entry.
+pub(super) fn chunk_fences<R: io::Read + io::Seek>(
+    keys: Arc<[Box<[Extractor]>]>,
+    options: SpillOptions,
+    r: &mut R,
+    range: Range<u64>,
+) -> Result<Vec<(u64, Fence)>, io::Error> {
+    let mut fences = Vec::new();
+    let mut next = range;
+
+    while !next.is_empty() {
+        let offset = next.start;
+        let segment = Segment::new(keys.clone(), options.clone(), r, next)?;
+
+        let (binding, root) = (segment.head.meta.binding(), segment.head.root.get());
+        fences.push((offset, Fence::new(&segment.keys, binding, root)));
+        next = segment.next;
+    }
+
+    Ok(fences)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -499,7 +734,9 @@ mod test {
         let mut spill = SpillWriter::new(io::Cursor::new(Vec::new())).unwrap();
 
         // 130 is calibrated to include two, but not three documents in a chunk.
-        spill.write_segment(&segment, 130).unwrap();
+        spill
+            .write_segment(&segment, 130, &SpillOptions::default())
+            .unwrap();
         let (mut spill, ranges) = spill.into_parts();
 
         // Assert we wrote the expected range and regression fixture.
@@ -522,7 +759,8 @@ mod test {
         "###);
 
         // Parse the region as a Segment.
-        let mut segment = Segment::new(keys, &mut spill, ranges[0].clone()).unwrap();
+        let mut segment =
+            Segment::new(keys, SpillOptions::default(), &mut spill, ranges[0].clone()).unwrap();
 
         // First chunk has two documents.
         assert_eq!(segment.head.meta.binding(), 0);
@@ -619,7 +857,9 @@ mod test {
 
         let mut spill = SpillWriter::new(io::Cursor::new(Vec::new())).unwrap();
         for segment in fixtures {
-            spill.write_segment(&segment, 2).unwrap();
+            spill
+                .write_segment(&segment, 2, &SpillOptions::default())
+                .unwrap();
         }
 
         // Map from SpillWriter => SpillDrainer.
@@ -756,7 +996,9 @@ mod test {
 
         let mut spill = SpillWriter::new(io::Cursor::new(Vec::new())).unwrap();
         for segment in fixtures {
-            spill.write_segment(&segment, CHUNK_TARGET_SIZE).unwrap();
+            spill
+                .write_segment(&segment, CHUNK_TARGET_SIZE, &SpillOptions::default())
+                .unwrap();
         }
         let (spill, ranges) = spill.into_parts();
         let mut drainer = SpillDrainer::new(spec, spill, &ranges).unwrap();
@@ -809,6 +1051,87 @@ mod test {
         assert_eq!(alloc.chunk_capacity(), 36800 - s.len());
     }
 
+    #[test]
+    fn test_spill_codecs_and_ciphers() {
+        let fixture = &[
+            (0, json!({"key": "aaa", "v": "apple"}), false),
+            (1, json!({"key": "bbb", "v": "banana"}), true),
+            (2, json!({"key": "ccc", "v": "carrot"}), true),
+        ];
+        let alloc = Bump::new();
+        let segment = segment_fixture(fixture, &alloc);
+        let keys: Arc<[Box<[Extractor]>]> = Vec::new().into();
+
+        for (codec, cipher) in [
+            (SpillCodec::Lz4, SpillCipher::None),
+            (SpillCodec::Zstd { level: 0 }, SpillCipher::None),
+            (SpillCodec::Zstd { level: 19 }, SpillCipher::None),
+            (SpillCodec::Lz4, SpillCipher::ephemeral_aes256gcm().unwrap()),
+            (
+                SpillCodec::Zstd { level: 3 },
+                SpillCipher::ephemeral_aes256gcm().unwrap(),
+            ),
+        ] {
+            let options = SpillOptions { codec, cipher };
+
+            let mut spill = SpillWriter::new(io::Cursor::new(Vec::new())).unwrap();
+            spill.write_segment(&segment, 130, &options).unwrap();
+            let (mut spill, ranges) = spill.into_parts();
+
+            // Encrypted chunks don't reveal document content.
+            if let SpillCipher::Aes256Gcm { .. } = &options.cipher {
+                let raw = spill.get_ref();
+                assert!(!raw.windows(6).any(|w| w == b"banana"));
+            }
+
+            // Read back all documents, in order.
+            let mut segment = Some(
+                Segment::new(keys.clone(), options.clone(), &mut spill, ranges[0].clone()).unwrap(),
+            );
+            for (binding, value, front) in fixture {
+                let (entry, next) = segment.take().unwrap().pop_head(&mut spill).unwrap();
+                assert_eq!(entry.meta.binding(), *binding as usize);
+                assert_eq!(entry.meta.front(), *front);
+                assert!(crate::compare(entry.root.get(), value).is_eq());
+                segment = next;
+            }
+            assert!(segment.is_none(), "{options:?}");
+        }
+    }
+
+    #[test]
+    fn test_sealed_chunk_is_authenticated() {
+        let alloc = Bump::new();
+        let segment = segment_fixture(&[(0, json!({"key": "aaa", "v": "apple"}), false)], &alloc);
+        let keys: Arc<[Box<[Extractor]>]> = Vec::new().into();
+
+        let options = SpillOptions {
+            codec: SpillCodec::Lz4,
+            cipher: SpillCipher::ephemeral_aes256gcm().unwrap(),
+        };
+        let mut spill = SpillWriter::new(io::Cursor::new(Vec::new())).unwrap();
+        spill.write_segment(&segment, 130, &options).unwrap();
+        let (mut spill, ranges) = spill.into_parts();
+
+        // A different key cannot open the chunk.
+        let other = SpillOptions {
+            cipher: SpillCipher::ephemeral_aes256gcm().unwrap(),
+            ..options.clone()
+        };
+        let err = Segment::new(keys.clone(), other, &mut spill, ranges[0].clone())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("failed to authenticate chunk"));
+
+        // Nor can a tampered chunk be opened.
+        let len = spill.get_ref().len();
+        spill.get_mut()[len - 20] ^= 0x01;
+        let err = Segment::new(keys, options, &mut spill, ranges[0].clone())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("failed to authenticate chunk"));
+    }
+
     fn to_hex(b: &[u8]) -> String {
         hexdump::hexdump_iter(b)
             .map(|line| format!("{line}"))
@@ -1,5 +1,6 @@
 use crate::{compare::compare, AsNode, Node, OwnedNode, Pointer, SerPolicy};
 use bytes::BufMut;
+use json::schema::formats::Format;
 use std::borrow::Cow;
 use tuple::TuplePack;
 
@@ -11,6 +12,15 @@ pub struct Extractor {
     policy: SerPolicy,
     default: serde_json::Value,
     is_uuid_v1_date_time: bool,
+    ordered: Option<Ordered>,
+}
+
+/// Ordered encodings of extracted values, used in place of their usual
+/// tuple encodings so that packed bytes order by the value represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ordered {
+    Decimal,
+    DateTime,
 }
 
 impl Extractor {
@@ -22,6 +32,7 @@ impl Extractor {
             policy: policy.clone(),
             default: serde_json::Value::Null,
             is_uuid_v1_date_time: false,
+            ordered: None,
         }
     }
 
@@ -33,6 +44,7 @@ impl Extractor {
             policy: policy.clone(),
             default,
             is_uuid_v1_date_time: false,
+            ordered: None,
         }
     }
 
@@ -43,9 +55,36 @@ impl Extractor {
             policy: SerPolicy::default(),
             default: serde_json::Value::Null,
             is_uuid_v1_date_time: true,
+            ordered: None,
         }
     }
 
+    /// Pack extracted values of the given string format using an
+    /// order-preserving encoding, so that packed keys order by the value
+    /// each string represents rather than by its text.
+    ///
+    /// Formats `integer` and `number` pack as a tuple::Decimal, as do JSON
+    /// numbers so that they order alongside numeric strings. Format `date-time`
+    /// packs RFC3339 strings as a tuple::Timestamp. Values which don't parse
+    /// are packed as usual, and other formats have no effect.
+    ///
+    /// Ordered encodings are not understood by the Go tuple decoder, and they
+    /// change the packed keys used for shuffles and grouping, so this must not
+    /// be applied to collection keys or projections without an explicit opt-in.
+    pub fn with_ordered_format(mut self, format: Format) -> Self {
+        self.ordered = match format {
+            Format::Integer | Format::Number => Some(Ordered::Decimal),
+            Format::DateTime => Some(Ordered::DateTime),
+            _ => None,
+        };
+        self
+    }
+
+    /// Pointer of the document location which is extracted.
+    pub fn ptr(&self) -> &Pointer {
+        &self.ptr
+    }
+
     /// Query the value extracted from the document.
     /// The result is Ok if a literal document node is extracted,
     /// or Err if the document value doesn't exist and an alternative
@@ -115,31 +154,74 @@ impl Extractor {
         }
     }
 
+    /// Map an extracted value into its ordered tuple::Element, if this
+    /// Extractor has an ordered format and the value parses under it.
+    fn ordered<N: AsNode>(&self, node: &N) -> Option<tuple::Element<'static>> {
+        use tuple::{Decimal, Element, Timestamp};
+
+        match (self.ordered?, node.as_node()) {
+            (Ordered::Decimal, Node::String(s)) => Decimal::parse(s).map(Element::Decimal),
+            (Ordered::Decimal, Node::PosInt(n)) => Some(Element::Decimal(n.into())),
+            (Ordered::Decimal, Node::NegInt(n)) => Some(Element::Decimal(n.into())),
+            (Ordered::Decimal, Node::Float(n)) => Some(Element::Decimal(n.into())),
+            (Ordered::DateTime, Node::String(s)) => {
+                time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339)
+                    .ok()
+                    .and_then(|t| Timestamp::from_unix_nanos(t.unix_timestamp_nanos()))
+                    .map(Element::Timestamp)
+            }
+            _ => None,
+        }
+    }
+
     /// Extract from an instance of doc::AsNode, writing a packed encoding into the writer.
     pub fn extract<N: AsNode, W: std::io::Write>(&self, doc: &N, w: &mut W) -> std::io::Result<()> {
+        let depth = tuple::TupleDepth::new().increment();
+
         match self.query(doc) {
-            Ok(v) => self
-                .policy
-                .on(v)
-                .pack(w, tuple::TupleDepth::new().increment())?,
-            Err(v) => self
-                .policy
-                .on(v.as_ref())
-                .pack(w, tuple::TupleDepth::new().increment())?,
+            Ok(v) => match self.ordered(v) {
+                Some(ordered) => ordered.pack(w, depth)?,
+                None => self.policy.on(v).pack(w, depth)?,
+            },
+            Err(v) => match self.ordered(v.as_ref()) {
+                Some(ordered) => ordered.pack(w, depth)?,
+                None => self.policy.on(v.as_ref()).pack(w, depth)?,
+            },
         };
         Ok(())
     }
 
     /// Compare the deep ordering of `lhs` and `rhs` with respect to a composite key.
+    ///
+    /// Values of an ordered format are compared by the value they represent,
+    /// and order after values which don't parse under the format,
+    /// so that keys compare as equal exactly when their packed encodings do.
     pub fn compare_key<L: AsNode, R: AsNode>(key: &[Self], lhs: &L, rhs: &R) -> std::cmp::Ordering {
         use std::cmp::Ordering;
 
         key.iter()
-            .map(|ex| match (ex.query(lhs), ex.query(rhs)) {
-                (Ok(lhs), Ok(rhs)) => compare(lhs, rhs),
-                (Err(lhs), Ok(rhs)) => compare(lhs.as_ref(), rhs),
-                (Ok(lhs), Err(rhs)) => compare(lhs, rhs.as_ref()),
-                (Err(lhs), Err(rhs)) => compare(lhs.as_ref(), rhs.as_ref()),
+            .map(|ex| {
+                let (lhs, rhs) = (ex.query(lhs), ex.query(rhs));
+
+                let ordered = (
+                    lhs.as_ref()
+                        .map_or_else(|v| ex.ordered(v.as_ref()), |n| ex.ordered(*n)),
+                    rhs.as_ref()
+                        .map_or_else(|v| ex.ordered(v.as_ref()), |n| ex.ordered(*n)),
+                );
+                match ordered {
+                    (Some(l), Some(r)) => return l.cmp(&r),
+                    (Some(_), None) => return Ordering::Greater,
+                    (None, Some(_)) => return Ordering::Less,
+                    (None, None) => (),
+                }
+
+                match (lhs, rhs) {
+                    (Ok(lhs), Ok(rhs)) => compare(lhs, rhs),
+                    (Err(lhs), Ok(rhs)) => compare(lhs.as_ref(), rhs),
+                    (Ok(lhs), Err(rhs)) => compare(lhs, rhs.as_ref()),
+                    (Err(lhs), Err(rhs)) => compare(lhs.as_ref(), rhs.as_ref()),
+                }
             })
             .find(|o| *o != Ordering::Equal)
             .unwrap_or(Ordering::Equal)
@@ -336,4 +418,115 @@ mod test {
         // Key exists at |d1| but not |d2|. Both are null (implicit and explicit).
         assert_eq!(Extractor::compare_key(&[a()], d1, d2), Ordering::Equal);
     }
+
+    #[test]
+    fn test_ordered_formats() {
+        let policy = SerPolicy::default();
+
+        // Fixtures are in ascending order of their represented value, preceded
+        // by values which don't parse under the format. Those order amongst
+        // themselves as they always have, which isn't checked here.
+        for (format, fixtures) in [
+            (
+                Format::Number,
+                json!([
+                    [null, true, "not a number", [], {}],
+                    "-Infinity",
+                    "-1e10",
+                    -12,
+                    "-2.5",
+                    "-0.0001",
+                    [0, "0.000", -0.0],
+                    "1e-3",
+                    0.5,
+                    [1, "1.0", "1_000e-3"],
+                    "1.0000000000000000000001",
+                    "12",
+                    [1e21, "1000000000000000000000"],
+                    "123456789012345678901234567890",
+                    "Infinity",
+                    "NaN",
+                ]),
+            ),
+            (
+                Format::DateTime,
+                json!([
+                    [null, 1234, "2024-01-01", "not a date-time"],
+                    "1969-12-31T23:59:59.999999999Z",
+                    ["1970-01-01T00:00:00Z", "1970-01-01T01:00:00+01:00"],
+                    "1970-01-01T00:00:00.000000001Z",
+                    [
+                        "2023-06-28T20:29:46.4945945Z",
+                        "2023-06-28T16:29:46.4945945-04:00"
+                    ],
+                    "2023-06-28T20:29:47Z",
+                    "9999-12-31T23:59:59Z",
+                ]),
+            ),
+        ] {
+            let ex = [Extractor::new("/k", &policy).with_ordered_format(format)];
+            let mut buf = bytes::BytesMut::new();
+
+            // Flatten fixtures into (rank, document), where equal values share a rank.
+            let docs: Vec<(usize, serde_json::Value)> = fixtures
+                .as_array()
+                .unwrap()
+                .iter()
+                .enumerate()
+                .flat_map(|(rank, v)| {
+                    match v {
+                        serde_json::Value::Array(equal) => equal.clone(),
+                        v => vec![v.clone()],
+                    }
+                    .into_iter()
+                    .map(move |v| (rank, json!({ "k": v })))
+                })
+                .collect();
+
+            for (l_rank, l_doc) in &docs {
+                for (r_rank, r_doc) in &docs {
+                    if *l_rank == 0 && *r_rank == 0 {
+                        continue;
+                    }
+                    let expect = l_rank.cmp(r_rank);
+                    let l_packed = Extractor::extract_all(l_doc, &ex, &mut buf);
+                    let r_packed = Extractor::extract_all(r_doc, &ex, &mut buf);
+
+                    assert_eq!(l_packed.cmp(&r_packed), expect, "{l_doc} vs {r_doc}");
+                    assert_eq!(
+                        Extractor::compare_key(&ex, l_doc, r_doc),
+                        expect,
+                        "{l_doc} vs {r_doc}"
+                    );
+                }
+            }
+        }
+
+        // Ordered values unpack as their tuple types.
+        let ex = [
+            Extractor::new("/n", &policy).with_ordered_format(Format::Integer),
+            Extractor::new("/t", &policy).with_ordered_format(Format::DateTime),
+            Extractor::new("/s", &policy).with_ordered_format(Format::Email),
+        ];
+        let doc = json!({"n": "-0012.50", "t": "2023-06-28T20:29:46.4945945Z", "s": "42"});
+        let packed = Extractor::extract_all(&doc, &ex, &mut bytes::BytesMut::new());
+        let unpacked: Vec<tuple::Element> = tuple::unpack(&packed).unwrap();
+
+        insta::assert_debug_snapshot!(unpacked, @r###"
+        [
+            Decimal(
+                -12.5,
+            ),
+            Timestamp(
+                Timestamp {
+                    seconds: 1687984186,
+                    nanos: 494594500,
+                },
+            ),
+            String(
+                "42",
+            ),
+        ]
+        "###);
+    }
 }
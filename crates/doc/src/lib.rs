@@ -99,8 +99,8 @@ pub use annotation::Annotation;
 // over AsNode implementations.
 pub mod validation;
 pub use validation::{
-    FailedValidation, RawValidator, Schema, SchemaIndex, SchemaIndexBuilder, Valid, Validation,
-    Validator,
+    FailedValidation, OutputFormat, RawValidator, Schema, SchemaIndex, SchemaIndexBuilder, Valid,
+    Validation, Validator,
 };
 
 // Doc implementations may be reduced.
@@ -122,6 +122,9 @@ pub use shape::Shape;
 pub mod diff;
 pub use diff::diff;
 
+// JSON Patch and JSON Merge Patch generation and application.
+pub mod patch;
+
 #[cfg(test)]
 mod test {
 
@@ -0,0 +1,681 @@
+use super::{
+    compare,
+    ptr::{Pointer, Token},
+    AsNode, BumpStr, BumpVec, Field, Fields, HeapField, HeapNode, Node, SerPolicy,
+};
+use itertools::{
+    EitherOrBoth::{Both, Left, Right},
+    Itertools,
+};
+
+/// Operation is an RFC 6902 JSON Patch operation, having values which
+/// reference an AsNode implementation.
+/// See: https://datatracker.ietf.org/doc/html/rfc6902
+#[derive(Debug)]
+pub enum Operation<'n, N: AsNode> {
+    Add { path: Pointer, value: &'n N },
+    Remove { path: Pointer },
+    Replace { path: Pointer, value: &'n N },
+    Move { from: Pointer, path: Pointer },
+    Copy { from: Pointer, path: Pointer },
+    Test { path: Pointer, value: &'n N },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("a JSON Patch must be an array of operations")]
+    NotAnArray,
+    #[error("JSON Patch operation {index} is malformed: {detail}")]
+    Malformed { index: usize, detail: &'static str },
+    #[error("location '{0}' does not exist")]
+    NotFound(Pointer),
+    #[error("array index of location '{0}' is out of bounds")]
+    OutOfBounds(Pointer),
+    #[error("the document root cannot be removed")]
+    RemoveRoot,
+    #[error("location '{from}' cannot be moved into its own child '{path}'")]
+    MoveIntoChild { from: Pointer, path: Pointer },
+    #[error("test of location '{0}' failed")]
+    TestFailed(Pointer),
+}
+
+/// Diff computes an RFC 6902 JSON Patch which transforms `lhs` into `rhs`.
+/// Operation values reference locations of `rhs`. The patch consists only of
+/// "add", "remove", and "replace" operations: arrays are compared index-wise,
+/// and are extended or truncated at their ends.
+pub fn diff<'r, L: AsNode, R: AsNode>(lhs: &L, rhs: &'r R) -> Vec<Operation<'r, R>> {
+    let mut out = Vec::new();
+    diff_inner(lhs, rhs, &mut Pointer::empty(), &mut out);
+    out
+}
+
+fn diff_inner<'r, L: AsNode, R: AsNode>(
+    lhs: &L,
+    rhs: &'r R,
+    path: &mut Pointer,
+    out: &mut Vec<Operation<'r, R>>,
+) {
+    match (lhs.as_node(), rhs.as_node()) {
+        (Node::Object(lhs), Node::Object(rhs)) => {
+            for eob in lhs
+                .iter()
+                .merge_join_by(rhs.iter(), |l, r| l.property().cmp(r.property()))
+            {
+                match eob {
+                    Left(lhs) => out.push(Operation::Remove {
+                        path: child(path, Token::from_str(lhs.property())),
+                    }),
+                    Right(rhs) => out.push(Operation::Add {
+                        path: child(path, Token::from_str(rhs.property())),
+                        value: rhs.value(),
+                    }),
+                    Both(lhs, rhs) => {
+                        path.push(Token::from_str(lhs.property()));
+                        diff_inner(lhs.value(), rhs.value(), path, out);
+                        path.0.pop();
+                    }
+                }
+            }
+        }
+        (Node::Array(lhs), Node::Array(rhs)) => {
+            for (index, (lhs, rhs)) in lhs.iter().zip(rhs.iter()).enumerate() {
+                path.push(Token::Index(index));
+                diff_inner(lhs, rhs, path, out);
+                path.0.pop();
+            }
+            for (index, rhs) in rhs.iter().enumerate().skip(lhs.len()) {
+                out.push(Operation::Add {
+                    path: child(path, Token::Index(index)),
+                    value: rhs,
+                });
+            }
+            // Remove trailing items from the back, so that indices remain stable.
+            for index in (rhs.len()..lhs.len()).rev() {
+                out.push(Operation::Remove {
+                    path: child(path, Token::Index(index)),
+                });
+            }
+        }
+        _ if compare(lhs, rhs).is_eq() => {}
+        _ => out.push(Operation::Replace {
+            path: path.clone(),
+            value: rhs,
+        }),
+    }
+}
+
+fn child(parent: &Pointer, token: Token) -> Pointer {
+    let mut ptr = parent.clone();
+    ptr.push(token);
+    ptr
+}
+
+/// Parse an RFC 6902 JSON Patch document into its Operations.
+/// Operation values reference locations of `patch`.
+pub fn parse<'p, N: AsNode>(patch: &'p N) -> Result<Vec<Operation<'p, N>>, Error> {
+    let Node::Array(ops) = patch.as_node() else {
+        return Err(Error::NotAnArray);
+    };
+    ops.iter()
+        .enumerate()
+        .map(|(index, op)| parse_op(op).map_err(|detail| Error::Malformed { index, detail }))
+        .collect()
+}
+
+fn parse_op<'p, N: AsNode>(op: &'p N) -> Result<Operation<'p, N>, &'static str> {
+    let Node::Object(fields) = op.as_node() else {
+        return Err("expected an object");
+    };
+    let pointer = |property: &str, detail: &'static str| match fields
+        .get(property)
+        .map(|field| field.value().as_node())
+    {
+        Some(Node::String(s)) => Ok(Pointer::from_str(s)),
+        _ => Err(detail),
+    };
+    let value = || {
+        fields
+            .get("value")
+            .map(|field| field.value())
+            .ok_or("missing 'value'")
+    };
+    let path = || pointer("path", "missing or invalid 'path'");
+    let from = || pointer("from", "missing or invalid 'from'");
+
+    match fields.get("op").map(|field| field.value().as_node()) {
+        Some(Node::String("add")) => Ok(Operation::Add {
+            path: path()?,
+            value: value()?,
+        }),
+        Some(Node::String("remove")) => Ok(Operation::Remove { path: path()? }),
+        Some(Node::String("replace")) => Ok(Operation::Replace {
+            path: path()?,
+            value: value()?,
+        }),
+        Some(Node::String("move")) => Ok(Operation::Move {
+            from: from()?,
+            path: path()?,
+        }),
+        Some(Node::String("copy")) => Ok(Operation::Copy {
+            from: from()?,
+            path: path()?,
+        }),
+        Some(Node::String("test")) => Ok(Operation::Test {
+            path: path()?,
+            value: value()?,
+        }),
+        Some(Node::String(_)) => Err("unknown 'op'"),
+        _ => Err("missing or invalid 'op'"),
+    }
+}
+
+/// Apply RFC 6902 JSON Patch operations to the HeapNode `doc`, in order.
+/// New document locations are allocated from `alloc`, which is typically the
+/// allocator of the MemTable holding `doc`. If an operation fails, its error is
+/// returned and `doc` reflects the operations which were applied before it.
+pub fn apply<'alloc, N: AsNode>(
+    doc: &mut HeapNode<'alloc>,
+    patch: &[Operation<'_, N>],
+    alloc: &'alloc bumpalo::Bump,
+) -> Result<(), Error> {
+    for op in patch {
+        match op {
+            Operation::Add { path, value } => {
+                add(doc, path, HeapNode::from_node(*value, alloc), alloc)?
+            }
+            Operation::Remove { path } => {
+                remove(doc, path)?;
+            }
+            Operation::Replace { path, value } => {
+                let target =
+                    query_mut(doc, &path.0).ok_or_else(|| Error::NotFound(path.clone()))?;
+                *target = HeapNode::from_node(*value, alloc);
+            }
+            Operation::Move { from, path } => {
+                if from == path {
+                    continue;
+                } else if path.0.starts_with(&from.0) {
+                    return Err(Error::MoveIntoChild {
+                        from: from.clone(),
+                        path: path.clone(),
+                    });
+                }
+                let value = remove(doc, from)?;
+                add(doc, path, value, alloc)?;
+            }
+            Operation::Copy { from, path } => {
+                let value = from
+                    .query(&*doc)
+                    .ok_or_else(|| Error::NotFound(from.clone()))?;
+                let value = HeapNode::from_node(value, alloc);
+                add(doc, path, value, alloc)?;
+            }
+            Operation::Test { path, value } => {
+                let target = path
+                    .query(&*doc)
+                    .ok_or_else(|| Error::NotFound(path.clone()))?;
+
+                if !compare(target, *value).is_eq() {
+                    return Err(Error::TestFailed(path.clone()));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn add<'alloc>(
+    doc: &mut HeapNode<'alloc>,
+    path: &Pointer,
+    value: HeapNode<'alloc>,
+    alloc: &'alloc bumpalo::Bump,
+) -> Result<(), Error> {
+    let Some((last, parent)) = path.0.split_last() else {
+        *doc = value;
+        return Ok(());
+    };
+
+    match query_mut(doc, parent) {
+        Some(HeapNode::Object(fields)) => {
+            *fields.insert_property(&last.to_string(), alloc) = value;
+        }
+        Some(HeapNode::Array(items)) => match last {
+            Token::Index(index) if *index <= items.len() => items.insert(*index, value, alloc),
+            Token::Index(_) => return Err(Error::OutOfBounds(path.clone())),
+            Token::NextIndex => items.push(value, alloc),
+            Token::Property(property) if property == "-" => items.push(value, alloc),
+            Token::Property(_) | Token::NextProperty => return Err(Error::NotFound(path.clone())),
+        },
+        _ => return Err(Error::NotFound(path.clone())),
+    }
+    Ok(())
+}
+
+fn remove<'alloc>(doc: &mut HeapNode<'alloc>, path: &Pointer) -> Result<HeapNode<'alloc>, Error> {
+    let Some((last, parent)) = path.0.split_last() else {
+        return Err(Error::RemoveRoot);
+    };
+
+    match (query_mut(doc, parent), last) {
+        (Some(HeapNode::Object(fields)), last) => fields.remove_property(&last.to_string()),
+        (Some(HeapNode::Array(items)), Token::Index(index)) if *index < items.len() => {
+            return Ok(items.remove(*index));
+        }
+        _ => None,
+    }
+    .map(|field| field.value)
+    .ok_or_else(|| Error::NotFound(path.clone()))
+}
+
+// Query a mutable existing location of the HeapNode.
+fn query_mut<'n, 'alloc>(
+    mut node: &'n mut HeapNode<'alloc>,
+    tokens: &[Token],
+) -> Option<&'n mut HeapNode<'alloc>> {
+    for token in tokens {
+        node = match (node, token) {
+            (HeapNode::Object(fields), token) => {
+                let property = token.to_string();
+                let index = fields
+                    .binary_search_by(|field| (*field.property).cmp(property.as_str()))
+                    .ok()?;
+                &mut fields[index].value
+            }
+            (HeapNode::Array(items), Token::Index(index)) => items.get_mut(*index)?,
+            _ => return None,
+        };
+    }
+    Some(node)
+}
+
+/// Merge Diff computes an RFC 7386 JSON Merge Patch which transforms `lhs`
+/// into `rhs`, or returns None if `lhs` and `rhs` are equal.
+///
+/// Merge Patches cannot represent explicit nulls within objects, nor partial
+/// updates of arrays. Null-valued properties of `rhs` are represented as
+/// removals, and arrays which differ are replaced in whole.
+/// See: https://datatracker.ietf.org/doc/html/rfc7386
+pub fn merge_diff<'alloc, L: AsNode, R: AsNode>(
+    lhs: &L,
+    rhs: &R,
+    alloc: &'alloc bumpalo::Bump,
+) -> Option<HeapNode<'alloc>> {
+    match (lhs.as_node(), rhs.as_node()) {
+        (Node::Object(lhs), Node::Object(rhs)) => {
+            let mut patch = BumpVec::new();
+
+            for eob in lhs
+                .iter()
+                .merge_join_by(rhs.iter(), |l, r| l.property().cmp(r.property()))
+            {
+                let (property, value) = match eob {
+                    Left(lhs) => (lhs.property(), HeapNode::Null),
+                    Right(rhs) => (rhs.property(), HeapNode::from_node(rhs.value(), alloc)),
+                    Both(lhs, rhs) => match merge_diff(lhs.value(), rhs.value(), alloc) {
+                        Some(value) => (lhs.property(), value),
+                        None => continue,
+                    },
+                };
+                // Properties are merged in sorted order, and remain sorted.
+                patch.push(
+                    HeapField {
+                        property: BumpStr::from_str(property, alloc),
+                        value,
+                    },
+                    alloc,
+                );
+            }
+
+            if patch.is_empty() {
+                None
+            } else {
+                Some(HeapNode::Object(patch))
+            }
+        }
+        _ if compare(lhs, rhs).is_eq() => None,
+        _ => Some(HeapNode::from_node(rhs, alloc)),
+    }
+}
+
+/// Merge Apply applies an RFC 7386 JSON Merge Patch to the HeapNode `doc`.
+/// New document locations are allocated from `alloc`.
+pub fn merge_apply<'alloc, N: AsNode>(
+    doc: &mut HeapNode<'alloc>,
+    patch: &N,
+    alloc: &'alloc bumpalo::Bump,
+) {
+    let Node::Object(patch) = patch.as_node() else {
+        *doc = HeapNode::from_node(patch, alloc);
+        return;
+    };
+    if !matches!(doc, HeapNode::Object(_)) {
+        *doc = HeapNode::Object(BumpVec::new());
+    }
+    let HeapNode::Object(fields) = doc else {
+        unreachable!("doc is an object")
+    };
+
+    for field in patch.iter() {
+        if let Node::Null = field.value().as_node() {
+            fields.remove_property(field.property());
+        } else {
+            merge_apply(
+                fields.insert_property(field.property(), alloc),
+                field.value(),
+                alloc,
+            );
+        }
+    }
+}
+
+impl<'n, N: AsNode> serde::Serialize for Operation<'n, N> {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let (op, from, path, value) = match self {
+            Operation::Add { path, value } => ("add", None, path, Some(value)),
+            Operation::Remove { path } => ("remove", None, path, None),
+            Operation::Replace { path, value } => ("replace", None, path, Some(value)),
+            Operation::Move { from, path } => ("move", Some(from), path, None),
+            Operation::Copy { from, path } => ("copy", Some(from), path, None),
+            Operation::Test { path, value } => ("test", None, path, Some(value)),
+        };
+
+        let mut map = s.serialize_map(None)?;
+        map.serialize_entry("op", op)?;
+        if let Some(from) = from {
+            map.serialize_entry("from", from)?;
+        }
+        map.serialize_entry("path", path)?;
+        if let Some(value) = value {
+            map.serialize_entry("value", &SerPolicy::default().on(*value))?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ArchivedNode, SerPolicy};
+    use serde_json::{json, Value};
+
+    fn to_value(doc: &HeapNode) -> Value {
+        serde_json::to_value(SerPolicy::default().on(doc)).unwrap()
+    }
+
+    #[test]
+    fn test_diff_and_apply() {
+        let lhs = json!({
+            "same": {"a": 1},
+            "removed": true,
+            "changed": {"a": 1, "b": [1, 2, 3], "c": "c"},
+            "shorter": [1, 2, 3],
+            "longer": [1],
+            "type": [1],
+            "a/b": 1,
+        });
+        let rhs = json!({
+            "same": {"a": 1},
+            "added": {"new": null},
+            "changed": {"a": 2, "b": [1, 4, 3], "d": "d"},
+            "shorter": [1],
+            "longer": [1, 2, 3],
+            "type": {"one": 1},
+            "a/b": 1.0,
+        });
+
+        let patch = diff(&lhs, &rhs);
+
+        insta::assert_json_snapshot!(patch, @r###"
+        [
+          {
+            "op": "add",
+            "path": "/added",
+            "value": {
+              "new": null
+            }
+          },
+          {
+            "op": "replace",
+            "path": "/changed/a",
+            "value": 2
+          },
+          {
+            "op": "replace",
+            "path": "/changed/b/1",
+            "value": 4
+          },
+          {
+            "op": "remove",
+            "path": "/changed/c"
+          },
+          {
+            "op": "add",
+            "path": "/changed/d",
+            "value": "d"
+          },
+          {
+            "op": "add",
+            "path": "/longer/1",
+            "value": 2
+          },
+          {
+            "op": "add",
+            "path": "/longer/2",
+            "value": 3
+          },
+          {
+            "op": "remove",
+            "path": "/removed"
+          },
+          {
+            "op": "remove",
+            "path": "/shorter/2"
+          },
+          {
+            "op": "remove",
+            "path": "/shorter/1"
+          },
+          {
+            "op": "replace",
+            "path": "/type",
+            "value": {
+              "one": 1
+            }
+          }
+        ]
+        "###);
+
+        // Apply the patch to an ArchivedNode-derived HeapNode.
+        let alloc = HeapNode::new_allocator();
+        let archive = HeapNode::from_node(&lhs, &alloc).to_archive();
+        let mut doc = HeapNode::from_node(ArchivedNode::from_archive(&archive), &alloc);
+
+        apply(&mut doc, &patch, &alloc).unwrap();
+        assert!(compare(&doc, &rhs).is_eq());
+
+        // A serialized patch may be parsed and applied.
+        let patch = serde_json::to_value(&patch).unwrap();
+        let mut doc = HeapNode::from_node(&lhs, &alloc);
+        apply(&mut doc, &parse(&patch).unwrap(), &alloc).unwrap();
+        assert!(compare(&doc, &rhs).is_eq());
+
+        // Identical documents have an empty patch.
+        assert!(diff(&rhs, &doc).is_empty());
+    }
+
+    #[test]
+    fn test_apply_operations() {
+        let alloc = HeapNode::new_allocator();
+        let fixture = json!({"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}, "arr": [1, 2]});
+
+        let run = |patch: Value| -> Result<Value, String> {
+            let mut doc = HeapNode::from_node(&fixture, &alloc);
+            let ops = parse(&patch).map_err(|err| err.to_string())?;
+            apply(&mut doc, &ops, &alloc).map_err(|err| err.to_string())?;
+            Ok(to_value(&doc))
+        };
+
+        let outcomes = vec![
+            run(json!([
+                {"op": "move", "from": "/foo/waldo", "path": "/qux/thud"},
+                {"op": "copy", "from": "/qux", "path": "/arr/1"},
+                {"op": "add", "path": "/arr/-", "value": 3},
+                {"op": "test", "path": "/arr/2", "value": 2.0},
+                {"op": "replace", "path": "/foo", "value": null},
+            ])),
+            run(json!([{"op": "replace", "path": "", "value": [true]}])),
+            run(json!([{"op": "add", "path": "/arr/3", "value": 3}])),
+            run(json!([{"op": "remove", "path": "/missing"}])),
+            run(json!([{"op": "remove", "path": ""}])),
+            run(json!([{"op": "move", "from": "/foo", "path": "/foo/bar/baz"}])),
+            run(json!([{"op": "test", "path": "/arr/0", "value": "1"}])),
+            run(json!([{"op": "add", "path": "/foo/bar/baz", "value": 1}])),
+            run(json!([{"op": "frob", "path": ""}])),
+            run(json!([{"op": "copy", "path": "/foo"}])),
+            run(json!({"op": "add"})),
+        ];
+
+        insta::assert_json_snapshot!(outcomes, @r###"
+        [
+          {
+            "Ok": {
+              "arr": [
+                1,
+                {
+                  "corge": "grault",
+                  "thud": "fred"
+                },
+                2,
+                3
+              ],
+              "foo": null,
+              "qux": {
+                "corge": "grault",
+                "thud": "fred"
+              }
+            }
+          },
+          {
+            "Ok": [
+              true
+            ]
+          },
+          {
+            "Err": "array index of location '/arr/3' is out of bounds"
+          },
+          {
+            "Err": "location '/missing' does not exist"
+          },
+          {
+            "Err": "the document root cannot be removed"
+          },
+          {
+            "Err": "location '/foo' cannot be moved into its own child '/foo/bar/baz'"
+          },
+          {
+            "Err": "test of location '/arr/0' failed"
+          },
+          {
+            "Err": "location '/foo/bar/baz' does not exist"
+          },
+          {
+            "Err": "JSON Patch operation 0 is malformed: unknown 'op'"
+          },
+          {
+            "Err": "JSON Patch operation 0 is malformed: missing or invalid 'from'"
+          },
+          {
+            "Err": "a JSON Patch must be an array of operations"
+          }
+        ]
+        "###);
+    }
+
+    #[test]
+    fn test_merge_patch() {
+        let alloc = HeapNode::new_allocator();
+
+        // Test cases of RFC 7386 Appendix A.
+        for (target, patch, expect) in [
+            (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+            (
+                json!({"a": "b"}),
+                json!({"b": "c"}),
+                json!({"a": "b", "b": "c"}),
+            ),
+            (json!({"a": "b"}), json!({"a": null}), json!({})),
+            (
+                json!({"a": "b", "b": "c"}),
+                json!({"a": null}),
+                json!({"b": "c"}),
+            ),
+            (json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"})),
+            (json!({"a": "c"}), json!({"a": ["b"]}), json!({"a": ["b"]})),
+            (
+                json!({"a": {"b": "c"}}),
+                json!({"a": {"b": "d", "c": null}}),
+                json!({"a": {"b": "d"}}),
+            ),
+            (
+                json!({"a": [{"b": "c"}]}),
+                json!({"a": [1]}),
+                json!({"a": [1]}),
+            ),
+            (json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"])),
+            (json!({"a": "b"}), json!(["c"]), json!(["c"])),
+            (json!({"a": "foo"}), json!(null), json!(null)),
+            (json!({"a": "foo"}), json!("bar"), json!("bar")),
+            (
+                json!({"e": null}),
+                json!({"a": 1}),
+                json!({"a": 1, "e": null}),
+            ),
+            (
+                json!([1, 2]),
+                json!({"a": "b", "c": null}),
+                json!({"a": "b"}),
+            ),
+            (
+                json!({}),
+                json!({"a": {"bb": {"ccc": null}}}),
+                json!({"a": {"bb": {}}}),
+            ),
+        ] {
+            let mut doc = HeapNode::from_node(&target, &alloc);
+            merge_apply(&mut doc, &patch, &alloc);
+            assert_eq!(to_value(&doc), expect, "{target} {patch}");
+
+            // A computed merge patch of `target` and `expect` is equivalent.
+            let mut doc = HeapNode::from_node(&target, &alloc);
+            if let Some(patch) = merge_diff(&target, &expect, &alloc) {
+                merge_apply(&mut doc, &patch, &alloc);
+            }
+            assert_eq!(to_value(&doc), expect, "{target} {patch}");
+        }
+
+        let patch = merge_diff(
+            &json!({"a": 1, "b": {"c": [1, 2]}, "d": {"e": true}}),
+            &json!({"b": {"c": [1, 3]}, "d": {"e": true}, "f": null}),
+            &alloc,
+        )
+        .unwrap();
+
+        insta::assert_json_snapshot!(to_value(&patch), @r###"
+        {
+          "a": null,
+          "b": {
+            "c": [
+              1,
+              3
+            ]
+          },
+          "f": null
+        }
+        "###);
+    }
+}
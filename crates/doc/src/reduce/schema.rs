@@ -1,7 +1,7 @@
 use super::{count_nodes, Cursor, Error, Result};
 use crate::{
     shape::limits,
-    shape::{limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT, schema::to_schema},
+    shape::{limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT, schema::to_schema, Provenance},
     AsNode, HeapNode, SerPolicy, Shape,
 };
 use json::schema::index::IndexBuilder;
@@ -30,6 +30,7 @@ pub fn json_schema_merge<'alloc, L: AsNode, R: AsNode>(
 
     let mut merged_shape = Shape::union(left, right);
     limits::enforce_shape_complexity_limit(&mut merged_shape, DEFAULT_SCHEMA_COMPLEXITY_LIMIT);
+    inline_references(&mut merged_shape);
 
     // Convert back from `Shape` into `HeapNode`.
     let merged_doc = serde_json::to_value(to_schema(merged_shape)).unwrap();
@@ -37,6 +38,30 @@ pub fn json_schema_merge<'alloc, L: AsNode, R: AsNode>(
     Ok(merged_doc)
 }
 
+// Referenced schemas are bundled by `to_schema` as resources having their own `$id`,
+// which would collide with the fixed URL under which merged schemas are built.
+// Map them in-place instead.
+fn inline_references(shape: &mut Shape) {
+    if let Provenance::Reference(_) = shape.provenance {
+        shape.provenance = Provenance::Inline;
+    }
+    for prop in shape.object.properties.iter_mut() {
+        inline_references(&mut prop.shape);
+    }
+    for pattern in shape.object.pattern_properties.iter_mut() {
+        inline_references(&mut pattern.shape);
+    }
+    if let Some(addl) = shape.object.additional_properties.as_mut() {
+        inline_references(addl);
+    }
+    for item in shape.array.tuple.iter_mut() {
+        inline_references(item);
+    }
+    if let Some(addl) = shape.array.additional_items.as_mut() {
+        inline_references(addl);
+    }
+}
+
 fn shape_from_node(node: serde_json::Value) -> Result<Shape> {
     let url = url::Url::parse("json-schema-reduction:///").unwrap();
 
@@ -108,4 +133,45 @@ mod test {
             ],
         )
     }
+
+    #[test]
+    fn test_merge_json_schemas_with_references() {
+        // References are mapped in-place, and their merged schema may be merged again.
+        run_reduce_cases(
+            json!({ "reduce": { "strategy": "jsonSchemaMerge" } }),
+            vec![
+                Partial {
+                    rhs: json!({
+                        "$defs": {"str": {"type": "string", "maxLength": 20}},
+                        "$ref": "#/$defs/str",
+                    }),
+                    expect: Ok(json!({
+                        "$defs": {"str": {"type": "string", "maxLength": 20}},
+                        "$ref": "#/$defs/str",
+                    })),
+                },
+                Partial {
+                    rhs: json!({
+                        "$defs": {"str": {"type": "string", "maxLength": 30}},
+                        "$ref": "#/$defs/str",
+                    }),
+                    expect: Ok(json!({
+                        "$schema": "https://json-schema.org/draft/2019-09/schema",
+                        "type": "string",
+                        "maxLength": 30,
+                    })),
+                },
+                Partial {
+                    rhs: json!({
+                        "type": "string",
+                        "minLength": 1,
+                    }),
+                    expect: Ok(json!({
+                        "$schema": "https://json-schema.org/draft/2019-09/schema",
+                        "type": "string",
+                    })),
+                },
+            ],
+        )
+    }
 }
@@ -0,0 +1,547 @@
+// This module compares two Shapes, such as the current and proposed schemas
+// of a collection, and reports how each of their locations has changed.
+use super::{location::Exists, *};
+use crate::ptr::{Pointer, Token};
+use std::collections::BTreeMap;
+
+/// Kind classifies a Change of a location between two Shapes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Kind {
+    /// The location permits values which it previously did not.
+    Widening,
+    /// The location no longer permits values which it previously did.
+    Narrowing,
+    /// The types, existence, enumeration, or format of a key location changed,
+    /// which alters how documents are keyed.
+    KeyAffecting,
+    /// The reduction strategy of the location changed,
+    /// which alters how documents are reduced.
+    ReductionChanging,
+}
+
+/// Change of a single location between two Shapes.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize)]
+pub struct Change {
+    /// JSON pointer of the changed location.
+    pub ptr: String,
+    /// Kind of the change.
+    pub kind: Kind,
+    /// Human-readable description of the change.
+    pub description: String,
+}
+
+/// Report of Changes between a previous and next Shape, ordered on location.
+#[derive(Debug, Default, Clone, Eq, PartialEq, serde::Serialize)]
+pub struct Report {
+    pub changes: Vec<Change>,
+}
+
+impl Report {
+    /// Is the next Shape a backward-compatible evolution of the previous Shape?
+    /// Documents which were valid under the previous Shape remain valid,
+    /// and are keyed and reduced in the same way.
+    pub fn is_backward_compatible(&self) -> bool {
+        self.changes.iter().all(|c| c.kind == Kind::Widening)
+    }
+
+    /// Is the next Shape a forward-compatible evolution of the previous Shape?
+    /// Documents which are valid under the next Shape were also valid under
+    /// the previous Shape, and are keyed and reduced in the same way.
+    pub fn is_forward_compatible(&self) -> bool {
+        self.changes.iter().all(|c| c.kind == Kind::Narrowing)
+    }
+}
+
+/// Compare the `previous` and `next` Shapes at each of their locations,
+/// where `key` is the composite key of documents having these Shapes.
+pub fn compare(previous: &Shape, next: &Shape, key: &[Pointer]) -> Report {
+    // Union the locations of both Shapes, and whether each is a pattern.
+    let mut locations = BTreeMap::new();
+    for (ptr, pattern, _, _) in previous.locations().into_iter().chain(next.locations()) {
+        *locations.entry(ptr).or_insert(false) |= pattern;
+    }
+
+    let mut changes = Vec::new();
+
+    for (ptr, pattern) in locations {
+        let (prev_shape, prev_exists) = locate(previous, &ptr, pattern);
+        let (next_shape, next_exists) = locate(next, &ptr, pattern);
+
+        compare_location(
+            &ptr.to_string(),
+            key.contains(&ptr),
+            (prev_shape, prev_exists),
+            (next_shape, next_exists),
+            &mut changes,
+        );
+    }
+
+    Report { changes }
+}
+
+// Locate a pointer within the Shape. Pattern locations are located using
+// their literal pattern, or `additional*` Shapes for `*` and `-` tokens,
+// where `Shape::locate` would instead treat these as concrete properties.
+fn locate<'s>(shape: &'s Shape, ptr: &Pointer, pattern: bool) -> (&'s Shape, Exists) {
+    if !pattern {
+        return shape.locate(ptr);
+    }
+    let mut shape = shape;
+
+    for token in ptr.iter() {
+        let next = match token {
+            Token::NextProperty => shape.object.additional_properties.as_deref(),
+            Token::NextIndex => shape.array.additional_items.as_deref(),
+            Token::Index(index) => shape.array.tuple.get(*index).or_else(|| {
+                let index = index.to_string();
+                obj_location(&shape.object, &index)
+            }),
+            Token::Property(property) => obj_location(&shape.object, property),
+        };
+
+        match next {
+            Some(next) => shape = next,
+            None => return (&ANYTHING, Exists::Implicit),
+        }
+    }
+
+    if shape.type_ == types::INVALID {
+        (shape, Exists::Cannot)
+    } else {
+        (shape, Exists::May)
+    }
+}
+
+fn obj_location<'s>(obj: &'s ObjShape, name: &str) -> Option<&'s Shape> {
+    if let Some(property) = obj.properties.iter().find(|p| *p.name == *name) {
+        Some(&property.shape)
+    } else {
+        obj.pattern_properties
+            .iter()
+            .find(|p| p.re.as_str() == name)
+            .map(|p| &p.shape)
+    }
+}
+
+fn compare_location(
+    ptr: &str,
+    is_key: bool,
+    (prev, prev_exists): (&Shape, Exists),
+    (next, next_exists): (&Shape, Exists),
+    out: &mut Vec<Change>,
+) {
+    let mut push = |kind, description: String| {
+        out.push(Change {
+            ptr: ptr.to_string(),
+            kind,
+            description,
+        })
+    };
+    // Changes to the types, existence, enumeration, or format of a key location
+    // affect how documents are keyed, rather than simply widening or narrowing.
+    let (widening, narrowing) = if is_key {
+        (Kind::KeyAffecting, Kind::KeyAffecting)
+    } else {
+        (Kind::Widening, Kind::Narrowing)
+    };
+
+    // Compare whether the location may be undefined, and whether it may exist.
+    let prev_present = !prev_exists.cannot();
+    let next_present = !next_exists.cannot();
+
+    if prev_present && next_present {
+        match (prev_exists.must(), next_exists.must()) {
+            (true, false) => push(widening, "location is no longer required".to_string()),
+            (false, true) => push(narrowing, "location is now required".to_string()),
+            _ => {}
+        }
+    } else if prev_present {
+        push(narrowing, "location can no longer exist".to_string());
+        return;
+    } else if next_present {
+        push(widening, "location may now exist".to_string());
+        return;
+    } else {
+        return; // Location cannot exist in either Shape.
+    }
+
+    let added = next.type_ - prev.type_;
+    let removed = prev.type_ - next.type_;
+
+    if added != types::INVALID {
+        push(widening, format!("types {added} were added"));
+    }
+    if removed != types::INVALID {
+        push(narrowing, format!("types {removed} were removed"));
+    }
+
+    match (&prev.enum_, &next.enum_) {
+        (None, Some(_)) => push(
+            narrowing,
+            "values are now restricted to an enumeration".to_string(),
+        ),
+        (Some(_), None) => push(
+            widening,
+            "values are no longer restricted to an enumeration".to_string(),
+        ),
+        (Some(prev), Some(next)) => {
+            if next.iter().any(|v| !prev.contains(v)) {
+                push(widening, "enumeration values were added".to_string());
+            }
+            if prev.iter().any(|v| !next.contains(v)) {
+                push(narrowing, "enumeration values were removed".to_string());
+            }
+        }
+        (None, None) => {}
+    }
+
+    // Type-specific keywords are compared only if both Shapes admit the type.
+    let both = prev.type_ & next.type_;
+
+    if both.overlaps(types::STRING) {
+        compare_lower(
+            "minLength",
+            Some(prev.string.min_length),
+            Some(next.string.min_length),
+            &mut push,
+        );
+        compare_upper(
+            "maxLength",
+            prev.string.max_length,
+            next.string.max_length,
+            &mut push,
+        );
+
+        match (&prev.string.format, &next.string.format) {
+            (None, Some(next)) => push(narrowing, format!("format {next} was added")),
+            (Some(prev), None) => push(widening, format!("format {prev} was removed")),
+            (Some(prev), Some(next)) if prev != next => {
+                push(narrowing, format!("format changed from {prev} to {next}"))
+            }
+            _ => {}
+        }
+    }
+    if both.overlaps(types::INT_OR_FRAC) {
+        compare_lower(
+            "minimum",
+            prev.numeric.minimum,
+            next.numeric.minimum,
+            &mut push,
+        );
+        compare_upper(
+            "maximum",
+            prev.numeric.maximum,
+            next.numeric.maximum,
+            &mut push,
+        );
+    }
+    if both.overlaps(types::ARRAY) {
+        compare_lower(
+            "minItems",
+            Some(prev.array.min_items),
+            Some(next.array.min_items),
+            &mut push,
+        );
+        compare_upper(
+            "maxItems",
+            prev.array.max_items,
+            next.array.max_items,
+            &mut push,
+        );
+    }
+
+    if prev.reduction != next.reduction {
+        push(
+            Kind::ReductionChanging,
+            format!(
+                "reduction changed from {} to {}",
+                reduction_str(&prev.reduction),
+                reduction_str(&next.reduction)
+            ),
+        );
+    }
+}
+
+// Compare lower bounds, where None is unbounded.
+fn compare_lower<T: PartialOrd + std::fmt::Display>(
+    keyword: &str,
+    prev: Option<T>,
+    next: Option<T>,
+    push: &mut impl FnMut(Kind, String),
+) {
+    match (prev, next) {
+        (Some(prev), None) => push(Kind::Widening, format!("{keyword} {prev} was removed")),
+        (None, Some(next)) => push(Kind::Narrowing, format!("{keyword} {next} was added")),
+        (Some(prev), Some(next)) if next < prev => push(
+            Kind::Widening,
+            format!("{keyword} was lowered from {prev} to {next}"),
+        ),
+        (Some(prev), Some(next)) if next > prev => push(
+            Kind::Narrowing,
+            format!("{keyword} was raised from {prev} to {next}"),
+        ),
+        _ => {}
+    }
+}
+
+// Compare upper bounds, where None is unbounded.
+fn compare_upper<T: PartialOrd + std::fmt::Display>(
+    keyword: &str,
+    prev: Option<T>,
+    next: Option<T>,
+    push: &mut impl FnMut(Kind, String),
+) {
+    match (prev, next) {
+        (Some(prev), None) => push(Kind::Widening, format!("{keyword} {prev} was removed")),
+        (None, Some(next)) => push(Kind::Narrowing, format!("{keyword} {next} was added")),
+        (Some(prev), Some(next)) if next > prev => push(
+            Kind::Widening,
+            format!("{keyword} was raised from {prev} to {next}"),
+        ),
+        (Some(prev), Some(next)) if next < prev => push(
+            Kind::Narrowing,
+            format!("{keyword} was lowered from {prev} to {next}"),
+        ),
+        _ => {}
+    }
+}
+
+fn reduction_str(reduction: &Reduction) -> String {
+    match reduction {
+        Reduction::Unset => "unset".to_string(),
+        Reduction::Multiple => "multiple strategies".to_string(),
+        Reduction::Strategy(strategy) => serde_json::to_string(strategy).unwrap(),
+    }
+}
+
+// Shape of locations which are not known to a Shape, which may take any value.
+static ANYTHING: Shape = Shape::anything();
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn changes(previous: &str, next: &str, key: &[&str]) -> (Report, Vec<(String, Kind)>) {
+        let key = key.iter().map(|k| Pointer::from_str(k)).collect::<Vec<_>>();
+        let report = compare(&shape_from(previous), &shape_from(next), &key);
+
+        let changes = report
+            .changes
+            .iter()
+            .map(|c| (format!("{}: {}", c.ptr, c.description), c.kind))
+            .collect();
+
+        (report, changes)
+    }
+
+    #[test]
+    fn test_identical_shapes() {
+        let schema = r#"
+        type: object
+        reduce: {strategy: merge}
+        required: [id]
+        properties:
+            id: {type: string}
+            count: {type: integer, reduce: {strategy: sum}}
+        "#;
+        let (report, changes) = changes(schema, schema, &["/id"]);
+
+        assert_eq!(changes, vec![]);
+        assert!(report.is_backward_compatible());
+        assert!(report.is_forward_compatible());
+    }
+
+    #[test]
+    fn test_widening() {
+        let (report, changes) = changes(
+            r#"
+            type: object
+            required: [id, name]
+            properties:
+                id: {type: string}
+                name: {type: string, maxLength: 32, format: email}
+                tags:
+                    type: array
+                    maxItems: 5
+                    items: {enum: [a, b]}
+                score: {type: integer, minimum: 0, maximum: 100}
+            additionalProperties: false
+            "#,
+            r#"
+            type: object
+            required: [id]
+            properties:
+                id: {type: string}
+                name: {type: [string, "null"]}
+                tags:
+                    type: array
+                    items: {enum: [a, b, c]}
+                score: {type: number, minimum: -10}
+            additionalProperties: {type: string}
+            "#,
+            &["/id"],
+        );
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    "/name: location is no longer required".to_string(),
+                    Kind::Widening
+                ),
+                (
+                    r#"/name: types "null" were added"#.to_string(),
+                    Kind::Widening
+                ),
+                (
+                    "/name: maxLength 32 was removed".to_string(),
+                    Kind::Widening
+                ),
+                (
+                    "/name: format email was removed".to_string(),
+                    Kind::Widening
+                ),
+                (
+                    r#"/score: types "fractional" were added"#.to_string(),
+                    Kind::Widening
+                ),
+                (
+                    "/score: minimum was lowered from 0 to -10".to_string(),
+                    Kind::Widening
+                ),
+                (
+                    "/score: maximum 100 was removed".to_string(),
+                    Kind::Widening
+                ),
+                ("/tags: maxItems 5 was removed".to_string(), Kind::Widening),
+                (
+                    "/tags/-: enumeration values were added".to_string(),
+                    Kind::Widening
+                ),
+                ("/*: location may now exist".to_string(), Kind::Widening),
+            ]
+        );
+        assert!(report.is_backward_compatible());
+        assert!(!report.is_forward_compatible());
+    }
+
+    #[test]
+    fn test_narrowing_and_key_and_reduction_changes() {
+        let (report, changes) = changes(
+            r#"
+            type: object
+            reduce: {strategy: merge}
+            required: [id]
+            properties:
+                id: {type: [integer, string]}
+                count: {type: integer, reduce: {strategy: sum}}
+                removed: {type: string}
+                nested:
+                    type: object
+                    properties:
+                        name: {type: string}
+            "#,
+            r#"
+            type: object
+            reduce: {strategy: merge}
+            required: [id, nested]
+            properties:
+                id: {type: string}
+                count: {type: integer, minimum: 0, reduce: {strategy: maximize}}
+                removed: false
+                nested:
+                    type: object
+                    properties:
+                        name: {type: string, minLength: 1}
+            "#,
+            &["/id"],
+        );
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    "/count: minimum 0 was added".to_string(),
+                    Kind::Narrowing
+                ),
+                (
+                    r#"/count: reduction changed from {"strategy":"sum"} to {"strategy":"maximize","key":[]}"#
+                        .to_string(),
+                    Kind::ReductionChanging
+                ),
+                (
+                    r#"/id: types "integer" were removed"#.to_string(),
+                    Kind::KeyAffecting
+                ),
+                (
+                    "/nested: location is now required".to_string(),
+                    Kind::Narrowing
+                ),
+                (
+                    "/nested/name: minLength was raised from 0 to 1".to_string(),
+                    Kind::Narrowing
+                ),
+                (
+                    "/removed: location can no longer exist".to_string(),
+                    Kind::Narrowing
+                ),
+            ]
+        );
+        assert!(!report.is_backward_compatible());
+        assert!(!report.is_forward_compatible());
+
+        assert_eq!(
+            serde_json::to_value(&report.changes[2]).unwrap(),
+            serde_json::json!({
+                "ptr": "/id",
+                "kind": "keyAffecting",
+                "description": "types \"integer\" were removed",
+            })
+        );
+    }
+
+    #[test]
+    fn test_key_enum_and_format_changes() {
+        let (report, changes) = changes(
+            r#"
+            type: object
+            required: [id, kind]
+            properties:
+                id: {type: string}
+                kind: {type: string, enum: [a, b]}
+                other: {type: string, enum: [a, b]}
+            "#,
+            r#"
+            type: object
+            required: [id, kind]
+            properties:
+                id: {type: string, format: integer}
+                kind: {type: string, enum: [a]}
+                other: {type: string, enum: [a]}
+            "#,
+            &["/id", "/kind"],
+        );
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    "/id: format integer was added".to_string(),
+                    Kind::KeyAffecting
+                ),
+                (
+                    "/kind: enumeration values were removed".to_string(),
+                    Kind::KeyAffecting
+                ),
+                (
+                    "/other: enumeration values were removed".to_string(),
+                    Kind::Narrowing
+                ),
+            ]
+        );
+        assert!(!report.is_backward_compatible());
+        assert!(!report.is_forward_compatible());
+    }
+}
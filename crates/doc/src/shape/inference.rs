@@ -73,6 +73,7 @@ impl Shape {
             matches!(
                 kw,
                 Keyword::Application(Application::Ref(_), _)
+                | Keyword::Application(Application::DynamicRef(_), _)
                 | Keyword::Application(Application::Def{ .. }, _)
                 | Keyword::Application(Application::Definition{ .. }, _)
                 | Keyword::Annotation(Annotation::Core(CoreAnnotation::Default(_)))
@@ -180,7 +181,7 @@ impl Shape {
                     // here as new annotations are added.
                     Annotation::Secret(b) => shape.secret = Some(*b),
                     Annotation::Multiline(_) => {}
-                    Annotation::Advanced(_) => {}
+                    Annotation::Advanced(b) => shape.advanced = Some(*b),
                     Annotation::Order(_) => {}
                     Annotation::Discriminator(_) => {}
                 },
@@ -194,10 +195,11 @@ impl Shape {
                     shape.array.additional_items =
                         Some(Box::new(Shape::infer_inner(schema, index, visited)));
                 }
-                Keyword::Application(Application::Items { index: Some(i) }, schema) => {
-                    shape.array.tuple.extend(
-                        std::iter::repeat(Shape::anything()).take(1 + i - shape.array.tuple.len()),
-                    );
+                Keyword::Application(Application::Items { index: Some(i) }, schema)
+                | Keyword::Application(Application::PrefixItems { index: i }, schema) => {
+                    if shape.array.tuple.len() <= *i {
+                        shape.array.tuple.resize(1 + i, Shape::anything());
+                    }
                     shape.array.tuple[*i] = Shape::infer_inner(schema, index, visited);
                 }
                 Keyword::Application(Application::AdditionalItems, schema) => {
@@ -285,7 +287,9 @@ impl Shape {
 
         for kw in &schema.kw {
             match kw {
-                Keyword::Application(Application::Ref(uri), _) => {
+                // Dynamic references are inferred from their static referent.
+                Keyword::Application(Application::Ref(uri), _)
+                | Keyword::Application(Application::DynamicRef(uri), _) => {
                     let mut referent = if visited.iter().any(|u| u.as_str() == uri.as_str()) {
                         Shape::anything() // Don't re-visit this location.
                     } else if let Some(schema) = index.fetch(uri) {
@@ -387,6 +391,7 @@ mod test {
                 default: john.doe@gmail.com
                 format: email
                 secret: true
+                advanced: true
                 "#,
                 // Mix of anyOf, oneOf, & ref.
                 r#"
@@ -394,6 +399,7 @@ mod test {
                   aDef:
                     type: [string, array]
                     secret: true
+                    advanced: true
                 allOf:
                 - title: a-title
                 - description: a-description
@@ -444,6 +450,9 @@ mod test {
                   - anyOf:
                     - secret: true
                     - secret: true
+                  - anyOf:
+                    - advanced: true
+                    - advanced: true
                   - anyOf:
                     - title: other-title
                     - description: other-description
@@ -453,6 +462,7 @@ mod test {
                     - default: jane.doe@gmail.com
                     - format: date-time
                     - secret: false
+                    - advanced: false
                 "#,
             ],
             Shape {
@@ -468,6 +478,7 @@ mod test {
                     None,
                 ))),
                 secret: Some(true),
+                advanced: Some(true),
                 string: StringShape {
                     content_encoding: Some("base64".into()),
                     content_type: Some("some/thing".into()),
@@ -590,6 +601,43 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_custom_format() {
+        // The format registry is shared by all tests,
+        // so this format detects only values having a unique prefix.
+        let ticker = json::schema::formats::register_custom("doc-ticker", true, |val| {
+            val.strip_prefix("doc-ticker:")
+                .map_or(false, |t| {
+                    t.len() == 4 && t.chars().all(|c| c.is_ascii_uppercase())
+                })
+                .into()
+        });
+
+        infer_test(
+            &[
+                "{type: string, format: doc-ticker}",
+                "{allOf: [{type: string}, {format: doc-ticker}]}",
+            ],
+            Shape {
+                type_: types::STRING,
+                provenance: Provenance::Inline,
+                string: StringShape {
+                    format: Some(Format::Custom(ticker)),
+                    ..StringShape::new()
+                },
+                ..Shape::anything()
+            },
+        );
+
+        // Custom formats are detected when widening, and are carried through to schemas.
+        let mut shape = Shape::nothing();
+        shape.widen(&json!("doc-ticker:ACME"));
+        assert_eq!(shape.string.format, Some(Format::Custom(ticker)));
+
+        let schema = serde_json::to_value(crate::shape::schema::to_schema(shape)).unwrap();
+        assert_eq!(schema["format"], json!("doc-ticker"));
+    }
+
     #[test]
     fn test_enum_type_extraction() {
         assert_eq!(
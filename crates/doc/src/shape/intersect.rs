@@ -219,6 +219,7 @@ impl Shape {
         let provenance = lhs.provenance.intersect(rhs.provenance);
         let default = lhs.default.or(rhs.default);
         let secret = lhs.secret.or(rhs.secret);
+        let advanced = lhs.advanced.or(rhs.advanced);
 
         let mut annotations = rhs.annotations;
         annotations.extend(lhs.annotations.into_iter());
@@ -261,6 +262,7 @@ impl Shape {
             provenance,
             default,
             secret,
+            advanced,
             annotations,
             string,
             array,
@@ -1,28 +1,65 @@
 // This module defines limits which are used to simplify complex,
 // typically inferred schema Shapes.
-use super::*;
-use crate::ptr::Token;
+use super::{location::Exists, *};
+use crate::ptr::{Pointer, Token};
 use itertools::Itertools;
-use std::cmp::Ordering;
 
-// Potential future improvement: currently this squashes any non-INVALID `additional*`
-// shape to accept anything, the equivalent to the `true` schema. But really, we just
-// want to remove recursive shapes if we overlap with `OBJECT` or `ARRAY`, and could
-// happily leave other non-recursive/atomic types alone, retaining e.g integer or string bounds.
+// Squash an `additional*` shape of a location which is itself being squashed.
+// Its object and array sub-shapes are removed, as they may recursively nest
+// arbitrarily many further locations, but other non-recursive/atomic portions
+// such as its types, format, and string or numeric bounds are retained.
 fn squash_addl(props: Option<Box<Shape>>) -> Option<Box<Shape>> {
     match props {
         Some(inner) if inner.type_.eq(&types::INVALID) => Some(Box::new(Shape::nothing())),
-        Some(_) | None => None,
+        Some(mut inner) => {
+            inner.array = ArrayShape::new();
+            inner.object = ObjShape::new();
+
+            if *inner == Shape::anything() {
+                None
+            } else {
+                Some(inner)
+            }
+        }
+        None => None,
+    }
+}
+
+// Squash the shape of property `prop_name`, which was removed from `shape`,
+// into the first matching pattern or else into `additionalProperties`.
+fn squash_property(shape: &mut Shape, prop_name: &str, shape_to_squash: Shape) {
+    // First check to see if it matches a pattern
+    // and if so squash into that pattern's shape
+    if let Some(pattern) = shape
+        .object
+        .pattern_properties
+        .iter_mut()
+        .find(|pattern| regex_matches(&pattern.re, prop_name))
+    {
+        pattern.shape = Shape::union(
+            // Ideally we'd use a function like `replace_with` to allow replacing
+            // pattern.shape with a value mapped from its previous value, but
+            // that function doesn't exist yet. See https://github.com/rust-lang/rfcs/pull/1736
+            // Instead, we must replace it with something temporarily while
+            // Shape::union runs. Once it finishes, this `Shape::nothing()` is discarded.
+            std::mem::replace(&mut pattern.shape, Shape::nothing()),
+            shape_to_squash,
+        )
+    } else if let Some(addl_properties) = shape.object.additional_properties.take() {
+        shape.object.additional_properties =
+            Some(Box::new(Shape::union(*addl_properties, shape_to_squash)));
+    } else {
+        shape.object.additional_properties = Some(Box::new(shape_to_squash))
     }
 }
 
 // Squashing a shape inside an array tuple is special because the location
 // of shapes inside the tuple is _itself_ the key into that container.
 // This means that if we do anything to shift the keys of still-existing shapes,
-// they won't be valid any longer. With that in mind, there's also no good reason
-// to squash one object field over any other, so let's just treat
-// Token::Index and Token::Property as signals to squash _an_ index or property,
-// leaving it up to the implementation to determine which one.
+// they won't be valid any longer. With that in mind, we treat Token::Index
+// as a signal to squash _an_ index, leaving it up to the implementation to
+// determine which one. Object properties are instead squashed by name, so that
+// the squashing order of `enforce_shape_complexity_limit` is respected.
 fn squash_location_inner(shape: &mut Shape, name: &Token) {
     match name {
         // Squashing of `additional*` fields is not possible here because we don't
@@ -49,46 +86,30 @@ fn squash_location_inner(shape: &mut Shape, name: &Token) {
                 shape.array.additional_items = Some(Box::new(shape_to_squash));
             }
         }
-        Token::Property(_) => {
-            // Remove location from parent properties
+        Token::Property(prop_name) => {
+            // Remove location from parent properties. Pattern locations are
+            // also addressed as properties: for these, squash _a_ property.
+            let properties = &mut shape.object.properties;
+            let index = match properties.binary_search_by(|prop| prop.name.as_ref().cmp(prop_name))
+            {
+                Ok(index) => index,
+                Err(_) => properties
+                    .len()
+                    .checked_sub(1)
+                    .expect("No object property to squash"),
+            };
             let ObjProperty {
                 shape: mut shape_to_squash,
                 name: prop_name,
                 ..
-            } = shape
-                .object
-                .properties
-                .pop()
-                .expect("No object property to squash");
+            } = shape.object.properties.remove(index);
 
             shape_to_squash.array.additional_items =
                 squash_addl(shape_to_squash.array.additional_items);
             shape_to_squash.object.additional_properties =
                 squash_addl(shape_to_squash.object.additional_properties);
 
-            // First check to see if it matches a pattern
-            // and if so squash into that pattern's shape
-            if let Some(pattern) = shape
-                .object
-                .pattern_properties
-                .iter_mut()
-                .find(|pattern| regex_matches(&pattern.re, &prop_name))
-            {
-                pattern.shape = Shape::union(
-                    // Ideally we'd use a function like `replace_with` to allow replacing
-                    // pattern.shape with a value mapped from its previous value, but
-                    // that function doesn't exist yet. See https://github.com/rust-lang/rfcs/pull/1736
-                    // Instead, we must replace it with something temporarily while
-                    // Shape::union runs. Once it finishes, this `Shape::nothing()` is discarded.
-                    std::mem::replace(&mut pattern.shape, Shape::nothing()),
-                    shape_to_squash,
-                )
-            } else if let Some(addl_properties) = shape.object.additional_properties.take() {
-                shape.object.additional_properties =
-                    Some(Box::new(Shape::union(*addl_properties, shape_to_squash)));
-            } else {
-                shape.object.additional_properties = Some(Box::new(shape_to_squash))
-            }
+            squash_property(shape, &prop_name, shape_to_squash);
         }
     }
 }
@@ -121,38 +142,185 @@ fn squash_location(shape: &mut Shape, location: &[Token]) {
     }
 }
 
-/// Reduce the size/complexity of a shape while making sure that all
-/// objects that used to pass validation still do.
-pub fn enforce_shape_complexity_limit(shape: &mut Shape, limit: usize) {
-    let mut pointers = shape
+/// Is this object shape map-like? Such objects have many properties
+/// which all take the same types, as is typical of objects keyed on
+/// dynamic values such as user IDs.
+fn is_map_like(obj: &ObjShape) -> bool {
+    obj.properties.len() >= MIN_MAP_PROPERTIES
+        && obj
+            .properties
+            .iter()
+            .map(|prop| prop.shape.type_)
+            .all_equal()
+}
+
+// Do the property names of a map-like object look like dynamic values,
+// such as IDs or dates, rather than a fixed set of field names?
+fn has_dynamic_names(obj: &ObjShape) -> bool {
+    obj.properties
+        .iter()
+        .all(|prop| prop.name.chars().any(|c| c.is_ascii_digit()))
+}
+
+// Step from a shape to one of its sub-shapes.
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Property(usize),
+    Pattern(usize),
+    AdditionalProperties,
+    Tuple(usize),
+    AdditionalItems,
+}
+
+// MapLike is a map-like object shape which is a candidate for collapsing.
+struct MapLike {
+    steps: Vec<Step>, // Steps from the root shape to the object.
+    dynamic: bool,    // Whether its property names look dynamic.
+    locations: usize, // Number of locations within the object.
+}
+
+// Walk `shape` to collect its map-like objects into `maps`,
+// returning the number of locations within `shape`.
+fn map_like_objects(shape: &Shape, steps: &mut Vec<Step>, maps: &mut Vec<MapLike>) -> usize {
+    let mut walk = |step: Step, child: &Shape, maps: &mut Vec<MapLike>| {
+        steps.push(step);
+        let locations = 1 + map_like_objects(child, steps, maps);
+        steps.pop();
+        locations
+    };
+    let mut locations = 0;
+
+    for (index, prop) in shape.object.properties.iter().enumerate() {
+        locations += walk(Step::Property(index), &prop.shape, maps);
+    }
+    for (index, pattern) in shape.object.pattern_properties.iter().enumerate() {
+        locations += walk(Step::Pattern(index), &pattern.shape, maps);
+    }
+    if let Some(addl) = shape.object.additional_properties.as_deref() {
+        locations += walk(Step::AdditionalProperties, addl, maps);
+    }
+    for (index, item) in shape.array.tuple.iter().enumerate() {
+        locations += walk(Step::Tuple(index), item, maps);
+    }
+    if let Some(addl) = shape.array.additional_items.as_deref() {
+        locations += walk(Step::AdditionalItems, addl, maps);
+    }
+
+    if is_map_like(&shape.object) {
+        maps.push(MapLike {
+            steps: steps.clone(),
+            dynamic: has_dynamic_names(&shape.object),
+            locations,
+        });
+    }
+    locations
+}
+
+fn shape_at_mut<'s>(mut shape: &'s mut Shape, steps: &[Step]) -> &'s mut Shape {
+    for step in steps {
+        shape = match *step {
+            Step::Property(index) => &mut shape.object.properties[index].shape,
+            Step::Pattern(index) => &mut shape.object.pattern_properties[index].shape,
+            Step::AdditionalProperties => {
+                shape.object.additional_properties.as_deref_mut().unwrap()
+            }
+            Step::Tuple(index) => &mut shape.array.tuple[index],
+            Step::AdditionalItems => shape.array.additional_items.as_deref_mut().unwrap(),
+        };
+    }
+    shape
+}
+
+// Collapse the properties of map-like objects into their pattern or
+// `additionalProperties` shapes. Unlike squashing, the full shapes of
+// properties are retained, as they're the shape of the map's values.
+//
+// Objects with dynamic-looking property names are always collapsed.
+// Other map-like objects may have many homogeneous but meaningful fields,
+// and are collapsed only while the shape exceeds `limit`, largest first.
+fn collapse_maps(shape: &mut Shape, limit: usize) {
+    loop {
+        let mut maps = Vec::new();
+        map_like_objects(shape, &mut Vec::new(), &mut maps);
+
+        let Some(map) = maps
+            .into_iter()
+            .max_by_key(|map| (map.dynamic, map.locations))
+        else {
+            return;
+        };
+        if !map.dynamic && squashable_locations(shape).len() <= limit {
+            return;
+        }
+
+        let obj = shape_at_mut(shape, &map.steps);
+        for ObjProperty {
+            name, shape: child, ..
+        } in std::mem::take(&mut obj.object.properties)
+        {
+            squash_property(obj, &name, child);
+        }
+    }
+}
+
+// Locations which are candidates for squashing, and whether they exist.
+fn squashable_locations(shape: &Shape) -> Vec<(Pointer, Exists)> {
+    shape
         .locations()
         .into_iter()
-        .filter_map(|(ptr, _, _, _)| match ptr.0.as_slice() {
+        .filter_map(|(ptr, _, _, exists)| match ptr.0.as_slice() {
             // We need to include `/*/foo` in order to squash inside `additional*` subschemas,
             // but we don't want to include those locations that are leaf nodes, since
             // leaf node recursion is squashed every time we squash a concrete property.
             [.., Token::NextIndex] => None,
             [.., Token::NextProperty] => None,
             [] => None,
-            _ => Some(ptr),
+            _ => Some((ptr, exists)),
         })
-        .collect_vec();
+        .collect_vec()
+}
+
+// Rank locations by how populated they're expected to be:
+// required locations were present in every document which was observed,
+// while optional ones may have been present in only a few.
+fn population(exists: Exists) -> u8 {
+    match exists {
+        Exists::Must => 3,
+        Exists::May => 2,
+        Exists::Implicit => 1,
+        Exists::Cannot => 0,
+    }
+}
+
+/// Reduce the size/complexity of a shape while making sure that all
+/// objects that used to pass validation still do.
+///
+/// Map-like objects are first collapsed into typed `additionalProperties`:
+/// those keyed on dynamic-looking names always, and others only as needed.
+/// If the shape remains too complex, then locations are squashed into their
+/// parent's `additional*` shape, deepest and least-populated locations first.
+pub fn enforce_shape_complexity_limit(shape: &mut Shape, limit: usize) {
+    let mut pointers = squashable_locations(shape);
 
     if pointers.len() < limit {
         return;
     }
 
-    pointers.sort_by(|a_ptr, b_ptr| {
-        // order by depth, then by pointer location
-        match a_ptr.0.len().cmp(&b_ptr.0.len()) {
-            // Same depth, stably sort by pointer location
-            Ordering::Equal => a_ptr.cmp(&b_ptr),
-            depth => depth,
-        }
+    collapse_maps(shape, limit);
+    pointers = squashable_locations(shape);
+
+    pointers.sort_by(|(a_ptr, a_exists), (b_ptr, b_exists)| {
+        // order by depth, then by descending population, then by pointer location
+        a_ptr
+            .0
+            .len()
+            .cmp(&b_ptr.0.len())
+            .then_with(|| population(*b_exists).cmp(&population(*a_exists)))
+            .then_with(|| a_ptr.cmp(b_ptr))
     });
 
     while pointers.len() > limit {
-        let location_ptr = pointers
+        let (location_ptr, _) = pointers
             .pop()
             .expect("locations vec was just checked to be non-empty");
 
@@ -160,6 +328,12 @@ pub fn enforce_shape_complexity_limit(shape: &mut Shape, limit: usize) {
     }
 }
 
+/// Objects having at least this many properties, all of the same types,
+/// are considered to be map-like by `enforce_shape_complexity_limit`.
+/// They're collapsed if their property names look dynamic (contain digits),
+/// or if collapsing is required to bring the shape within its limit.
+pub const MIN_MAP_PROPERTIES: usize = 50;
+
 pub const DEFAULT_SCHEMA_COMPLEXITY_LIMIT: usize = 1_000;
 
 #[cfg(test)]
@@ -248,6 +422,10 @@ mod test {
                       minimum: 0
                       maximum: 10000
                     - type: object
+                      additionalProperties:
+                        type: integer
+                        minimum: 0
+                        maximum: 10000
             "#,
             &[json!(root)],
             Some(0),
@@ -412,12 +590,165 @@ mod test {
             type: object
             additionalProperties:
                 type: object
+                additionalProperties:
+                    type: object
             "#,
             &[doc],
             Some(0),
         );
     }
 
+    #[test]
+    fn test_map_like_objects_are_collapsed() {
+        let docs = (0..100)
+            .map(|id| {
+                json!({
+                    "known_key": "hello",
+                    "users": {
+                        format!("user-{id}"): {
+                            "created": "2024-01-01T00:00:00Z",
+                            "visits": 3,
+                        },
+                    },
+                })
+            })
+            .collect_vec();
+
+        // `users` is collapsed into a typed `additionalProperties`,
+        // which brings the shape within the limit without further squashing.
+        widening_snapshot_helper(
+            None,
+            r#"
+            type: object
+            additionalProperties: false
+            required: [known_key, users]
+            properties:
+                known_key:
+                    type: string
+                    minLength: 4
+                    maxLength: 8
+                users:
+                    type: object
+                    additionalProperties:
+                        type: object
+                        additionalProperties: false
+                        required: [created, visits]
+                        properties:
+                            created:
+                                type: string
+                                format: date-time
+                                minLength: 16
+                                maxLength: 32
+                            visits:
+                                type: integer
+                                minimum: 1
+                                maximum: 10
+            "#,
+            &docs,
+            Some(10),
+        );
+    }
+
+    #[test]
+    fn test_map_like_objects_are_collapsed_largest_first() {
+        // Property names which don't look dynamic.
+        let fields = |count: usize| {
+            (0..count)
+                .map(|n| {
+                    let name = format!(
+                        "{}{}",
+                        (b'a' + (n / 26) as u8) as char,
+                        (b'a' + (n % 26) as u8) as char
+                    );
+                    (name, json!("value"))
+                })
+                .collect::<serde_json::Map<_, _>>()
+        };
+        let mut shape = Shape::nothing();
+        shape.widen(&json!({"small": fields(60), "large": fields(80)}));
+
+        // Collapsing `large` alone brings the shape within the limit.
+        enforce_shape_complexity_limit(&mut shape, 100);
+
+        let [large, small] = shape.object.properties.as_slice() else {
+            panic!("expected two properties");
+        };
+        assert_eq!(large.shape.object.properties.len(), 0);
+        assert!(large.shape.object.additional_properties.is_some());
+        assert_eq!(small.shape.object.properties.len(), 60);
+
+        // Objects with dynamic property names are collapsed before larger objects.
+        let ids = (0..60)
+            .map(|n| (format!("id-{n}"), json!(n)))
+            .collect::<serde_json::Map<_, _>>();
+        let mut shape = Shape::nothing();
+        shape.widen(&json!({"fields": fields(80), "ids": ids}));
+
+        enforce_shape_complexity_limit(&mut shape, 130);
+
+        let [fields, ids] = shape.object.properties.as_slice() else {
+            panic!("expected two properties");
+        };
+        assert_eq!(fields.shape.object.properties.len(), 80);
+        assert_eq!(ids.shape.object.properties.len(), 0);
+        assert!(ids.shape.object.additional_properties.is_some());
+    }
+
+    #[test]
+    fn test_squashing_retains_atomic_shapes() {
+        widening_snapshot_helper(
+            None,
+            r#"
+            type: object
+            additionalProperties: false
+            required: [a, b]
+            properties:
+                a:
+                    type: object
+                    additionalProperties:
+                        type: object
+                        additionalProperties:
+                            type: string
+                            format: date-time
+                            minLength: 16
+                            maxLength: 32
+                b:
+                    type: integer
+                    minimum: 1
+                    maximum: 10
+            "#,
+            &[json!({"a": {"x": {"ts": "2024-01-01T00:00:00Z"}}, "b": 1})],
+            Some(2),
+        );
+    }
+
+    #[test]
+    fn test_least_populated_locations_are_squashed_first() {
+        // `optional` was observed in only one document and is squashed,
+        // while `required` (which sorts after it) is retained.
+        widening_snapshot_helper(
+            None,
+            r#"
+            type: object
+            additionalProperties:
+                type: integer
+                minimum: 1
+                maximum: 10
+            required: [required]
+            properties:
+                required:
+                    type: integer
+                    minimum: 1
+                    maximum: 10
+            "#,
+            &[
+                json!({"optional": 1, "required": 1}),
+                json!({"required": 2}),
+            ],
+            Some(1),
+        );
+    }
+
     #[test]
     fn test_quickcheck_regression() {
         widening_snapshot_helper(
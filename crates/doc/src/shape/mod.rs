@@ -4,6 +4,7 @@ use serde_json::Value;
 use std::collections::BTreeMap;
 use url::Url;
 
+pub mod compatibility;
 mod inference;
 pub mod inspections;
 mod intersect;
@@ -35,6 +36,8 @@ pub struct Shape {
     pub default: Option<Box<(Value, Option<super::FailedValidation>)>>,
     /// Is this location sensitive? For example, a password or credential.
     pub secret: Option<bool>,
+    /// Is this location an advanced configuration section, which a UI should collapse by default?
+    pub advanced: Option<bool>,
     /// Annotations are any keywords starting with `X-` or `x-`.
     /// Their keys and values are collected here, without performing any
     /// normalization of prefix case. Technically both `x-foo` and `X-foo` may be
@@ -174,6 +177,7 @@ impl Shape {
             provenance: Provenance::Unset,
             default: None,
             secret: None,
+            advanced: None,
             annotations: BTreeMap::new(),
             array: ArrayShape::new(),
             numeric: NumericShape::new(),
@@ -194,6 +198,7 @@ impl Shape {
             provenance: Provenance::Inline,
             default: None,
             secret: None,
+            advanced: None,
             annotations: BTreeMap::new(),
             array: ArrayShape::new(),
             numeric: NumericShape::new(),
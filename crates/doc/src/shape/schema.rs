@@ -1,4 +1,15 @@
 // This module maps a Shape into a representative JSON Schema.
+//
+// The mapping is lossless with respect to inference: for any Shape which was
+// itself inferred from a schema, `Shape::infer` of its mapped schema is equal
+// to the original Shape. Locations having a Provenance::Reference are mapped
+// into a `$ref` of their URL, and the referenced schema is bundled into the
+// root `$defs` as a resource having that URL as its `$id`.
+//
+// One exception is recursion which inference elided. Where an elided `$ref`
+// is intersected into a location outside of its recursion (for example,
+// by applying a pattern property to a matching named property), its mapped
+// `$ref` is re-inferred with one further level of expansion.
 use super::*;
 use json::schema::{keywords, types};
 use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
@@ -6,23 +17,69 @@ use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrV
 // TODO(johnny): This *probably* should be an impl Shape { into_schema(self) -> RootSchema }
 // Consider refactoring as such if we're happy with this interface.
 pub fn to_schema(shape: Shape) -> RootSchema {
+    let mut bundle = Bundle::default();
+    let mut schema = into_object(to_sub_schema(shape, &mut bundle));
+
+    if !bundle.0.is_empty() {
+        schema
+            .extensions
+            .insert(keywords::DEF.to_string(), bundle.into_defs());
+    }
+
     RootSchema {
-        schema: to_sub_schema(shape).into_object(),
+        schema,
         meta_schema: schemars::gen::SchemaSettings::draft2019_09().meta_schema,
         ..Default::default()
     }
 }
 
-fn to_sub_schema(shape: Shape) -> Schema {
+fn to_sub_schema(mut shape: Shape, bundle: &mut Bundle) -> Schema {
+    if shape == Shape::nothing() {
+        return Schema::Bool(false);
+    }
+    let mut out = SchemaObject::default();
+
+    // Metadata keywords. These don't influence inferred Provenance,
+    // and are always mapped at the present location.
+    {
+        let out = out.metadata();
+
+        out.title = shape.title.take().map(Into::into);
+        out.description = shape.description.take().map(Into::into);
+        out.default = shape.default.take().map(|d| d.0);
+    }
+
+    let inline = match std::mem::replace(&mut shape.provenance, Provenance::Unset) {
+        Provenance::Unset => false,
+        Provenance::Inline => true,
+        Provenance::Reference(url) => match resource_path(&url) {
+            Some((base, path)) => {
+                bundle.insert(base, path, shape);
+                out.reference = Some(url.to_string());
+                return Schema::Object(out);
+            }
+            // This URL cannot be bundled. Map the referent in-place instead.
+            None => true,
+        },
+    };
+
+    // An inline location must have a keyword other than `title`, `description`,
+    // or `default`, which don't influence inferred Provenance. If it would
+    // otherwise have none, then use an explicit `type` which permits anything.
+    if inline && shape == Shape::anything() {
+        out.instance_type = Some(shape_type_to_schema_type(types::ANY));
+    }
+
     let Shape {
         type_,
         enum_,
-        title,
-        description,
+        title: _,
+        description: _,
         reduction,
-        provenance: _, // Not mapped to a schema.
-        default,
+        provenance: _,
+        default: _,
         secret,
+        advanced,
         annotations,
         array,
         numeric,
@@ -30,11 +87,7 @@ fn to_sub_schema(shape: Shape) -> Schema {
         string,
     } = shape;
 
-    let mut out = SchemaObject::default();
-
-    if type_ == types::INVALID {
-        return Schema::Bool(false);
-    } else if type_ == types::ANY {
+    if type_ == types::ANY {
         // Don't set instance_type.
     } else {
         out.instance_type = Some(shape_type_to_schema_type(type_));
@@ -42,17 +95,11 @@ fn to_sub_schema(shape: Shape) -> Schema {
 
     out.enum_values = enum_;
 
-    // Metadata keywords.
-    {
-        let out = out.metadata();
-
-        out.title = title.map(Into::into);
-        out.description = description.map(Into::into);
-        out.default = default.map(|d| d.0);
-    }
+    // Type-specific keywords are mapped even where the location's types don't
+    // overlap with them, because Shape inference doesn't discard them either.
 
     // Object keywords.
-    if type_.overlaps(types::OBJECT) {
+    if object != ObjShape::new() {
         let ObjShape {
             properties,
             pattern_properties: patterns,
@@ -70,19 +117,21 @@ fn to_sub_schema(shape: Shape) -> Schema {
             if is_required {
                 out.required.insert(name.clone().into());
             }
-            out.properties.insert(name.into(), to_sub_schema(shape));
+            out.properties
+                .insert(name.into(), to_sub_schema(shape, bundle));
         }
 
         for ObjPattern { re, shape } in patterns {
             out.pattern_properties
-                .insert(re.as_str().to_owned(), to_sub_schema(shape));
+                .insert(re.as_str().to_owned(), to_sub_schema(shape, bundle));
         }
 
-        out.additional_properties = additional_properties.map(|s| Box::new(to_sub_schema(*s)));
+        out.additional_properties =
+            additional_properties.map(|s| Box::new(to_sub_schema(*s, bundle)));
     }
 
     // Array keywords.
-    if type_.overlaps(types::ARRAY) {
+    if array != ArrayShape::new() {
         let ArrayShape {
             min_items,
             max_items,
@@ -101,16 +150,19 @@ fn to_sub_schema(shape: Shape) -> Schema {
 
         if !tuple.is_empty() {
             out.items = Some(SingleOrVec::Vec(
-                tuple.into_iter().map(to_sub_schema).collect(),
+                tuple
+                    .into_iter()
+                    .map(|s| to_sub_schema(s, bundle))
+                    .collect(),
             ));
-            out.additional_items = additional_items.map(|s| Box::new(to_sub_schema(*s)));
+            out.additional_items = additional_items.map(|s| Box::new(to_sub_schema(*s, bundle)));
         } else if let Some(addl) = additional_items {
-            out.items = Some(SingleOrVec::Single(Box::new(to_sub_schema(*addl))));
+            out.items = Some(SingleOrVec::Single(Box::new(to_sub_schema(*addl, bundle))));
         }
     }
 
     // String keywords.
-    if type_.overlaps(types::STRING) {
+    if string != StringShape::new() {
         let StringShape {
             content_encoding,
             content_type,
@@ -144,7 +196,7 @@ fn to_sub_schema(shape: Shape) -> Schema {
     }
 
     // Numeric keywords.
-    if type_.overlaps(types::INT_OR_FRAC) {
+    {
         let NumericShape { minimum, maximum } = numeric;
 
         // The schemars::SchemaObject::number() sub-type has minimum / maximum
@@ -163,9 +215,13 @@ fn to_sub_schema(shape: Shape) -> Schema {
 
     // Extensions.
     {
-        if let Some(true) = secret {
+        if let Some(secret) = secret {
+            out.extensions
+                .insert("secret".to_string(), serde_json::json!(secret));
+        }
+        if let Some(advanced) = advanced {
             out.extensions
-                .insert("secret".to_string(), serde_json::json!(true));
+                .insert("advanced".to_string(), serde_json::json!(advanced));
         }
 
         match reduction {
@@ -175,9 +231,22 @@ fn to_sub_schema(shape: Shape) -> Schema {
                     .insert("reduce".to_string(), serde_json::json!(strategy));
             }
             Reduction::Multiple => {
-                out.extensions.insert(
-                    "reduce".to_string(),
-                    serde_json::json!("multiple-strategies"),
+                // There's no keyword for multiple strategies, but they're
+                // inferred from in-place applications which disagree.
+                out.subschemas().any_of = Some(
+                    [
+                        crate::reduce::Strategy::FirstWriteWins(Default::default()),
+                        crate::reduce::Strategy::LastWriteWins(Default::default()),
+                    ]
+                    .into_iter()
+                    .map(|strategy| {
+                        let mut branch = SchemaObject::default();
+                        branch
+                            .extensions
+                            .insert("reduce".to_string(), serde_json::json!(strategy));
+                        Schema::Object(branch)
+                    })
+                    .collect(),
                 );
             }
         }
@@ -188,6 +257,134 @@ fn to_sub_schema(shape: Shape) -> Schema {
     Schema::Object(out)
 }
 
+// Bundle is a compound of referenced schemas, indexed on their base URL.
+#[derive(Default)]
+struct Bundle(BTreeMap<Url, Resource>);
+
+// Resource is a bundled schema and its nested `$defs` or `definitions`.
+#[derive(Default)]
+struct Resource {
+    // Mapped referent, and the length of its serialization.
+    schema: Option<(Schema, usize)>,
+    // Nested resources, keyed on their keyword and definition name.
+    children: BTreeMap<(&'static str, String), Resource>,
+}
+
+impl Bundle {
+    // Insert the referent Shape of a `$ref` into the resource at `base` and `path`.
+    //
+    // A URL may be referenced from many locations, and their referent Shapes
+    // can differ: inference elides a recursive `$ref` to Shape::anything(),
+    // and intersections discard type-specific inferences which don't apply.
+    // Bundle the referent having the most expansive mapping, which is the
+    // least-intersected referent. Inference from it reproduces the others.
+    fn insert(&mut self, base: Url, path: Vec<(&'static str, String)>, referent: Shape) {
+        let schema = to_sub_schema(referent, self);
+        let size = serde_json::to_string(&schema).unwrap().len();
+
+        let resource = self.resource(&base, &path);
+        if !matches!(resource.schema, Some((_, prior)) if prior >= size) {
+            resource.schema = Some((schema, size));
+        }
+    }
+
+    fn resource(&mut self, base: &Url, path: &[(&'static str, String)]) -> &mut Resource {
+        let mut resource = self.0.entry(base.clone()).or_default();
+        for (keyword, name) in path {
+            resource = resource
+                .children
+                .entry((*keyword, name.clone()))
+                .or_default();
+        }
+        resource
+    }
+
+    fn into_defs(self) -> serde_json::Value {
+        let defs = self
+            .0
+            .into_iter()
+            .map(|(base, resource)| {
+                let mut schema = resource.into_object();
+                schema.metadata().id = Some(base.to_string());
+                (base.to_string(), serde_json::to_value(schema).unwrap())
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        serde_json::Value::Object(defs)
+    }
+}
+
+impl Resource {
+    fn into_object(self) -> SchemaObject {
+        let Resource { schema, children } = self;
+
+        let mut out = match schema {
+            Some((schema, _)) => into_object(schema),
+            None => SchemaObject::default(),
+        };
+
+        for ((keyword, name), child) in children {
+            let defs = out
+                .extensions
+                .entry(keyword.to_string())
+                .or_insert_with(|| serde_json::json!({}));
+
+            defs[name] = serde_json::to_value(child.into_object()).unwrap();
+        }
+        out
+    }
+}
+
+// Split a referenced URL into its base URL and the path of nested `$defs`
+// or `definitions` which its fragment points to. Other fragments, such as
+// anchors or pointers to sub-schemas of other keywords, are not supported.
+fn resource_path(url: &Url) -> Option<(Url, Vec<(&'static str, String)>)> {
+    let mut base = url.clone();
+    base.set_fragment(None);
+
+    if base.cannot_be_a_base() {
+        return None;
+    }
+    let mut path = Vec::new();
+
+    let Some(fragment) = url.fragment() else {
+        return Some((base, path));
+    };
+    let mut tokens = fragment.split('/');
+
+    if tokens.next() != Some("") {
+        return None; // Not a JSON pointer.
+    }
+    while let Some(keyword) = tokens.next() {
+        let keyword = match keyword {
+            keywords::DEF => keywords::DEF,
+            keywords::DEFINITIONS => keywords::DEFINITIONS,
+            _ => return None,
+        };
+        let name = percent_encoding::percent_decode_str(tokens.next()?)
+            .decode_utf8()
+            .ok()?
+            .replace("~1", "/")
+            .replace("~0", "~");
+
+        path.push((keyword, name));
+    }
+    Some((base, path))
+}
+
+// Map a Schema into a SchemaObject, which unlike a boolean Schema
+// may have further keywords (such as `$id`) added to it.
+fn into_object(schema: Schema) -> SchemaObject {
+    match schema {
+        Schema::Object(obj) => obj,
+        Schema::Bool(true) => SchemaObject::default(),
+        Schema::Bool(false) => SchemaObject {
+            instance_type: Some(SingleOrVec::Vec(Vec::new())),
+            ..Default::default()
+        },
+    }
+}
+
 fn shape_type_to_schema_type(type_set: types::Set) -> SingleOrVec<InstanceType> {
     let mut v = type_set
         .iter()
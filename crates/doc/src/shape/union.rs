@@ -233,6 +233,7 @@ impl Shape {
         let provenance = lhs.provenance.union(rhs.provenance);
         let default = union_option(lhs.default, rhs.default);
         let secret = union_option(lhs.secret, rhs.secret);
+        let advanced = union_option(lhs.advanced, rhs.advanced);
 
         // Union of annotations is actually an _intersection_, which yields only
         // the annotations that are guaranteed to apply at a given location.
@@ -281,6 +282,7 @@ impl Shape {
             provenance,
             default,
             secret,
+            advanced,
             annotations,
             string,
             array,
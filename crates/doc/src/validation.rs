@@ -1,6 +1,7 @@
 use super::{reduce, walker::walk_document, Annotation, AsNode, SerPolicy};
 use json::validator::Context;
 use std::pin::Pin;
+use std::sync::Arc;
 
 // Specialize json templates for the Flow `Annotation` type.
 pub type Schema = json::schema::Schema<Annotation>;
@@ -9,6 +10,7 @@ pub type SchemaIndex<'sm> = json::schema::index::Index<'sm, Annotation>;
 pub type FullContext = json::validator::FullContext;
 pub type SpanContext = json::validator::SpanContext;
 pub type RawValidator<'sm> = json::validator::Validator<'sm, Annotation, SpanContext>;
+pub type OutputFormat = json::validator::OutputFormat;
 
 // Re-export build_schema for lower-level usages.
 pub use json::schema::build::build_schema;
@@ -42,10 +44,19 @@ pub fn build_bundle(bundle: &str) -> Result<Schema, json::schema::build::Error>
 }
 
 // Validator wraps a json::Validator and manages ownership of the schemas under validation.
+// Schemas and their index are immutable and are shared by clones of a Validator,
+// each of which has its own validation state.
 pub struct Validator {
     // Careful, order matters! Fields are dropped in declaration order.
     inner: json::validator::Validator<'static, Annotation, SpanContext>,
-    index: Pin<Box<SchemaIndex<'static>>>,
+    indexed: Arc<Indexed>,
+    output_format: OutputFormat,
+}
+
+// Indexed is the shared, immutable portion of a Validator.
+struct Indexed {
+    // Careful, order matters! `index` references `schemas` and is dropped first.
+    index: SchemaIndex<'static>,
     schemas: Pin<Box<[Schema]>>,
 }
 
@@ -72,27 +83,39 @@ impl Validator {
         }
         index.verify_references()?;
 
-        // Safety: we manually keep the owned index alongside the associated validator,
-        // and drop it before the validator.
-        let index = Box::pin(index.into_index());
-        let index_static =
-            unsafe { std::mem::transmute::<&'_ SchemaIndex, &'static SchemaIndex>(&index) };
+        let indexed = Arc::new(Indexed {
+            index: index.into_index(),
+            schemas,
+        });
 
         Ok(Self {
-            inner: json::validator::Validator::new(index_static),
-            index,
-            schemas,
+            inner: json::validator::Validator::new(Self::index_static(&indexed)),
+            indexed,
+            output_format: OutputFormat::Basic,
         })
     }
 
+    /// Use the given OutputFormat for errors of failed validations,
+    /// in addition to the "basic" output which is always produced.
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = output_format;
+        self
+    }
+
     /// Fetch the SchemaIndex of this Validator.
     pub fn schema_index(&self) -> &SchemaIndex<'static> {
-        &self.index
+        &self.indexed.index
     }
 
     /// Fetch the Schemas indexed by this Validator.
     pub fn schemas(&self) -> &[Schema] {
-        &self.schemas
+        &self.indexed.schemas
+    }
+
+    // Safety: we manually keep the shared index alongside the associated validator,
+    // and drop the validator before it.
+    fn index_static(indexed: &Arc<Indexed>) -> &'static SchemaIndex<'static> {
+        unsafe { std::mem::transmute::<&'_ SchemaIndex, &'static SchemaIndex>(&indexed.index) }
     }
 
     /// Validate validates the given document against the given schema.
@@ -104,7 +127,7 @@ impl Validator {
     ) -> Result<Validation<'static, 'doc, 'v, N>, json::schema::index::Error> {
         let effective_schema = match schema {
             Some(schema) => schema,
-            None if self.schemas.len() == 1 => &self.schemas[0].curi,
+            None if self.indexed.schemas.len() == 1 => &self.indexed.schemas[0].curi,
             None => {
                 panic!("root_curi() may only be used with Validators having a single root schema")
             }
@@ -119,10 +142,22 @@ impl Validator {
             schema: effective_schema,
             span,
             validator: &mut self.inner,
+            output_format: self.output_format,
         })
     }
 }
 
+// Clones share indexed schemas, and have independent validation state.
+impl Clone for Validator {
+    fn clone(&self) -> Self {
+        Self {
+            inner: json::validator::Validator::new(Self::index_static(&self.indexed)),
+            indexed: self.indexed.clone(),
+            output_format: self.output_format,
+        }
+    }
+}
+
 /// Validation represents the outcome of a document validation.
 pub struct Validation<'schema, 'doc, 'tmp, N: AsNode> {
     /// Document which was validated.
@@ -134,6 +169,8 @@ pub struct Validation<'schema, 'doc, 'tmp, N: AsNode> {
     /// Validator which holds the validation outcome.
     // Note use of Validator in a loop requires that we separate these lifetimes.
     pub validator: &'tmp mut RawValidator<'schema>,
+    /// Output format of errors, should the validation have failed.
+    pub output_format: OutputFormat,
 }
 
 // Valid is a Validation known to have had a valid outcome.
@@ -162,6 +199,7 @@ impl<'schema, 'doc, 'tmp, N: AsNode> Validation<'schema, 'doc, 'tmp, N> {
             schema,
             span,
             validator,
+            output_format: OutputFormat::Basic,
         })
     }
 
@@ -179,6 +217,7 @@ impl<'schema, 'doc, 'tmp, N: AsNode> Validation<'schema, 'doc, 'tmp, N> {
             document,
             validator,
             span,
+            output_format,
         } = self;
 
         // Repeat the validation, but this time with FullContext for better error generation.
@@ -193,9 +232,24 @@ impl<'schema, 'doc, 'tmp, N: AsNode> Validation<'schema, 'doc, 'tmp, N> {
         assert!(full_validator.invalid());
         assert_eq!(span, full_span);
 
+        let outcomes = full_validator.outcomes();
+        let (mut detailed_output, mut verbose_output) = (None, None);
+
+        match output_format {
+            OutputFormat::Basic => (),
+            OutputFormat::Detailed => {
+                detailed_output = Some(Box::new(json::validator::build_detailed_output(outcomes)))
+            }
+            OutputFormat::Verbose => {
+                verbose_output = Some(Box::new(json::validator::build_verbose_output(outcomes)))
+            }
+        }
+
         Err(FailedValidation {
             document: serde_json::to_value(SerPolicy::debug().on(document)).unwrap(),
-            basic_output: json::validator::build_basic_output(full_validator.outcomes()),
+            basic_output: json::validator::build_basic_output(outcomes),
+            detailed_output,
+            verbose_output,
         })
     }
 }
@@ -203,6 +257,10 @@ impl<'schema, 'doc, 'tmp, N: AsNode> Validation<'schema, 'doc, 'tmp, N> {
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct FailedValidation {
     pub basic_output: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detailed_output: Option<Box<serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbose_output: Option<Box<serde_json::Value>>,
     pub document: serde_json::Value,
 }
 
@@ -1,9 +1,13 @@
+use doc::combine::{MemTable, ParallelDrainer, SpillCipher, SpillCodec, SpillDrainer, SpillWriter};
 use doc::{Extractor, Validator};
 use json::schema::build::build_schema;
 use rand::{distributions::Distribution, Rng, SeedableRng};
 use serde_json::{json, value::RawValue, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::io::Write;
-use std::time::Instant;
+use std::ops::Range;
+use std::time::{Duration, Instant};
 
 // This benchmark is regularly run as part of our test sweet to ensure it remains functional.
 // When actually developing it, you may wish to run as:
@@ -31,6 +35,10 @@ const PROB_SAMPLE_GH: f64 = 0.5;
 // These model reductions which have poor performance when applied strictly linearly
 // (as opposed to taking advantage of associativity during reduction).
 const PROB_SAMPLE_SET: f64 = 0.5;
+// When comparing spill options, how many rounds are added to a MemTable before it's spilled?
+const SPILL_EVERY_ROUNDS: usize = 100;
+// Target size of spilled chunks, matching that used by the combine Accumulator.
+const CHUNK_TARGET_SIZE: usize = 1 << 18;
 
 // Re-use github and citi-bike event data for this benchmark. See:
 // * crates/json/benches/github_events.rs
@@ -48,6 +56,203 @@ const CITI_RIDES: &[u8] = include_bytes!("../../json/benches/testdata/citi-rides
 
 #[test]
 pub fn combiner_perf() {
+    let mut generator = Generator::new();
+
+    // Initialize the combiner itself.
+    let spec = doc::combine::Spec::with_one_binding(
+        true, // Full reductions.
+        vec![Extractor::new("/key", &doc::SerPolicy::default())],
+        None,
+        build_validator(),
+    );
+    let mut accum = doc::combine::Accumulator::new(spec, tempfile::tempfile().unwrap()).unwrap();
+
+    // Begin to measure performance.
+    let start_stats = allocator::current_mem_stats();
+    let begin = Instant::now();
+
+    let mut buf = Vec::new();
+    for _round in 0..TOTAL_ROUNDS {
+        generator.next_doc(&mut buf);
+
+        let memtable = accum.memtable().unwrap();
+        let doc = doc::HeapNode::from_serde(
+            &mut serde_json::Deserializer::from_slice(&buf),
+            memtable.alloc(),
+        )
+        .unwrap();
+
+        memtable.add(0, doc, false).unwrap();
+    }
+
+    let peak_stats = allocator::current_mem_stats();
+    let mut drained: usize = 0;
+    let mut shape = doc::Shape::nothing();
+
+    for drained_doc in accum.into_drainer().unwrap() {
+        let drained_doc = drained_doc.unwrap();
+        drained += 1;
+        shape.widen_owned(&drained_doc.root);
+    }
+
+    let duration = begin.elapsed();
+    let trough_stats = allocator::current_mem_stats();
+
+    eprintln!(
+        "Rounds: {}\nDrained: {}\nElapsed: {}s\nMemory: active {}MB allocated {}MB resident {}MB retained {}MB alloc_ops {} dealloc_ops {} realloc_ops {}",
+        TOTAL_ROUNDS,
+        drained,
+        duration.as_secs_f64(),
+        peak_stats.active / (1024 * 1024),
+        peak_stats.allocated / (1024 * 1024),
+        peak_stats.resident / (1024 * 1024),
+        peak_stats.retained / (1024 * 1024),
+        trough_stats.counts.alloc_ops - start_stats.counts.alloc_ops,
+        trough_stats.counts.dealloc_ops - start_stats.counts.dealloc_ops,
+        trough_stats.counts.realloc_ops - start_stats.counts.realloc_ops,
+    );
+}
+
+// Compare the cost of spilling and draining an identical sequence of documents
+// under each spill codec and cipher.
+#[test]
+pub fn spill_perf() {
+    let options = [
+        ("lz4", SpillCodec::Lz4, SpillCipher::None),
+        ("zstd-1", SpillCodec::Zstd { level: 1 }, SpillCipher::None),
+        ("zstd-3", SpillCodec::Zstd { level: 3 }, SpillCipher::None),
+        ("zstd-9", SpillCodec::Zstd { level: 9 }, SpillCipher::None),
+        (
+            "lz4+aes256gcm",
+            SpillCodec::Lz4,
+            SpillCipher::ephemeral_aes256gcm().unwrap(),
+        ),
+        (
+            "zstd-3+aes256gcm",
+            SpillCodec::Zstd { level: 3 },
+            SpillCipher::ephemeral_aes256gcm().unwrap(),
+        ),
+    ];
+
+    for (name, codec, cipher) in options {
+        let spec = doc::combine::Spec::with_one_binding(
+            true, // Full reductions.
+            vec![Extractor::new("/key", &doc::SerPolicy::default())],
+            None,
+            build_validator(),
+        )
+        .with_spill_codec(codec)
+        .with_spill_cipher(cipher);
+
+        let (spill, ranges, spec, spill_duration) = spill_generated(spec);
+        let spilled_bytes: u64 = ranges.iter().map(|r| r.end - r.start).sum();
+
+        let begin = Instant::now();
+        let mut drained: usize = 0;
+        for drained_doc in SpillDrainer::new(spec, spill, &ranges).unwrap() {
+            drained_doc.unwrap();
+            drained += 1;
+        }
+        let drain_duration = begin.elapsed();
+
+        eprintln!(
+            "{name}: Segments: {} Drained: {drained} Spilled: {}KB Spill: {}s Drain: {}s",
+            ranges.len(),
+            spilled_bytes / 1024,
+            spill_duration.as_secs_f64(),
+            drain_duration.as_secs_f64(),
+        );
+    }
+}
+
+// Compare a sequential SpillDrainer with a ParallelDrainer over varied numbers
+// of threads, verifying that each drains identical documents in identical order.
+#[test]
+pub fn parallel_drain_perf() {
+    let mut expect: Option<(usize, u64)> = None;
+
+    for threads in [0, 1, 2, 4, 8] {
+        let spec = doc::combine::Spec::with_one_binding(
+            true, // Full reductions.
+            vec![Extractor::new("/key", &doc::SerPolicy::default())],
+            None,
+            build_validator(),
+        );
+        let (spill, ranges, spec, _) = spill_generated(spec);
+
+        // Zero threads is our sequential baseline.
+        let begin = Instant::now();
+        let drainer: Box<dyn Iterator<Item = Result<doc::combine::DrainedDoc, _>>> = if threads == 0
+        {
+            Box::new(SpillDrainer::new(spec, spill, &ranges).unwrap())
+        } else {
+            Box::new(ParallelDrainer::new(spec, spill, &ranges, threads).unwrap())
+        };
+
+        let mut drained: usize = 0;
+        let mut hasher = DefaultHasher::new();
+
+        for drained_doc in drainer {
+            let drained_doc = drained_doc.unwrap();
+            drained += 1;
+
+            serde_json::to_vec(&doc::SerPolicy::default().on_owned(&drained_doc.root))
+                .unwrap()
+                .hash(&mut hasher);
+        }
+        let duration = begin.elapsed();
+        let actual = (drained, hasher.finish());
+
+        eprintln!(
+            "Threads: {threads} Segments: {} Drained: {drained} Elapsed: {}s",
+            ranges.len(),
+            duration.as_secs_f64(),
+        );
+
+        match &expect {
+            None => expect = Some(actual),
+            Some(expect) => assert_eq!(expect, &actual, "threads {threads}"),
+        }
+    }
+}
+
+// Spill documents of the Generator into a spill file, at a regular cadence
+// so that a drain merges many segments. Returns the spill file, its segment
+// ranges, the Spec, and the total time spent spilling.
+fn spill_generated(
+    mut spec: doc::combine::Spec,
+) -> (std::fs::File, Vec<Range<u64>>, doc::combine::Spec, Duration) {
+    // Each caller sees the same documents.
+    let mut generator = Generator::new();
+
+    let mut spill = SpillWriter::new(tempfile::tempfile().unwrap()).unwrap();
+    let mut buf = Vec::new();
+    let mut spill_duration = Duration::ZERO;
+
+    for _segment in 0..TOTAL_ROUNDS / SPILL_EVERY_ROUNDS {
+        let memtable = MemTable::new(spec);
+
+        for _round in 0..SPILL_EVERY_ROUNDS {
+            generator.next_doc(&mut buf);
+
+            let doc = doc::HeapNode::from_serde(
+                &mut serde_json::Deserializer::from_slice(&buf),
+                memtable.alloc(),
+            )
+            .unwrap();
+            memtable.add(0, doc, false).unwrap();
+        }
+
+        let begin = Instant::now();
+        spec = memtable.spill(&mut spill, CHUNK_TARGET_SIZE).unwrap();
+        spill_duration += begin.elapsed();
+    }
+    let (spill, ranges) = spill.into_parts();
+
+    (spill, ranges, spec, spill_duration)
+}
+
+fn build_validator() -> Validator {
     let github_schema: Value = serde_json::from_slice(GITHUB_SCHEMA).unwrap();
     let citi_schema: Value = serde_json::from_slice(CITI_RIDES_SCHEMA).unwrap();
     let set_schema: Value = json!({
@@ -84,48 +289,54 @@ pub fn combiner_perf() {
     )
     .unwrap();
 
-    // Load all github document fixtures into RawValue.
-    let github_docs = GITHUB_SCRAPES
-        .iter()
-        .copied()
-        .flat_map(|s| serde_json::from_slice::<Vec<Box<RawValue>>>(s).unwrap())
-        .collect::<Vec<Box<RawValue>>>();
+    Validator::new(schema).unwrap()
+}
+
+// Generator produces a deterministic sequence of documents to combine.
+struct Generator {
+    github_docs: Vec<Box<RawValue>>,
+    ride_docs: Vec<Box<RawValue>>,
+    rng: rand::rngs::SmallRng,
+    key_dist: rand_distr::Zipf<f64>,
+}
 
-    // Load all citi-bike document fixtures into RawValue.
-    let ride_docs = serde_json::Deserializer::from_slice(CITI_RIDES).into_iter::<Box<RawValue>>();
-    let ride_docs = ride_docs.collect::<Result<Vec<_>, _>>().unwrap();
+impl Generator {
+    fn new() -> Self {
+        // Load all github document fixtures into RawValue.
+        let github_docs = GITHUB_SCRAPES
+            .iter()
+            .copied()
+            .flat_map(|s| serde_json::from_slice::<Vec<Box<RawValue>>>(s).unwrap())
+            .collect::<Vec<Box<RawValue>>>();
 
-    // Assemble parts for document generation and validation.
-    let mut rng = rand::rngs::SmallRng::seed_from_u64(8675309);
-    let key_dist = rand_distr::Zipf::new(u64::MAX, ZIPF_PARAM).unwrap();
+        // Load all citi-bike document fixtures into RawValue.
+        let ride_docs =
+            serde_json::Deserializer::from_slice(CITI_RIDES).into_iter::<Box<RawValue>>();
+        let ride_docs = ride_docs.collect::<Result<Vec<_>, _>>().unwrap();
 
-    // Initialize the combiner itself.
-    let spec = doc::combine::Spec::with_one_binding(
-        true, // Full reductions.
-        vec![Extractor::new("/key", &doc::SerPolicy::default())],
-        None,
-        Validator::new(schema).unwrap(),
-    );
-    let mut accum = doc::combine::Accumulator::new(spec, tempfile::tempfile().unwrap()).unwrap();
+        Self {
+            github_docs,
+            ride_docs,
+            rng: rand::rngs::SmallRng::seed_from_u64(8675309),
+            key_dist: rand_distr::Zipf::new(u64::MAX, ZIPF_PARAM).unwrap(),
+        }
+    }
 
-    // Begin to measure performance.
-    let start_stats = allocator::current_mem_stats();
-    let begin = Instant::now();
+    // Build up the the next document to combine into `buf`.
+    fn next_doc(&mut self, buf: &mut Vec<u8>) {
+        let Self {
+            github_docs,
+            ride_docs,
+            rng,
+            key_dist,
+        } = self;
 
-    let mut buf = Vec::new();
-    for _round in 0..TOTAL_ROUNDS {
-        // Build up the the next document to combine.
         buf.clear();
-        write!(
-            &mut buf,
-            "{{\"key\":{},\"cnt\":1",
-            key_dist.sample(&mut rng) as u64
-        )
-        .unwrap();
+        write!(buf, "{{\"key\":{},\"cnt\":1", key_dist.sample(rng) as u64).unwrap();
 
         if rng.gen_bool(PROB_SAMPLE_CITI) {
             write!(
-                &mut buf,
+                buf,
                 ",\"citi\":{}",
                 ride_docs[rng.gen::<usize>() % ride_docs.len()].get()
             )
@@ -133,7 +344,7 @@ pub fn combiner_perf() {
         }
         if rng.gen_bool(PROB_SAMPLE_GH) {
             write!(
-                &mut buf,
+                buf,
                 ",\"gh\":{}",
                 github_docs[rng.gen::<usize>() % github_docs.len()].clone(),
             )
@@ -141,7 +352,7 @@ pub fn combiner_perf() {
         }
         if rng.gen_bool(PROB_SAMPLE_SET) {
             write!(
-                &mut buf,
+                buf,
                 ",\"set\":{{\"add\":{{\"s{}\":1}},\"remove\":{{\"s{}\":1}}}}",
                 rng.gen::<u16>() % 1024,
                 rng.gen::<u16>() % 1024,
@@ -149,41 +360,5 @@ pub fn combiner_perf() {
             .unwrap();
         }
         buf.push(b'}');
-
-        let memtable = accum.memtable().unwrap();
-        let doc = doc::HeapNode::from_serde(
-            &mut serde_json::Deserializer::from_slice(&buf),
-            memtable.alloc(),
-        )
-        .unwrap();
-
-        memtable.add(0, doc, false).unwrap();
-    }
-
-    let peak_stats = allocator::current_mem_stats();
-    let mut drained: usize = 0;
-    let mut shape = doc::Shape::nothing();
-
-    for drained_doc in accum.into_drainer().unwrap() {
-        let drained_doc = drained_doc.unwrap();
-        drained += 1;
-        shape.widen_owned(&drained_doc.root);
     }
-
-    let duration = begin.elapsed();
-    let trough_stats = allocator::current_mem_stats();
-
-    eprintln!(
-        "Rounds: {}\nDrained: {}\nElapsed: {}s\nMemory: active {}MB allocated {}MB resident {}MB retained {}MB alloc_ops {} dealloc_ops {} realloc_ops {}",
-        TOTAL_ROUNDS,
-        drained,
-        duration.as_secs_f64(),
-        peak_stats.active / (1024 * 1024),
-        peak_stats.allocated / (1024 * 1024),
-        peak_stats.resident / (1024 * 1024),
-        peak_stats.retained / (1024 * 1024),
-        trough_stats.counts.alloc_ops - start_stats.counts.alloc_ops,
-        trough_stats.counts.dealloc_ops - start_stats.counts.dealloc_ops,
-        trough_stats.counts.realloc_ops - start_stats.counts.realloc_ops,
-    );
 }
@@ -0,0 +1,113 @@
+use doc::{compare, patch, HeapNode, SerPolicy};
+use quickcheck::quickcheck;
+use serde_json::Value;
+
+mod arbitrary_value;
+use arbitrary_value::ArbitraryValue;
+
+quickcheck! {
+    fn json_patch_diff_fuzz(lhs: ArbitraryValue, rhs: ArbitraryValue) -> bool {
+        json_patch_diff(lhs.0, rhs.0)
+    }
+
+    fn json_patch_parse_fuzz(lhs: ArbitraryValue, rhs: ArbitraryValue) -> bool {
+        json_patch_parse(lhs.0, rhs.0)
+    }
+
+    fn merge_patch_diff_fuzz(lhs: ArbitraryValue, rhs: ArbitraryValue) -> bool {
+        merge_patch_diff(lhs.0, rhs.0)
+    }
+
+    fn merge_patch_apply_fuzz(doc: ArbitraryValue, patch: ArbitraryValue) -> bool {
+        merge_patch_apply(doc.0, patch.0)
+    }
+}
+
+fn json_patch_diff(lhs: Value, rhs: Value) -> bool {
+    let alloc = HeapNode::new_allocator();
+    let ops = patch::diff(&lhs, &rhs);
+
+    // Applying the patch to `lhs` produces `rhs`.
+    let mut doc = HeapNode::from_node(&lhs, &alloc);
+    patch::apply(&mut doc, &ops, &alloc).unwrap();
+
+    if !compare(&doc, &rhs).is_eq() {
+        return false;
+    }
+
+    // The serialized patch is understood by another implementation.
+    let ops = serde_json::to_value(&ops).unwrap();
+    let ops: json_patch::Patch = serde_json::from_value(ops).unwrap();
+
+    let mut doc = lhs.clone();
+    json_patch::patch(&mut doc, &ops).unwrap();
+
+    compare(&doc, &rhs).is_eq()
+}
+
+fn json_patch_parse(lhs: Value, rhs: Value) -> bool {
+    let alloc = HeapNode::new_allocator();
+
+    // Parse and apply a patch produced by another implementation.
+    let ops = serde_json::to_value(json_patch::diff(&lhs, &rhs)).unwrap();
+    let ops = patch::parse(&ops).unwrap();
+
+    let mut doc = HeapNode::from_node(&lhs, &alloc);
+    patch::apply(&mut doc, &ops, &alloc).unwrap();
+
+    compare(&doc, &rhs).is_eq()
+}
+
+fn merge_patch_diff(lhs: Value, mut rhs: Value) -> bool {
+    let alloc = HeapNode::new_allocator();
+
+    // Merge Patches cannot represent explicit nulls of object properties.
+    strip_null_properties(&mut rhs);
+
+    let Some(merge_patch) = patch::merge_diff(&lhs, &rhs, &alloc) else {
+        return compare(&lhs, &rhs).is_eq();
+    };
+
+    let mut doc = HeapNode::from_node(&lhs, &alloc);
+    patch::merge_apply(&mut doc, &merge_patch, &alloc);
+
+    if !compare(&doc, &rhs).is_eq() {
+        return false;
+    }
+
+    // The patch is understood by another implementation.
+    let merge_patch = serde_json::to_value(SerPolicy::default().on(&merge_patch)).unwrap();
+    let mut doc = lhs.clone();
+    json_patch::merge(&mut doc, &merge_patch);
+
+    compare(&doc, &rhs).is_eq()
+}
+
+fn merge_patch_apply(target: Value, merge_patch: Value) -> bool {
+    let alloc = HeapNode::new_allocator();
+
+    let mut doc = HeapNode::from_node(&target, &alloc);
+    patch::merge_apply(&mut doc, &merge_patch, &alloc);
+
+    let mut expect = target.clone();
+    json_patch::merge(&mut expect, &merge_patch);
+
+    compare(&doc, &expect).is_eq()
+}
+
+// Remove null-valued properties of objects, and of objects nested therein.
+// Nulls within arrays are preserved, as arrays are replaced in whole by a merge.
+fn strip_null_properties(value: &mut Value) {
+    if let Value::Object(fields) = value {
+        fields.retain(|_, value| !value.is_null());
+        fields.values_mut().for_each(strip_null_properties);
+    }
+}
+
+#[test]
+fn test_merge_diff_with_null_properties() {
+    assert!(merge_patch_diff(
+        serde_json::json!({"a": {"b": 1}}),
+        serde_json::json!({"a": {"b": null, "c": [null]}}),
+    ))
+}
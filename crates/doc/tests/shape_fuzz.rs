@@ -1,10 +1,14 @@
 use doc::{
-    shape::{limits::enforce_shape_complexity_limit, schema::to_schema},
+    shape::{
+        limits::enforce_shape_complexity_limit, schema::to_schema, ArrayShape, NumericShape,
+        ObjShape, StringShape,
+    },
     Shape, Validator,
 };
 use itertools::Itertools;
-use quickcheck::{Gen, QuickCheck, TestResult};
-use serde_json::{json, Value};
+use json::schema::types;
+use quickcheck::{Arbitrary, Gen, QuickCheck, TestResult};
+use serde_json::{json, Map, Value};
 
 mod arbitrary_value;
 use arbitrary_value::ArbitraryValue;
@@ -114,3 +118,359 @@ fn fuzz_limiting() {
         .tests(1000)
         .quickcheck(inner_test as fn(Vec<ArbitraryValue>, usize) -> TestResult);
 }
+
+// Build and infer the Shape of a schema, which is built under a fixed URL.
+fn infer_shape(schema: &Value) -> Shape {
+    let curi = url::Url::parse("https://example/schema").unwrap();
+    let schema = json::schema::build::build_schema(curi, schema).unwrap();
+    let validator = Validator::new(schema).unwrap();
+
+    let mut shape = Shape::infer(&validator.schemas()[0], validator.schema_index());
+    clear_default_outcomes(&mut shape);
+    clear_inapplicable(&mut shape);
+    shape
+}
+
+// Validation outcomes of `default` values are not part of a schema.
+// They're re-computed against the schema location where the default
+// is declared, which may differ after a round trip. Compare only values.
+fn clear_default_outcomes(shape: &mut Shape) {
+    if let Some(default) = shape.default.as_mut() {
+        default.1 = None;
+    }
+    for prop in shape.object.properties.iter_mut() {
+        clear_default_outcomes(&mut prop.shape);
+    }
+    for pattern in shape.object.pattern_properties.iter_mut() {
+        clear_default_outcomes(&mut pattern.shape);
+    }
+    if let Some(addl) = shape.object.additional_properties.as_mut() {
+        clear_default_outcomes(addl);
+    }
+    for item in shape.array.tuple.iter_mut() {
+        clear_default_outcomes(item);
+    }
+    if let Some(addl) = shape.array.additional_items.as_mut() {
+        clear_default_outcomes(addl);
+    }
+}
+
+// Keywords which don't apply to any of a location's types (such as
+// `minLength` of an integer location) have no effect on validation.
+// Whether they're kept depends on how they were inferred, as intersection
+// drops them while other operations may not. Compare only applicable keywords.
+fn clear_inapplicable(shape: &mut Shape) {
+    if !shape.type_.overlaps(types::STRING) {
+        shape.string = StringShape::new();
+    }
+    if !shape.type_.overlaps(types::INT_OR_FRAC) {
+        shape.numeric = NumericShape::new();
+    }
+    if !shape.type_.overlaps(types::ARRAY) {
+        shape.array = ArrayShape::new();
+    }
+    if !shape.type_.overlaps(types::OBJECT) {
+        shape.object = ObjShape::new();
+    }
+    for prop in shape.object.properties.iter_mut() {
+        clear_inapplicable(&mut prop.shape);
+    }
+    for pattern in shape.object.pattern_properties.iter_mut() {
+        clear_inapplicable(&mut pattern.shape);
+    }
+    if let Some(addl) = shape.object.additional_properties.as_mut() {
+        clear_inapplicable(addl);
+    }
+    for item in shape.array.tuple.iter_mut() {
+        clear_inapplicable(item);
+    }
+    if let Some(addl) = shape.array.additional_items.as_mut() {
+        clear_inapplicable(addl);
+    }
+}
+
+// ArbitrarySchema is a JSON schema composed of keywords which Shape inference
+// understands, including `$ref`s into a separately identified resource.
+#[derive(Clone, Debug)]
+struct ArbitrarySchema(Value);
+
+const DEFS_ID: &str = "https://example/defs";
+// Referenced schemas may only reference those which follow them.
+// Recursion elided by inference isn't mapped losslessly in all cases.
+const REFS: &[&str] = &[
+    "https://example/defs",
+    "https://example/defs#/$defs/one",
+    "https://example/defs#/$defs/one/$defs/a~1b",
+    "https://example/defs#/$defs/two",
+    "https://example/defs#/definitions/three",
+];
+
+impl Arbitrary for ArbitrarySchema {
+    fn arbitrary(g: &mut Gen) -> Self {
+        // Referenced schemas may themselves reference one another.
+        let mut nested = Map::new();
+        nested.insert("a/b".to_string(), gen_schema(g, 2, &REFS[3..]));
+
+        let mut one = gen_keywords(g, 2, &REFS[2..]);
+        one.insert("$defs".to_string(), Value::Object(nested));
+
+        let mut defs = gen_keywords(g, 2, &REFS[1..]);
+        defs.insert("$id".to_string(), json!(DEFS_ID));
+        defs.insert(
+            "$defs".to_string(),
+            json!({"one": one, "two": gen_schema(g, 2, &REFS[4..])}),
+        );
+        defs.insert(
+            "definitions".to_string(),
+            json!({"three": gen_schema(g, 2, &REFS[5..])}),
+        );
+
+        let mut root = gen_keywords(g, 3, REFS);
+        root.insert("$defs".to_string(), json!({ "resource": defs }));
+
+        Self(Value::Object(root))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(shrink_schema(&self.0).into_iter().map(Self))
+    }
+}
+
+// Shrink a schema by removing one of its keywords, at any depth.
+// Definitions are never removed, as they may be referenced.
+fn shrink_schema(schema: &Value) -> Vec<Value> {
+    let Value::Object(map) = schema else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+
+    for (key, child) in map {
+        // Referenced schemas are not removed, as they may be referenced.
+        let is_defs = matches!(key.as_str(), "$defs" | "definitions");
+
+        if !is_defs && key != "$id" {
+            let mut map = map.clone();
+            map.remove(key);
+            out.push(Value::Object(map));
+        }
+        // Shrink only sub-schemas, and not the values of other keywords.
+        let shrunk = match (key.as_str(), child) {
+            ("properties" | "patternProperties" | "$defs" | "definitions", Value::Object(_)) => {
+                shrink_children(child, !is_defs)
+            }
+            ("items" | "anyOf", Value::Array(_)) => shrink_children(child, false),
+            ("items" | "additionalItems" | "additionalProperties", _) => shrink_schema(child),
+            _ => Vec::new(),
+        };
+        for child in shrunk {
+            let mut map = map.clone();
+            map.insert(key.clone(), child);
+            out.push(Value::Object(map));
+        }
+    }
+    out
+}
+
+// Shrink the sub-schemas of an object or array, optionally removing entries of an object.
+fn shrink_children(value: &Value, remove: bool) -> Vec<Value> {
+    let mut out = Vec::new();
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                if remove {
+                    let mut map = map.clone();
+                    map.remove(key);
+                    out.push(Value::Object(map));
+                }
+                for child in shrink_schema(child) {
+                    let mut map = map.clone();
+                    map.insert(key.clone(), child);
+                    out.push(Value::Object(map));
+                }
+            }
+        }
+        Value::Array(arr) => {
+            for (index, child) in arr.iter().enumerate() {
+                for child in shrink_schema(child) {
+                    let mut arr = arr.clone();
+                    arr[index] = child;
+                    out.push(Value::Array(arr));
+                }
+            }
+        }
+        _ => {}
+    }
+    out
+}
+
+fn gen_schema(g: &mut Gen, depth: usize, refs: &[&str]) -> Value {
+    match u8::arbitrary(g) % 8 {
+        0 => json!(false),
+        1 => json!({}),
+        2 if !refs.is_empty() => {
+            let mut out = gen_metadata(g);
+            out.insert("$ref".to_string(), json!(g.choose(refs).unwrap()));
+            Value::Object(out)
+        }
+        _ => Value::Object(gen_keywords(g, depth, refs)),
+    }
+}
+
+fn gen_metadata(g: &mut Gen) -> Map<String, Value> {
+    let mut out = Map::new();
+
+    if bool::arbitrary(g) {
+        out.insert(
+            "title".to_string(),
+            json!(g.choose(&["one", "two"]).unwrap()),
+        );
+    }
+    if bool::arbitrary(g) {
+        out.insert("description".to_string(), json!("a description"));
+    }
+    if bool::arbitrary(g) {
+        out.insert("default".to_string(), gen_literal(g));
+    }
+    out
+}
+
+fn gen_literal(g: &mut Gen) -> Value {
+    g.choose(&[
+        json!(null),
+        json!(true),
+        json!(-3),
+        json!(7),
+        json!(1.5),
+        json!("a@b.com"),
+        json!("c"),
+        json!([1, "c"]),
+        json!({"a": 7}),
+    ])
+    .unwrap()
+    .clone()
+}
+
+fn gen_keywords(g: &mut Gen, depth: usize, refs: &[&str]) -> Map<String, Value> {
+    let mut out = gen_metadata(g);
+
+    let types = [
+        "array", "boolean", "integer", "null", "number", "object", "string",
+    ]
+    .into_iter()
+    .filter(|_| bool::arbitrary(g))
+    .collect_vec();
+    maybe(&mut out, "type", json!(types), g);
+
+    let variants = (0..u8::arbitrary(g) % 4)
+        .map(|_| gen_literal(g))
+        .unique()
+        .collect_vec();
+    maybe(&mut out, "enum", json!(variants), g);
+
+    let strategy = g
+        .choose(&[
+            "append",
+            "firstWriteWins",
+            "lastWriteWins",
+            "maximize",
+            "sum",
+        ])
+        .unwrap();
+    maybe(&mut out, "reduce", json!({ "strategy": strategy }), g);
+    maybe(
+        &mut out,
+        "anyOf",
+        json!([
+            {"reduce": {"strategy": "firstWriteWins"}},
+            {"reduce": {"strategy": "merge"}},
+        ]),
+        g,
+    );
+    maybe(&mut out, "secret", json!(bool::arbitrary(g)), g);
+    maybe(&mut out, "advanced", json!(bool::arbitrary(g)), g);
+    maybe(&mut out, "x-annotation", gen_literal(g), g);
+
+    maybe(&mut out, "minimum", json!(i8::arbitrary(g)), g);
+    maybe(
+        &mut out,
+        "maximum",
+        json!(f64::from(i8::arbitrary(g)) + 0.5),
+        g,
+    );
+    maybe(&mut out, "minLength", json!(u8::arbitrary(g) % 4), g);
+    maybe(&mut out, "maxLength", json!(u8::arbitrary(g) % 8), g);
+    maybe(
+        &mut out,
+        "format",
+        json!(g.choose(&["date-time", "email", "uuid"]).unwrap()),
+        g,
+    );
+    maybe(&mut out, "contentEncoding", json!("base64"), g);
+    maybe(&mut out, "contentMediaType", json!("application/json"), g);
+    maybe(&mut out, "minItems", json!(u8::arbitrary(g) % 4), g);
+    maybe(&mut out, "maxItems", json!(u8::arbitrary(g) % 8), g);
+
+    if depth == 0 {
+        return out;
+    }
+    let child = |g: &mut Gen| gen_schema(g, depth - 1, refs);
+
+    if bool::arbitrary(g) {
+        maybe(&mut out, "items", child(g), g);
+    } else {
+        let tuple = (0..1 + u8::arbitrary(g) % 2)
+            .map(|_| child(g))
+            .collect_vec();
+        maybe(&mut out, "items", json!(tuple), g);
+        maybe(&mut out, "additionalItems", child(g), g);
+    }
+
+    let mut properties = Map::new();
+    for name in ["a", "ab", "b"] {
+        if bool::arbitrary(g) {
+            properties.insert(name.to_string(), child(g));
+        }
+    }
+    maybe(&mut out, "properties", Value::Object(properties), g);
+
+    let required = ["a", "b", "c"]
+        .into_iter()
+        .filter(|_| bool::arbitrary(g))
+        .collect_vec();
+    maybe(&mut out, "required", json!(required), g);
+    maybe(&mut out, "patternProperties", json!({ "^a": child(g) }), g);
+    maybe(&mut out, "additionalProperties", child(g), g);
+
+    out
+}
+
+// Insert a keyword into `out` with 1/4 probability.
+fn maybe(out: &mut Map<String, Value>, key: &str, value: Value, g: &mut Gen) {
+    if u8::arbitrary(g) % 4 == 0 {
+        out.insert(key.to_string(), value);
+    }
+}
+
+#[test]
+fn fuzz_shape_schema_round_trip() {
+    fn inner_test(schema: ArbitrarySchema) -> bool {
+        let shape = infer_shape(&schema.0);
+        let mapped = serde_json::to_value(to_schema(shape.clone())).unwrap();
+        let round_trip = infer_shape(&mapped);
+
+        if shape != round_trip {
+            println!(
+                "schema: {}\nmapped: {}\nshape: {shape:#?}\nround trip: {round_trip:#?}",
+                schema.0, mapped
+            );
+            return false;
+        }
+        true
+    }
+
+    QuickCheck::new()
+        .gen(Gen::new(10))
+        .tests(1000)
+        .quickcheck(inner_test as fn(ArbitrarySchema) -> bool);
+}
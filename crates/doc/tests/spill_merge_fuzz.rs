@@ -23,7 +23,12 @@ pub enum FuzzError {
     Unexpected(serde_json::Value),
 }
 
-fn run_sequence(seq: Vec<(u8, u8, bool)>) -> Result<(), FuzzError> {
+// Run a fuzzed sequence, draining with a ParallelDrainer if `threads` is Some.
+fn run_sequence(
+    seq: Vec<(u8, u8, bool)>,
+    chunk_target: usize,
+    threads: Option<usize>,
+) -> Result<(), FuzzError> {
     let ser_policy = doc::SerPolicy::default();
     let spec = combine::Spec::with_bindings(
         std::iter::repeat_with(|| {
@@ -56,8 +61,7 @@ fn run_sequence(seq: Vec<(u8, u8, bool)>) -> Result<(), FuzzError> {
         .take(2),
     );
 
-    let mut spill = combine::SpillWriter::new(std::io::Cursor::new(Vec::new())).unwrap();
-    let chunk_target = 1 << 20;
+    let mut spill = combine::SpillWriter::new(tempfile::tempfile().unwrap()).unwrap();
     let mut memtable = combine::MemTable::new(spec);
     let mut expect = BTreeMap::new();
 
@@ -100,7 +104,13 @@ fn run_sequence(seq: Vec<(u8, u8, bool)>) -> Result<(), FuzzError> {
     // Spill final MemTable and begin to drain.
     let spec = memtable.spill(&mut spill, chunk_target).unwrap();
     let (spill, ranges) = spill.into_parts();
-    let drainer = combine::SpillDrainer::new(spec, spill, &ranges).unwrap();
+    let drainer: Box<dyn Iterator<Item = Result<combine::DrainedDoc, combine::Error>>> =
+        match threads {
+            None => Box::new(combine::SpillDrainer::new(spec, spill, &ranges).unwrap()),
+            Some(threads) => {
+                Box::new(combine::ParallelDrainer::new(spec, spill, &ranges, threads).unwrap())
+            }
+        };
 
     let mut expect_it = expect.into_iter();
 
@@ -129,7 +139,19 @@ fn run_sequence(seq: Vec<(u8, u8, bool)>) -> Result<(), FuzzError> {
 
 #[quickcheck]
 fn test_spill_and_merge_fuzzing(seq: Vec<(u8, u8, bool)>) -> bool {
-    match run_sequence(seq) {
+    match run_sequence(seq, 1 << 20, None) {
+        Err(err) => {
+            eprintln!("error: {err}");
+            false
+        }
+        Ok(()) => true,
+    }
+}
+
+#[quickcheck]
+fn test_parallel_spill_and_merge_fuzzing(seq: Vec<(u8, u8, bool)>, threads: u8) -> bool {
+    // Use tiny chunks, so that the spilled key space is split into many partitions.
+    match run_sequence(seq, 64, Some(1 + threads as usize % 4)) {
         Err(err) => {
             eprintln!("error: {err}");
             false
@@ -138,9 +160,9 @@ fn test_spill_and_merge_fuzzing(seq: Vec<(u8, u8, bool)>) -> bool {
     }
 }
 
-// If the above quickcheck test ever fails, it will produce a minimized
+// If the above quickcheck tests ever fail, it will produce a minimized
 // reproduction case that can be put here for debugging.
 #[test]
 fn test_spill_and_merge_repro() {
-    run_sequence(vec![(0, 0, false), (0, 0, true)]).unwrap()
+    run_sequence(vec![(0, 0, false), (0, 0, true)], 1 << 20, None).unwrap()
 }
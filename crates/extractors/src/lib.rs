@@ -140,6 +140,7 @@ mod test {
                 },
                 default: String("user_key"),
                 is_uuid_v1_date_time: false,
+                ordered: None,
             },
             Extractor {
                 ptr: Pointer(
@@ -157,6 +158,7 @@ mod test {
                 },
                 default: Null,
                 is_uuid_v1_date_time: false,
+                ordered: None,
             },
         ]
         "###);
@@ -179,6 +181,7 @@ mod test {
                 },
                 default: Null,
                 is_uuid_v1_date_time: false,
+                ordered: None,
             },
             Extractor {
                 ptr: Pointer(
@@ -193,6 +196,7 @@ mod test {
                 },
                 default: Number(32),
                 is_uuid_v1_date_time: false,
+                ordered: None,
             },
             Extractor {
                 ptr: Pointer(
@@ -210,6 +214,7 @@ mod test {
                 },
                 default: Null,
                 is_uuid_v1_date_time: true,
+                ordered: None,
             },
         ]
         "###);
@@ -16,6 +16,10 @@ use tokio_util::compat::FuturesAsyncReadCompatExt;
 pub struct SchemaInferenceArgs {
     #[clap(flatten)]
     pub selector: CollectionJournalSelector,
+    /// Print the full inference response, including statistics of each
+    /// document location and suggested keys and enums, rather than only the schema.
+    #[clap(long)]
+    pub statistics: bool,
 }
 
 pub async fn get_collection_inferred_schema(
@@ -41,9 +45,13 @@ pub async fn get_collection_inferred_schema(
         StatusCode::OK => {
             let response: schema_inference::server::InferenceResponse =
                 inference_response.json().await?;
-            let schema_json = serde_json::to_string(&response.schema)?;
+            let output_json = if args.statistics {
+                serde_json::to_string(&response)?
+            } else {
+                serde_json::to_string(&response.schema)?
+            };
 
-            println!("{}", schema_json);
+            println!("{}", output_json);
         }
         err => {
             anyhow::bail!("[{}]: {}", err, inference_response.text().await?);
@@ -14,6 +14,9 @@ use author::do_author;
 mod develop;
 use develop::do_develop;
 
+mod review;
+use review::do_review;
+
 pub use author::upsert_draft_specs;
 
 #[derive(Debug, clap::Args)]
@@ -57,6 +60,13 @@ pub enum Command {
     Develop(develop::Develop),
     /// List your catalog drafts.
     List,
+    /// Review schema changes of collections in your current draft.
+    ///
+    /// Compare the schema of each collection in your draft with its live
+    /// specification, and list each location which is widened, narrowed,
+    /// or changed in a way that alters how documents are keyed or reduced.
+    /// Collections which aren't yet live are not listed.
+    Review,
     /// Test and then publish the current draft.
     ///
     /// A publication only occurs if tests pass.
@@ -93,6 +103,7 @@ impl Draft {
             Command::Develop(develop) => do_develop(ctx, develop).await,
             Command::List => do_list(ctx).await,
             Command::Publish => do_publish(ctx, false).await,
+            Command::Review => do_review(ctx).await,
             Command::Select(select) => do_select(ctx, select).await,
             Command::Test => do_publish(ctx, true).await,
         }
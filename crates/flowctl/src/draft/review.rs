@@ -0,0 +1,121 @@
+use super::develop::DraftSpecRow;
+use crate::{
+    api_exec_paginated,
+    catalog::{self, List, LiveSpecRow, NameSelector, SpecTypeSelector},
+    output::{to_table_row, CliOutput, JsonCell},
+};
+use anyhow::Context;
+use doc::shape::compatibility::{self, Change};
+use serde::Serialize;
+
+pub async fn do_review(ctx: &mut crate::CliContext) -> anyhow::Result<()> {
+    let draft_id = ctx.config().cur_draft()?;
+    let client = ctx.controlplane_client().await?;
+
+    let rows: Vec<DraftSpecRow> = api_exec_paginated(
+        client
+            .from("draft_specs")
+            .select("catalog_name,spec,spec_type")
+            .eq("spec_type", "collection")
+            .eq("draft_id", &draft_id),
+    )
+    .await?;
+    let drafted = catalog::collect_specs(rows)?.collections;
+
+    if drafted.is_empty() {
+        tracing::info!(%draft_id, "draft has no collections to review");
+        return Ok(());
+    }
+
+    let live_specs = catalog::fetch_live_specs::<LiveSpecRow>(
+        client,
+        &List {
+            flows: false,
+            name_selector: NameSelector {
+                name: drafted.keys().map(|name| name.to_string()).collect(),
+                prefix: Vec::new(),
+            },
+            type_selector: SpecTypeSelector {
+                collections: Some(true),
+                ..Default::default()
+            },
+            deleted: false,
+        },
+        vec!["catalog_name", "id", "updated_at", "spec_type", "spec"],
+    )
+    .await?;
+    let live = catalog::collect_specs(live_specs)?.collections;
+
+    let mut out = Vec::new();
+
+    // Collections which are new to the draft have nothing to compare against.
+    for (name, next) in &drafted {
+        let Some(previous) = live.get(name) else {
+            continue;
+        };
+        let report = compare_collections(previous, next)
+            .with_context(|| format!("comparing the schemas of collection {name}"))?;
+
+        out.extend(report.changes.into_iter().map(|change| Row {
+            collection: name.to_string(),
+            change,
+        }));
+    }
+
+    ctx.write_all(out, ())
+}
+
+// Compare the write schemas of the live and drafted specifications of a collection.
+// Read schemas are not compared, as they may reference inferred schemas.
+fn compare_collections(
+    previous: &models::CollectionDef,
+    next: &models::CollectionDef,
+) -> anyhow::Result<compatibility::Report> {
+    let (previous_shape, next_shape) = (write_shape(previous)?, write_shape(next)?);
+
+    // A location is a key location if it's part of either key.
+    let key: Vec<doc::Pointer> = previous
+        .key
+        .iter()
+        .chain(next.key.iter())
+        .map(|ptr| doc::Pointer::from_str(ptr))
+        .collect();
+
+    Ok(compatibility::compare(&previous_shape, &next_shape, &key))
+}
+
+fn write_shape(collection: &models::CollectionDef) -> anyhow::Result<doc::Shape> {
+    let schema = collection
+        .schema
+        .as_ref()
+        .or(collection.write_schema.as_ref())
+        .context("collection must define either schema or writeSchema")?;
+
+    let schema = doc::validation::build_bundle(schema.get())?;
+    let validator = doc::Validator::new(schema)?;
+
+    Ok(doc::Shape::infer(
+        &validator.schemas()[0],
+        validator.schema_index(),
+    ))
+}
+
+#[derive(Serialize)]
+struct Row {
+    collection: String,
+    #[serde(flatten)]
+    change: Change,
+}
+
+impl CliOutput for Row {
+    type TableAlt = ();
+    type CellValue = JsonCell;
+
+    fn table_headers(_alt: Self::TableAlt) -> Vec<&'static str> {
+        vec!["Collection", "Location", "Change", "Description"]
+    }
+
+    fn into_table_row(self, _alt: Self::TableAlt) -> Vec<Self::CellValue> {
+        to_table_row(self, &["/collection", "/ptr", "/kind", "/description"])
+    }
+}
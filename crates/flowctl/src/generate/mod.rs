@@ -10,6 +10,10 @@ pub struct Generate {
     /// Path or URL to a Flow specification file to generate development files for.
     #[clap(long)]
     source: String,
+    /// Also generate types of each collection in the given language.
+    /// May be repeated. Types are written under `flow_generated/<lang>/`.
+    #[clap(long, value_enum)]
+    lang: Vec<schemalate::typegen::Lang>,
 }
 
 impl Generate {
@@ -44,12 +48,51 @@ impl Generate {
 
         build::write_files(&project_root, files)?;
 
+        if !self.lang.is_empty() {
+            let files = generate_collection_types(&sources, &project_root, &self.lang)?;
+            build::write_files(&project_root, files)?;
+        }
+
         let client = ctx.controlplane_client().await?;
         let () = local_specs::generate_files(client, sources).await?;
         Ok(())
     }
 }
 
+// Generates types of each collection in each of `langs`, returning tuples of
+// the generated file URL and its content.
+fn generate_collection_types(
+    sources: &tables::Sources,
+    project_root: &url::Url,
+    langs: &[schemalate::typegen::Lang],
+) -> anyhow::Result<Vec<(url::Url, Vec<u8>)>> {
+    let mut files = Vec::new();
+
+    for tables::Collection {
+        collection, spec, ..
+    } in sources.collections.iter()
+    {
+        // Types model documents as they're read, using the read schema if there is one.
+        let Some(schema) = spec
+            .read_schema
+            .as_ref()
+            .or(spec.schema.as_ref())
+            .or(spec.write_schema.as_ref())
+        else {
+            continue;
+        };
+
+        for lang in langs {
+            let (path, content) =
+                schemalate::typegen::collection_types(*lang, collection, schema.get())?;
+            let url = url::Url::parse(&format!("{project_root}/{path}"))?;
+            files.push((url, content.into_bytes()));
+        }
+    }
+
+    Ok(files)
+}
+
 // Generates stubs for all missing connector configuration files,
 // returning tuples of:
 // * The missing config file URL.
@@ -210,11 +253,11 @@ async fn generate_missing_collection_configs(
             },
             serde_json::from_str::<url::Url>(config.config.get()).ok(),
         ),
-        // TypeScript and SQLite always generate their own configs.
+        // TypeScript, Python, and SQLite always generate their own configs.
         // Other connectors may as well, and they'll override those generated here.
-        models::DeriveUsing::Sqlite(_) | models::DeriveUsing::Typescript(_) => {
-            return Ok(Vec::new())
-        }
+        models::DeriveUsing::Sqlite(_)
+        | models::DeriveUsing::Typescript(_)
+        | models::DeriveUsing::Python(_) => return Ok(Vec::new()),
     };
     let missing_resource_urls: Vec<(url::Url, models::Collection)> = transforms
         .iter()
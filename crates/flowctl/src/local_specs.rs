@@ -25,6 +25,19 @@ pub(crate) async fn load_and_validate_full(
     Ok((sources, surface_errors(validations.into_result())?))
 }
 
+/// Load and validate sources and derivation and materialization connectors.
+/// Capture connectors are not validated.
+pub(crate) async fn load_and_validate_no_captures(
+    client: crate::controlplane::Client,
+    source: &str,
+    network: &str,
+) -> anyhow::Result<(tables::Sources, tables::Validations)> {
+    let source = build::arg_source_to_url(source, false)?;
+    let sources = surface_errors(load(&source).await.into_result())?;
+    let (sources, validations) = validate(client, true, false, false, sources, network).await;
+    Ok((sources, surface_errors(validations.into_result())?))
+}
+
 /// Generate connector files by validating sources with derivation connectors.
 pub(crate) async fn generate_files(
     client: crate::controlplane::Client,
@@ -19,6 +19,28 @@ pub enum OutputType {
     Table,
 }
 
+/// JSON-Schema output format of errors of documents which fail validation.
+#[derive(clap::ValueEnum, Debug, Default, Copy, Clone, PartialEq)]
+pub enum ValidationOutput {
+    /// A flat list of errors.
+    #[default]
+    Basic,
+    /// A condensed hierarchy of errors, following the structure of the schema.
+    Detailed,
+    /// An uncondensed hierarchy of errors and annotations.
+    Verbose,
+}
+
+impl From<ValidationOutput> for doc::OutputFormat {
+    fn from(value: ValidationOutput) -> Self {
+        match value {
+            ValidationOutput::Basic => doc::OutputFormat::Basic,
+            ValidationOutput::Detailed => doc::OutputFormat::Detailed,
+            ValidationOutput::Verbose => doc::OutputFormat::Verbose,
+        }
+    }
+}
+
 /// A trait for things that can be output from the CLI as either JSON, YAML, or a table.
 /// The body of this trait is focused on table output, since JSON and YAML are both handled
 /// by `Serialize`.
@@ -59,6 +59,20 @@ pub struct Preview {
     /// Docker network to run connector images.
     #[clap(long, default_value = "bridge")]
     network: String,
+    /// Path of a file into which all Requests and Responses exchanged with
+    /// the task's connector are recorded, for later use with --replay.
+    #[clap(long, conflicts_with = "replay")]
+    record: Option<std::path::PathBuf>,
+    /// Path of a file written by --record, from which connector Responses are
+    /// replayed instead of running the task's connector.
+    /// Capture connectors are not asked to validate the task when replaying,
+    /// so their source system need not be reachable.
+    #[clap(long)]
+    replay: Option<std::path::PathBuf>,
+    /// JSON-Schema output format of errors of documents which fail validation.
+    /// "basic" output is always included.
+    #[clap(long, value_enum, default_value_t)]
+    validation_output: crate::output::ValidationOutput,
 }
 
 impl Preview {
@@ -71,22 +85,35 @@ impl Preview {
             sessions,
             fixture,
             network,
+            record,
+            replay,
+            validation_output,
         } = self;
 
         let source = build::arg_source_to_url(source, false)?;
         let client = ctx.controlplane_client().await?;
 
         // TODO(johnny): validate only `name`, if presented.
-        let (_sources, validations) =
-            local_specs::load_and_validate_full(client, source.as_str(), &network).await?;
+        let (_sources, validations) = if replay.is_some() {
+            local_specs::load_and_validate_no_captures(client, source.as_str(), &network).await?
+        } else {
+            local_specs::load_and_validate_full(client, source.as_str(), &network).await?
+        };
 
-        let runtime = runtime::Runtime::new(
+        let mut runtime = runtime::Runtime::new(
             true, // Allow local.
             network.clone(),
             ops::tracing_log_handler,
             None,
             "preview".to_string(),
-        );
+        )
+        .with_validation_output((*validation_output).into());
+
+        if let Some(record) = record {
+            runtime = runtime.with_recording(record)?;
+        } else if let Some(replay) = replay {
+            runtime = runtime.with_replay(replay)?;
+        }
 
         // Default to no delay.
         let delay = delay
@@ -0,0 +1,594 @@
+use crate::local_specs;
+use crate::output::{to_table_row, CliOutput, JsonCell};
+use anyhow::Context;
+use futures::TryStreamExt;
+use proto_flow::{capture, flow, materialize};
+use runtime::harness::fixture::{Fixture, Reader};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, clap::Args)]
+#[clap(rename_all = "kebab-case")]
+pub struct Conformance {
+    /// Path or URL to a Flow specification file.
+    #[clap(long)]
+    source: String,
+    /// Name of the capture or materialization to test within the Flow specification file.
+    /// Required if there are multiple tasks in --source specifications.
+    #[clap(long)]
+    name: Option<String>,
+    /// Path to a transactions fixture of documents to materialize,
+    /// in the same format as `flowctl preview --fixture`.
+    /// Required when testing a materialization.
+    #[clap(long)]
+    fixture: Option<String>,
+    /// How long can the task produce no data before a scenario session stops?
+    #[clap(long, default_value = "30s")]
+    timeout: humantime::Duration,
+    /// Bytes of padding added to each document of the large-documents scenario.
+    #[clap(long, default_value = "8388608")]
+    large_document_bytes: usize,
+    /// Docker network to run connector images.
+    #[clap(long, default_value = "bridge")]
+    network: String,
+}
+
+/// Scenario is a scripted exercise of a connector through the runtime harness.
+struct Scenario {
+    name: &'static str,
+    description: &'static str,
+    // Number of sessions and their target transactions, as with `flowctl preview --sessions`.
+    sessions: Vec<usize>,
+    // Number of runs which fail by dropping the runtime RPC in the middle of
+    // their second transaction, before the final run of `sessions` which must
+    // resume from the last committed transaction.
+    failures: usize,
+    // Delay before each poll of a capture, simulating back-pressure.
+    poll_delay: std::time::Duration,
+    // Fixture of the scenario, which is used only by materializations.
+    fixture: Fixture,
+    // Expected loaded documents of each transaction of a materialization,
+    // by collection name. Transactions beyond its length are not checked.
+    loads: Vec<BTreeMap<String, u32>>,
+}
+
+/// Outcome of running a Scenario.
+#[derive(serde::Serialize)]
+struct Outcome {
+    scenario: &'static str,
+    description: &'static str,
+    passed: bool,
+    responses: usize,
+    // Protocol violation or other error of the scenario, which includes the offending message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    violation: Option<String>,
+}
+
+impl CliOutput for Outcome {
+    type TableAlt = ();
+    type CellValue = JsonCell;
+
+    fn table_headers(_alt: Self::TableAlt) -> Vec<&'static str> {
+        vec!["Scenario", "Passed", "Responses", "Violation"]
+    }
+
+    fn into_table_row(self, _alt: Self::TableAlt) -> Vec<Self::CellValue> {
+        to_table_row(self, &["/scenario", "/passed", "/responses", "/violation"])
+    }
+}
+
+pub async fn do_conformance(
+    ctx: &mut crate::CliContext,
+    Conformance {
+        source,
+        name,
+        fixture,
+        timeout,
+        large_document_bytes,
+        network,
+    }: &Conformance,
+) -> anyhow::Result<()> {
+    let client = ctx.controlplane_client().await?;
+    let (_sources, validations) =
+        local_specs::load_and_validate_full(client, source, network).await?;
+
+    let runtime = runtime::Runtime::new(
+        true, // Allow local.
+        network.clone(),
+        ops::tracing_log_handler,
+        None,
+        "conformance".to_string(),
+    );
+    let timeout: std::time::Duration = timeout.clone().into();
+
+    let num_tasks = validations.built_captures.len() + validations.built_materializations.len();
+
+    if num_tasks == 0 {
+        anyhow::bail!(
+            "sourced specification files do not contain any captures or materializations"
+        );
+    } else if num_tasks > 1 && name.is_none() {
+        anyhow::bail!("sourced specification files contain multiple captures or materializations. Use --name to identify a specific task");
+    }
+
+    let mut outcomes = Vec::new();
+
+    if let Some(capture) = validations
+        .built_captures
+        .iter()
+        .find(|c| name.is_none() || matches!(name, Some(n) if n == &c.capture))
+    {
+        let mut spec = capture.spec.clone();
+
+        // Disable UUID placeholders.
+        for binding in spec.bindings.iter_mut() {
+            binding.collection.as_mut().unwrap().uuid_ptr = String::new();
+        }
+
+        for scenario in capture_scenarios() {
+            outcomes.push(run_capture_scenario(&runtime, &spec, scenario, timeout).await);
+        }
+    } else if let Some(materialization) = validations
+        .built_materializations
+        .iter()
+        .find(|m| name.is_none() || matches!(name, Some(n) if n == m.materialization.as_str()))
+    {
+        let fixture = fixture
+            .as_ref()
+            .context("--fixture is required to test a materialization")?;
+        let fixture = std::fs::read(fixture).context("couldn't open fixture file")?;
+        let fixture: Fixture =
+            serde_json::from_slice(&fixture).context("couldn't parse fixture")?;
+
+        for scenario in materialize_scenarios(&materialization.spec, fixture, *large_document_bytes)
+        {
+            outcomes.push(
+                run_materialize_scenario(&runtime, &materialization.spec, scenario, timeout).await,
+            );
+        }
+    } else {
+        anyhow::bail!("could not find task {}", name.as_ref().unwrap());
+    }
+
+    let failed = outcomes.iter().filter(|o| !o.passed).count();
+    let total = outcomes.len();
+    ctx.write_all(outcomes, ())?;
+
+    if failed != 0 {
+        anyhow::bail!("{failed} of {total} conformance scenarios failed");
+    }
+    Ok(())
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self {
+            name: "",
+            description: "",
+            sessions: vec![usize::MAX],
+            failures: 0,
+            poll_delay: std::time::Duration::ZERO,
+            fixture: Fixture::new(),
+            loads: Vec::new(),
+        }
+    }
+}
+
+// Scenarios of captures. Materialization scenarios which vary the documents
+// of a fixture have no capture counterpart, because a capture's documents
+// and transactions are produced by its connector rather than the harness.
+fn capture_scenarios() -> Vec<Scenario> {
+    vec![
+        Scenario {
+            name: "baseline",
+            description: "Three transactions within a single session.",
+            sessions: vec![3],
+            ..Default::default()
+        },
+        Scenario {
+            name: "restart-every-checkpoint",
+            description: "The connector is restarted after every committed checkpoint.",
+            sessions: vec![1; 5],
+            ..Default::default()
+        },
+        Scenario {
+            name: "open-after-failure",
+            description: "Runs which fail before committing their second transaction are followed by an Open which must resume.",
+            sessions: vec![1],
+            failures: 3,
+            ..Default::default()
+        },
+        Scenario {
+            name: "back-pressure",
+            description: "Each transaction is polled only after a delay, while the connector continues to produce data.",
+            sessions: vec![3],
+            poll_delay: std::time::Duration::from_secs(1),
+            ..Default::default()
+        },
+    ]
+}
+
+fn materialize_scenarios(
+    spec: &flow::MaterializationSpec,
+    fixture: Fixture,
+    large_document_bytes: usize,
+) -> Vec<Scenario> {
+    let transactions = fixture.len();
+    let repeated = with_repeated_transactions(fixture.clone());
+    let loads = repeated_loads(spec, &repeated);
+
+    vec![
+        Scenario {
+            name: "baseline",
+            description: "All fixture transactions within a single session.",
+            fixture: fixture.clone(),
+            ..Default::default()
+        },
+        Scenario {
+            name: "restart-every-checkpoint",
+            description: "The connector is restarted after every committed checkpoint.",
+            sessions: vec![1; transactions],
+            fixture: fixture.clone(),
+            ..Default::default()
+        },
+        Scenario {
+            name: "open-after-failure",
+            description: "Runs which fail before committing their second transaction are followed by an Open which must resume.",
+            failures: transactions,
+            fixture: fixture.clone(),
+            ..Default::default()
+        },
+        Scenario {
+            name: "empty-transactions",
+            description: "Transactions having no documents are interleaved with fixture transactions.",
+            fixture: with_empty_transactions(fixture.clone()),
+            ..Default::default()
+        },
+        Scenario {
+            name: "duplicate-loads",
+            description: "Every fixture transaction is repeated, and the repeat must load each key stored by the prior transaction.",
+            fixture: repeated,
+            loads,
+            ..Default::default()
+        },
+        Scenario {
+            name: "large-documents",
+            description: "Fixture documents are padded with a large `_conformancePadding` property.",
+            fixture: with_large_documents(fixture, large_document_bytes),
+            ..Default::default()
+        },
+    ]
+}
+
+async fn run_capture_scenario<L: runtime::LogHandler>(
+    runtime: &runtime::Runtime<L>,
+    spec: &flow::CaptureSpec,
+    scenario: Scenario,
+    timeout: std::time::Duration,
+) -> Outcome {
+    tracing::info!(
+        scenario = scenario.name,
+        "starting capture conformance scenario"
+    );
+    let state_dir = tempfile::tempdir().unwrap();
+    let mut responses = 0;
+
+    let run = |sessions| {
+        runtime::harness::run_capture(
+            scenario.poll_delay,
+            runtime.clone(),
+            sessions,
+            spec,
+            models::RawValue::default(),
+            state_dir.path(),
+            timeout,
+        )
+    };
+    // The final Checkpoint of a transaction is read before it's committed.
+    let is_final_checkpoint = |response: &capture::Response| {
+        matches!(
+            response.checkpoint,
+            Some(capture::response::Checkpoint { state: None })
+        )
+    };
+
+    let result = async {
+        for _ in 0..scenario.failures {
+            let responses_rx = run(vec![usize::MAX]);
+            read_run(
+                responses_rx,
+                &mut responses,
+                Some(is_final_checkpoint),
+                |_| Ok(()),
+            )
+            .await?;
+        }
+        let responses_rx = run(scenario.sessions.clone());
+        read_run(responses_rx, &mut responses, None, |_| Ok(())).await
+    }
+    .await;
+
+    Outcome::new(scenario, responses, result)
+}
+
+async fn run_materialize_scenario<L: runtime::LogHandler>(
+    runtime: &runtime::Runtime<L>,
+    spec: &flow::MaterializationSpec,
+    scenario: Scenario,
+    timeout: std::time::Duration,
+) -> Outcome {
+    tracing::info!(
+        scenario = scenario.name,
+        "starting materialization conformance scenario"
+    );
+    let state_dir = tempfile::tempdir().unwrap();
+    let mut responses = 0;
+
+    let run = |sessions| {
+        runtime::harness::run_materialize(
+            Reader(scenario.fixture.clone()),
+            runtime.clone(),
+            sessions,
+            spec,
+            models::RawValue::default(),
+            state_dir.path(),
+            timeout,
+        )
+    };
+    // Flushed is read before the transaction is committed.
+    let is_flushed = |response: &materialize::Response| response.flushed.is_some();
+
+    let result = async {
+        for _ in 0..scenario.failures {
+            let responses_rx = run(vec![usize::MAX]);
+            read_run(responses_rx, &mut responses, Some(is_flushed), |_| Ok(())).await?;
+        }
+
+        let mut transaction = 0;
+        let check_loads = |response: &materialize::Response| {
+            if response.flushed.is_none() {
+                return Ok(());
+            }
+            transaction += 1;
+
+            let Some(expect) = scenario.loads.get(transaction - 1) else {
+                return Ok(());
+            };
+            let stats = response
+                .get_internal()
+                .context("failed to decode internal runtime.MaterializeResponseExt")?
+                .flushed
+                .and_then(|flushed| flushed.stats)
+                .unwrap_or_default();
+
+            for (collection, expect) in expect {
+                let loaded = stats
+                    .materialize
+                    .get(collection)
+                    .and_then(|binding| binding.left.as_ref())
+                    .map(|left| left.docs_total)
+                    .unwrap_or_default();
+
+                if loaded != *expect {
+                    anyhow::bail!(
+                        "transaction {transaction} loaded {loaded} documents of {collection}, but its {expect} distinct keys were stored by the prior transaction"
+                    );
+                }
+            }
+            Ok(())
+        };
+
+        let responses_rx = run(scenario.sessions.clone());
+        read_run(responses_rx, &mut responses, None, check_loads).await
+    }
+    .await;
+
+    Outcome::new(scenario, responses, result)
+}
+
+// Read the responses of a harness run, counting them into `responses` and
+// verifying each with `check`. If `fail_at` is given, the run is instead
+// dropped upon the second response it matches, which fails the runtime RPC
+// and its connector in the middle of an uncommitted transaction.
+async fn read_run<R>(
+    responses_rx: impl futures::Stream<Item = anyhow::Result<R>>,
+    responses: &mut usize,
+    fail_at: Option<fn(&R) -> bool>,
+    mut check: impl FnMut(&R) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    tokio::pin!(responses_rx);
+    let mut matched = 0;
+
+    while let Some(response) = responses_rx.try_next().await? {
+        *responses += 1;
+        () = check(&response)?;
+
+        if matches!(fail_at, Some(fail_at) if fail_at(&response)) {
+            matched += 1;
+        }
+        if matched == 2 {
+            tracing::debug!("failing the run in the middle of a transaction");
+            return Ok(()); // Drop `responses_rx`.
+        }
+    }
+    Ok(())
+}
+
+impl Outcome {
+    fn new(scenario: Scenario, responses: usize, result: anyhow::Result<()>) -> Self {
+        let Scenario {
+            name, description, ..
+        } = scenario;
+
+        let violation = result.err().map(|err| format!("{err:#}"));
+
+        if let Some(violation) = &violation {
+            tracing::error!(scenario = name, violation, "conformance scenario failed");
+        }
+
+        Self {
+            scenario: name,
+            description,
+            passed: violation.is_none(),
+            responses,
+            violation,
+        }
+    }
+}
+
+// Interleave an empty transaction before each transaction of the fixture, and after the last.
+fn with_empty_transactions(fixture: Fixture) -> Fixture {
+    let mut out = vec![Vec::new()];
+
+    for transaction in fixture {
+        out.push(transaction);
+        out.push(Vec::new());
+    }
+    out
+}
+
+// Repeat each transaction of the fixture, so that every key stored by
+// a transaction is loaded again by the next. The runtime de-duplicates loads
+// of a key within a transaction, so repeats must span transactions.
+fn with_repeated_transactions(fixture: Fixture) -> Fixture {
+    fixture
+        .into_iter()
+        .flat_map(|transaction| [transaction.clone(), transaction])
+        .collect()
+}
+
+// Map each repeated transaction of `with_repeated_transactions` to the number
+// of distinct keys which it must load, by collection. Bindings which use
+// delta updates don't load documents, and aren't counted.
+fn repeated_loads(
+    spec: &flow::MaterializationSpec,
+    repeated: &Fixture,
+) -> Vec<BTreeMap<String, u32>> {
+    let mut loads = Vec::new();
+
+    for (index, transaction) in repeated.iter().enumerate() {
+        let mut keys = BTreeMap::<String, BTreeSet<(usize, String)>>::new();
+
+        for (binding_index, binding) in spec.bindings.iter().enumerate() {
+            let Some(collection) = &binding.collection else {
+                continue;
+            };
+            if binding.delta_updates {
+                continue;
+            }
+            let entry = keys.entry(collection.name.clone()).or_default();
+
+            for (_, doc) in transaction
+                .iter()
+                .filter(|(name, _)| name.as_str() == collection.name)
+            {
+                let key: Vec<_> = collection
+                    .key
+                    .iter()
+                    .map(|ptr| doc::Pointer::from_str(ptr).query(doc))
+                    .collect();
+
+                entry.insert((binding_index, serde_json::to_string(&key).unwrap()));
+            }
+        }
+
+        // Only the second of each pair of transactions has an expectation.
+        loads.push(if index % 2 == 1 {
+            keys.into_iter()
+                .map(|(name, keys)| (name, keys.len() as u32))
+                .collect()
+        } else {
+            BTreeMap::new()
+        });
+    }
+    loads
+}
+
+// Pad each document object with a string property having `bytes` of content.
+fn with_large_documents(mut fixture: Fixture, bytes: usize) -> Fixture {
+    let padding = serde_json::Value::String("x".repeat(bytes));
+
+    for (_collection, doc) in fixture.iter_mut().flatten() {
+        if let serde_json::Value::Object(doc) = doc {
+            doc.insert("_conformancePadding".to_string(), padding.clone());
+        }
+    }
+    fixture
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_fixture_scenarios() {
+        let fixture: Fixture = serde_json::from_value(json!([
+            [["acmeCo/one", {"id": 1}], ["acmeCo/two", {"id": 2}]],
+            [["acmeCo/one", {"id": 3}]],
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(with_empty_transactions(fixture.clone())).unwrap(),
+            json!([
+                [],
+                [["acmeCo/one", {"id": 1}], ["acmeCo/two", {"id": 2}]],
+                [],
+                [["acmeCo/one", {"id": 3}]],
+                [],
+            ])
+        );
+        assert_eq!(
+            serde_json::to_value(with_repeated_transactions(fixture.clone())).unwrap(),
+            json!([
+                [["acmeCo/one", {"id": 1}], ["acmeCo/two", {"id": 2}]],
+                [["acmeCo/one", {"id": 1}], ["acmeCo/two", {"id": 2}]],
+                [["acmeCo/one", {"id": 3}]],
+                [["acmeCo/one", {"id": 3}]],
+            ])
+        );
+
+        // Bindings of acmeCo/one, one of which uses delta updates, and acmeCo/two.
+        let spec = flow::MaterializationSpec {
+            bindings: [
+                ("acmeCo/one", false),
+                ("acmeCo/one", true),
+                ("acmeCo/two", false),
+            ]
+            .into_iter()
+            .map(
+                |(name, delta_updates)| flow::materialization_spec::Binding {
+                    collection: Some(flow::CollectionSpec {
+                        name: name.to_string(),
+                        key: vec!["/id".to_string()],
+                        ..Default::default()
+                    }),
+                    delta_updates,
+                    ..Default::default()
+                },
+            )
+            .collect(),
+            ..Default::default()
+        };
+        let repeated: Fixture = serde_json::from_value(json!([
+            [["acmeCo/one", {"id": 1}], ["acmeCo/one", {"id": 1, "v": 2}], ["acmeCo/one", {"id": 2}]],
+            [["acmeCo/one", {"id": 1}], ["acmeCo/one", {"id": 1, "v": 2}], ["acmeCo/one", {"id": 2}]],
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(repeated_loads(&spec, &repeated)).unwrap(),
+            json!([{}, {"acmeCo/one": 2, "acmeCo/two": 0}]),
+        );
+        assert_eq!(
+            serde_json::to_value(with_large_documents(fixture, 4)).unwrap(),
+            json!([
+                [
+                    ["acmeCo/one", {"id": 1, "_conformancePadding": "xxxx"}],
+                    ["acmeCo/two", {"id": 2, "_conformancePadding": "xxxx"}],
+                ],
+                [["acmeCo/one", {"id": 3, "_conformancePadding": "xxxx"}]],
+            ])
+        );
+    }
+}
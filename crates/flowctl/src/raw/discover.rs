@@ -130,6 +130,7 @@ pub async fn do_discover(
             disable: false,
             resource: models::RawValue::from_string(binding.resource_config_json)?,
             backfill: 0,
+            rate_limit: None,
         });
 
         collections.insert(
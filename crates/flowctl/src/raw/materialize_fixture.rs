@@ -146,20 +146,22 @@ pub async fn do_materialize_fixture(
         for store in stores {
             emit(store)
         }
+        let checkpoint = RuntimeCheckpoint {
+            sources: [(
+                "a/read/journal;suffix".to_string(),
+                runtime_checkpoint::Source {
+                    read_through: round as i64,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..Default::default()
+        };
         emit(Request {
             start_commit: Some(request::StartCommit {
-                runtime_checkpoint: Some(RuntimeCheckpoint {
-                    sources: [(
-                        "a/read/journal;suffix".to_string(),
-                        runtime_checkpoint::Source {
-                            read_through: round as i64,
-                            ..Default::default()
-                        },
-                    )]
-                    .into(),
-                    ..Default::default()
-                }),
-                ..Default::default()
+                // Our only source changes with every round.
+                runtime_checkpoint_delta: Some(checkpoint.clone()),
+                runtime_checkpoint: Some(checkpoint),
             }),
             ..Default::default()
         });
@@ -6,10 +6,12 @@ use std::{
     path::PathBuf,
 };
 
+mod conformance;
 mod discover;
 mod materialize_fixture;
 mod oauth;
 mod spec;
+mod state;
 mod suggest_schema;
 
 #[derive(Debug, clap::Args)]
@@ -53,8 +55,20 @@ pub enum Command {
     Discover(discover::Discover),
     /// Get the spec output of a connector
     Spec(spec::Spec),
+    /// Drive a capture or materialization connector through scripted
+    /// scenarios, and report any protocol violations it commits.
+    ///
+    /// Scenarios include restarting the connector at every checkpoint,
+    /// re-opening it after a session which failed to commit, and (for
+    /// materializations) empty transactions, duplicate Loads, and large documents.
+    Conformance(conformance::Conformance),
     /// Test a connector's OAuth config
     Oauth(oauth::Oauth),
+    /// Export, import, or edit the persisted state of a task RocksDB.
+    ///
+    /// This is intended for offline inspection and repair of task state
+    /// during incident recovery. The task must not be running.
+    State(state::State),
     /// Suggest a schema that would alleviate document schema violations of a specific collection
     SuggestSchema(suggest_schema::SuggestSchema),
     /// Emit the Flow specification JSON-Schema.
@@ -132,6 +146,10 @@ pub struct Combine {
     /// Name of a collection in the Flow specification file.
     #[clap(long)]
     collection: String,
+    /// JSON-Schema output format of errors of documents which fail validation.
+    /// "basic" output is always included.
+    #[clap(long, value_enum, default_value_t)]
+    validation_output: crate::output::ValidationOutput,
 }
 
 impl Advanced {
@@ -148,7 +166,9 @@ impl Advanced {
             }
             Command::Discover(args) => discover::do_discover(ctx, args).await,
             Command::Spec(args) => spec::do_spec(ctx, args).await,
+            Command::Conformance(args) => conformance::do_conformance(ctx, args).await,
             Command::Oauth(args) => oauth::do_oauth(ctx, args).await,
+            Command::State(state) => state.run(ctx).await,
             Command::SuggestSchema(args) => suggest_schema::do_suggest_schema(ctx, args).await,
             Command::JsonSchema => {
                 let schema = models::Catalog::root_json_schema();
@@ -245,7 +265,11 @@ async fn do_bundle(ctx: &mut crate::CliContext, Bundle { source }: &Bundle) -> a
 
 async fn do_combine(
     ctx: &mut crate::CliContext,
-    Combine { source, collection }: &Combine,
+    Combine {
+        source,
+        collection,
+        validation_output,
+    }: &Combine,
 ) -> anyhow::Result<()> {
     let (_sources, validations) =
         local_specs::load_and_validate(ctx.controlplane_client().await?, source).await?;
@@ -273,7 +297,9 @@ async fn do_combine(
                 &doc::SerPolicy::default(),
             )?,
             None,
-            doc::Validator::new(schema).unwrap(),
+            doc::Validator::new(schema)
+                .unwrap()
+                .with_output_format((*validation_output).into()),
         ),
         tempfile::tempfile().context("opening tempfile")?,
     )?;
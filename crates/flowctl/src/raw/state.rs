@@ -0,0 +1,209 @@
+use anyhow::Context;
+use proto_flow::runtime::RocksDbDescriptor;
+use proto_gazette::consumer;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, clap::Args)]
+#[clap(rename_all = "kebab-case")]
+pub struct State {
+    #[clap(subcommand)]
+    cmd: Command,
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[clap(rename_all = "kebab-case")]
+pub enum Command {
+    /// Export the runtime checkpoint and connector state of a task RocksDB as JSON.
+    Export(Export),
+    /// Import a runtime checkpoint and connector state into a task RocksDB,
+    /// replacing its current state.
+    ///
+    /// The imported JSON has the same shape as the output of `export`.
+    Import(Import),
+    /// Edit the runtime checkpoint and connector state of a task RocksDB
+    /// using $EDITOR, and import the result.
+    Edit(Edit),
+}
+
+#[derive(Debug, clap::Args)]
+#[clap(rename_all = "kebab-case")]
+pub struct Export {
+    /// Path to the RocksDB directory of the task.
+    /// The RocksDB must not be in use by a running task.
+    #[clap(long)]
+    rocksdb: PathBuf,
+}
+
+#[derive(Debug, clap::Args)]
+#[clap(rename_all = "kebab-case")]
+pub struct Import {
+    /// Path to the RocksDB directory of the task.
+    /// The RocksDB must not be in use by a running task.
+    #[clap(long)]
+    rocksdb: PathBuf,
+    /// Path of the JSON state to import. If not set, the state is read from stdin.
+    #[clap(long)]
+    file: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+#[clap(rename_all = "kebab-case")]
+pub struct Edit {
+    /// Path to the RocksDB directory of the task.
+    /// The RocksDB must not be in use by a running task.
+    #[clap(long)]
+    rocksdb: PathBuf,
+}
+
+/// TaskState is the JSON representation of the state of a task RocksDB.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct TaskState {
+    // Runtime checkpoint of the task.
+    #[serde(default)]
+    checkpoint: consumer::Checkpoint,
+    // Connector state of the task, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    connector_state: Option<Box<serde_json::value::RawValue>>,
+}
+
+impl State {
+    pub async fn run(&self, _ctx: &mut crate::CliContext) -> anyhow::Result<()> {
+        match &self.cmd {
+            Command::Export(Export { rocksdb }) => {
+                // Exports inspect the RocksDB as-is, without migrating a legacy state file.
+                let state = load(&open(rocksdb, false)?)?;
+                serde_json::to_writer_pretty(std::io::stdout(), &state)?;
+                println!();
+                Ok(())
+            }
+            Command::Import(Import { rocksdb, file }) => {
+                let content = match file {
+                    Some(file) => {
+                        std::fs::read(file).with_context(|| format!("failed to read {file:?}"))?
+                    }
+                    None => {
+                        let mut content = Vec::new();
+                        std::io::stdin().read_to_end(&mut content)?;
+                        content
+                    }
+                };
+                let state: TaskState =
+                    serde_json::from_slice(&content).context("failed to parse state")?;
+
+                store(&open(rocksdb, true)?, &state)
+            }
+            Command::Edit(Edit { rocksdb }) => do_edit(rocksdb),
+        }
+    }
+}
+
+fn do_edit(rocksdb: &Path) -> anyhow::Result<()> {
+    let db = open(rocksdb, true)?;
+    let state = load(&db)?;
+
+    let tmp = tempfile::Builder::new()
+        .prefix("task-state-")
+        .suffix(".json")
+        .tempfile()
+        .context("creating temporary file")?;
+    serde_json::to_writer_pretty(tmp.as_file(), &state)?;
+
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    let mut editor = editor.split_whitespace();
+    let program = editor.next().context("$EDITOR is empty")?;
+
+    let status = std::process::Command::new(program)
+        .args(editor)
+        .arg(tmp.path())
+        .status()
+        .with_context(|| format!("failed to run editor {program:?}"))?;
+
+    if !status.success() {
+        anyhow::bail!("editor exited with {status}; the task state was not changed");
+    }
+
+    let content = std::fs::read(tmp.path())?;
+    let edited: TaskState =
+        serde_json::from_slice(&content).context("failed to parse edited state")?;
+
+    if serde_json::to_vec(&edited)? == serde_json::to_vec(&state)? {
+        tracing::info!("task state was not changed");
+        return Ok(());
+    }
+    store(&db, &edited)?;
+    tracing::info!("imported edited task state");
+
+    Ok(())
+}
+
+fn open(rocksdb: &Path, migrate: bool) -> anyhow::Result<runtime::RocksDB> {
+    if !rocksdb.is_dir() {
+        anyhow::bail!("{rocksdb:?} is not a RocksDB directory");
+    }
+    let desc = Some(RocksDbDescriptor {
+        rocksdb_path: rocksdb.to_string_lossy().to_string(),
+        rocksdb_env_memptr: 0,
+    });
+
+    if migrate {
+        runtime::RocksDB::open(desc)
+    } else {
+        runtime::RocksDB::open_without_migration(desc)
+    }
+}
+
+fn load(db: &runtime::RocksDB) -> anyhow::Result<TaskState> {
+    let checkpoint = db.load_checkpoint()?;
+    let connector_state = db
+        .load_connector_state()?
+        .map(serde_json::value::RawValue::from_string)
+        .transpose()
+        .context("persisted connector state is not valid JSON")?;
+
+    Ok(TaskState {
+        checkpoint,
+        connector_state,
+    })
+}
+
+fn store(db: &runtime::RocksDB, state: &TaskState) -> anyhow::Result<()> {
+    db.write_state(
+        &state.checkpoint,
+        state.connector_state.as_ref().map(|state| state.get()),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_state_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = open(dir.path(), true).unwrap();
+
+        // A new RocksDB has an empty state.
+        assert_eq!(
+            serde_json::to_value(load(&db).unwrap()).unwrap(),
+            json!({"checkpoint": {}})
+        );
+
+        let fixture = json!({
+            "checkpoint": {
+                "sources": {
+                    "a/journal;suffix": {"readThrough": "1234"}
+                }
+            },
+            "connectorState": {"cursor": 42},
+        });
+        store(&db, &serde_json::from_str(&fixture.to_string()).unwrap()).unwrap();
+        assert_eq!(serde_json::to_value(load(&db).unwrap()).unwrap(), fixture);
+
+        // Removing `connectorState` removes the persisted connector state.
+        store(&db, &serde_json::from_str(r#"{"checkpoint":{}}"#).unwrap()).unwrap();
+        assert_eq!(db.load_connector_state().unwrap(), None);
+    }
+}
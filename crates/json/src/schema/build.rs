@@ -1,6 +1,6 @@
 use crate::schema::{
-    intern, keywords, types, Annotation, Application, CoreAnnotation, HashedLiteral, Keyword,
-    Schema, Validation,
+    intern, keywords, types, Annotation, Application, CoreAnnotation, Draft, HashedLiteral,
+    Keyword, Schema, Validation,
 };
 use crate::{de, NoopWalker, Number};
 use fancy_regex as regex;
@@ -75,6 +75,7 @@ where
     curi: url::Url,
     kw: Vec<Keyword<A>>,
     tbl: intern::Table,
+    draft: Draft,
 
     // "nullable" support for OpenAPI schemas prior to version 3.1,
     // which are still prevelant as of Sept 2021.
@@ -124,18 +125,20 @@ where
                 K::Application(A::AdditionalProperties, _) => 4,
                 // UnevaluatedProperties is evaluated last.
 
-                // Contains is always applied. Items conditions whether AdditionalItems applies.
+                // Contains is always applied. PrefixItems conditions whether Items applies,
+                // and Items conditions whether AdditionalItems applies.
                 K::Application(A::Contains, _) => 5,
-                K::Application(A::Items { .. }, _) => 6,
+                K::Application(A::PrefixItems { .. }, _) => 6,
+                K::Application(A::Items { .. }, _) => 7,
                 // AdditionalItems also conditions whether UnevaluatedItems applies.
-                K::Application(A::AdditionalItems, _) => 7,
+                K::Application(A::AdditionalItems, _) => 8,
                 // UnevaluatedItems is evaluated last.
 
                 // When unwinding applications, we want to know which branch was taken before
                 // we examine branch results.
-                K::Application(A::Else, _) => 8,
-                K::Application(A::Then, _) => 9,
-                K::Application(A::If, _) => 10,
+                K::Application(A::Else, _) => 9,
+                K::Application(A::Then, _) => 10,
+                K::Application(A::If, _) => 11,
 
                 _ => 100,
             }
@@ -166,6 +169,13 @@ where
                 sj::Value::Bool(b) if !*b => (), // Ignore.
                 _ => return Err(ExpectedBool),
             },
+            keywords::DYNAMIC_ANCHOR if self.draft == Draft::Draft2020_12 => match v {
+                sj::Value::String(anchor) => {
+                    let anchor = self.curi.join(&format!("#{}", anchor))?;
+                    self.kw.push(Keyword::DynamicAnchor(anchor))
+                }
+                _ => return Err(ExpectedString),
+            },
             keywords::ANCHOR => match v {
                 sj::Value::String(anchor) => {
                     let anchor = self.curi.join(&format!("#{}", anchor))?;
@@ -209,6 +219,16 @@ where
                 }
                 _ => return Err(ExpectedString),
             },
+            keywords::DYNAMIC_REF if self.draft == Draft::Draft2020_12 => match v {
+                sj::Value::String(ref_uri) => {
+                    let mut ref_uri = self.curi.join(ref_uri)?;
+                    if let Some("") = ref_uri.fragment() {
+                        ref_uri.set_fragment(None);
+                    }
+                    self.add_application(App::DynamicRef(ref_uri), &true_placeholder)?;
+                }
+                _ => return Err(ExpectedString),
+            },
             keywords::ANY_OF => match v {
                 sj::Value::Array(children) => {
                     for (i, child) in children.iter().enumerate() {
@@ -287,6 +307,10 @@ where
                 sj::Value::Object(_) | sj::Value::Bool(_) => {
                     self.add_application(App::Items { index: None }, v)?
                 }
+                // Draft 2020-12 replaced the array form of "items" with "prefixItems".
+                sj::Value::Array(_) if self.draft == Draft::Draft2020_12 => {
+                    return Err(ExpectedSchema)
+                }
                 sj::Value::Array(vec) => {
                     for (i, child) in vec.iter().enumerate() {
                         self.add_application(App::Items { index: Some(i) }, child)?;
@@ -294,6 +318,16 @@ where
                 }
                 _ => return Err(ExpectedSchemaOrArrayOfSchemas),
             },
+            keywords::PREFIX_ITEMS if self.draft == Draft::Draft2020_12 => match v {
+                sj::Value::Array(vec) => {
+                    for (i, child) in vec.iter().enumerate() {
+                        self.add_application(App::PrefixItems { index: i }, child)?;
+                    }
+                }
+                _ => return Err(ExpectedArray),
+            },
+            // Draft 2020-12 removed "additionalItems" in favor of "items", but we continue
+            // to support it for schemas which use it alongside "prefixItems".
             keywords::ADDITIONAL_ITEMS => self.add_application(App::AdditionalItems, v)?,
             keywords::UNEVALUATED_ITEMS => self.add_application(App::UnevaluatedItems, v)?,
 
@@ -435,7 +469,7 @@ where
         // Note that it could still override with it's own $id keyword.
         let child_uri = self.curi.join(ptr.as_str()).unwrap();
 
-        let child = build_schema(child_uri, child)?;
+        let child = build_schema_with_draft(child_uri, child, self.draft)?;
         self.kw.push(Keyword::Application(app, child));
 
         Ok(())
@@ -443,7 +477,23 @@ where
 }
 
 /// `build_schema` builds a Schema instance from a JSON-Schema document.
+/// The document is built under the draft of its `$schema` keyword,
+/// or under draft 2019-09 if it doesn't have one.
 pub fn build_schema<A>(curi: url::Url, v: &sj::Value) -> Result<Schema<A>, Error>
+where
+    A: AnnotationBuilder,
+{
+    build_schema_with_draft(curi, v, Draft::Draft2019_09)
+}
+
+/// `build_schema_with_draft` builds a Schema instance from a JSON-Schema document,
+/// under the given default `draft` which applies if the document (or a sub-schema)
+/// doesn't declare its own draft through a `$schema` keyword.
+pub fn build_schema_with_draft<A>(
+    curi: url::Url,
+    v: &sj::Value,
+    draft: Draft,
+) -> Result<Schema<A>, Error>
 where
     A: AnnotationBuilder,
 {
@@ -477,6 +527,11 @@ where
         curi: build_curi(curi, obj.get(keywords::ID))?,
         kw,
         tbl,
+        draft: obj
+            .get(keywords::SCHEMA)
+            .and_then(|s| s.as_str())
+            .and_then(Draft::from_uri)
+            .unwrap_or(draft),
         nullable: obj
             .get(keywords::NULLABLE)
             .and_then(|n| n.as_bool())
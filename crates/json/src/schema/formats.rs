@@ -3,12 +3,16 @@ use bigdecimal::BigDecimal;
 use fancy_regex::Regex;
 use iri_string::spec::{IriSpec, UriSpec};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 use std::{net::IpAddr, str::FromStr};
 use time::macros::format_description;
 use uuid::Uuid;
 
 use crate::validator::ValidationResult;
 
+mod codes;
+
 #[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum Format {
@@ -18,10 +22,8 @@ pub enum Format {
     Time,
     Email,
     Hostname,
-    /// IdnHostname is parsed but is not supported (validation always fails).
     #[serde(rename = "idn-hostname")]
     IdnHostname,
-    /// IdnEmail is parsed but is not supported (validation always fails).
     #[serde(rename = "idn-email")]
     IdnEmail,
     Ipv4,
@@ -47,6 +49,126 @@ pub enum Format {
     #[serde(alias = "uint32", alias = "uint64")]
     Integer,
     Number,
+    /// ISO 4217 alphabetic currency code, such as "USD".
+    #[serde(rename = "iso-4217")]
+    Iso4217,
+    /// ISO 3166-1 alpha-2 or alpha-3 country code, such as "US" or "USA".
+    #[serde(rename = "iso-3166")]
+    Iso3166,
+    /// E.164 international telephone number, such as "+14155552671".
+    E164,
+    /// Custom is a format registered through `register_custom`.
+    #[serde(untagged)]
+    Custom(CustomFormat),
+}
+
+/// CustomFormat is a format which was registered through `register_custom`.
+/// It's an index into the registry of custom formats, which keeps Format small.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CustomFormat(u16);
+
+impl CustomFormat {
+    pub fn name(&self) -> &'static str {
+        REGISTRY.read().unwrap()[self.0 as usize].name
+    }
+}
+
+impl std::fmt::Debug for CustomFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CustomFormat").field(&self.name()).finish()
+    }
+}
+
+impl Serialize for CustomFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+
+        match REGISTRY.read().unwrap().iter().position(|c| c.name == name) {
+            Some(index) => Ok(CustomFormat(index as u16)),
+            None => Err(serde::de::Error::custom(format!("unknown format {name:?}"))),
+        }
+    }
+}
+
+type CustomValidator = Arc<dyn Fn(&str) -> ValidationResult + Send + Sync>;
+
+struct Registered {
+    name: &'static str,
+    detect: bool,
+    validate: CustomValidator,
+}
+
+/// Register a custom format `name` which is validated by `validate`.
+/// Once registered, schemas may use the format and it's validated by `Format::validate`.
+/// If `detect` is true, then `Format::detect` will also detect strings which
+/// validate as the format, if they don't match a built-in detected format.
+/// Formats are detected in the order of their registration.
+/// Registering an already-registered format replaces its validator.
+///
+/// # Panics
+///
+/// If `name` is a built-in format.
+pub fn register_custom<F>(name: &str, detect: bool, validate: F) -> CustomFormat
+where
+    F: Fn(&str) -> ValidationResult + Send + Sync + 'static,
+{
+    if let Ok(format) = serde_json::from_value::<Format>(serde_json::Value::String(name.into())) {
+        assert!(
+            matches!(format, Format::Custom(_)),
+            "{name:?} is a built-in format"
+        );
+    }
+    let mut registry = REGISTRY.write().unwrap();
+
+    if let Some(index) = registry.iter().position(|c| c.name == name) {
+        if registry[index].detect != detect {
+            update_detected(detect);
+        }
+        registry[index].detect = detect;
+        registry[index].validate = Arc::new(validate);
+        return CustomFormat(index as u16);
+    }
+    assert!(
+        registry.len() <= u16::MAX as usize,
+        "too many custom formats"
+    );
+
+    if detect {
+        update_detected(true);
+    }
+    // Custom formats are never removed, and their names are leaked
+    // so that they may be borrowed without holding the registry lock.
+    registry.push(Registered {
+        name: Box::leak(name.to_string().into_boxed_str()),
+        detect,
+        validate: Arc::new(validate),
+    });
+    CustomFormat(registry.len() as u16 - 1)
+}
+
+// Number of registered custom formats which are detected, which lets
+// `Format::detect` skip the registry when there are none. It's updated
+// only while holding the REGISTRY write lock.
+static DETECTED: AtomicUsize = AtomicUsize::new(0);
+
+fn update_detected(detect: bool) {
+    if detect {
+        DETECTED.fetch_add(1, Ordering::Release);
+    } else {
+        DETECTED.fetch_sub(1, Ordering::Release);
+    }
 }
 
 // Some are from https://github.com/JamesNK/Newtonsoft.Json.Schema/blob/master/Src/Newtonsoft.Json.Schema/Infrastructure/FormatHelpers.cs
@@ -66,12 +188,15 @@ lazy_static::lazy_static! {
     static ref JSON_POINTER_RE: Regex = Regex::new(r"^(\/([^~]|(~[01]))*)*$").expect("Is a valid regex");
     static ref MACADDR: Regex = Regex::new(r"^([0-9A-Fa-f]{2}[:-]?){5}[0-9A-Fa-f]{2}$").expect("Is a valid regex");
     static ref MACADDR8: Regex = Regex::new(r"^([0-9A-Fa-f]{2}[:-]?){7}[0-9A-Fa-f]{2}$").expect("Is a valid regex");
+    static ref E164_RE: Regex = Regex::new(r"^\+[1-9][0-9]{1,14}\z").expect("Is a valid regex");
+
+    static ref REGISTRY: RwLock<Vec<Registered>> = RwLock::new(Vec::new());
 }
 
-impl ToString for Format {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let serde_json::Value::String(s) = serde_json::json!(self) {
-            s
+            f.write_str(&s)
         } else {
             panic!("Format must serialize as JSON string")
         }
@@ -116,11 +241,8 @@ impl Format {
             }
             Self::Email => ValidationResult::from(parse_email_address(val)),
             Self::Hostname => ValidationResult::from(parse_domain_name(val)),
-            // The rules/test cases for these are absolutely bonkers
-            // If we end up needing this let's revisit (jshearer)
-            Self::IdnHostname | Self::IdnEmail => {
-                ValidationResult::Invalid(Some(format!("{self:?} is not supported")))
-            }
+            Self::IdnHostname => ValidationResult::from(validate_idn_hostname(val)),
+            Self::IdnEmail => ValidationResult::from(validate_idn_email(val)),
             Self::Ipv4 => {
                 if val.starts_with('0') {
                     return ValidationResult::Invalid(None);
@@ -181,6 +303,17 @@ impl Format {
                 BigDecimal::from_str(val).is_ok()
                     || ["NaN", "Infinity", "-Infinity"].contains(&val),
             ),
+            Self::Iso4217 => ValidationResult::from(codes::CURRENCY.binary_search(&val).is_ok()),
+            Self::Iso3166 => ValidationResult::from(
+                codes::COUNTRY_ALPHA_2.binary_search(&val).is_ok()
+                    || codes::COUNTRY_ALPHA_3.binary_search(&val).is_ok(),
+            ),
+            Self::E164 => ValidationResult::from(E164_RE.is_match(val).unwrap_or(false)),
+            Self::Custom(CustomFormat(index)) => {
+                // Clone the validator so that it's not called under the registry lock.
+                let validate = REGISTRY.read().unwrap()[*index as usize].validate.clone();
+                validate(val)
+            }
         }
     }
 
@@ -192,21 +325,86 @@ impl Format {
             _ if Format::DateTime.validate(val).is_ok() => Some(Format::DateTime),
             _ if Format::Date.validate(val).is_ok() => Some(Format::Date),
             _ if Format::Uuid.validate(val).is_ok() => Some(Format::Uuid),
-            _ => None,
+            _ => Self::detect_custom(val),
         }
     }
+
+    fn detect_custom(val: &str) -> Option<Self> {
+        if DETECTED.load(Ordering::Acquire) == 0 {
+            return None;
+        }
+        let detected: Vec<_> = REGISTRY
+            .read()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.detect)
+            .map(|(index, c)| (index, c.validate.clone()))
+            .collect();
+
+        detected
+            .into_iter()
+            .find(|(_, validate)| validate(val).is_ok())
+            .map(|(index, _)| Format::Custom(CustomFormat(index as u16)))
+    }
+}
+
+// Map an internationalized domain to its IDNA ASCII form, applying the STD3 rules,
+// DNS length limits, and leading or trailing hyphen checks of UTS #46.
+fn domain_to_ascii(domain: &str) -> Result<String, String> {
+    idna::Config::default()
+        .use_std3_ascii_rules(true)
+        .verify_dns_length(true)
+        .check_hyphens(true)
+        .to_ascii(domain)
+        .map_err(|err| err.to_string())
+}
+
+// An internationalized hostname is valid if its IDNA ASCII form is a valid hostname.
+fn validate_idn_hostname(val: &str) -> Result<(), String> {
+    let ascii = domain_to_ascii(val)?;
+    parse_domain_name(&ascii).map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+// An internationalized email has an RFC 6531 local part and an internationalized hostname.
+fn validate_idn_email(val: &str) -> Result<(), String> {
+    let (local, domain) = val
+        .rsplit_once('@')
+        .ok_or_else(|| "missing '@'".to_string())?;
+
+    let domain = domain_to_ascii(domain)?;
+
+    if local.is_ascii() {
+        parse_email_address(&format!("{local}@{domain}")).map_err(|err| err.to_string())?;
+        return Ok(());
+    }
+    parse_domain_name(&domain).map_err(|err| err.to_string())?;
+
+    // A non-ASCII local part must be a dot-atom of ASCII `atext` and UTF-8 characters.
+    let is_atext = |c: char| {
+        !c.is_ascii() && !c.is_control() && !c.is_whitespace()
+            || c.is_ascii_alphanumeric()
+            || "!#$%&'*+-/=?^_`{|}~".contains(c)
+    };
+    if local.len() > 64
+        || local
+            .split('.')
+            .any(|atom| atom.is_empty() || !atom.chars().all(is_atext))
+    {
+        return Err(format!("{local:?} is not a valid local part"));
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod test {
-    use super::Format;
+    use super::{register_custom, Format};
     use crate::validator::ValidationResult;
 
     #[test]
     fn test_format_cases() {
         // Missing format cases:
-        //  * idn-hostname
-        //  * idn-email
         //  * iri
         //  * iri-reference
         for (format, value, expect) in [
@@ -231,6 +429,20 @@ mod test {
             ("email", "john at doe.com", false),
             ("hostname", "hostname.com", true),
             ("hostname", "hostname dot com", false),
+            ("idn-hostname", "hostname.com", true),
+            ("idn-hostname", "bücher.example", true),
+            ("idn-hostname", "xn--bcher-kva.example", true),
+            ("idn-hostname", "実例.テスト", true),
+            ("idn-hostname", "bücher dot example", false),
+            ("idn-hostname", "-bücher.example", false),
+            ("idn-hostname", "", false),
+            ("idn-email", "john@doe.com", true),
+            ("idn-email", "用户@例子.广告", true),
+            ("idn-email", "josé@bücher.example", true),
+            ("idn-email", "john at doe.com", false),
+            ("idn-email", "用户..名@例子.广告", false),
+            ("idn-email", "用户 名@例子.广告", false),
+            ("idn-email", "用户@-例子.广告", false),
             ("ipv4", "123.45.6.78", true),
             ("ipv4", "123.45.6.78.9", false),
             ("ipv4", "0.1.2.3", false),
@@ -282,6 +494,20 @@ mod test {
             ("number", "Infinity", true),
             ("number", "-Infinity", true),
             ("number", "infinity", false),
+            ("iso-4217", "USD", true),
+            ("iso-4217", "EUR", true),
+            ("iso-4217", "usd", false),
+            ("iso-4217", "ABC", false),
+            ("iso-3166", "US", true),
+            ("iso-3166", "DEU", true),
+            ("iso-3166", "us", false),
+            ("iso-3166", "XX", false),
+            ("e164", "+14155552671", true),
+            ("e164", "+442071838750", true),
+            ("e164", "14155552671", false),
+            ("e164", "+1 415 555 2671", false),
+            ("e164", "+0123456", false),
+            ("e164", "+1234567890123456", false),
         ] {
             let format: Format =
                 serde_json::from_value(serde_json::Value::String(format.to_string())).unwrap();
@@ -298,4 +524,50 @@ mod test {
             }
         }
     }
+    #[test]
+    fn test_custom_formats() {
+        // The registry is shared by all tests, so formats registered here
+        // have unique names and detect only values having a unique prefix.
+        // Unregistered formats fail to parse.
+        assert!(serde_json::from_value::<Format>(serde_json::json!("json-ticker")).is_err());
+
+        let ticker = register_custom("json-ticker", true, |val| {
+            ValidationResult::from(val.strip_prefix("json-ticker:").map_or(false, |t| {
+                (1..=5).contains(&t.len()) && t.chars().all(|c| c.is_ascii_uppercase())
+            }))
+        });
+        let sku = register_custom("json-sku", false, |val| {
+            ValidationResult::from(val.starts_with("json-sku:"))
+        });
+        assert_eq!(ticker.name(), "json-ticker");
+
+        let format: Format = serde_json::from_value(serde_json::json!("json-ticker")).unwrap();
+        assert_eq!(format, Format::Custom(ticker));
+        assert_eq!(format.to_string(), "json-ticker");
+        assert!(format.validate("json-ticker:ACME").is_ok());
+        assert!(!format.validate("json-ticker:acme").is_ok());
+
+        // Built-in formats are detected before custom formats,
+        // and only formats registered with detection are detected.
+        assert_eq!(
+            Format::detect("json-ticker:ACME"),
+            Some(Format::Custom(ticker))
+        );
+        assert_eq!(Format::detect("1234"), Some(Format::Integer));
+        assert_eq!(Format::detect("json-sku:123"), None);
+        assert!(Format::Custom(sku).validate("json-sku:123").is_ok());
+
+        // Re-registration replaces the validator.
+        let sku = register_custom("json-sku", true, |val| {
+            ValidationResult::from(val.starts_with("json-sku:") && val.len() == 12)
+        });
+        assert_eq!(Format::detect("json-sku:123"), Some(Format::Custom(sku)));
+        assert!(!Format::Custom(sku).validate("json-sku:1234").is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "\"uuid\" is a built-in format")]
+    fn test_custom_format_cannot_shadow_builtin() {
+        register_custom("uuid", false, |_| ValidationResult::Valid);
+    }
 }
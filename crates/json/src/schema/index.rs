@@ -48,7 +48,8 @@ where
                 // Recurse to index a subordinate schema application.
                 Keyword::Application(_, child) => self.add(child)?,
                 // Index an alternative, anchor-form canonical URI.
-                Keyword::Anchor(auri) => {
+                // Dynamic anchors are also plain anchors of their schema.
+                Keyword::Anchor(auri) | Keyword::DynamicAnchor(auri) => {
                     if let Some(_) = self.0.insert(auri, schema) {
                         return Err(Error::DuplicateAnchorURI(schema.curi.clone()));
                     }
@@ -97,7 +98,8 @@ where
     fn references<'a>(&'a self) -> impl Iterator<Item = (&'s url::Url, &'s url::Url)> + 'a {
         self.0.iter().flat_map(|(referrer, schema)| {
             schema.kw.iter().filter_map(move |kw| match kw {
-                Keyword::Application(Application::Ref(referrent), _) => {
+                Keyword::Application(Application::Ref(referrent), _)
+                | Keyword::Application(Application::DynamicRef(referrent), _) => {
                     Some((*referrer, referrent))
                 }
                 _ => None,
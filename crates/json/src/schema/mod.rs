@@ -11,6 +11,33 @@ pub mod types;
 
 pub use build::Error as BuildError;
 
+/// Draft of the JSON-Schema specification which a schema is built under.
+/// The draft of a schema is determined by its `$schema` keyword,
+/// and is otherwise that of its parent schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Draft {
+    /// https://json-schema.org/draft/2019-09/schema, which is the default.
+    Draft2019_09,
+    /// https://json-schema.org/draft/2020-12/schema,
+    /// which is also the dialect of OpenAPI 3.1.
+    Draft2020_12,
+}
+
+impl Draft {
+    /// Returns the Draft identified by the URI of a `$schema` keyword,
+    /// or None if the URI isn't a recognized draft.
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        match uri.trim_end_matches('#') {
+            "https://json-schema.org/draft/2019-09/schema"
+            | "http://json-schema.org/draft/2019-09/schema" => Some(Self::Draft2019_09),
+            "https://json-schema.org/draft/2020-12/schema"
+            | "http://json-schema.org/draft/2020-12/schema"
+            | "https://spec.openapis.org/oas/3.1/dialect/base" => Some(Self::Draft2020_12),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Schema<A>
 where
@@ -61,6 +88,11 @@ where
     // the current *dynamic* scope, then its base URI should be used when
     // resolving a $recursiveRef of a sub-schema of the current scope.
     RecursiveAnchor,
+    // $dynamicAnchor keyword is indexed like $anchor, under its canonical
+    // anchor-form URI. Additionally, should this schema's resource appear
+    // first in the current *dynamic* scope, then a $dynamicRef of that
+    // anchor resolves to this schema.
+    DynamicAnchor(url::Url),
     // $anchor keyword indicates that this Schema should be indexed under
     // an additional canonical URI, which is computed as the base URI
     // extended with a URI fragment composed of the Anchor string.
@@ -90,6 +122,8 @@ pub enum Application {
     // In-place applications.
     Ref(url::Url),
     RecursiveRef(String),
+    // $dynamicRef holds its statically-resolved URI.
+    DynamicRef(url::Url),
     AnyOf {
         index: usize,
     },
@@ -124,6 +158,9 @@ pub enum Application {
     Items {
         index: Option<usize>,
     },
+    PrefixItems {
+        index: usize,
+    },
     AdditionalItems,
     UnevaluatedItems,
     Inline,
@@ -140,6 +177,7 @@ impl Application {
             // In-place keywords.
             Ref(_) => parent.push_prop(keywords::REF),
             RecursiveRef(_) => parent.push_prop(keywords::RECURSIVE_REF),
+            DynamicRef(_) => parent.push_prop(keywords::DYNAMIC_REF),
             AnyOf { .. } => parent.push_prop(keywords::ANY_OF),
             AllOf { .. } => parent.push_prop(keywords::ALL_OF),
             OneOf { .. } => parent.push_prop(keywords::ONE_OF),
@@ -159,6 +197,7 @@ impl Application {
             // Item keywords.
             Contains => parent.push_prop(keywords::CONTAINS),
             Items { .. } => parent.push_prop(keywords::ITEMS),
+            PrefixItems { .. } => parent.push_prop(keywords::PREFIX_ITEMS),
             AdditionalItems => parent.push_prop(keywords::ADDITIONAL_ITEMS),
             UnevaluatedItems => parent.push_prop(keywords::UNEVALUATED_ITEMS),
 
@@ -183,6 +222,7 @@ impl Application {
             // In-place keywords.
             Ref(_) => *parent,
             RecursiveRef(_) => *parent,
+            DynamicRef(_) => *parent,
             AnyOf { index } => parent.push_item(*index),
             AllOf { index } => parent.push_item(*index),
             OneOf { index } => parent.push_item(*index),
@@ -199,10 +239,31 @@ impl Application {
             Contains => *parent,
             Items { index: None } => *parent,
             Items { index: Some(i) } => parent.push_item(*i),
+            PrefixItems { index } => parent.push_item(*index),
             AdditionalItems | UnevaluatedItems | Inline => *parent,
         }
     }
 
+    /// Returns true if this Application applies to a child of the
+    /// current document location, rather than to the location itself.
+    pub fn applies_to_child(&self) -> bool {
+        use Application::*;
+        match self {
+            Properties { .. }
+            | PatternProperties { .. }
+            | AdditionalProperties
+            | UnevaluatedProperties
+            | Contains
+            | Items { .. }
+            | PrefixItems { .. }
+            | AdditionalItems
+            | UnevaluatedItems => true,
+            // PropertyNames is applied to the property name, which is
+            // attributed to the location of the object itself.
+            _ => false,
+        }
+    }
+
     /// Extend |ptr| with the JSON-Pointer components of this Application.
     pub fn extend_fragment_pointer(&self, mut ptr: String) -> String {
         let l_root = super::Location::Root;
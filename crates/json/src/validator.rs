@@ -63,6 +63,30 @@ pub struct FullContext {
     pub canonical_uri: String,
     pub keyword_location: String,
     pub span: Span,
+    // Locations of the Scope of this context and each of its parent Scopes,
+    // ordered from the root Scope. Used to build hierarchical outputs.
+    pub scopes: Vec<ScopeLocation>,
+}
+
+/// ScopeLocation is the location of a Scope which applied a schema to a
+/// document location, as used in "detailed" and "verbose" outputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeLocation {
+    pub keyword_location: String,
+    pub absolute_keyword_location: String,
+    pub instance_location: String,
+}
+
+impl ScopeLocation {
+    // Output unit of this location, to which an error or annotation is added.
+    fn output_unit(&self, valid: bool) -> serde_json::Value {
+        serde_json::json!({
+            "valid": valid,
+            "keywordLocation": self.keyword_location,
+            "absoluteKeywordLocation": self.absolute_keyword_location,
+            "instanceLocation": self.instance_location,
+        })
+    }
 }
 
 impl Context for FullContext {
@@ -75,10 +99,14 @@ impl Context for FullContext {
     where
         A: Annotation,
     {
+        let instance_ptr = loc.url_escaped().to_string();
+        let scopes = scope.scope_locations(parents, instance_ptr.clone());
+
         FullContext {
-            instance_ptr: loc.url_escaped().to_string(),
+            instance_ptr,
             canonical_uri: scope.schema.curi.as_str().to_owned(),
             keyword_location: scope.keyword_location(parents),
+            scopes,
             span: Span {
                 begin: span.begin,
                 end: span.end,
@@ -210,6 +238,151 @@ pub fn build_basic_output<'sm, C: Context, A: Annotation>(
     })
 }
 
+/// OutputFormat is a standard JSON-Schema output format.
+/// See: https://json-schema.org/draft/2019-09/json-schema-core.html#rfc.section.10.4
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputFormat {
+    /// A flat list of errors.
+    #[default]
+    Basic,
+    /// A condensed hierarchy of errors, following the structure of the schema.
+    Detailed,
+    /// An uncondensed hierarchy of errors and annotations, following the structure of the schema.
+    Verbose,
+}
+
+/// Build output of the given OutputFormat from a set of validator outcomes.
+pub fn build_output<'sm, A: Annotation>(
+    format: OutputFormat,
+    outcomes: &[(Outcome<'sm, A>, FullContext)],
+) -> serde_json::Value {
+    match format {
+        OutputFormat::Basic => build_basic_output(outcomes),
+        OutputFormat::Detailed => build_detailed_output(outcomes),
+        OutputFormat::Verbose => build_verbose_output(outcomes),
+    }
+}
+
+/// Build "detailed" output from a set of validator outcomes.
+/// Only errors are included, and intermediate nodes having a single child
+/// are replaced by that child.
+/// See: https://json-schema.org/draft/2019-09/json-schema-core.html#rfc.section.10.4.3
+pub fn build_detailed_output<'sm, A: Annotation>(
+    outcomes: &[(Outcome<'sm, A>, FullContext)],
+) -> serde_json::Value {
+    match OutputNode::build(outcomes.iter().filter(|(o, _)| o.is_error())) {
+        Some(root) => root.into_detailed(true),
+        None => valid_output(),
+    }
+}
+
+/// Build "verbose" output from a set of validator outcomes.
+/// Errors and annotations are included, and the hierarchy is not condensed.
+/// Note that the hierarchy includes only those scopes which produced an outcome.
+/// See: https://json-schema.org/draft/2019-09/json-schema-core.html#rfc.section.10.4.4
+pub fn build_verbose_output<'sm, A: Annotation>(
+    outcomes: &[(Outcome<'sm, A>, FullContext)],
+) -> serde_json::Value {
+    match OutputNode::build(outcomes.iter()) {
+        Some(root) => root.into_verbose().1,
+        None => valid_output(),
+    }
+}
+
+fn valid_output() -> serde_json::Value {
+    serde_json::json!({
+        "valid": true,
+        "keywordLocation": "#",
+        "instanceLocation": "",
+    })
+}
+
+// OutputNode is a node of a hierarchical output, corresponding to a Scope.
+struct OutputNode<'c> {
+    location: &'c ScopeLocation,
+    children: Vec<OutputNode<'c>>,
+    // Errors and annotations of this node, as (valid, unit) tuples.
+    units: Vec<(bool, serde_json::Value)>,
+}
+
+impl<'c> OutputNode<'c> {
+    fn build<'sm: 'c, A: Annotation + 'sm>(
+        outcomes: impl Iterator<Item = &'c (Outcome<'sm, A>, FullContext)>,
+    ) -> Option<Self> {
+        let mut root: Option<Self> = None;
+
+        for (outcome, ctx) in outcomes {
+            let (first, rest) = match ctx.scopes.split_first() {
+                Some(split) => split,
+                None => continue,
+            };
+            let mut node = root.get_or_insert_with(|| Self::new(first));
+
+            for location in rest {
+                let ind = match node.children.iter().position(|c| c.location == location) {
+                    Some(ind) => ind,
+                    None => {
+                        node.children.push(Self::new(location));
+                        node.children.len() - 1
+                    }
+                };
+                node = &mut node.children[ind];
+            }
+
+            let mut unit = node.location.output_unit(!outcome.is_error());
+            match outcome {
+                Outcome::Annotation(a) => unit["annotation"] = format!("{:?}", a).into(),
+                _ => unit["error"] = format!("{}", outcome).into(),
+            }
+            node.units.push((!outcome.is_error(), unit));
+        }
+        root
+    }
+
+    fn new(location: &'c ScopeLocation) -> Self {
+        Self {
+            location,
+            children: Vec::new(),
+            units: Vec::new(),
+        }
+    }
+
+    // Nodes of detailed output are built only from errors, and are always invalid.
+    fn into_detailed(self, is_root: bool) -> serde_json::Value {
+        let mut errors = self.units.into_iter().map(|(_, u)| u).collect::<Vec<_>>();
+        for child in self.children {
+            errors.push(child.into_detailed(false));
+        }
+
+        if errors.len() == 1 && !is_root {
+            return errors.pop().unwrap();
+        }
+        let mut out = self.location.output_unit(false);
+        out["errors"] = errors.into();
+        out
+    }
+
+    fn into_verbose(self) -> (bool, serde_json::Value) {
+        let mut units = self.units;
+        for child in self.children {
+            units.push(child.into_verbose());
+        }
+        let valid = units.iter().all(|(valid, _)| *valid);
+
+        let (annotations, errors): (Vec<_>, Vec<_>) = units.into_iter().partition(|(v, _)| *v);
+        let mut out = self.location.output_unit(valid);
+
+        if !errors.is_empty() {
+            out["errors"] = errors.into_iter().map(|(_, u)| u).collect();
+        }
+        if !annotations.is_empty() {
+            out["annotations"] = annotations.into_iter().map(|(_, u)| u).collect();
+        }
+        (valid, out)
+    }
+}
+
 type BitVec = bitvec::prelude::BitVec<bitvec::prelude::LocalBits>;
 
 pub struct Scope<'sm, A, C>
@@ -284,6 +457,41 @@ where
         }
     }
 
+    // Returns locations of this Scope and its parents, ordered from the root.
+    // |instance_location| is the instance location of this Scope, and parent
+    // instance locations are derived by popping a pointer component for each
+    // application which applies to a child of its parent's instance.
+    fn scope_locations(
+        &self,
+        parents: &[Scope<'sm, A, C>],
+        instance_location: String,
+    ) -> Vec<ScopeLocation> {
+        let (mut out, keyword_location) = match self.parent {
+            Some((ind, app)) => {
+                let parent_instance = match instance_location.rfind('/') {
+                    Some(pivot) if app.applies_to_child() => instance_location[..pivot].to_owned(),
+                    _ => instance_location.clone(),
+                };
+                let out = parents[ind].scope_locations(parents, parent_instance);
+                let keyword_location =
+                    app.extend_fragment_pointer(out.last().unwrap().keyword_location.clone());
+                (out, keyword_location)
+            }
+            None => (Vec::new(), "#".to_owned()),
+        };
+
+        let location = ScopeLocation {
+            keyword_location,
+            absolute_keyword_location: self.schema.curi.as_str().to_owned(),
+            instance_location,
+        };
+        // Inline applications have the same location as their parent.
+        if out.last() != Some(&location) {
+            out.push(location);
+        }
+        out
+    }
+
     fn dynamic_base(&self, parents: &[Scope<'sm, A, C>]) -> Option<&'sm url::Url> {
         let mut r = None;
         if let Some((ind, _)) = self.parent {
@@ -296,6 +504,31 @@ where
             })
         })
     }
+
+    // Returns the outermost dynamic anchor URI of the dynamic scope which has
+    // the given anchor |fragment|, or None if no scope's resource has such an anchor.
+    fn dynamic_anchor(
+        &self,
+        parents: &[Scope<'sm, A, C>],
+        index: &index::Index<'sm, A>,
+        fragment: &str,
+    ) -> Option<url::Url> {
+        let mut r = None;
+        if let Some((ind, _)) = self.parent {
+            r = parents[ind].dynamic_anchor(parents, index, fragment);
+        }
+        r.or_else(|| {
+            let mut uri = self.schema.curi.clone();
+            uri.set_fragment(Some(fragment));
+
+            let schema = index.fetch(&uri)?;
+            schema
+                .kw
+                .iter()
+                .any(|kw| matches!(kw, Keyword::DynamicAnchor(auri) if *auri == uri))
+                .then_some(uri)
+        })
+    }
 }
 
 pub struct Validator<'sm, A, C>
@@ -392,7 +625,7 @@ where
     fn push_item<'a>(&mut self, span: &Span, loc: &'a LocatedItem<'a>) {
         //println!("\t\t\t\tpush_item {} @ {:?}", Location::Item(*loc), span);
 
-        use Application::{AdditionalItems, Contains, Items, UnevaluatedItems};
+        use Application::{AdditionalItems, Contains, Items, PrefixItems, UnevaluatedItems};
         use Keyword::Application as KWApp;
 
         let active_from = *self.active_offsets.last().unwrap();
@@ -411,10 +644,10 @@ where
                 // Item applications also have preference rules (which keywords are sorted by).
                 // C.f https://json-schema.org/draft/2019-09/json-schema-core.html#rfc.section.9.3.1
                 let evaluates = match app {
-                    // Items without an index always applies.
-                    Items { index: None } => true,
-                    // Items with an index matches on location index equality.
-                    Items { index: Some(i) } => {
+                    // Items without an index applies if no PrefixItems matched.
+                    Items { index: None } if !evaluated => true,
+                    // Items or PrefixItems with an index matches on location index equality.
+                    Items { index: Some(i) } | PrefixItems { index: i } => {
                         indexed_items = true;
                         if *i != loc.index {
                             continue;
@@ -869,9 +1102,12 @@ where
             App::Def { .. } | App::Definition { .. } => panic!("unexpected Def"),
 
             // In-place keywords which must always validate.
-            App::AllOf { .. } | App::Ref(_) | App::RecursiveRef(_) | App::Not | App::Inline => {
-                RequiredInPlace
-            }
+            App::AllOf { .. }
+            | App::Ref(_)
+            | App::RecursiveRef(_)
+            | App::DynamicRef(_)
+            | App::Not
+            | App::Inline => RequiredInPlace,
 
             // In-place keywords which must validate subject to the state
             // of a previously-collected annotation.
@@ -915,6 +1151,7 @@ where
             App::PatternProperties { .. }
             | App::AdditionalProperties
             | App::Items { .. }
+            | App::PrefixItems { .. }
             | App::Properties { .. }
             | App::PropertyNames
             | App::AdditionalItems => RequiredChild,
@@ -975,7 +1212,8 @@ where
 
     fn expand_scope<'a>(&mut self, index: usize, span: &Span, loc: &'a Location<'a>) {
         use Application::{
-            AllOf, AnyOf, DependentSchema, Else, If, Inline, Not, OneOf, RecursiveRef, Ref, Then,
+            AllOf, AnyOf, DependentSchema, DynamicRef, Else, If, Inline, Not, OneOf, RecursiveRef,
+            Ref, Then,
         };
 
         //println!("expand_scope '{}' '{}'", self.scopes[index].keyword_location(&self.scopes), self.scopes[index].schema.curi);
@@ -1012,6 +1250,26 @@ where
                     }
                     (schema, Some(uri))
                 }
+                DynamicRef(uri) => {
+                    // If the statically-resolved referent has a dynamic anchor matching |uri|,
+                    // then the reference resolves to the outermost schema resource of the
+                    // dynamic scope which also has that dynamic anchor.
+                    let is_dynamic = self.index.fetch(uri).map_or(false, |referent| {
+                        referent
+                            .kw
+                            .iter()
+                            .any(|kw| matches!(kw, Keyword::DynamicAnchor(auri) if auri == uri))
+                    });
+
+                    let scope = &self.scopes[index];
+                    let dynamic = match uri.fragment() {
+                        Some(fragment) if is_dynamic => {
+                            scope.dynamic_anchor(&self.scopes, self.index, fragment)
+                        }
+                        _ => None,
+                    };
+                    (schema, Some(dynamic.map_or(Cow::Borrowed(uri), Cow::Owned)))
+                }
                 AnyOf { .. }
                 | AllOf { .. }
                 | OneOf { .. }
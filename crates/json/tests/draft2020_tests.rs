@@ -0,0 +1,226 @@
+//! DO NOT EDIT THIS FILE!
+//! This file is generated by regenerate-tests.sh based on the official
+//! test cases in the submodule.
+
+mod validator_test_utils;
+use validator_test_utils::run_draft2020_test;
+
+#[test]
+fn test_d20_additional_properties() {
+    run_draft2020_test("additionalProperties.json");
+}
+
+#[test]
+fn test_d20_all_of() {
+    run_draft2020_test("allOf.json");
+}
+
+#[test]
+fn test_d20_anchor() {
+    run_draft2020_test("anchor.json");
+}
+
+#[test]
+fn test_d20_any_of() {
+    run_draft2020_test("anyOf.json");
+}
+
+#[test]
+fn test_d20_boolean_schema() {
+    run_draft2020_test("boolean_schema.json");
+}
+
+#[test]
+fn test_d20_const() {
+    run_draft2020_test("const.json");
+}
+
+#[test]
+fn test_d20_contains() {
+    run_draft2020_test("contains.json");
+}
+
+#[test]
+fn test_d20_default() {
+    run_draft2020_test("default.json");
+}
+
+#[test]
+fn test_d20_defs() {
+    run_draft2020_test("defs.json");
+}
+
+#[test]
+fn test_d20_dependent_required() {
+    run_draft2020_test("dependentRequired.json");
+}
+
+#[test]
+fn test_d20_dependent_schemas() {
+    run_draft2020_test("dependentSchemas.json");
+}
+
+#[test]
+fn test_d20_dynamic_ref() {
+    run_draft2020_test("dynamicRef.json");
+}
+
+#[test]
+fn test_d20_enum() {
+    run_draft2020_test("enum.json");
+}
+
+#[test]
+fn test_d20_exclusive_maximum() {
+    run_draft2020_test("exclusiveMaximum.json");
+}
+
+#[test]
+fn test_d20_exclusive_minimum() {
+    run_draft2020_test("exclusiveMinimum.json");
+}
+
+#[test]
+fn test_d20_format() {
+    run_draft2020_test("format.json");
+}
+
+#[test]
+fn test_d20_id() {
+    run_draft2020_test("id.json");
+}
+
+#[test]
+fn test_d20_if_then_else() {
+    run_draft2020_test("if-then-else.json");
+}
+
+#[test]
+fn test_d20_items() {
+    run_draft2020_test("items.json");
+}
+
+#[test]
+fn test_d20_max_contains() {
+    run_draft2020_test("maxContains.json");
+}
+
+#[test]
+fn test_d20_maximum() {
+    run_draft2020_test("maximum.json");
+}
+
+#[test]
+fn test_d20_max_items() {
+    run_draft2020_test("maxItems.json");
+}
+
+#[test]
+fn test_d20_max_length() {
+    run_draft2020_test("maxLength.json");
+}
+
+#[test]
+fn test_d20_max_properties() {
+    run_draft2020_test("maxProperties.json");
+}
+
+#[test]
+fn test_d20_min_contains() {
+    run_draft2020_test("minContains.json");
+}
+
+#[test]
+fn test_d20_minimum() {
+    run_draft2020_test("minimum.json");
+}
+
+#[test]
+fn test_d20_min_items() {
+    run_draft2020_test("minItems.json");
+}
+
+#[test]
+fn test_d20_min_length() {
+    run_draft2020_test("minLength.json");
+}
+
+#[test]
+fn test_d20_min_properties() {
+    run_draft2020_test("minProperties.json");
+}
+
+#[test]
+fn test_d20_multiple_of() {
+    run_draft2020_test("multipleOf.json");
+}
+
+#[test]
+fn test_d20_not() {
+    run_draft2020_test("not.json");
+}
+
+#[test]
+fn test_d20_one_of() {
+    run_draft2020_test("oneOf.json");
+}
+
+#[test]
+fn test_d20_pattern() {
+    run_draft2020_test("pattern.json");
+}
+
+#[test]
+fn test_d20_pattern_properties() {
+    run_draft2020_test("patternProperties.json");
+}
+
+#[test]
+fn test_d20_prefix_items() {
+    run_draft2020_test("prefixItems.json");
+}
+
+#[test]
+fn test_d20_properties() {
+    run_draft2020_test("properties.json");
+}
+
+#[test]
+fn test_d20_property_names() {
+    run_draft2020_test("propertyNames.json");
+}
+
+#[test]
+fn test_d20_ref() {
+    run_draft2020_test("ref.json");
+}
+
+#[test]
+fn test_d20_ref_remote() {
+    run_draft2020_test("refRemote.json");
+}
+
+#[test]
+fn test_d20_required() {
+    run_draft2020_test("required.json");
+}
+
+#[test]
+fn test_d20_type() {
+    run_draft2020_test("type.json");
+}
+
+#[test]
+fn test_d20_unevaluated_items() {
+    run_draft2020_test("unevaluatedItems.json");
+}
+
+#[test]
+fn test_d20_unevaluated_properties() {
+    run_draft2020_test("unevaluatedProperties.json");
+}
+
+#[test]
+fn test_d20_unique_items() {
+    run_draft2020_test("uniqueItems.json");
+}
@@ -0,0 +1,121 @@
+use json::{
+    de,
+    schema::{build, index, CoreAnnotation},
+    validator::{self, OutputFormat},
+};
+use serde_json::json;
+
+fn validate(schema: serde_json::Value, doc: serde_json::Value) -> [serde_json::Value; 3] {
+    let curi = url::Url::parse("http://example/schema").unwrap();
+    let schema = build::build_schema::<CoreAnnotation>(curi, &schema).unwrap();
+
+    let mut ind = index::IndexBuilder::new();
+    ind.add(&schema).unwrap();
+    ind.verify_references().unwrap();
+    let ind = ind.into_index();
+
+    let mut val = validator::Validator::<CoreAnnotation, validator::FullContext>::new(&ind);
+    val.prepare(&schema.curi).unwrap();
+    de::walk(&doc, &mut val).unwrap();
+
+    [
+        OutputFormat::Basic,
+        OutputFormat::Detailed,
+        OutputFormat::Verbose,
+    ]
+    .map(|format| validator::build_output(format, val.outcomes()))
+}
+
+#[test]
+fn test_output_formats_of_invalid_document() {
+    let schema = json!({
+        "$defs": {
+            "positive": {"type": "integer", "minimum": 1},
+        },
+        "type": "object",
+        "properties": {
+            "a": {"$ref": "#/$defs/positive"},
+            "b": {"items": {"type": "string", "title": "B item"}},
+        },
+        "required": ["c"],
+    });
+    let [basic, detailed, verbose] = validate(schema, json!({"a": 0, "b": ["x", 1]}));
+
+    assert_eq!(basic["valid"], false);
+    assert_eq!(basic["errors"].as_array().unwrap().len(), 3);
+
+    assert_eq!(
+        detailed,
+        json!({
+            "valid": false,
+            "keywordLocation": "#",
+            "absoluteKeywordLocation": "http://example/schema",
+            "instanceLocation": "",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "#",
+                    "absoluteKeywordLocation": "http://example/schema",
+                    "instanceLocation": "",
+                    "error": "Invalid: Properties \"c\" are required.",
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "#/properties/a/$ref",
+                    "absoluteKeywordLocation": "http://example/schema#/$defs/positive",
+                    "instanceLocation": "/a",
+                    "error": "Invalid: Must be greater than or equal to 1.",
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "#/properties/b/items",
+                    "absoluteKeywordLocation": "http://example/schema#/properties/b/items",
+                    "instanceLocation": "/b/1",
+                    "error": "Invalid: Must be of type \"string\".",
+                },
+            ],
+        })
+    );
+
+    // Verbose output is not condensed, and includes annotations of valid scopes.
+    let b = &verbose["errors"][2];
+    assert_eq!(b["keywordLocation"], "#/properties/b");
+    assert_eq!(b["instanceLocation"], "/b");
+    assert_eq!(b["valid"], false);
+    assert_eq!(b["errors"][0]["instanceLocation"], "/b/1");
+    assert_eq!(b["annotations"][0]["instanceLocation"], "/b/0");
+    assert_eq!(b["annotations"][0]["valid"], true);
+    assert_eq!(
+        b["annotations"][0]["annotations"][0]["annotation"],
+        "Title(\"B item\")"
+    );
+}
+
+#[test]
+fn test_output_formats_of_valid_document() {
+    let schema = json!({"properties": {"a": {"title": "A"}}});
+    let [basic, detailed, verbose] = validate(schema, json!({"a": 1}));
+
+    assert_eq!(basic, json!({"valid": true, "errors": []}));
+    assert_eq!(
+        detailed,
+        json!({"valid": true, "keywordLocation": "#", "instanceLocation": ""})
+    );
+    assert_eq!(verbose["valid"], true);
+    assert_eq!(
+        verbose["annotations"][0],
+        json!({
+            "valid": true,
+            "keywordLocation": "#/properties/a",
+            "absoluteKeywordLocation": "http://example/schema#/properties/a",
+            "instanceLocation": "/a",
+            "annotations": [{
+                "valid": true,
+                "keywordLocation": "#/properties/a",
+                "absoluteKeywordLocation": "http://example/schema#/properties/a",
+                "instanceLocation": "/a",
+                "annotation": "Title(\"A\")",
+            }],
+        })
+    );
+}
@@ -39,3 +39,27 @@ fn test_d09_${test_base_name}() {
 EOF
 done
 
+DRAFT2020_TESTS="${DIR}/draft2020_tests.rs";
+cat >"$DRAFT2020_TESTS" <<EOF
+//! DO NOT EDIT THIS FILE!
+//! This file is generated by $(basename "$0") based on the official
+//! test cases in the submodule.
+
+mod validator_test_utils;
+use validator_test_utils::run_draft2020_test;
+
+EOF
+
+for file in $DIR/official/tests/draft2020-12/*.json; do
+    filename=$(basename "$file")
+    test_base_name="$(make_base_test_name "$filename")"
+    printf 'Using file "%s" to generate test: "%s"\n' "$filename" "$test_base_name"
+
+    cat >> "$DRAFT2020_TESTS" << EOF
+#[test]
+fn test_d20_${test_base_name}() {
+    run_draft2020_test("${filename}");
+}
+
+EOF
+done
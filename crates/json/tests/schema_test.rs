@@ -1,4 +1,8 @@
-use json::schema::{build, index, CoreAnnotation};
+use json::{
+    de,
+    schema::{build, index, CoreAnnotation},
+    validator,
+};
 use serde_json::json;
 
 type Result = std::result::Result<(), Box<dyn std::error::Error>>;
@@ -51,3 +55,70 @@ fn test_bassssic() -> Result {
     print!("{:?}", sch);
     Ok(())
 }
+
+#[test]
+fn test_draft2020_12() -> Result {
+    // A generic list which is extended with a specific item type through $dynamicRef.
+    let list = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "http://example.com/list.json",
+        "type": "array",
+        "items": {"$dynamicRef": "#item"},
+        "$defs": {
+            "item": {"$dynamicAnchor": "item"},
+        },
+    });
+    let root = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "http://example.com/root.json",
+        "type": "object",
+        "properties": {
+            "pair": {
+                "prefixItems": [{"type": "integer"}, {"type": "string"}],
+                "items": {"type": "boolean"},
+            },
+            "names": {"$ref": "list.json"},
+        },
+        "$defs": {
+            "item": {"$dynamicAnchor": "item", "type": "string"},
+        },
+    });
+
+    let list = build::build_schema::<CoreAnnotation>(
+        url::Url::parse("http://example.com/list.json")?,
+        &list,
+    )?;
+    let root = build::build_schema::<CoreAnnotation>(
+        url::Url::parse("http://example.com/root.json")?,
+        &root,
+    )?;
+
+    let mut ind = index::IndexBuilder::new();
+    ind.add(&list)?;
+    ind.add(&root)?;
+    ind.verify_references()?;
+    let ind = ind.into_index();
+
+    let mut val = validator::Validator::<CoreAnnotation, validator::SpanContext>::new(&ind);
+
+    for (doc, expect) in [
+        (json!({"pair": [1, "two", true, false]}), true),
+        (json!({"pair": [1]}), true),
+        (json!({"pair": ["one", "two"]}), false),
+        (json!({"pair": [1, "two", 3]}), false),
+        (json!({"names": ["a", "b"]}), true),
+        // Items of the list resolve to the dynamic anchor of the root schema.
+        (json!({"names": ["a", 2]}), false),
+    ] {
+        val.prepare(&root.curi)?;
+        de::walk(&doc, &mut val)?;
+        assert_eq!(!val.invalid(), expect, "{}", doc);
+    }
+
+    // The generic list, validated on its own, accepts any items.
+    val.prepare(&list.curi)?;
+    de::walk(&json!(["a", 2]), &mut val)?;
+    assert!(!val.invalid());
+
+    Ok(())
+}
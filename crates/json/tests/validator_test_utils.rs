@@ -2,7 +2,7 @@
 use glob;
 use json::{
     de,
-    schema::{build, index, CoreAnnotation, Schema},
+    schema::{build, index, CoreAnnotation, Draft, Schema},
     validator,
 };
 use serde_json as sj;
@@ -12,21 +12,37 @@ use std::{env, fs, io, path};
 // This is not actually dead code (used by draft2019_tests.rs).
 #[allow(dead_code)]
 pub fn run_draft09_test(target: &str) {
-    run_file_test(&["official", "tests", "draft2019-09", target]);
+    run_file_test(
+        &["official", "tests", "draft2019-09", target],
+        Draft::Draft2019_09,
+    );
+}
+
+/// Runs tests from the given file within the `draft2020-12/` directory.
+// This is not actually dead code (used by draft2020_tests.rs).
+#[allow(dead_code)]
+pub fn run_draft2020_test(target: &str) {
+    run_file_test(
+        &["official", "tests", "draft2020-12", target],
+        Draft::Draft2020_12,
+    );
 }
 
 /// Runs tests from the given file within the `draft2019-09/optional/format` directory.
 // This is not actually dead code (used by draft2019_format_tests.rs).
 #[allow(dead_code)]
 pub fn run_draft09_format_test(target: &str) {
-    run_file_test(&[
-        "official",
-        "tests",
-        "draft2019-09",
-        "optional",
-        "format",
-        target,
-    ]);
+    run_file_test(
+        &[
+            "official",
+            "tests",
+            "draft2019-09",
+            "optional",
+            "format",
+            target,
+        ],
+        Draft::Draft2019_09,
+    );
 }
 
 fn read_json_file(target: &[&str]) -> sj::Value {
@@ -70,7 +86,7 @@ where
     })
 }
 
-fn run_file_test(target: &[&str]) {
+fn run_file_test(target: &[&str], draft: Draft) {
     let test_root = &env::var("CARGO_MANIFEST_DIR").unwrap_or(".".to_owned());
 
     let url = url::Url::parse("http://localhost:1234").unwrap();
@@ -97,7 +113,8 @@ fn run_file_test(target: &[&str]) {
 
         println!("{}:", desc);
         println!("\t{}:", schema);
-        let schema = build::build_schema::<CoreAnnotation>(url.clone(), schema).unwrap();
+        let schema =
+            build::build_schema_with_draft::<CoreAnnotation>(url.clone(), schema, draft).unwrap();
         println!("\t{:?}", schema);
 
         let mut ind = index::IndexBuilder::new();
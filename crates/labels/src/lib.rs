@@ -30,6 +30,12 @@ pub const HOSTNAME: &str = "estuary.dev/hostname";
 pub const EXPOSE_PORT: &str = "estuary.dev/expose-port";
 pub const PORT_PROTO_PREFIX: &str = "estuary.dev/port-proto/";
 pub const PORT_PUBLIC_PREFIX: &str = "estuary.dev/port-public/";
+// Shard labels related to rate limiting and back-pressure.
+pub const MAX_COMBINER_BYTES: &str = "estuary.dev/max-combiner-bytes";
+pub const RATE_LIMIT_DOCS: &str = "estuary.dev/rate-limit-docs";
+pub const RATE_LIMIT_DOCS_PREFIX: &str = "estuary.dev/rate-limit-docs/";
+pub const RATE_LIMIT_BYTES: &str = "estuary.dev/rate-limit-bytes";
+pub const RATE_LIMIT_BYTES_PREFIX: &str = "estuary.dev/rate-limit-bytes/";
 
 // A used subset of Gazette labels, defined in go.gazette.dev/core/labels/labels.go.
 pub const CONTENT_TYPE: &str = "content-type";
@@ -1,4 +1,4 @@
-use super::{Collection, ConnectorConfig, LocalConfig, RawValue, ShardTemplate};
+use super::{Collection, ConnectorConfig, LocalConfig, RateLimit, RawValue, ShardTemplate};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -93,6 +93,11 @@ pub struct CaptureBinding {
     /// of a preceding backfill.
     #[serde(default, skip_serializing_if = "super::is_u32_zero")]
     pub backfill: u32,
+    /// # Rate limit of documents captured by this binding.
+    /// This limit applies in addition to any rate limit of the capture's shards.
+    /// If not set, the binding is not rate limited.
+    #[serde(default, rename = "rateLimit", skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
 }
 
 impl CaptureDef {
@@ -124,6 +129,7 @@ impl CaptureBinding {
             disable: false,
             target: Collection::new("target/collection"),
             backfill: 0,
+            rate_limit: None,
         }
     }
 }
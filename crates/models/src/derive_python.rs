@@ -0,0 +1,33 @@
+use super::{RawValue, RelativeUrl};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_value, json};
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DeriveUsingPython {
+    /// # Python module implementing this derivation.
+    /// Module is either a relative URL of a Python module file,
+    /// or is an inline representation of a Python module.
+    /// The module must have an exported Derivation class which
+    /// extends the corresponding generated IDerivation class.
+    #[schemars(schema_with = "DeriveUsingPython::module_schema")]
+    pub module: RawValue,
+}
+
+impl DeriveUsingPython {
+    fn module_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let url_schema = RelativeUrl::json_schema(gen);
+
+        from_value(json!({
+            "oneOf": [
+                url_schema,
+                {
+                    "type": "string",
+                    "contentMediaType": "text/x.python",
+                }
+            ]
+        }))
+        .unwrap()
+    }
+}
@@ -2,6 +2,7 @@ use super::{RawValue, RelativeUrl};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, json};
+use validator::Validate;
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
@@ -9,9 +10,47 @@ pub struct DeriveUsingSqlite {
     /// # Ordered migrations which are used to initialize the database.
     /// Migrations may be provided as an inline string,
     /// or as a relative URL to a file containing the migration SQL.
+    /// Migrations and lambdas may use SQLite functions for regular expressions
+    /// (regex_match, regex_extract, regex_replace), hashing (sha256, xxhash,
+    /// sha256_agg, xxhash_agg), UUIDs (uuid_v4, uuid_v5), RFC 3339 timestamps
+    /// (rfc3339_to_unix, rfc3339_from_unix, rfc3339_to_offset, rfc3339_add),
+    /// and JSON pointers (json_pointer).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[schemars(schema_with = "DeriveUsingSqlite::migrations_schema")]
     pub migrations: Vec<RawValue>,
+    /// # Snapshots of the database, which persist it across shard assignments.
+    /// When set, each shard of the derivation periodically writes a full
+    /// snapshot of its database as of a committed transaction, and restores
+    /// it when opened.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<SqliteSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, Validate)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SqliteSnapshot {
+    /// # Directory into which database snapshots are written.
+    /// This is typically a mount of a recovery-log-backed or object-store-backed
+    /// file system. Each shard writes a snapshot file named by its key and r-clock
+    /// range, and a shard split from a parent restores from the parent's snapshot.
+    /// Each snapshot is a full copy of the database, rather than an incremental
+    /// delta, and its cost grows with the size of the database.
+    pub directory: String,
+    /// # Number of database pages copied in each step of a snapshot.
+    /// If not set, a default of 1024 is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 2147483647))]
+    pub pages_per_step: Option<u32>,
+    /// # Minimum interval between snapshots of a shard's database.
+    /// A restored snapshot resumes from its committed transaction, which may
+    /// be older than the shard's last one. If not set, a default of 5m is used.
+    #[serde(
+        default,
+        with = "humantime_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(schema_with = "super::duration_schema")]
+    pub interval: Option<std::time::Duration>,
 }
 
 impl DeriveUsingSqlite {
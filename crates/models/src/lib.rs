@@ -5,6 +5,7 @@ mod catalogs;
 mod collections;
 mod connector;
 mod derivation;
+mod derive_python;
 mod derive_sqlite;
 mod derive_typescript;
 mod journals;
@@ -23,7 +24,8 @@ pub use catalogs::Catalog;
 pub use collections::{CollectionDef, Projection};
 pub use connector::{ConnectorConfig, LocalConfig};
 pub use derivation::{Derivation, DeriveUsing, Shuffle, ShuffleType, TransformDef};
-pub use derive_sqlite::DeriveUsingSqlite;
+pub use derive_python::DeriveUsingPython;
+pub use derive_sqlite::{DeriveUsingSqlite, SqliteSnapshot};
 pub use derive_typescript::DeriveUsingTypescript;
 pub use journals::{
     BucketAndPrefix, CompressionCodec, CustomStore, FragmentTemplate, JournalTemplate, StorageDef,
@@ -39,7 +41,7 @@ pub use references::{
     RelativeUrl, StorageEndpoint, Test, Transform, CATALOG_PREFIX_RE, TOKEN_RE,
 };
 pub use schemas::Schema;
-pub use shards::ShardTemplate;
+pub use shards::{RateLimit, ShardTemplate};
 pub use source::{FullSource, PartitionSelector, Source};
 pub use tests::{TestDocuments, TestStep, TestStepIngest, TestStepVerify};
 
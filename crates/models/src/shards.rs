@@ -73,6 +73,34 @@ pub struct ShardTemplate {
     // we'll introduce a modular logging capability.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub log_level: Option<String>,
+    /// # Rate limit of documents processed by this task's shards.
+    /// The limit applies across all bindings of the task, and each shard
+    /// enforces it independently. When exceeded, the shard pauses reading
+    /// from its connector until the rate falls back within the limit.
+    /// Currently only captures enforce rate limits, and other tasks may not set one.
+    /// If not set, the task is not rate limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
+    /// # Maximum bytes of documents combined within a single transaction.
+    /// Upon reaching this threshold, the shard stops reading further documents
+    /// from its connector until its current transaction has committed.
+    /// Currently only captures enforce this threshold, and other tasks may not set it.
+    /// If not set, a reasonable default (currently 32MB) is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_combiner_bytes: Option<u64>,
+}
+
+/// A RateLimit bounds the throughput of documents processed by a task or binding.
+#[derive(Serialize, Deserialize, Debug, Default, JsonSchema, Clone, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[schemars(example = "RateLimit::example")]
+pub struct RateLimit {
+    /// # Maximum number of documents per second.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs_per_second: Option<u32>,
+    /// # Maximum number of document bytes per second.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes_per_second: Option<u64>,
 }
 
 impl ShardTemplate {
@@ -92,6 +120,8 @@ impl ShardTemplate {
             ring_buffer_size: o4,
             read_channel_size: o5,
             log_level: o6,
+            rate_limit: o7,
+            max_combiner_bytes: o8,
         } = self;
 
         !disable
@@ -101,5 +131,16 @@ impl ShardTemplate {
             && o4.is_none()
             && o5.is_none()
             && o6.is_none()
+            && o7.is_none()
+            && o8.is_none()
+    }
+}
+
+impl RateLimit {
+    pub fn example() -> Self {
+        Self {
+            docs_per_second: Some(1000),
+            bytes_per_second: Some(1 << 20),
+        }
     }
 }
@@ -182,6 +182,13 @@ pub mod request {
         pub runtime_checkpoint: ::core::option::Option<
             ::proto_gazette::consumer::Checkpoint,
         >,
+        /// Sources and acknowledgement intents of `runtime_checkpoint` which were
+        /// added or changed since the previous StartCommit or, for the first
+        /// transaction of a session, since the checkpoint of Opened.
+        #[prost(message, optional, tag = "2")]
+        pub runtime_checkpoint_delta: ::core::option::Option<
+            ::proto_gazette::consumer::Checkpoint,
+        >,
     }
     /// Reset any internal state, as if the derivation were just initialized.
     /// This is used only when running Flow tests, and clears the effects of
@@ -274,7 +281,16 @@ pub mod response {
     /// Opened responds to Request.Open.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct Opened {}
+    pub struct Opened {
+        /// Flow runtime checkpoint to begin processing from.
+        /// If empty, the most recent checkpoint of the Flow recovery log is used.
+        /// Connectors which transactionally persist the checkpoints of StartCommit
+        /// may return their last-committed checkpoint to recover exactly-once.
+        #[prost(message, optional, tag = "1")]
+        pub runtime_checkpoint: ::core::option::Option<
+            ::proto_gazette::consumer::Checkpoint,
+        >,
+    }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Published {
@@ -895,10 +895,16 @@ impl serde::Serialize for request::StartCommit {
         if self.runtime_checkpoint.is_some() {
             len += 1;
         }
+        if self.runtime_checkpoint_delta.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("derive.Request.StartCommit", len)?;
         if let Some(v) = self.runtime_checkpoint.as_ref() {
             struct_ser.serialize_field("runtimeCheckpoint", v)?;
         }
+        if let Some(v) = self.runtime_checkpoint_delta.as_ref() {
+            struct_ser.serialize_field("runtimeCheckpointDelta", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -911,11 +917,14 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
         const FIELDS: &[&str] = &[
             "runtime_checkpoint",
             "runtimeCheckpoint",
+            "runtime_checkpoint_delta",
+            "runtimeCheckpointDelta",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             RuntimeCheckpoint,
+            RuntimeCheckpointDelta,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -938,6 +947,7 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
                     {
                         match value {
                             "runtimeCheckpoint" | "runtime_checkpoint" => Ok(GeneratedField::RuntimeCheckpoint),
+                            "runtimeCheckpointDelta" | "runtime_checkpoint_delta" => Ok(GeneratedField::RuntimeCheckpointDelta),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -958,6 +968,7 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut runtime_checkpoint__ = None;
+                let mut runtime_checkpoint_delta__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::RuntimeCheckpoint => {
@@ -966,10 +977,17 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
                             }
                             runtime_checkpoint__ = map.next_value()?;
                         }
+                        GeneratedField::RuntimeCheckpointDelta => {
+                            if runtime_checkpoint_delta__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runtimeCheckpointDelta"));
+                            }
+                            runtime_checkpoint_delta__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(request::StartCommit {
                     runtime_checkpoint: runtime_checkpoint__,
+                    runtime_checkpoint_delta: runtime_checkpoint_delta__,
                 })
             }
         }
@@ -1620,8 +1638,14 @@ impl serde::Serialize for response::Opened {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("derive.Response.Opened", len)?;
+        let mut len = 0;
+        if self.runtime_checkpoint.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("derive.Response.Opened", len)?;
+        if let Some(v) = self.runtime_checkpoint.as_ref() {
+            struct_ser.serialize_field("runtimeCheckpoint", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1632,10 +1656,13 @@ impl<'de> serde::Deserialize<'de> for response::Opened {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "runtime_checkpoint",
+            "runtimeCheckpoint",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            RuntimeCheckpoint,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1656,7 +1683,10 @@ impl<'de> serde::Deserialize<'de> for response::Opened {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "runtimeCheckpoint" | "runtime_checkpoint" => Ok(GeneratedField::RuntimeCheckpoint),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1674,10 +1704,19 @@ impl<'de> serde::Deserialize<'de> for response::Opened {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                let mut runtime_checkpoint__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::RuntimeCheckpoint => {
+                            if runtime_checkpoint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runtimeCheckpoint"));
+                            }
+                            runtime_checkpoint__ = map.next_value()?;
+                        }
+                    }
                 }
                 Ok(response::Opened {
+                    runtime_checkpoint: runtime_checkpoint__,
                 })
             }
         }
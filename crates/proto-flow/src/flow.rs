@@ -318,6 +318,7 @@ pub mod collection_spec {
             Typescript = 2,
             Image = 3,
             Local = 4,
+            Python = 5,
         }
         impl ConnectorType {
             /// String value of the enum field names used in the ProtoBuf definition.
@@ -331,6 +332,7 @@ pub mod collection_spec {
                     ConnectorType::Typescript => "TYPESCRIPT",
                     ConnectorType::Image => "IMAGE",
                     ConnectorType::Local => "LOCAL",
+                    ConnectorType::Python => "PYTHON",
                 }
             }
             /// Creates an enum from field names used in the ProtoBuf definition.
@@ -341,6 +343,7 @@ pub mod collection_spec {
                     "TYPESCRIPT" => Some(Self::Typescript),
                     "IMAGE" => Some(Self::Image),
                     "LOCAL" => Some(Self::Local),
+                    "PYTHON" => Some(Self::Python),
                     _ => None,
                 }
             }
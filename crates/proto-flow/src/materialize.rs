@@ -211,6 +211,13 @@ pub mod request {
         pub runtime_checkpoint: ::core::option::Option<
             ::proto_gazette::consumer::Checkpoint,
         >,
+        /// Sources and acknowledgement intents of `runtime_checkpoint` which were
+        /// added or changed since the previous StartCommit or, for the first
+        /// transaction of a session, since the checkpoint of Opened.
+        #[prost(message, optional, tag = "2")]
+        pub runtime_checkpoint_delta: ::core::option::Option<
+            ::proto_gazette::consumer::Checkpoint,
+        >,
     }
     /// Acknowledge to the connector that the previous transaction
     /// has committed to the Flow runtime's recovery log.
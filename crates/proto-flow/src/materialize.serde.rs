@@ -1202,10 +1202,16 @@ impl serde::Serialize for request::StartCommit {
         if self.runtime_checkpoint.is_some() {
             len += 1;
         }
+        if self.runtime_checkpoint_delta.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("materialize.Request.StartCommit", len)?;
         if let Some(v) = self.runtime_checkpoint.as_ref() {
             struct_ser.serialize_field("runtimeCheckpoint", v)?;
         }
+        if let Some(v) = self.runtime_checkpoint_delta.as_ref() {
+            struct_ser.serialize_field("runtimeCheckpointDelta", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1218,11 +1224,14 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
         const FIELDS: &[&str] = &[
             "runtime_checkpoint",
             "runtimeCheckpoint",
+            "runtime_checkpoint_delta",
+            "runtimeCheckpointDelta",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             RuntimeCheckpoint,
+            RuntimeCheckpointDelta,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1245,6 +1254,7 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
                     {
                         match value {
                             "runtimeCheckpoint" | "runtime_checkpoint" => Ok(GeneratedField::RuntimeCheckpoint),
+                            "runtimeCheckpointDelta" | "runtime_checkpoint_delta" => Ok(GeneratedField::RuntimeCheckpointDelta),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1265,6 +1275,7 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut runtime_checkpoint__ = None;
+                let mut runtime_checkpoint_delta__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::RuntimeCheckpoint => {
@@ -1273,10 +1284,17 @@ impl<'de> serde::Deserialize<'de> for request::StartCommit {
                             }
                             runtime_checkpoint__ = map.next_value()?;
                         }
+                        GeneratedField::RuntimeCheckpointDelta => {
+                            if runtime_checkpoint_delta__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("runtimeCheckpointDelta"));
+                            }
+                            runtime_checkpoint_delta__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(request::StartCommit {
                     runtime_checkpoint: runtime_checkpoint__,
+                    runtime_checkpoint_delta: runtime_checkpoint_delta__,
                 })
             }
         }
@@ -182,6 +182,12 @@ pub struct Stats {
     >,
     #[prost(message, optional, tag = "9")]
     pub interval: ::core::option::Option<stats::Interval>,
+    /// Duration of time that the transaction was throttled by rate limits,
+    /// during which the task shard paused reading from its connector.
+    /// When aggregating, this is total time throttled across all transactions
+    /// within the interval.
+    #[prost(double, tag = "10")]
+    pub throttled_seconds_total: f64,
 }
 /// Nested message and enum types in `Stats`.
 pub mod stats {
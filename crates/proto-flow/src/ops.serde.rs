@@ -791,6 +791,9 @@ impl serde::Serialize for Stats {
         if self.interval.is_some() {
             len += 1;
         }
+        if self.throttled_seconds_total != 0. {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("ops.Stats", len)?;
         if let Some(v) = self.meta.as_ref() {
             struct_ser.serialize_field("_meta", v)?;
@@ -819,6 +822,9 @@ impl serde::Serialize for Stats {
         if let Some(v) = self.interval.as_ref() {
             struct_ser.serialize_field("interval", v)?;
         }
+        if self.throttled_seconds_total != 0. {
+            struct_ser.serialize_field("throttledSecondsTotal", &self.throttled_seconds_total)?;
+        }
         struct_ser.end()
     }
 }
@@ -842,6 +848,8 @@ impl<'de> serde::Deserialize<'de> for Stats {
             "derive",
             "materialize",
             "interval",
+            "throttled_seconds_total",
+            "throttledSecondsTotal",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -855,6 +863,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
             Derive,
             Materialize,
             Interval,
+            ThrottledSecondsTotal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -885,6 +894,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
                             "derive" => Ok(GeneratedField::Derive),
                             "materialize" => Ok(GeneratedField::Materialize),
                             "interval" => Ok(GeneratedField::Interval),
+                            "throttledSecondsTotal" | "throttled_seconds_total" => Ok(GeneratedField::ThrottledSecondsTotal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -913,6 +923,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
                 let mut derive__ = None;
                 let mut materialize__ = None;
                 let mut interval__ = None;
+                let mut throttled_seconds_total__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Meta => {
@@ -977,6 +988,14 @@ impl<'de> serde::Deserialize<'de> for Stats {
                             }
                             interval__ = map.next_value()?;
                         }
+                        GeneratedField::ThrottledSecondsTotal => {
+                            if throttled_seconds_total__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("throttledSecondsTotal"));
+                            }
+                            throttled_seconds_total__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(Stats {
@@ -989,6 +1008,7 @@ impl<'de> serde::Deserialize<'de> for Stats {
                     derive: derive__,
                     materialize: materialize__.unwrap_or_default(),
                     interval: interval__,
+                    throttled_seconds_total: throttled_seconds_total__.unwrap_or_default(),
                 })
             }
         }
@@ -485,6 +485,7 @@ fn ex_derive_request() -> derive::Request {
         flush: Some(derive::request::Flush {}),
         start_commit: Some(derive::request::StartCommit {
             runtime_checkpoint: Some(ex_consumer_checkpoint()),
+            runtime_checkpoint_delta: Some(ex_consumer_checkpoint()),
         }),
         reset: Some(derive::request::Reset {}),
         internal: ex_internal(),
@@ -511,7 +512,9 @@ fn ex_derive_response() -> derive::Response {
             )]
             .into(),
         }),
-        opened: Some(derive::response::Opened {}),
+        opened: Some(derive::response::Opened {
+            runtime_checkpoint: Some(ex_consumer_checkpoint()),
+        }),
         published: Some(derive::response::Published {
             doc_json: json!({"published": "doc"}).to_string(),
         }),
@@ -569,6 +572,7 @@ fn ex_materialize_request() -> materialize::Request {
         }),
         start_commit: Some(materialize::request::StartCommit {
             runtime_checkpoint: Some(ex_consumer_checkpoint()),
+            runtime_checkpoint_delta: Some(ex_consumer_checkpoint()),
         }),
         internal: ex_internal(),
     }
@@ -756,6 +760,7 @@ fn ex_stats() -> ops::Stats {
             uptime_seconds: 300,
             usage_rate: 1.5,
         }),
+        throttled_seconds_total: 2.25,
     }
 }
 
@@ -10,6 +10,38 @@ use proto_flow::{
 // Start a capture connector as indicated by the `initial` Request.
 // Returns a pair of Streams for sending Requests and receiving Responses.
 pub async fn start<L: LogHandler>(
+    runtime: &Runtime<L>,
+    initial: Request,
+) -> anyhow::Result<(
+    mpsc::Sender<Request>,
+    BoxStream<'static, anyhow::Result<Response>>,
+)> {
+    crate::recording::start(
+        runtime.recording.as_ref(),
+        initial,
+        |response: &Response| response.checkpoint.is_some(),
+        mask_replayed_request,
+        |initial| start_connector(runtime, initial),
+    )
+    .await
+}
+
+// Captures are replayed without validating with their connector, so their
+// bindings have placeholder resource paths (and state keys derived from them)
+// which cannot match the recording. Other fields of requests must still match.
+fn mask_replayed_request(request: &mut serde_json::Value) {
+    for ptr in ["/open/capture/bindings", "/apply/capture/bindings"] {
+        let Some(serde_json::Value::Array(bindings)) = request.pointer_mut(ptr) else {
+            continue;
+        };
+        for binding in bindings.iter_mut().filter_map(|b| b.as_object_mut()) {
+            binding.remove("resourcePath");
+            binding.remove("stateKey");
+        }
+    }
+}
+
+async fn start_connector<L: LogHandler>(
     runtime: &Runtime<L>,
     mut initial: Request,
 ) -> anyhow::Result<(
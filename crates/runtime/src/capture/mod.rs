@@ -7,6 +7,7 @@ mod connector;
 mod protocol;
 mod serve;
 mod task;
+mod throttle;
 
 pub trait RequestStream: Stream<Item = anyhow::Result<Request>> + Send + Unpin + 'static {}
 impl<T: Stream<Item = anyhow::Result<Request>> + Send + Unpin + 'static> RequestStream for T {}
@@ -18,12 +19,16 @@ impl<T: Stream<Item = anyhow::Result<Response>> + Send + 'static> ResponseStream
 pub struct Task {
     // Bindings of this task.
     bindings: Vec<Binding>,
+    // Bytes of captured documents which may be combined within a transaction.
+    combiner_byte_threshold: usize,
     // Does the capture connector want explicit acknowledgements?
     explicit_acknowledgements: bool,
     // Instant at which this Task is eligible for restart.
     restart: tokio::time::Instant,
     // ShardRef of this task.
     shard_ref: ops::ShardRef,
+    // Rate limits of this task and its bindings.
+    throttle: throttle::Throttle,
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +61,8 @@ pub struct Transaction {
     started_at: std::time::SystemTime,
     // Statistics of (read documents, combined documents) for each binding.
     stats: BTreeMap<u32, (DocsAndBytes, DocsAndBytes)>,
+    // Duration for which reads from the connector were paused by rate limits.
+    throttled: std::time::Duration,
     // Set of bindings which updated their inferred Shape this transaction.
     updated_inferences: BTreeSet<usize>,
 }
@@ -73,6 +80,7 @@ const LONG_POLL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5)
 // optimistically combined within a capture transaction, while awaiting the
 // commit of a previous transaction. Upon reaching this threshold, further
 // documents and checkpoints will not be folded into the transaction.
+// It's the default threshold of a task which doesn't configure its own.
 const COMBINER_BYTE_THRESHOLD: usize = 1 << 25; // 32MB.
 
 impl Transaction {
@@ -83,6 +91,7 @@ impl Transaction {
             connector_eof: false,
             started_at: std::time::SystemTime::UNIX_EPOCH,
             stats: Default::default(),
+            throttled: std::time::Duration::ZERO,
             updated_inferences: Default::default(),
         }
     }
@@ -54,6 +54,7 @@ pub fn recv_connector_opened(
     open: &Request,
     opened: Option<Response>,
     shapes_by_key: &mut BTreeMap<String, doc::Shape>,
+    validation_output: doc::OutputFormat,
 ) -> anyhow::Result<(
     Task,
     Task,
@@ -71,8 +72,14 @@ pub fn recv_connector_opened(
     let shapes = task.binding_shapes_by_index(std::mem::take(shapes_by_key));
 
     // Create a pair of accumulators. While one is draining, the other is accumulating.
-    let a1 = doc::combine::Accumulator::new(task.combine_spec()?, tempfile::tempfile()?)?;
-    let a2 = doc::combine::Accumulator::new(task.combine_spec()?, tempfile::tempfile()?)?;
+    let a1 = doc::combine::Accumulator::new(
+        task.combine_spec(validation_output)?,
+        tempfile::tempfile()?,
+    )?;
+    let a2 = doc::combine::Accumulator::new(
+        task.combine_spec(validation_output)?,
+        tempfile::tempfile()?,
+    )?;
 
     let opened = Response {
         opened: Some(response::Opened {
@@ -220,6 +227,7 @@ pub fn send_client_final_checkpoint(
         }),
         open_seconds_total: txn.started_at.elapsed().unwrap().as_secs_f64(),
         shard: Some(task.shard_ref.clone()),
+        throttled_seconds_total: txn.throttled.as_secs_f64(),
         timestamp: Some(proto_flow::as_timestamp(txn.started_at)),
         txn_count: 1,
     };
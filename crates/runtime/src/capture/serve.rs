@@ -83,7 +83,7 @@ async fn serve_session<L: LogHandler>(
     let opened = TryStreamExt::try_next(&mut connector_rx).await?;
 
     let (task, task_clone, mut shapes, accumulator, mut next_accumulator, opened) =
-        recv_connector_opened(&open, opened, shapes_by_key)?;
+        recv_connector_opened(&open, opened, shapes_by_key, runtime.validation_output)?;
 
     () = co.yield_(opened).await;
 
@@ -194,7 +194,7 @@ async fn serve_session<L: LogHandler>(
 pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
     mut accumulator: doc::combine::Accumulator,
     mut connector_rx: R,
-    task: Task,
+    mut task: Task,
     timeout: std::time::Duration, // How long we'll wait for a first checkpoint.
     yield_rx: oneshot::Receiver<()>, // Signaled when we should return.
 ) -> anyhow::Result<(doc::combine::Accumulator, R, Task, Transaction)> {
@@ -203,10 +203,22 @@ pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
     let mut yield_rx = yield_rx.fuse();
     tokio::pin!(timeout);
 
+    // When a rate limit is exceeded, we pause reading from the connector
+    // until it's again within its limit. The connector is back-pressured
+    // as it's not being polled for further responses.
+    // `paused` is the Instant at which a current pause began.
+    let throttle = tokio::time::sleep(std::time::Duration::ZERO);
+    let mut paused: Option<std::time::Instant> = None;
+    tokio::pin!(throttle);
+
     // Loop over one or more response checkpoints.
     loop {
         let (woken, initial) = tokio::select! {
-            initial = connector_rx.try_next(), if !txn.connector_eof && txn.captured_bytes < super::COMBINER_BYTE_THRESHOLD => (false, initial?),
+            initial = connector_rx.try_next(), if paused.is_none() && !txn.connector_eof && txn.captured_bytes < task.combiner_byte_threshold => (false, initial?),
+            () = &mut throttle, if paused.is_some() => {
+                txn.throttled += paused.take().unwrap().elapsed();
+                continue;
+            }
             _ = &mut timeout => (true, None),
             _ = &mut yield_rx => (true, None),
         };
@@ -216,17 +228,23 @@ pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
                     txn.started_at = std::time::SystemTime::now();
                 }
 
-                () = read_checkpoint(
+                let delay = read_checkpoint(
                     &mut accumulator,
                     &mut connector_rx,
                     initial,
-                    &task,
+                    &mut task,
                     &mut txn,
                 )
                 .await?;
 
+                if !delay.is_zero() {
+                    throttle.as_mut().reset(tokio::time::Instant::now() + delay);
+                    paused = Some(std::time::Instant::now());
+                }
+
                 // Were we previously asked to yield, and only now have a checkpoint to return?
                 if yield_rx.is_terminated() {
+                    txn.throttled += paused.map(|p| p.elapsed()).unwrap_or_default();
                     return Ok((accumulator, connector_rx, task, txn));
                 }
             }
@@ -236,6 +254,9 @@ pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
             (true, _none) => {
                 // Have we been asked to yield, and either have a non-empty transaction or reached our timeout?
                 if yield_rx.is_terminated() && (txn.checkpoints != 0 || timeout.is_terminated()) {
+                    // A pause cut short by yielding counts only the time actually waited.
+                    // Any remaining debt is carried by the rate limit's buckets.
+                    txn.throttled += paused.map(|p| p.elapsed()).unwrap_or_default();
                     return Ok((accumulator, connector_rx, task, txn));
                 }
             }
@@ -243,17 +264,41 @@ pub async fn read_transaction<R: ResponseStream + FusedStream + Unpin>(
     }
 }
 
+// Read a checkpoint from the connector, returning the Duration for which
+// reading must then pause to stay within the task's rate limits.
+// Limits are also enforced within the checkpoint, so that a single large
+// checkpoint (such as a backfill) cannot flood past them.
 async fn read_checkpoint(
     accumulator: &mut doc::combine::Accumulator,
     connector_rx: &mut (impl ResponseStream + Unpin),
     mut response: Response,
-    task: &Task,
+    task: &mut Task,
     txn: &mut Transaction,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<std::time::Duration> {
+    let mut resume_at = std::time::Instant::now();
+
     // Read all Captured responses of the checkpoint.
     while let Some(captured) = response.captured {
+        // The transaction cannot yield within a checkpoint, so pause for the
+        // debt of preceding documents before taking tokens for another.
+        let wait = resume_at.saturating_duration_since(std::time::Instant::now());
+        if !wait.is_zero() {
+            let started = std::time::Instant::now();
+            tokio::time::sleep(wait).await;
+            txn.throttled += started.elapsed();
+        }
+
+        let (binding, doc_bytes) = (captured.binding, captured.doc_json.len());
         recv_connector_captured(accumulator, captured, task, txn)?;
 
+        // Track the time at which all exceeded limits will have recovered.
+        // If this is the checkpoint's last document, the pause happens
+        // between checkpoints where the transaction may also yield.
+        let delay = task.throttle.take(binding, doc_bytes);
+        if !delay.is_zero() {
+            resume_at = resume_at.max(std::time::Instant::now() + delay);
+        }
+
         // Read next response.
         response = match connector_rx.try_next().await? {
             Some(response) => response,
@@ -261,5 +306,6 @@ async fn read_checkpoint(
         };
     }
 
-    recv_connector_checkpoint(accumulator, response, task, txn)
+    () = recv_connector_checkpoint(accumulator, response, task, txn)?;
+    Ok(resume_at.saturating_duration_since(std::time::Instant::now()))
 }
@@ -1,4 +1,4 @@
-use super::{Binding, Task};
+use super::{throttle::Throttle, Binding, Task};
 use anyhow::Context;
 use proto_flow::capture::{request, response, Request, Response};
 use proto_flow::flow;
@@ -25,7 +25,7 @@ impl Task {
             name,
             network_ports: _,
             recovery_log_template: _,
-            shard_template: _,
+            shard_template,
         } = spec.as_ref().context("missing capture")?;
         let range = range.context("missing range")?;
 
@@ -37,6 +37,15 @@ impl Task {
             .map(|(index, spec)| Binding::new(spec, ser_policy.clone()).context(index))
             .collect::<Result<Vec<_>, _>>()?;
 
+        let combiner_byte_threshold =
+            match crate::shard_label(shard_template.as_ref(), labels::MAX_COMBINER_BYTES) {
+                Some(value) => value.parse().with_context(|| {
+                    format!("invalid {} label {value:?}", labels::MAX_COMBINER_BYTES)
+                })?,
+                None => super::COMBINER_BYTE_THRESHOLD,
+            };
+        let throttle = Throttle::new(shard_template.as_ref(), bindings.len())?;
+
         let restart = std::time::Duration::from_secs(*interval_seconds as u64);
         let restart = tokio::time::Instant::now().checked_add(restart).unwrap();
 
@@ -49,9 +58,11 @@ impl Task {
 
         Ok(Self {
             bindings,
+            combiner_byte_threshold,
             explicit_acknowledgements,
             restart,
             shard_ref,
+            throttle,
         })
     }
 
@@ -83,7 +94,10 @@ impl Task {
         by_key
     }
 
-    pub fn combine_spec(&self) -> anyhow::Result<doc::combine::Spec> {
+    pub fn combine_spec(
+        &self,
+        validation_output: doc::OutputFormat,
+    ) -> anyhow::Result<doc::combine::Spec> {
         let combiner_spec = self
             .bindings
             .iter()
@@ -99,7 +113,10 @@ impl Task {
         let combiner_spec = doc::combine::Spec::with_bindings(
             combiner_spec
                 .into_iter()
-                .map(|(is_full, key, validator)| (is_full, key, None, validator))
+                .map(|(is_full, key, validator)| {
+                    let validator = validator.with_output_format(validation_output);
+                    (is_full, key, None, validator)
+                })
                 .chain(std::iter::once((false, Vec::new(), None, state_validator))),
         );
 
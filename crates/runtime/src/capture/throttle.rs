@@ -0,0 +1,190 @@
+use anyhow::Context;
+use proto_gazette::consumer::ShardSpec;
+use std::time::{Duration, Instant};
+
+/// Throttle enforces the rate limits of a capture task and its bindings over
+/// documents read from the connector. Each limit is a token bucket which
+/// refills continuously at its configured rate, and which holds at most one
+/// second's worth of tokens. Reading a document takes tokens from the buckets
+/// of its task and binding, and a bucket which goes into debt must refill
+/// before further documents may be read.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    // Limits which apply to all bindings of the task.
+    task: Limit,
+    // Limits of each task binding, indexed by binding offset.
+    bindings: Vec<Limit>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Limit {
+    docs: Option<Bucket>,
+    bytes: Option<Bucket>,
+}
+
+#[derive(Debug, Clone)]
+struct Bucket {
+    // Rate at which the bucket refills, in tokens per second.
+    rate: f64,
+    // Current tokens of the bucket, which are negative if it's in debt.
+    tokens: f64,
+    // Instant at which `tokens` was last updated.
+    updated: Instant,
+}
+
+impl Throttle {
+    /// Build a Throttle from the rate limit labels of a task's ShardSpec.
+    pub fn new(shard: Option<&ShardSpec>, num_bindings: usize) -> anyhow::Result<Self> {
+        let task = Limit::new(
+            crate::shard_label(shard, labels::RATE_LIMIT_DOCS),
+            crate::shard_label(shard, labels::RATE_LIMIT_BYTES),
+        )
+        .context("invalid task rate limit")?;
+
+        let bindings = (0..num_bindings)
+            .map(|index| {
+                let docs = format!("{}{index}", labels::RATE_LIMIT_DOCS_PREFIX);
+                let bytes = format!("{}{index}", labels::RATE_LIMIT_BYTES_PREFIX);
+
+                Limit::new(
+                    crate::shard_label(shard, &docs),
+                    crate::shard_label(shard, &bytes),
+                )
+                .with_context(|| format!("invalid rate limit of binding {index}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self { task, bindings })
+    }
+
+    /// Take tokens for a document of `binding` having `doc_bytes`, returning
+    /// the Duration for which the caller must pause before reading another
+    /// document. The returned Duration is zero if no limit is exceeded.
+    pub fn take(&mut self, binding: u32, doc_bytes: usize) -> Duration {
+        self.take_at(binding, doc_bytes, Instant::now())
+    }
+
+    fn take_at(&mut self, binding: u32, doc_bytes: usize, now: Instant) -> Duration {
+        let mut delay = self.task.take(doc_bytes, now);
+
+        if let Some(limit) = self.bindings.get_mut(binding as usize) {
+            delay = delay.max(limit.take(doc_bytes, now));
+        }
+        delay
+    }
+}
+
+impl Limit {
+    fn new(docs: Option<&str>, bytes: Option<&str>) -> anyhow::Result<Self> {
+        Ok(Self {
+            docs: docs.map(Bucket::parse).transpose()?,
+            bytes: bytes.map(Bucket::parse).transpose()?,
+        })
+    }
+
+    fn take(&mut self, doc_bytes: usize, now: Instant) -> Duration {
+        let docs = self.docs.as_mut().map(|b| b.take(1.0, now));
+        let bytes = self.bytes.as_mut().map(|b| b.take(doc_bytes as f64, now));
+
+        docs.max(bytes).unwrap_or_default()
+    }
+}
+
+impl Bucket {
+    fn parse(rate: &str) -> anyhow::Result<Self> {
+        let rate: u64 = rate
+            .parse()
+            .with_context(|| format!("rate limit {rate:?} is not an integer"))?;
+
+        if rate == 0 {
+            anyhow::bail!("rate limit must be greater than zero");
+        }
+        let rate = rate as f64;
+
+        Ok(Self {
+            rate,
+            tokens: rate,
+            updated: Instant::now(),
+        })
+    }
+
+    // Take `n` tokens from the bucket as-of `now`, returning the Duration
+    // until the bucket has refilled out of any resulting debt.
+    fn take(&mut self, n: f64, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate) - n;
+        self.updated = now;
+
+        if self.tokens < 0.0 {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proto_gazette::broker::{Label, LabelSet};
+
+    fn shard_spec(labels: &[(&str, &str)]) -> ShardSpec {
+        let mut labels: Vec<_> = labels
+            .iter()
+            .map(|(name, value)| Label {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect();
+        labels.sort_by(|l, r| l.name.cmp(&r.name));
+
+        ShardSpec {
+            labels: Some(LabelSet { labels }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_task_and_binding_limits() {
+        let shard = shard_spec(&[
+            (labels::RATE_LIMIT_DOCS, "10"),
+            ("estuary.dev/rate-limit-bytes/1", "1000"),
+        ]);
+        let mut throttle = Throttle::new(Some(&shard), 2).unwrap();
+        let now = Instant::now();
+
+        // The task bucket bursts up to ten documents before it's in debt.
+        for _ in 0..10 {
+            assert_eq!(throttle.take_at(0, 50, now), Duration::ZERO);
+        }
+        assert_eq!(throttle.take_at(0, 50, now), Duration::from_millis(100));
+
+        // After a second the task bucket has refilled, but a large
+        // document of binding 1 puts its bytes bucket into debt.
+        let now = now + Duration::from_secs(1);
+        assert_eq!(throttle.take_at(1, 1500, now), Duration::from_millis(500));
+
+        // Waiting out the delay brings the binding back out of debt.
+        let now = now + Duration::from_millis(500);
+        assert_eq!(throttle.take_at(1, 0, now), Duration::ZERO);
+
+        // Binding 0 has no limit of its own.
+        assert_eq!(throttle.take_at(0, 1 << 20, now), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_limits() {
+        let shard = shard_spec(&[(labels::RATE_LIMIT_DOCS, "0")]);
+        insta::assert_display_snapshot!(
+            format!("{:#}", Throttle::new(Some(&shard), 0).unwrap_err()),
+            @"invalid task rate limit: rate limit must be greater than zero"
+        );
+
+        let shard = shard_spec(&[("estuary.dev/rate-limit-docs/0", "fast")]);
+        insta::assert_display_snapshot!(
+            format!("{:#}", Throttle::new(Some(&shard), 1).unwrap_err()),
+            @r###"invalid rate limit of binding 0: rate limit "fast" is not an integer: invalid digit found in string"###
+        );
+    }
+}
@@ -10,6 +10,23 @@ use proto_flow::{
 // Start a derivation connector as indicated by the `initial` Request.
 // Returns a pair of Streams for sending Requests and receiving Responses.
 pub async fn start<L: LogHandler>(
+    runtime: &Runtime<L>,
+    initial: Request,
+) -> anyhow::Result<(
+    mpsc::Sender<Request>,
+    BoxStream<'static, anyhow::Result<Response>>,
+)> {
+    crate::recording::start(
+        runtime.recording.as_ref(),
+        initial,
+        |response: &Response| response.started_commit.is_some(),
+        |_request| (),
+        |initial| start_connector(runtime, initial),
+    )
+    .await
+}
+
+async fn start_connector<L: LogHandler>(
     runtime: &Runtime<L>,
     mut initial: Request,
 ) -> anyhow::Result<(
@@ -93,7 +110,7 @@ pub async fn start<L: LogHandler>(
             connector_tx.try_send(initial).unwrap();
             ::derive_sqlite::connector(connector_rx).boxed()
         }
-        models::DeriveUsing::Typescript(_) => unreachable!(),
+        models::DeriveUsing::Typescript(_) | models::DeriveUsing::Python(_) => unreachable!(),
     };
 
     Ok((connector_tx, connector_rx))
@@ -176,6 +193,16 @@ fn extract_endpoint<'r>(
             log_level,
             config_json,
         ))
+    } else if connector_type == ConnectorType::Python as i32 {
+        Ok((
+            models::DeriveUsing::Connector(models::ConnectorConfig {
+                image: "ghcr.io/estuary/derive-python:dev".to_string(),
+                config: models::RawValue::from_str(config_json)
+                    .context("parsing connector config")?,
+            }),
+            log_level,
+            config_json,
+        ))
     } else {
         anyhow::bail!("invalid connector type: {connector_type}");
     }
@@ -43,6 +43,7 @@ pub fn recv_connector_opened(
     db: &RocksDB,
     open: &Request,
     opened: Option<Response>,
+    validation_output: doc::OutputFormat,
 ) -> anyhow::Result<(
     Task,
     Vec<doc::Validator>,
@@ -55,33 +56,52 @@ pub fn recv_connector_opened(
     };
 
     let task = Task::new(&open, &opened)?;
-    let validators = task.validators()?;
-    let accumulator = doc::combine::Accumulator::new(task.combine_spec()?, tempfile::tempfile()?)?;
+    let validators = task.validators(validation_output)?;
+    let accumulator = doc::combine::Accumulator::new(
+        task.combine_spec(validation_output)?,
+        tempfile::tempfile()?,
+    )?;
 
     let mut checkpoint = db
         .load_checkpoint()
         .context("failed to load runtime checkpoint from RocksDB")?;
 
-    // TODO(johnny): Expose Opened.runtime_checkpoint in the public protocol.
+    // Connectors may also provide a checkpoint through the legacy OpenedExt.
+    // An empty checkpoint of either is treated as absent, and defers to the
+    // checkpoint of the recovery log.
+    let is_present = |checkpoint: &consumer::Checkpoint| *checkpoint != Default::default();
+
+    let legacy_checkpoint = opened
+        .get_internal()?
+        .opened
+        .and_then(|ext| ext.runtime_checkpoint)
+        .filter(is_present);
+
+    let response::Opened { runtime_checkpoint } = opened.opened.as_mut().unwrap();
+
+    if let Some(connector_checkpoint) = runtime_checkpoint
+        .clone()
+        .filter(is_present)
+        .or(legacy_checkpoint)
+    {
+        checkpoint = connector_checkpoint;
+        tracing::debug!(
+            checkpoint=?ops::DebugJson(&checkpoint),
+            "using connector-provided Opened.runtime_checkpoint",
+        );
+    } else {
+        tracing::debug!(
+            checkpoint=?ops::DebugJson(&checkpoint),
+            "loaded and attached a persisted Opened.runtime_checkpoint",
+        );
+    }
+    *runtime_checkpoint = Some(checkpoint.clone());
+
+    // Also attach the legacy OpenedExt, for clients which have yet to migrate.
     opened.set_internal(|internal| {
-        if let Some(derive_response_ext::Opened {
-            runtime_checkpoint: Some(connector_checkpoint),
-        }) = &internal.opened
-        {
-            checkpoint = connector_checkpoint.clone();
-            tracing::debug!(
-                checkpoint=?ops::DebugJson(&checkpoint),
-                "using connector-provided OpenedExt.runtime_checkpoint",
-            );
-        } else {
-            internal.opened = Some(derive_response_ext::Opened {
-                runtime_checkpoint: Some(checkpoint.clone()),
-            });
-            tracing::debug!(
-                checkpoint=?ops::DebugJson(&checkpoint),
-                "loaded and attached a persisted OpenedExt.runtime_checkpoint",
-            );
-        }
+        internal.opened = Some(derive_response_ext::Opened {
+            runtime_checkpoint: Some(checkpoint.clone()),
+        });
     });
 
     Ok((task, validators, accumulator, checkpoint, opened))
@@ -276,6 +296,7 @@ pub fn send_client_flushed(buf: &mut bytes::BytesMut, task: &Task, txn: &Transac
         }),
         open_seconds_total: txn.started_at.elapsed().unwrap().as_secs_f64(),
         shard: Some(task.shard_ref.clone()),
+        throttled_seconds_total: 0.0,
         timestamp: Some(proto_flow::as_timestamp(txn.started_at)),
         txn_count: 1,
     };
@@ -295,27 +316,32 @@ pub fn recv_client_start_commit(
     txn: &mut Transaction,
 ) -> anyhow::Result<(Request, rocksdb::WriteBatch)> {
     let verify = verify("client", "StartCommit with runtime_checkpoint");
-    let request = verify.not_eof(request)?;
+    let mut request = verify.not_eof(request)?;
 
     let Request {
         start_commit:
             Some(request::StartCommit {
                 runtime_checkpoint: Some(runtime_checkpoint),
+                runtime_checkpoint_delta,
             }),
         ..
-    } = &request
+    } = &mut request
     else {
         return verify.fail(request);
     };
 
-    // TODO(johnny): Diff the previous and current checkpoint to build a
-    // merge-able, incremental update that's written to the WriteBatch.
-    let _last_checkpoint = last_checkpoint;
+    // Send the connector only those sources and acknowledgement intents
+    // which were added or changed since the last checkpoint.
+    *runtime_checkpoint_delta = Some(crate::checkpoint_delta(
+        &last_checkpoint,
+        runtime_checkpoint,
+    ));
 
     let mut wb = rocksdb::WriteBatch::default();
 
     tracing::debug!(
         checkpoint=?ops::DebugJson(&runtime_checkpoint),
+        delta=?ops::DebugJson(&runtime_checkpoint_delta),
         "persisting StartCommit.runtime_checkpoint",
     );
     wb.put(RocksDB::CHECKPOINT_KEY, runtime_checkpoint.encode_to_vec());
@@ -81,7 +81,7 @@ async fn serve_session<L: LogHandler>(
     let opened = TryStreamExt::try_next(&mut connector_rx).await?;
 
     let (task, mut validators, mut accumulator, mut last_checkpoint, opened) =
-        recv_connector_opened(&db, &open, opened)?;
+        recv_connector_opened(&db, &open, opened, runtime.validation_output)?;
 
     () = co.yield_(opened).await;
 
@@ -12,7 +12,9 @@ impl Task {
             version: _,
         } = open.clone().open.context("expected Open")?;
 
-        let response::Opened {} = opened.opened.as_ref().context("expected Opened")?;
+        let response::Opened {
+            runtime_checkpoint: _,
+        } = opened.opened.as_ref().context("expected Opened")?;
 
         let flow::CollectionSpec {
             ack_template_json: _,
@@ -74,11 +76,15 @@ impl Task {
         })
     }
 
-    pub fn combine_spec(&self) -> anyhow::Result<doc::combine::Spec> {
+    pub fn combine_spec(
+        &self,
+        validation_output: doc::OutputFormat,
+    ) -> anyhow::Result<doc::combine::Spec> {
         let built_schema = doc::validation::build_bundle(&self.write_schema_json)
             .context("collection write_schema_json is not a JSON schema")?;
         let validator =
             doc::Validator::new(built_schema).context("could not build a schema validator")?;
+        let validator = validator.with_output_format(validation_output);
 
         Ok(doc::combine::Spec::with_one_binding(
             false,
@@ -88,13 +94,17 @@ impl Task {
         ))
     }
 
-    pub fn validators(&self) -> anyhow::Result<Vec<doc::Validator>> {
+    pub fn validators(
+        &self,
+        validation_output: doc::OutputFormat,
+    ) -> anyhow::Result<Vec<doc::Validator>> {
         self.transforms
             .iter()
             .map(|transform| {
-                transform.validator().with_context(|| {
+                let validator = transform.validator().with_context(|| {
                     format!("failed to build validator for transform {}", transform.name)
-                })
+                })?;
+                Ok(validator.with_output_format(validation_output))
             })
             .collect::<Result<Vec<_>, _>>()
     }
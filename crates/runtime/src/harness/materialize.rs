@@ -240,6 +240,7 @@ async fn run_session(
             .try_send(Ok(Request {
                 start_commit: Some(request::StartCommit {
                     runtime_checkpoint: Some(checkpoint),
+                    runtime_checkpoint_delta: None, // Filled by the runtime.
                 }),
                 ..Default::default()
             }))
@@ -9,6 +9,7 @@ pub mod harness;
 mod image_connector;
 mod local_connector;
 mod materialize;
+mod recording;
 mod rocksdb;
 mod task_service;
 mod tokio_context;
@@ -17,6 +18,7 @@ mod unseal;
 pub mod uuid;
 
 pub use container::flow_runtime_protocol;
+pub use rocksdb::RocksDB;
 pub use task_service::TaskService;
 pub use tokio_context::TokioContext;
 
@@ -90,8 +92,10 @@ pub struct Runtime<L: LogHandler> {
     allow_local: bool,
     container_network: String,
     log_handler: L,
+    recording: Option<recording::Recording>,
     set_log_level: Option<Arc<dyn Fn(ops::LogLevel) + Send + Sync>>,
     task_name: String,
+    validation_output: doc::OutputFormat,
 }
 
 impl<L: LogHandler> Runtime<L> {
@@ -112,11 +116,36 @@ impl<L: LogHandler> Runtime<L> {
             allow_local,
             container_network,
             log_handler,
+            recording: None,
             set_log_level,
             task_name,
+            validation_output: doc::OutputFormat::Basic,
         }
     }
 
+    /// Record all Requests and Responses exchanged with connectors of this
+    /// Runtime into a zstd-compressed recording, which is created at `path`.
+    pub fn with_recording(mut self, path: &std::path::Path) -> anyhow::Result<Self> {
+        let recorder = recording::Recorder::create(path)?;
+        self.recording = Some(recording::Recording::Record(Arc::new(recorder)));
+        Ok(self)
+    }
+
+    /// Replay connector Responses from the recording at `path`,
+    /// instead of starting the connectors of this Runtime.
+    pub fn with_replay(mut self, path: &std::path::Path) -> anyhow::Result<Self> {
+        let replayer = recording::Replayer::open(path)?;
+        self.recording = Some(recording::Recording::Replay(Arc::new(replayer)));
+        Ok(self)
+    }
+
+    /// Include the given JSON-Schema OutputFormat in errors of documents
+    /// which fail schema validation, in addition to the "basic" output.
+    pub fn with_validation_output(mut self, validation_output: doc::OutputFormat) -> Self {
+        self.validation_output = validation_output;
+        self
+    }
+
     /// Build a tonic Server which includes all of the Runtime's services.
     pub fn build_tonic_server(self) -> tonic::transport::server::Router {
         tonic::transport::Server::builder()
@@ -140,6 +169,14 @@ impl<L: LogHandler> Runtime<L> {
 
 // Extract a LogLevel from a ShardSpec.
 fn shard_log_level(shard: Option<&proto_gazette::consumer::ShardSpec>) -> Option<ops::LogLevel> {
+    shard_label(shard, ::labels::LOG_LEVEL).and_then(ops::LogLevel::from_str_name)
+}
+
+// Extract the value of the first label having `name` from a ShardSpec.
+fn shard_label<'s>(
+    shard: Option<&'s proto_gazette::consumer::ShardSpec>,
+    name: &str,
+) -> Option<&'s str> {
     let labels = shard
         .and_then(|shard| shard.labels.as_ref())
         .map(|l| l.labels.as_slice());
@@ -147,9 +184,39 @@ fn shard_log_level(shard: Option<&proto_gazette::consumer::ShardSpec>) -> Option
     let Some(labels) = labels else {
         return None;
     };
-    match labels.binary_search_by(|label| label.name.as_str().cmp(::labels::LOG_LEVEL)) {
-        Ok(index) => ops::LogLevel::from_str_name(&labels[index].value),
-        Err(_index) => None,
+    // Labels are a multiset, so use a lower bound of `name` rather than `binary_search`.
+    let index = labels.partition_point(|label| label.name.as_str() < name);
+
+    match labels.get(index) {
+        Some(label) if label.name == name => Some(&label.value),
+        _ => None,
+    }
+}
+
+// Build the delta of `next` over `last`: the Checkpoint of sources and
+// acknowledgement intents which were added or changed by `next`.
+// Journals present in `last` but not `next` are not represented.
+fn checkpoint_delta(
+    last: &proto_gazette::consumer::Checkpoint,
+    next: &proto_gazette::consumer::Checkpoint,
+) -> proto_gazette::consumer::Checkpoint {
+    let sources = next
+        .sources
+        .iter()
+        .filter(|(journal, source)| last.sources.get(*journal) != Some(*source))
+        .map(|(journal, source)| (journal.clone(), source.clone()))
+        .collect();
+
+    let ack_intents = next
+        .ack_intents
+        .iter()
+        .filter(|(journal, intent)| last.ack_intents.get(*journal) != Some(*intent))
+        .map(|(journal, intent)| (journal.clone(), intent.clone()))
+        .collect();
+
+    proto_gazette::consumer::Checkpoint {
+        sources,
+        ack_intents,
     }
 }
 
@@ -247,3 +314,72 @@ where
         }
     })
 }
+
+#[cfg(test)]
+mod test {
+    use proto_gazette::consumer;
+
+    #[test]
+    fn test_checkpoint_delta() {
+        let source = |read_through| consumer::checkpoint::Source {
+            read_through,
+            producers: Vec::new(),
+        };
+        let last = consumer::Checkpoint {
+            sources: [
+                ("a/journal".to_string(), source(10)),
+                ("b/journal".to_string(), source(20)),
+                ("c/journal".to_string(), source(30)),
+            ]
+            .into(),
+            ack_intents: [
+                ("x/journal".to_string(), b"x-one".to_vec()),
+                ("y/journal".to_string(), b"y-one".to_vec()),
+            ]
+            .into(),
+        };
+        let next = consumer::Checkpoint {
+            sources: [
+                ("a/journal".to_string(), source(10)), // Unchanged.
+                ("b/journal".to_string(), source(25)), // Changed.
+                ("d/journal".to_string(), source(40)), // Added.
+            ]
+            .into(),
+            ack_intents: [
+                ("x/journal".to_string(), b"x-one".to_vec()), // Unchanged.
+                ("y/journal".to_string(), b"y-two".to_vec()), // Changed.
+                ("z/journal".to_string(), b"z-one".to_vec()), // Added.
+            ]
+            .into(),
+        };
+
+        let delta = super::checkpoint_delta(&last, &next);
+
+        assert_eq!(
+            delta,
+            consumer::Checkpoint {
+                sources: [
+                    ("b/journal".to_string(), source(25)),
+                    ("d/journal".to_string(), source(40)),
+                ]
+                .into(),
+                ack_intents: [
+                    ("y/journal".to_string(), b"y-two".to_vec()),
+                    ("z/journal".to_string(), b"z-one".to_vec()),
+                ]
+                .into(),
+            }
+        );
+
+        // The delta of a checkpoint over itself is empty,
+        // and over an empty checkpoint is itself.
+        assert_eq!(
+            super::checkpoint_delta(&next, &next),
+            consumer::Checkpoint::default()
+        );
+        assert_eq!(
+            super::checkpoint_delta(&consumer::Checkpoint::default(), &next),
+            next
+        );
+    }
+}
@@ -10,6 +10,23 @@ use proto_flow::{
 // Start a materialization connector as indicated by the `initial` Request.
 // Returns a pair of Streams for sending Requests and receiving Responses.
 pub async fn start<L: LogHandler>(
+    runtime: &Runtime<L>,
+    initial: Request,
+) -> anyhow::Result<(
+    mpsc::Sender<Request>,
+    BoxStream<'static, anyhow::Result<Response>>,
+)> {
+    crate::recording::start(
+        runtime.recording.as_ref(),
+        initial,
+        |response: &Response| response.started_commit.is_some(),
+        |_request| (),
+        |initial| start_connector(runtime, initial),
+    )
+    .await
+}
+
+async fn start_connector<L: LogHandler>(
     runtime: &Runtime<L>,
     mut initial: Request,
 ) -> anyhow::Result<(
@@ -47,6 +47,7 @@ pub fn recv_connector_opened(
     db: &RocksDB,
     open: &Request,
     opened: Option<Response>,
+    validation_output: doc::OutputFormat,
 ) -> anyhow::Result<(
     Task,
     doc::combine::Accumulator,
@@ -64,7 +65,10 @@ pub fn recv_connector_opened(
     };
 
     let task = Task::new(&open)?;
-    let accumulator = doc::combine::Accumulator::new(task.combine_spec()?, tempfile::tempfile()?)?;
+    let accumulator = doc::combine::Accumulator::new(
+        task.combine_spec(validation_output)?,
+        tempfile::tempfile()?,
+    )?;
 
     let mut checkpoint = db
         .load_checkpoint()
@@ -283,6 +287,7 @@ pub fn send_client_flushed(buf: &mut bytes::BytesMut, task: &Task, txn: &Transac
         }),
         open_seconds_total: txn.started_at.elapsed().unwrap().as_secs_f64(),
         shard: Some(task.shard_ref.clone()),
+        throttled_seconds_total: 0.0,
         timestamp: Some(proto_flow::as_timestamp(txn.started_at)),
         txn_count: 1,
     };
@@ -302,27 +307,32 @@ pub fn recv_client_start_commit(
     txn: &mut Transaction,
 ) -> anyhow::Result<(Request, rocksdb::WriteBatch)> {
     let verify = verify("client", "StartCommit with runtime_checkpoint");
-    let request = verify.not_eof(request)?;
+    let mut request = verify.not_eof(request)?;
 
     let Request {
         start_commit:
             Some(request::StartCommit {
                 runtime_checkpoint: Some(runtime_checkpoint),
+                runtime_checkpoint_delta,
             }),
         ..
-    } = &request
+    } = &mut request
     else {
         return verify.fail(request);
     };
 
-    // TODO(johnny): Diff the previous and current checkpoint to build a
-    // merge-able, incremental update that's written to the WriteBatch.
-    let _last_checkpoint = last_checkpoint;
+    // Send the connector only those sources and acknowledgement intents
+    // which were added or changed since the last checkpoint.
+    *runtime_checkpoint_delta = Some(crate::checkpoint_delta(
+        &last_checkpoint,
+        runtime_checkpoint,
+    ));
 
     let mut wb = rocksdb::WriteBatch::default();
 
     tracing::debug!(
         checkpoint=?ops::DebugJson(&runtime_checkpoint),
+        delta=?ops::DebugJson(&runtime_checkpoint_delta),
         "persisting StartCommit.runtime_checkpoint",
     );
     wb.put(RocksDB::CHECKPOINT_KEY, runtime_checkpoint.encode_to_vec());
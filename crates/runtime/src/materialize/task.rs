@@ -60,7 +60,10 @@ impl Task {
         })
     }
 
-    pub fn combine_spec(&self) -> anyhow::Result<doc::combine::Spec> {
+    pub fn combine_spec(
+        &self,
+        validation_output: doc::OutputFormat,
+    ) -> anyhow::Result<doc::combine::Spec> {
         let combiner_spec = self
             .bindings
             .iter()
@@ -69,11 +72,12 @@ impl Task {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Build combiner Spec with all bindings, plus one extra for state reductions.
-        let combiner_spec = doc::combine::Spec::with_bindings(
-            combiner_spec
-                .into_iter()
-                .map(|(is_full, key, validator)| (is_full, key, None, validator)),
-        );
+        let combiner_spec = doc::combine::Spec::with_bindings(combiner_spec.into_iter().map(
+            |(is_full, key, validator)| {
+                let validator = validator.with_output_format(validation_output);
+                (is_full, key, None, validator)
+            },
+        ));
 
         Ok(combiner_spec)
     }
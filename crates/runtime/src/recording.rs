@@ -0,0 +1,497 @@
+use anyhow::Context;
+use futures::{channel::mpsc, stream::BoxStream, Future, SinkExt, StreamExt};
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{BufRead, Write};
+use std::sync::{atomic::AtomicU32, Arc, Mutex};
+
+/// Recording configures a Runtime to either record the protocol exchanges
+/// of its connectors, or to replay a previous recording in their place.
+#[derive(Clone)]
+pub enum Recording {
+    Record(Arc<Recorder>),
+    Replay(Arc<Replayer>),
+}
+
+/// Entry is a single line of a recording, which is a zstd-compressed stream
+/// of newline-delimited JSON entries. Each entry holds either a Request sent
+/// to a connector, a Response which was received from it, or an error which
+/// ended its session.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Entry<Request, Response> {
+    // Connector session of this entry. Each started connector is a new session.
+    session: u32,
+    // Wall-clock time at which the entry was recorded.
+    #[serde(with = "time::serde::rfc3339")]
+    ts: time::OffsetDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request: Option<Request>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response: Option<Response>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Recorder writes entries of connector sessions into a recording.
+/// The compressed frame is finished as each session ends, and flushed at each
+/// transaction boundary, so that a recording remains readable through its last
+/// completed frame if the process crashes.
+pub struct Recorder {
+    next_session: AtomicU32,
+    writer: Mutex<Option<zstd::Encoder<'static, std::io::BufWriter<std::fs::File>>>>,
+}
+
+/// Replayer holds the sessions of a recording which have yet to be replayed.
+pub struct Replayer {
+    sessions: Mutex<VecDeque<Vec<String>>>,
+}
+
+/// Start a connector using `start_connector`, recording or replaying its
+/// exchange if a Recording is configured. `is_boundary` identifies Responses
+/// which complete a transaction, after which the recording is flushed.
+/// `mask` removes fields of a JSON-encoded Request which aren't expected to
+/// match its recording, before the two are compared during a replay.
+pub async fn start<Request, Response, Start, Fut>(
+    recording: Option<&Recording>,
+    initial: Request,
+    is_boundary: fn(&Response) -> bool,
+    mask: fn(&mut serde_json::Value),
+    start_connector: Start,
+) -> anyhow::Result<(
+    mpsc::Sender<Request>,
+    BoxStream<'static, anyhow::Result<Response>>,
+)>
+where
+    Request: serde::Serialize + Send + Sync + 'static,
+    Response: serde::Serialize + for<'de> serde::Deserialize<'de> + Send + Sync + 'static,
+    Start: FnOnce(Request) -> Fut,
+    Fut: Future<
+        Output = anyhow::Result<(
+            mpsc::Sender<Request>,
+            BoxStream<'static, anyhow::Result<Response>>,
+        )>,
+    >,
+{
+    match recording {
+        None => start_connector(initial).await,
+        Some(Recording::Replay(replayer)) => replayer.replay(initial, mask),
+        Some(Recording::Record(recorder)) => {
+            let session = recorder
+                .next_session
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+            // Record `initial` before the connector is started, because
+            // starting it will unseal its (recorded) sealed configuration.
+            recorder.write::<_, ()>(session, Some(&initial), None, None)?;
+
+            let (connector_tx, connector_rx) = match start_connector(initial).await {
+                Ok(ok) => ok,
+                Err(err) => {
+                    recorder.write::<(), ()>(session, None, None, Some(format!("{err:#}")))?;
+                    recorder.finish_frame()?;
+                    return Err(err);
+                }
+            };
+            Ok(recorder
+                .clone()
+                .record(session, is_boundary, connector_tx, connector_rx))
+        }
+    }
+}
+
+impl Recorder {
+    /// Create a new recording at `path`, truncating any existing file.
+    pub fn create(path: &std::path::Path) -> anyhow::Result<Self> {
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("failed to create recording {path:?}"))?;
+        let writer = zstd::Encoder::new(std::io::BufWriter::new(file), 0)?;
+
+        Ok(Self {
+            next_session: AtomicU32::new(0),
+            writer: Mutex::new(Some(writer)),
+        })
+    }
+
+    fn record<Request, Response>(
+        self: Arc<Self>,
+        session: u32,
+        is_boundary: fn(&Response) -> bool,
+        mut connector_tx: mpsc::Sender<Request>,
+        connector_rx: BoxStream<'static, anyhow::Result<Response>>,
+    ) -> (
+        mpsc::Sender<Request>,
+        BoxStream<'static, anyhow::Result<Response>>,
+    )
+    where
+        Request: serde::Serialize + Send + 'static,
+        Response: serde::Serialize + Send + 'static,
+    {
+        let (request_tx, mut request_rx) = mpsc::channel(crate::CHANNEL_BUFFER);
+
+        // Forward requests to the connector, recording each along the way.
+        // Dropping `connector_tx` upon the caller's EOF signals EOF to the connector.
+        let recorder = self.clone();
+        tokio::spawn(async move {
+            while let Some(request) = request_rx.next().await {
+                if let Err(err) = recorder.write::<_, ()>(session, Some(&request), None, None) {
+                    tracing::warn!(?err, "failed to record connector request");
+                }
+                if connector_tx.send(request).await.is_err() {
+                    break; // Connector has exited.
+                }
+            }
+        });
+
+        // Record each response or error of the connector. A connector error
+        // is recorded before it's returned, so that it may be replayed.
+        let mut connector_rx = connector_rx;
+        let connector_rx = coroutines::try_coroutine(move |mut co| async move {
+            loop {
+                match connector_rx.next().await {
+                    Some(Ok(response)) => {
+                        self.write::<(), _>(session, None, Some(&response), None)?;
+                        if is_boundary(&response) {
+                            self.flush()?;
+                        }
+                        () = co.yield_(response).await;
+                    }
+                    Some(Err(err)) => {
+                        self.write::<(), ()>(session, None, None, Some(format!("{err:#}")))?;
+                        self.finish_frame()?;
+                        return Err(err);
+                    }
+                    None => {
+                        self.finish_frame()?;
+                        return Ok(());
+                    }
+                }
+            }
+        })
+        .boxed();
+
+        (request_tx, connector_rx)
+    }
+
+    fn write<Request: serde::Serialize, Response: serde::Serialize>(
+        &self,
+        session: u32,
+        request: Option<&Request>,
+        response: Option<&Response>,
+        error: Option<String>,
+    ) -> anyhow::Result<()> {
+        let entry = Entry {
+            session,
+            ts: time::OffsetDateTime::now_utc(),
+            request,
+            response,
+            error,
+        };
+
+        let mut writer = self.writer.lock().unwrap();
+        let writer = writer.as_mut().context("recording is closed")?;
+
+        serde_json::to_writer(&mut *writer, &entry)?;
+        writer.write_all(b"\n")?;
+
+        Ok(())
+    }
+
+    // Flush buffered entries through to the recording file.
+    fn flush(&self) -> anyhow::Result<()> {
+        let mut writer = self.writer.lock().unwrap();
+        let writer = writer.as_mut().context("recording is closed")?;
+
+        writer.flush().context("failed to flush recording")
+    }
+
+    // Finish the current compressed frame and begin a new one.
+    // Replay decodes the concatenation of all frames of the recording.
+    fn finish_frame(&self) -> anyhow::Result<()> {
+        let mut guard = self.writer.lock().unwrap();
+        let writer = guard.take().context("recording is closed")?;
+
+        let mut inner = writer
+            .finish()
+            .context("failed to finish recording frame")?;
+        inner.flush().context("failed to flush recording")?;
+
+        *guard = Some(zstd::Encoder::new(inner, 0)?);
+        Ok(())
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        let Some(writer) = self.writer.get_mut().unwrap().take() else {
+            return;
+        };
+        if let Err(err) = writer.finish().and_then(|mut w| w.flush()) {
+            tracing::error!(?err, "failed to finish connector recording");
+        }
+    }
+}
+
+impl Replayer {
+    /// Open the recording at `path` for replay.
+    pub fn open(path: &std::path::Path) -> anyhow::Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("failed to open recording {path:?}"))?;
+        let reader = std::io::BufReader::new(zstd::Decoder::new(file)?);
+
+        #[derive(serde::Deserialize)]
+        struct Session {
+            session: u32,
+        }
+
+        let mut sessions: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                // A recording which wasn't cleanly closed ends with a partial frame.
+                // Entries of its prior, completed frames may still be replayed.
+                Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => {
+                    tracing::warn!(?err, index, "recording ends with an incomplete frame");
+                    break;
+                }
+                Err(err) => return Err(err).context("failed to read recording"),
+            };
+            let Session { session } = serde_json::from_str(&line)
+                .with_context(|| format!("failed to parse recording entry {index}"))?;
+
+            sessions.entry(session).or_default().push(line);
+        }
+
+        Ok(Self {
+            sessions: Mutex::new(sessions.into_values().collect()),
+        })
+    }
+
+    // Replay the next recorded session in place of a connector.
+    // Recorded Responses are yielded in their recorded order, and each
+    // recorded Request is first matched by an equal Request of the caller,
+    // with `initial` matching the session's first recorded Request.
+    // Replay fails at the first Request which differs from the recording,
+    // returns a recorded connector error, and stops early if the caller sends EOF.
+    fn replay<Request, Response>(
+        &self,
+        initial: Request,
+        mask: fn(&mut serde_json::Value),
+    ) -> anyhow::Result<(
+        mpsc::Sender<Request>,
+        BoxStream<'static, anyhow::Result<Response>>,
+    )>
+    where
+        Request: serde::Serialize + Send + Sync + 'static,
+        Response: for<'de> serde::Deserialize<'de> + Send + Sync + 'static,
+    {
+        let Some(session) = self.sessions.lock().unwrap().pop_front() else {
+            anyhow::bail!("recording has no further connector sessions to replay");
+        };
+        let (request_tx, request_rx) = mpsc::channel(crate::CHANNEL_BUFFER);
+        let mut request_rx = futures::stream::iter([initial]).chain(request_rx);
+
+        let response_rx = coroutines::try_coroutine(move |mut co| async move {
+            for line in session {
+                let Entry::<serde_json::Value, Response> {
+                    request,
+                    response,
+                    error,
+                    ..
+                } = serde_json::from_str(&line).context("failed to parse recording entry")?;
+
+                if let Some(mut recorded) = request {
+                    let Some(actual) = request_rx.next().await else {
+                        break; // Caller sent EOF.
+                    };
+                    let mut actual = serde_json::to_value(&actual)?;
+
+                    mask(&mut recorded);
+                    mask(&mut actual);
+
+                    if actual != recorded {
+                        anyhow::bail!(
+                            "connector request doesn't match the recording:\nrecorded: {recorded}\nactual: {actual}"
+                        );
+                    }
+                }
+                if let Some(response) = response {
+                    () = co.yield_(response).await;
+                }
+                if let Some(error) = error {
+                    return Err(anyhow::anyhow!(error));
+                }
+            }
+            Ok(())
+        });
+
+        Ok((request_tx, response_rx.boxed()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::{json, Value};
+
+    // Start a connector which responds to each request with an echo.
+    async fn echo(
+        initial: Value,
+    ) -> anyhow::Result<(
+        mpsc::Sender<Value>,
+        BoxStream<'static, anyhow::Result<Value>>,
+    )> {
+        let (mut request_tx, request_rx) = mpsc::channel(crate::CHANNEL_BUFFER);
+        request_tx.try_send(initial).unwrap();
+
+        // A request of `{"fail": ...}` fails the connector.
+        let response_rx = request_rx
+            .map(|request: Value| match request.get("fail") {
+                Some(reason) => Err(anyhow::anyhow!("connector failed: {reason}")),
+                None => Ok(json!({"echo": request})),
+            })
+            .boxed();
+
+        Ok((request_tx, response_rx))
+    }
+
+    // Run a session which sends `requests` after an initial Open,
+    // and collects its responses and a final error, if any.
+    async fn run_session(recording: &Recording, requests: &[Value]) -> Vec<Result<Value, String>> {
+        let (mut request_tx, response_rx) = start(
+            Some(recording),
+            json!({"open": 1}),
+            is_ack,
+            mask_nonce,
+            echo,
+        )
+        .await
+        .unwrap();
+
+        for request in requests {
+            request_tx.send(request.clone()).await.unwrap();
+        }
+        std::mem::drop(request_tx);
+
+        response_rx
+            .map(|r| r.map_err(|err| format!("{err:#}")))
+            .collect()
+            .await
+    }
+
+    fn is_ack(response: &Value) -> bool {
+        response.pointer("/echo/ack").is_some()
+    }
+
+    fn mask_nonce(request: &mut Value) {
+        if let Some(request) = request.as_object_mut() {
+            request.remove("nonce");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.zst");
+        let acks = [json!({"ack": 2}), json!({"ack": 3})];
+        let failure = [json!({"fail": "whoops"}), json!({"ack": 4})];
+
+        let recording = Recording::Record(Arc::new(Recorder::create(&path).unwrap()));
+        let recorded = vec![
+            run_session(&recording, &acks).await,
+            run_session(&recording, &failure).await,
+            run_session(&recording, &acks).await,
+        ];
+        std::mem::drop(recording);
+
+        insta::assert_json_snapshot!(recorded[..2], @r###"
+        [
+          [
+            {
+              "Ok": {
+                "echo": {
+                  "open": 1
+                }
+              }
+            },
+            {
+              "Ok": {
+                "echo": {
+                  "ack": 2
+                }
+              }
+            },
+            {
+              "Ok": {
+                "echo": {
+                  "ack": 3
+                }
+              }
+            }
+          ],
+          [
+            {
+              "Ok": {
+                "echo": {
+                  "open": 1
+                }
+              }
+            },
+            {
+              "Err": "connector failed: \"whoops\""
+            }
+          ]
+        ]
+        "###);
+
+        let recording = Recording::Replay(Arc::new(Replayer::open(&path).unwrap()));
+        let replayed = vec![
+            run_session(&recording, &acks).await,
+            run_session(&recording, &failure).await,
+            run_session(&recording, &acks).await,
+        ];
+        assert_eq!(recorded, replayed);
+
+        // All recorded sessions have been replayed.
+        let Err(err) = start(Some(&recording), json!({}), is_ack, mask_nonce, echo).await else {
+            panic!("expected an error");
+        };
+        assert_eq!(
+            err.to_string(),
+            "recording has no further connector sessions to replay"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replay_request_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.zst");
+
+        let recording = Recording::Record(Arc::new(Recorder::create(&path).unwrap()));
+        let masked = run_session(&recording, &[json!({"ack": 2, "nonce": "a"})]).await;
+        run_session(&recording, &[json!({"ack": 2})]).await;
+
+        // Sessions are readable once finished, even if the recording isn't closed.
+        let replay = Recording::Replay(Arc::new(Replayer::open(&path).unwrap()));
+
+        // Masked fields of requests needn't match the recording.
+        assert_eq!(
+            run_session(&replay, &[json!({"ack": 2, "nonce": "b"})]).await,
+            masked
+        );
+
+        insta::assert_json_snapshot!(run_session(&replay, &[json!({"ack": 5})]).await, @r###"
+        [
+          {
+            "Ok": {
+              "echo": {
+                "open": 1
+              }
+            }
+          },
+          {
+            "Err": "connector request doesn't match the recording:\nrecorded: {\"ack\":2}\nactual: {\"ack\":5}"
+          }
+        ]
+        "###);
+    }
+}
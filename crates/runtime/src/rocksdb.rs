@@ -6,7 +6,7 @@ use proto_gazette::consumer;
 /// RocksDB database used for task state.
 pub struct RocksDB {
     db: rocksdb::DB,
-    _path: std::path::PathBuf,
+    path: std::path::PathBuf,
     _tmp: Option<tempfile::TempDir>,
 }
 
@@ -21,7 +21,17 @@ impl std::ops::Deref for RocksDB {
 impl RocksDB {
     /// Open a RocksDB from an optional descriptor.
     /// If a descriptor isn't provided, then a tempdir is used instead.
+    /// A legacy JSON state file within the RocksDB directory is migrated.
     pub fn open(desc: Option<RocksDbDescriptor>) -> anyhow::Result<Self> {
+        let db = Self::open_without_migration(desc)?;
+        db.migrate_legacy_state()?;
+
+        Ok(db)
+    }
+
+    /// Open a RocksDB from an optional descriptor, without migrating
+    /// a legacy JSON state file, such as for inspecting its current state.
+    pub fn open_without_migration(desc: Option<RocksDbDescriptor>) -> anyhow::Result<Self> {
         let (mut opts, path, _tmp) = match desc {
             Some(RocksDbDescriptor {
                 rocksdb_path,
@@ -88,13 +98,72 @@ impl RocksDB {
         let db = rocksdb::DB::open_cf_descriptors(&opts, &path, cf_descriptors)
             .context("failed to open RocksDB")?;
 
-        // TODO(johnny): Handle migration from a JSON state file here ?
+        Ok(Self { db, path, _tmp })
+    }
+
+    /// Migrate the connector state and runtime checkpoint of a legacy JSON
+    /// state file, as written by the Go runtime's JSONFileStore into the same
+    /// recorded directory. A marker key is written in the same WriteBatch as
+    /// the migrated state, so a legacy file is examined at most once, and its
+    /// state is imported only if the RocksDB doesn't already have a state.
+    fn migrate_legacy_state(&self) -> anyhow::Result<()> {
+        let legacy_path = self.path.join(Self::LEGACY_STATE_FILE);
 
-        Ok(Self {
-            db,
-            _path: path,
-            _tmp,
-        })
+        if self
+            .db
+            .get_pinned(Self::LEGACY_STATE_MIGRATED_KEY)
+            .context("failed to load legacy state migration marker")?
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        let legacy = match std::fs::read(&legacy_path) {
+            Ok(legacy) => legacy,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read legacy state {legacy_path:?}"))
+            }
+        };
+
+        let mut wb = rocksdb::WriteBatch::default();
+        wb.put(Self::LEGACY_STATE_MIGRATED_KEY, &legacy);
+
+        let has_checkpoint = self
+            .db
+            .get_pinned(Self::CHECKPOINT_KEY)
+            .context("failed to load checkpoint")?
+            .is_some();
+
+        if has_checkpoint || self.load_connector_state()?.is_some() {
+            tracing::debug!(
+                ?legacy_path,
+                "skipping migration of legacy state file, as task state already exists"
+            );
+        } else {
+            let (driver_checkpoint, checkpoint) = parse_legacy_state(&legacy)
+                .with_context(|| format!("failed to parse legacy state {legacy_path:?}"))?;
+
+            tracing::info!(
+                ?legacy_path,
+                state = ?driver_checkpoint.as_ref().map(|s| s.get()),
+                ?checkpoint,
+                "migrating legacy state file into RocksDB"
+            );
+            wb.put(Self::CHECKPOINT_KEY, checkpoint.encode_to_vec());
+
+            // A `null` driverCheckpoint is parsed as None, and isn't migrated.
+            if let Some(driver_checkpoint) = driver_checkpoint {
+                wb.put(Self::CONNECTOR_STATE_KEY, driver_checkpoint.get());
+            }
+        }
+
+        self.db
+            .write(wb)
+            .context("failed to write migrated task state")?;
+
+        Ok(())
     }
 
     /// Load a persisted runtime Checkpoint.
@@ -122,10 +191,70 @@ impl RocksDB {
         }
     }
 
+    /// Replace the persisted runtime Checkpoint and connector state.
+    /// A None `connector_state` removes any persisted connector state.
+    pub fn write_state(
+        &self,
+        checkpoint: &consumer::Checkpoint,
+        connector_state: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let mut wb = rocksdb::WriteBatch::default();
+
+        wb.put(Self::CHECKPOINT_KEY, checkpoint.encode_to_vec());
+        match connector_state {
+            Some(state) => wb.put(Self::CONNECTOR_STATE_KEY, state),
+            None => wb.delete(Self::CONNECTOR_STATE_KEY),
+        }
+        self.db
+            .write(wb)
+            .context("failed to write task state to RocksDB")?;
+
+        Ok(())
+    }
+
     // Key encoding under which a marshalled checkpoint is stored.
     pub const CHECKPOINT_KEY: &str = "checkpoint";
     // Key encoding under which a connector state is stored.
     pub const CONNECTOR_STATE_KEY: &str = "connector-state";
+    // File name of a legacy JSON state, which is migrated into RocksDB.
+    pub const LEGACY_STATE_FILE: &str = "state.json";
+    // Key under which the content of a migrated legacy state file is stored,
+    // marking that its migration has completed.
+    pub const LEGACY_STATE_MIGRATED_KEY: &str = "legacy-state-migrated";
+}
+
+// Parse a legacy JSONFileStore state file, which is a stream of three JSON
+// documents: journal offsets (which are unused), the store's state having a
+// `driverCheckpoint`, and the consumer Checkpoint.
+fn parse_legacy_state(
+    legacy: &[u8],
+) -> anyhow::Result<(
+    Option<Box<serde_json::value::RawValue>>,
+    consumer::Checkpoint,
+)> {
+    #[derive(serde::Deserialize)]
+    struct StoreState {
+        // Go's encoding/json matches field names case-insensitively.
+        #[serde(rename = "driverCheckpoint", alias = "DriverCheckpoint", default)]
+        driver_checkpoint: Option<Box<serde_json::value::RawValue>>,
+    }
+    let mut docs = serde_json::Deserializer::from_slice(legacy)
+        .into_iter::<Box<serde_json::value::RawValue>>();
+
+    let _offsets = docs.next().transpose().context("failed to parse offsets")?;
+
+    let StoreState { driver_checkpoint } = match docs.next().transpose()? {
+        Some(state) => serde_json::from_str(state.get()).context("failed to parse state")?,
+        None => anyhow::bail!("missing state document"),
+    };
+    let checkpoint = match docs.next().transpose()? {
+        Some(checkpoint) => {
+            serde_json::from_str(checkpoint.get()).context("failed to parse checkpoint")?
+        }
+        None => anyhow::bail!("missing checkpoint document"),
+    };
+
+    Ok((driver_checkpoint, checkpoint))
 }
 
 // RocksDB merge operator schema which uses `state_schema` for keys matching "connector-state".
@@ -270,4 +399,99 @@ mod test {
 
         assert_eq!(output, r#"{"a":"c","ans":42,"d":"e","n":null}"#);
     }
+
+    #[test]
+    fn legacy_state_migration() {
+        let dir = tempfile::tempdir().unwrap();
+        let desc = || {
+            Some(RocksDbDescriptor {
+                rocksdb_path: dir.path().to_str().unwrap().to_string(),
+                rocksdb_env_memptr: 0,
+            })
+        };
+        let legacy_path = dir.path().join(RocksDB::LEGACY_STATE_FILE);
+
+        // Offsets, followed by state, followed by checkpoint.
+        // See go/runtime/connector_store_test.go.
+        std::fs::write(
+            &legacy_path,
+            concat!(
+                "{}\n",
+                r#"{"driverCheckpoint":{"cursor":"abc"}}"#,
+                "\n",
+                r#"{"sources":{"a/journal;suffix":{"read_through":1234,"producers":[{"id":"AQIDBAUG","state":{"last_ack":5678,"begin":-1}}]}}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let db = RocksDB::open(desc()).unwrap();
+        assert_eq!(
+            db.load_connector_state().unwrap().as_deref(),
+            Some(r#"{"cursor":"abc"}"#)
+        );
+        insta::assert_json_snapshot!(db.load_checkpoint().unwrap(), @r###"
+        {
+          "sources": {
+            "a/journal;suffix": {
+              "readThrough": "1234",
+              "producers": [
+                {
+                  "id": "AQIDBAUG",
+                  "state": {
+                    "lastAck": "5678",
+                    "begin": "-1"
+                  }
+                }
+              ]
+            }
+          }
+        }
+        "###);
+        std::mem::drop(db);
+
+        // A subsequent open doesn't re-import the legacy state,
+        // even after the task state has been removed.
+        std::fs::write(
+            &legacy_path,
+            "{}\n{\"driverCheckpoint\":{\"cursor\":\"def\"}}\n{}\n",
+        )
+        .unwrap();
+
+        let db = RocksDB::open(desc()).unwrap();
+        db.write_state(&Default::default(), None).unwrap();
+        assert_eq!(db.load_connector_state().unwrap(), None);
+        std::mem::drop(db);
+
+        let db = RocksDB::open(desc()).unwrap();
+        assert_eq!(db.load_connector_state().unwrap(), None);
+        std::mem::drop(db);
+
+        // A `null` legacy state is not migrated, and neither is a legacy
+        // file opened without migration.
+        let dir = tempfile::tempdir().unwrap();
+        let desc = Some(RocksDbDescriptor {
+            rocksdb_path: dir.path().to_str().unwrap().to_string(),
+            rocksdb_env_memptr: 0,
+        });
+        std::fs::write(
+            dir.path().join(RocksDB::LEGACY_STATE_FILE),
+            "{}\n{\"driverCheckpoint\":null}\n{}\n",
+        )
+        .unwrap();
+
+        let db = RocksDB::open_without_migration(desc.clone()).unwrap();
+        assert!(db
+            .get(RocksDB::LEGACY_STATE_MIGRATED_KEY)
+            .unwrap()
+            .is_none());
+        std::mem::drop(db);
+
+        let db = RocksDB::open(desc).unwrap();
+        assert_eq!(db.load_connector_state().unwrap(), None);
+        assert!(db
+            .get(RocksDB::LEGACY_STATE_MIGRATED_KEY)
+            .unwrap()
+            .is_some());
+    }
 }
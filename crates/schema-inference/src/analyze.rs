@@ -1,13 +1,43 @@
+use doc::shape::limits::{enforce_shape_complexity_limit, DEFAULT_SCHEMA_COMPLEXITY_LIMIT};
 use doc::shape::schema::to_schema;
+use doc::shape::Shape;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 use schemars::schema::RootSchema;
 use serde_json::Value as JsonValue;
-use std::io::BufRead;
+use std::io::{self, BufRead};
 
 use crate::inference::infer_shape;
 use crate::shape;
 
 type StreamResult = serde_json::Result<JsonValue>;
 
+/// Options which control the documents sampled by an analysis,
+/// and the complexity of its inferred schema.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Maximum number of documents to read. Reading stops once it's reached.
+    pub max_docs: Option<usize>,
+    /// If set, the schema is inferred from a uniform random sample
+    /// of at most this many documents, rather than from every document.
+    pub sample_size: Option<usize>,
+    /// Seed of the random sample, which makes samples reproducible.
+    pub seed: u64,
+    /// Maximum number of locations of the inferred schema.
+    /// More complex schemas are simplified until they're within the limit.
+    pub complexity_limit: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            max_docs: None,
+            sample_size: None,
+            seed: 0,
+            complexity_limit: DEFAULT_SCHEMA_COMPLEXITY_LIMIT,
+        }
+    }
+}
+
 pub fn infer_schema<R: BufRead + 'static>(reader: R) -> Result<RootSchema, anyhow::Error> {
     let stream = serde_json::de::Deserializer::from_reader(reader).into_iter();
     let documents = stream;
@@ -16,25 +46,160 @@ pub fn infer_schema<R: BufRead + 'static>(reader: R) -> Result<RootSchema, anyho
     Ok(schema)
 }
 
+/// Infer a schema from documents of `input`, which may be in any format
+/// and compression supported by the `parser` crate, as resolved by `config`.
+/// Documents are streamed from the parser, and only sampled documents are held in memory.
+pub fn infer_schema_from_input(
+    config: &parser::ParseConfig,
+    input: parser::Input,
+    options: &Options,
+) -> anyhow::Result<RootSchema> {
+    let mut sink = Sink {
+        sampler: Sampler::new(options),
+        line: Vec::new(),
+        done: false,
+    };
+
+    match parser::parse(config, input, &mut sink) {
+        Ok(()) => sink.flush_line()?,
+        // The sink fails its write to stop the parser once `max_docs` are read.
+        Err(_) if sink.done => (),
+        Err(err) => return Err(err.into()),
+    }
+
+    sink.sampler.finish()
+}
+
 fn analyze<S>(values: S) -> anyhow::Result<RootSchema>
 where
     S: Iterator<Item = StreamResult>,
 {
-    let mut acc = None;
+    analyze_with(values, &Options::default())
+}
+
+fn analyze_with<S>(values: S, options: &Options) -> anyhow::Result<RootSchema>
+where
+    S: Iterator<Item = StreamResult>,
+{
+    let mut sampler = Sampler::new(options);
 
     for result in values {
-        let shape = infer_shape(&result?);
-        if let Some(acc_shape) = acc {
-            acc = Some(shape::merge(acc_shape, shape));
-        } else {
-            acc = Some(shape);
+        if !sampler.add(result?) {
+            break;
         }
     }
+    sampler.finish()
+}
+
+// Sampler accumulates the inferred shape of documents. If a sample size is set,
+// it maintains a uniform random sample of documents using reservoir sampling,
+// and their shapes are accumulated only once all documents have been seen.
+struct Sampler<'o> {
+    options: &'o Options,
+    rng: SmallRng,
+    // Number of documents seen so far.
+    seen: usize,
+    reservoir: Vec<JsonValue>,
+    acc: Option<Shape>,
+}
+
+impl<'o> Sampler<'o> {
+    fn new(options: &'o Options) -> Self {
+        Self {
+            options,
+            rng: SmallRng::seed_from_u64(options.seed),
+            seen: 0,
+            reservoir: Vec::new(),
+            acc: None,
+        }
+    }
+
+    // Add a document, returning false if no further documents should be added.
+    fn add(&mut self, doc: JsonValue) -> bool {
+        match self.options.sample_size {
+            Some(size) if self.reservoir.len() < size => self.reservoir.push(doc),
+            Some(size) => {
+                let index = self.rng.gen_range(0..=self.seen);
+                if index < size {
+                    self.reservoir[index] = doc;
+                }
+            }
+            None => self.merge(&doc),
+        }
+        self.seen += 1;
+
+        !matches!(self.options.max_docs, Some(max) if self.seen >= max)
+    }
+
+    fn merge(&mut self, doc: &JsonValue) {
+        let shape = infer_shape(doc);
+
+        self.acc = Some(match self.acc.take() {
+            Some(acc) => shape::merge(acc, shape),
+            None => shape,
+        });
+    }
+
+    fn finish(mut self) -> anyhow::Result<RootSchema> {
+        for doc in std::mem::take(&mut self.reservoir) {
+            self.merge(&doc);
+        }
+        tracing::debug!(docs = self.seen, "finished reading documents");
+
+        let Some(mut shape) = self.acc else {
+            return Err(anyhow::anyhow!("no documents found"));
+        };
+        enforce_shape_complexity_limit(&mut shape, self.options.complexity_limit);
 
-    if let Some(shape) = acc {
         Ok(to_schema(shape))
-    } else {
-        Err(anyhow::anyhow!("no documents found"))
+    }
+}
+
+// Sink is an io::Write of newline-delimited JSON documents produced by the
+// parser, which adds each document to its Sampler as it's written.
+struct Sink<'o> {
+    sampler: Sampler<'o>,
+    // Partial line which hasn't yet been terminated by a newline.
+    line: Vec<u8>,
+    // Has the Sampler stopped accepting documents?
+    done: bool,
+}
+
+impl Sink<'_> {
+    fn flush_line(&mut self) -> io::Result<()> {
+        let line = std::mem::take(&mut self.line);
+        if self.done || line.iter().all(u8::is_ascii_whitespace) {
+            return Ok(());
+        }
+        let doc = serde_json::from_slice(&line)?;
+
+        if !self.sampler.add(doc) {
+            self.done = true;
+        }
+        Ok(())
+    }
+}
+
+impl io::Write for Sink<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for chunk in buf.split_inclusive(|b| *b == b'\n') {
+            self.line.extend_from_slice(chunk);
+
+            if chunk.ends_with(b"\n") {
+                self.flush_line()?;
+            }
+            if self.done {
+                return Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "reached the maximum number of documents",
+                ));
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
     }
 }
 
@@ -280,4 +445,91 @@ mod test {
         let schema = analyze(to_stream(vec![data_one, data_two])).unwrap();
         insta::assert_json_snapshot!(schema);
     }
+
+    fn numbered(count: usize) -> Vec<JsonValue> {
+        (0..count).map(|n| json!({ format!("p{n}"): n })).collect()
+    }
+
+    fn property_names(schema: &RootSchema) -> Vec<String> {
+        let object = schema.schema.object.as_ref().unwrap();
+        object.properties.keys().cloned().collect()
+    }
+
+    #[test]
+    fn max_docs_stops_reading() {
+        let options = Options {
+            max_docs: Some(3),
+            ..Options::default()
+        };
+        let mut documents = to_stream(numbered(5)).collect::<Vec<_>>();
+        // Documents after `max_docs` are never read.
+        documents.push(Err(serde_json::from_str::<JsonValue>("{").unwrap_err()));
+
+        let schema = analyze_with(documents.into_iter(), &options).unwrap();
+        assert_eq!(property_names(&schema), vec!["p0", "p1", "p2"]);
+    }
+
+    #[test]
+    fn reservoir_sampling() {
+        let options = Options {
+            sample_size: Some(4),
+            seed: 42,
+            ..Options::default()
+        };
+        let schema = analyze_with(to_stream(numbered(100)), &options).unwrap();
+        let names = property_names(&schema);
+        assert_eq!(names.len(), 4);
+
+        // The sample is uniform over all documents, and not merely the first ones.
+        assert!(names.iter().any(|n| n[1..].parse::<usize>().unwrap() >= 4));
+
+        // Samples are reproducible given a seed.
+        let again = analyze_with(to_stream(numbered(100)), &options).unwrap();
+        assert_eq!(property_names(&again), names);
+
+        // A sample larger than the documents includes all of them.
+        let options = Options {
+            sample_size: Some(200),
+            ..Options::default()
+        };
+        let schema = analyze_with(to_stream(numbered(100)), &options).unwrap();
+        assert_eq!(property_names(&schema).len(), 100);
+    }
+
+    #[test]
+    fn complexity_limit_is_enforced() {
+        let options = Options {
+            complexity_limit: 10,
+            ..Options::default()
+        };
+        let schema = analyze_with(to_stream(numbered(20)), &options).unwrap();
+        assert_eq!(property_names(&schema).len(), 10);
+    }
+
+    #[test]
+    fn sink_reads_documents_split_across_writes() {
+        use std::io::Write;
+
+        let options = Options {
+            max_docs: Some(3),
+            ..Options::default()
+        };
+        let mut sink = Sink {
+            sampler: Sampler::new(&options),
+            line: Vec::new(),
+            done: false,
+        };
+
+        sink.write_all(b"{\"a\": 1}\n{\"b\"").unwrap();
+        sink.write_all(b": true}\n\n").unwrap();
+        assert!(!sink.done);
+
+        // Writes fail once `max_docs` are read, which stops the parser.
+        let err = sink.write_all(b"{\"c\": \"x\"}\n{\"d\": null}\n");
+        assert!(err.is_err());
+        assert!(sink.done);
+
+        let schema = sink.sampler.finish().unwrap();
+        assert_eq!(property_names(&schema), vec!["a", "b", "c"]);
+    }
 }
@@ -21,17 +21,8 @@ pub fn infer_shape(value: &JsonValue) -> Shape {
 }
 
 fn infer_string_shape(value: &str) -> StringShape {
-    let format = match value {
-        _ if Format::Integer.validate(value).is_ok() => Some(Format::Integer),
-        _ if Format::Number.validate(value).is_ok() => Some(Format::Number),
-        _ if Format::DateTime.validate(value).is_ok() => Some(Format::DateTime),
-        _ if Format::Date.validate(value).is_ok() => Some(Format::Date),
-        _ if Format::Uuid.validate(value).is_ok() => Some(Format::Uuid),
-        _ => None,
-    };
-
     StringShape {
-        format,
+        format: Format::detect(value),
         ..StringShape::new()
     }
 }
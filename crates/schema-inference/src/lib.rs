@@ -3,3 +3,4 @@ pub mod inference;
 pub mod json_decoder;
 pub mod server;
 pub mod shape;
+pub mod stats;
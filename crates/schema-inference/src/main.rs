@@ -1,6 +1,7 @@
+use doc::shape::limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT;
+use parser::{Format, Input, ParseConfig};
 use std::fmt::Display;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use tracing_subscriber::filter::{EnvFilter, LevelFilter};
 
@@ -49,16 +50,54 @@ pub struct AnalyzeArgs {
     /// Path to a file with the data to parse. If no file is given, reads
     /// from stdin.
     file: Option<PathBuf>,
+    /// Path to a parser configuration file. Run `flow-parser spec` to see its JSON schema.
+    /// If the configuration doesn't specify a format, it's inferred from the file name,
+    /// and newline-delimited JSON is assumed for stdin.
+    #[clap(long)]
+    config_file: Option<PathBuf>,
+    /// Maximum number of documents to read from the input.
+    #[clap(long)]
+    max_docs: Option<usize>,
+    /// Infer the schema from a uniform random sample of at most this many documents.
+    #[clap(long)]
+    sample_size: Option<usize>,
+    /// Seed of the random sample of documents.
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+    /// Maximum number of locations of the inferred schema, which is simplified
+    /// until it's within this limit.
+    #[clap(long, default_value_t = DEFAULT_SCHEMA_COMPLEXITY_LIMIT)]
+    complexity_limit: usize,
 }
 
 impl AnalyzeArgs {
     fn run(&self) -> Result<(), anyhow::Error> {
-        let input: Box<dyn BufRead> = match &self.file {
-            Some(path) => Box::new(BufReader::new(File::open(path)?)),
-            None => Box::new(std::io::stdin().lock()),
+        let mut config = match &self.config_file {
+            Some(path) => ParseConfig::load(path)?,
+            None => ParseConfig::default(),
+        };
+        let input = match &self.file {
+            Some(path) => {
+                if config.filename.is_none() {
+                    config.filename = Some(path.display().to_string());
+                }
+                Input::File(File::open(path)?)
+            }
+            None => {
+                if config.format.is_auto() && config.content_type.is_none() {
+                    config = config.with_format(Format::Json);
+                }
+                Input::Stream(Box::new(std::io::stdin()))
+            }
+        };
+        let options = analyze::Options {
+            max_docs: self.max_docs,
+            sample_size: self.sample_size,
+            seed: self.seed,
+            complexity_limit: self.complexity_limit,
         };
 
-        let schema = analyze::infer_schema(input)?;
+        let schema = analyze::infer_schema_from_input(&config, input, &options)?;
 
         serde_json::to_writer_pretty(std::io::stdout().lock(), &schema)?;
 
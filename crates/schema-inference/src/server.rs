@@ -1,6 +1,7 @@
 use crate::inference::infer_shape;
 use crate::json_decoder::{JsonCodec, JsonCodecError};
 use crate::shape;
+use crate::stats::{Report, Stats};
 use anyhow::Context;
 use assemble::journal_selector;
 use bytesize::ByteSize;
@@ -92,6 +93,8 @@ struct ShapeAndMeta {
     docs: u64,
     /// The number of bytes that composed the documents from which this shape was inferred
     bytes: u64,
+    /// Statistics of the documents from which this shape was inferred
+    stats: Stats,
 }
 
 async fn healthz(broker_url: String) -> Response {
@@ -106,6 +109,9 @@ pub struct InferenceResponse {
     pub schema: RootSchema,
     pub documents_read: u64,
     pub exceeded_deadline: bool,
+    /// Statistics of document locations, with suggested keys and enums.
+    #[serde(default)]
+    pub statistics: Report,
 }
 
 impl InferenceResponse {
@@ -150,7 +156,12 @@ async fn infer_schema(
         .buffer_unordered(3);
 
     let root_schema = match reduce_shape_stream(buffered).await? {
-        Some(ShapeAndMeta { shape, docs, bytes }) => {
+        Some(ShapeAndMeta {
+            shape,
+            docs,
+            bytes,
+            stats,
+        }) => {
             let end_time = Instant::now().duration_since(start_instant);
             tracing::info!(
                 collection=collection_name,
@@ -164,6 +175,7 @@ async fn infer_schema(
                 schema: to_schema(shape),
                 documents_read: docs,
                 exceeded_deadline: *abort_rx.borrow(),
+                statistics: stats.report(),
             })
         }
         None => Err(InferenceError::NoDocsFound),
@@ -239,6 +251,7 @@ async fn fragment_to_shape(
 
     let mut accumulator: Option<Shape> = None;
     let mut docs: u64 = 0;
+    let mut stats = Stats::default();
 
     let mut duration_total = Duration::default();
     let mut duration_count: u64 = 0;
@@ -254,6 +267,7 @@ async fn fragment_to_shape(
                         let start = Instant::now();
                         if parsed.pointer("/_meta/ack").is_none() {
                             let inferred_shape = infer_shape(&parsed);
+                            stats.add(&parsed);
 
                             if let Some(accumulated_shape) = accumulator {
                                 accumulator = Some(shape::merge(accumulated_shape, inferred_shape))
@@ -295,6 +309,7 @@ async fn fragment_to_shape(
             shape: accum,
             docs,
             bytes: bytes_read,
+            stats,
         })),
         None => Ok(None),
     }
@@ -310,13 +325,15 @@ async fn reduce_shape_stream(
                     Some(ref mut accumulator) => {
                         accumulator.shape = shape::merge(accumulator.shape.clone(), item.shape);
                         accumulator.docs += item.docs;
-                        accumulator.bytes += item.bytes
+                        accumulator.bytes += item.bytes;
+                        accumulator.stats.merge(item.stats);
                     }
                     None => {
                         maybe_accum = Some(ShapeAndMeta {
                             shape: item.shape,
                             docs: item.docs,
                             bytes: item.bytes,
+                            stats: item.stats,
                         })
                     }
                 }
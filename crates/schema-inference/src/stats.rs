@@ -0,0 +1,432 @@
+use doc::shape::limits::DEFAULT_SCHEMA_COMPLEXITY_LIMIT;
+use json::schema::types;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
+
+/// Number of smallest value hashes retained by each distinct-count sketch.
+/// Counts are exact below this size, and are estimated with a standard
+/// error of about 3% above it.
+const SKETCH_SIZE: usize = 1024;
+/// Maximum number of distinct values of an enum candidate.
+const MAX_ENUM_VALUES: usize = 16;
+/// Minimum number of times that the values of an enum candidate must be
+/// observed, on average, for the location to be suggested as an enum.
+const MIN_ENUM_SUPPORT: u64 = 4;
+/// Number of example values retained for each location.
+const MAX_EXAMPLES: usize = 3;
+/// Maximum number of locations which are tracked. Further locations, such as
+/// the properties of objects keyed on dynamic values, are not tracked.
+const MAX_LOCATIONS: usize = DEFAULT_SCHEMA_COMPLEXITY_LIMIT;
+
+/// Stats accumulates statistics of the locations of documents.
+/// Stats of disjoint sets of documents may be merged.
+#[derive(Debug, Default, Clone)]
+pub struct Stats {
+    docs: u64,
+    locations: BTreeMap<String, LocationStats>,
+    // Were locations not tracked because MAX_LOCATIONS was reached?
+    truncated: bool,
+}
+
+#[derive(Debug, Clone)]
+struct LocationStats {
+    // Is this location within an array?
+    in_array: bool,
+    types: types::Set,
+    exists: u64,
+    nulls: u64,
+    // The smallest SKETCH_SIZE hashes of distinct scalar values (a KMV sketch).
+    hashes: BTreeSet<u64>,
+    min: Option<JsonValue>,
+    max: Option<JsonValue>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    // Distinct scalar values, or None if there are more than MAX_ENUM_VALUES.
+    values: Option<BTreeSet<String>>,
+    examples: Vec<JsonValue>,
+}
+
+/// Report of statistics gathered during inference,
+/// and the keys and enums which they suggest.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    /// Statistics of each document location, keyed on JSON pointer.
+    /// Array items are represented by the `*` token.
+    pub locations: BTreeMap<String, Location>,
+    /// Whether further locations were observed but not tracked,
+    /// because the number of tracked locations reached its limit.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub locations_truncated: bool,
+    /// Locations which are scalars that always exist and, in the documents
+    /// which were read, are unique. These are candidates for the collection key.
+    /// Uniqueness can only be established while distinct counts are exact,
+    /// so locations having many distinct values are never suggested.
+    pub key_candidates: Vec<String>,
+    /// Locations having a small number of distinct string values,
+    /// which are candidates for an `enum` of those values.
+    pub enum_candidates: BTreeMap<String, Vec<JsonValue>>,
+}
+
+/// Statistics of a document location.
+/// Locations within arrays are counted once for each array item.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    /// Types which the location was observed to take.
+    pub types: Vec<String>,
+    /// Number of times the location existed.
+    pub exists: u64,
+    /// Fraction of times the location existed with a null value.
+    pub null_rate: f64,
+    /// Number of distinct scalar values of the location, which is estimated
+    /// if there are many distinct values.
+    pub distinct: u64,
+    /// Minimum scalar value of the location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<JsonValue>,
+    /// Maximum scalar value of the location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<JsonValue>,
+    /// Minimum length of string values, in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+    /// Maximum length of string values, in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+    /// Example values of the location.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<JsonValue>,
+}
+
+impl Stats {
+    /// Add the locations of a document to these Stats.
+    pub fn add(&mut self, doc: &JsonValue) {
+        self.docs += 1;
+        self.add_location(&mut String::new(), false, doc);
+    }
+
+    /// Merge `other` Stats of a disjoint set of documents into these Stats.
+    pub fn merge(&mut self, other: Stats) {
+        self.docs += other.docs;
+        self.truncated |= other.truncated;
+
+        for (ptr, rhs) in other.locations {
+            if let Some(lhs) = self.locations.get_mut(&ptr) {
+                lhs.merge(rhs);
+            } else if self.locations.len() < MAX_LOCATIONS {
+                self.locations.insert(ptr, rhs);
+            } else {
+                self.truncated = true;
+            }
+        }
+    }
+
+    /// Number of documents which were added to these Stats.
+    pub fn docs(&self) -> u64 {
+        self.docs
+    }
+
+    /// Build a Report of these Stats.
+    pub fn report(&self) -> Report {
+        let mut report = Report {
+            locations_truncated: self.truncated,
+            ..Default::default()
+        };
+
+        for (ptr, stats) in &self.locations {
+            let location = stats.location();
+
+            if stats.is_key_candidate(self.docs) {
+                report.key_candidates.push(ptr.clone());
+            }
+            if let Some(values) = stats.enum_candidate() {
+                report.enum_candidates.insert(ptr.clone(), values);
+            }
+            report.locations.insert(ptr.clone(), location);
+        }
+        // Prefer shallower key candidates, which are more likely to be natural keys.
+        report
+            .key_candidates
+            .sort_by_key(|ptr| ptr.matches('/').count());
+
+        report
+    }
+
+    fn add_location(&mut self, ptr: &mut String, in_array: bool, value: &JsonValue) {
+        if let Some(stats) = self.locations.get_mut(ptr.as_str()) {
+            stats.add(value);
+        } else if self.locations.len() < MAX_LOCATIONS {
+            let mut stats = LocationStats::new(in_array);
+            stats.add(value);
+            self.locations.insert(ptr.clone(), stats);
+        } else {
+            // Locations nested within an untracked location are also untracked.
+            self.truncated = true;
+            return;
+        }
+
+        let len = ptr.len();
+        match value {
+            JsonValue::Object(fields) => {
+                for (property, value) in fields {
+                    ptr.push('/');
+                    ptr.push_str(&property.replace('~', "~0").replace('/', "~1"));
+                    self.add_location(ptr, in_array, value);
+                    ptr.truncate(len);
+                }
+            }
+            JsonValue::Array(items) => {
+                ptr.push_str("/*");
+                for item in items {
+                    self.add_location(ptr, true, item);
+                }
+                ptr.truncate(len);
+            }
+            _ => (),
+        }
+    }
+}
+
+impl LocationStats {
+    fn new(in_array: bool) -> Self {
+        Self {
+            in_array,
+            types: types::INVALID,
+            exists: 0,
+            nulls: 0,
+            hashes: BTreeSet::new(),
+            min: None,
+            max: None,
+            min_length: None,
+            max_length: None,
+            values: Some(BTreeSet::new()),
+            examples: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, value: &JsonValue) {
+        self.types = self.types | types::Set::for_value(value);
+        self.exists += 1;
+
+        match value {
+            JsonValue::Null => {
+                self.nulls += 1;
+                return;
+            }
+            JsonValue::Array(_) | JsonValue::Object(_) => return,
+            JsonValue::String(s) => {
+                let length = s.chars().count();
+                self.min_length = Some(self.min_length.map_or(length, |l| l.min(length)));
+                self.max_length = Some(self.max_length.map_or(length, |l| l.max(length)));
+            }
+            _ => (),
+        }
+
+        let encoded = value.to_string();
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        encoded.hash(&mut hasher);
+        self.add_hash(hasher.finish());
+
+        if let Some(values) = &mut self.values {
+            values.insert(encoded);
+
+            if values.len() > MAX_ENUM_VALUES {
+                self.values = None;
+            }
+        }
+        self.add_bounds(value, value);
+
+        if self.examples.len() < MAX_EXAMPLES && !self.examples.contains(value) {
+            self.examples.push(value.clone());
+        }
+    }
+
+    fn merge(&mut self, other: LocationStats) {
+        self.in_array |= other.in_array;
+        self.types = self.types | other.types;
+        self.exists += other.exists;
+        self.nulls += other.nulls;
+
+        for hash in other.hashes {
+            self.add_hash(hash);
+        }
+        if let (Some(min), Some(max)) = (&other.min, &other.max) {
+            self.add_bounds(min, max);
+        }
+        self.min_length = match (self.min_length, other.min_length) {
+            (Some(l), Some(r)) => Some(l.min(r)),
+            (l, r) => l.or(r),
+        };
+        self.max_length = match (self.max_length, other.max_length) {
+            (Some(l), Some(r)) => Some(l.max(r)),
+            (l, r) => l.or(r),
+        };
+        self.values = match (self.values.take(), other.values) {
+            (Some(mut l), Some(r)) => {
+                l.extend(r);
+                Some(l).filter(|values| values.len() <= MAX_ENUM_VALUES)
+            }
+            _ => None,
+        };
+        for example in other.examples {
+            if self.examples.len() < MAX_EXAMPLES && !self.examples.contains(&example) {
+                self.examples.push(example);
+            }
+        }
+    }
+
+    fn add_hash(&mut self, hash: u64) {
+        if self.hashes.len() < SKETCH_SIZE {
+            self.hashes.insert(hash);
+        } else if hash < *self.hashes.iter().next_back().unwrap() && self.hashes.insert(hash) {
+            let largest = *self.hashes.iter().next_back().unwrap();
+            self.hashes.remove(&largest);
+        }
+    }
+
+    fn add_bounds(&mut self, min: &JsonValue, max: &JsonValue) {
+        if !matches!(&self.min, Some(cur) if doc::compare(cur, min).is_le()) {
+            self.min = Some(min.clone());
+        }
+        if !matches!(&self.max, Some(cur) if doc::compare(cur, max).is_ge()) {
+            self.max = Some(max.clone());
+        }
+    }
+
+    // Number of distinct values, which is exact if the sketch isn't full
+    // and is otherwise estimated from its largest retained hash.
+    fn distinct(&self) -> u64 {
+        if self.hashes.len() < SKETCH_SIZE {
+            return self.hashes.len() as u64;
+        }
+        let largest = *self.hashes.iter().next_back().unwrap() as f64;
+        ((SKETCH_SIZE - 1) as f64 * (u64::MAX as f64 / largest)) as u64
+    }
+
+    fn location(&self) -> Location {
+        Location {
+            types: self.types.to_vec(),
+            exists: self.exists,
+            null_rate: self.nulls as f64 / self.exists as f64,
+            distinct: self.distinct(),
+            min: self.min.clone(),
+            max: self.max.clone(),
+            min_length: self.min_length,
+            max_length: self.max_length,
+            examples: self.examples.clone(),
+        }
+    }
+
+    fn is_key_candidate(&self, docs: u64) -> bool {
+        let is_keyable = matches!(self.types, types::STRING | types::INTEGER | types::BOOLEAN);
+        // A full sketch only estimates the number of distinct values,
+        // which cannot establish that every value is unique.
+        let is_unique = self.hashes.len() < SKETCH_SIZE && self.hashes.len() as u64 == docs;
+
+        is_keyable && !self.in_array && self.exists == docs && is_unique
+    }
+
+    fn enum_candidate(&self) -> Option<Vec<JsonValue>> {
+        let values = self.values.as_ref()?;
+
+        if self.types - types::NULL != types::STRING
+            || values.is_empty()
+            || self.exists - self.nulls < MIN_ENUM_SUPPORT * values.len() as u64
+        {
+            return None;
+        }
+        Some(
+            values
+                .iter()
+                .map(|value| serde_json::from_str(value).unwrap())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn stats_of(docs: impl IntoIterator<Item = JsonValue>) -> Stats {
+        let mut stats = Stats::default();
+        for doc in docs {
+            stats.add(&doc);
+        }
+        stats
+    }
+
+    fn fixtures() -> Vec<JsonValue> {
+        (0..12)
+            .map(|n| {
+                let status = ["new", "open", "closed"][n % 3];
+                json!({
+                    "id": format!("id-{n}"),
+                    "seq": n,
+                    "status": status,
+                    "note": if n % 4 == 0 { JsonValue::Null } else { json!(format!("note {n}")) },
+                    "tags": [format!("t{}", n % 2)],
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn report_of_locations_and_candidates() {
+        insta::assert_json_snapshot!(stats_of(fixtures()).report());
+    }
+
+    #[test]
+    fn merged_stats_match_combined_stats() {
+        let docs = fixtures();
+        let (lhs, rhs) = docs.split_at(5);
+        let mut merged = stats_of(lhs.to_vec());
+        merged.merge(stats_of(rhs.to_vec()));
+
+        assert_eq!(merged.docs(), 12);
+        assert_eq!(merged.report(), stats_of(fixtures()).report());
+    }
+
+    #[test]
+    fn distinct_counts_are_estimated() {
+        let stats = stats_of((0..20_000).map(|n| json!({"id": n, "mod": n % 100})));
+        let report = stats.report();
+
+        let id = report.locations["/id"].distinct as f64;
+        assert!((id - 20_000.0).abs() / 20_000.0 < 0.1, "{id}");
+        assert_eq!(report.locations["/mod"].distinct, 100);
+
+        // `/id` is unique, but that can't be established from its estimate.
+        assert!(report.key_candidates.is_empty());
+        assert!(report.enum_candidates.is_empty());
+
+        let report = stats_of((0..1_000).map(|n| json!({"id": n}))).report();
+        assert_eq!(report.key_candidates, vec!["/id"]);
+    }
+
+    #[test]
+    fn locations_are_limited() {
+        let doc = |offset: usize| {
+            JsonValue::Object(
+                (offset..offset + MAX_LOCATIONS)
+                    .map(|n| (format!("p{n}"), json!({ "nested": n })))
+                    .collect(),
+            )
+        };
+        let mut stats = stats_of([doc(0)]);
+        assert_eq!(stats.locations.len(), MAX_LOCATIONS);
+        assert!(stats.report().locations_truncated);
+
+        // Previously tracked locations continue to be updated.
+        stats.merge(stats_of([doc(MAX_LOCATIONS)]));
+        stats.add(&json!({"p0": {"nested": 0}}));
+
+        let report = stats.report();
+        assert_eq!(report.locations.len(), MAX_LOCATIONS);
+        assert_eq!(report.locations[""].exists, 3);
+        assert_eq!(report.locations["/p0"].exists, 2);
+        assert!(report.locations_truncated);
+    }
+}
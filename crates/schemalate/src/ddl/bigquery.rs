@@ -0,0 +1,55 @@
+use super::{quote_table, quote_with, Column, ColumnType};
+
+pub fn create_table(table: &str, columns: &[Column]) -> String {
+    let lines = columns.iter().map(column).collect::<Vec<_>>();
+
+    // BigQuery doesn't have primary keys. Instead cluster by up to four
+    // key columns, which must have a clustering-eligible type.
+    let keys = columns
+        .iter()
+        .filter(|c| c.is_key && !matches!(c.type_, ColumnType::Number | ColumnType::Json))
+        .take(4)
+        .map(|c| quote(&c.field))
+        .collect::<Vec<_>>();
+
+    let mut out = format!(
+        "CREATE TABLE IF NOT EXISTS {} (\n  {}\n)",
+        quote_table(table, quote),
+        lines.join(",\n  ")
+    );
+    if !keys.is_empty() {
+        out.push_str(&format!("\nCLUSTER BY {}", keys.join(", ")));
+    }
+    out.push_str(";\n");
+    out
+}
+
+pub fn column(column: &Column) -> String {
+    format!(
+        "{} {}{}",
+        quote(&column.field),
+        type_name(column.type_),
+        if column.nullable { "" } else { " NOT NULL" }
+    )
+}
+
+fn type_name(type_: ColumnType) -> &'static str {
+    match type_ {
+        ColumnType::Boolean => "BOOL",
+        ColumnType::Integer => "INT64",
+        ColumnType::Number => "FLOAT64",
+        ColumnType::String => "STRING",
+        ColumnType::Date => "DATE",
+        ColumnType::DateTime => "TIMESTAMP",
+        // TIME doesn't represent a time zone offset.
+        ColumnType::Time => "STRING",
+        ColumnType::Uuid => "STRING",
+        ColumnType::IntegerString => "BIGNUMERIC(38, 0)",
+        ColumnType::NumberString => "FLOAT64",
+        ColumnType::Json => "JSON",
+    }
+}
+
+pub fn quote(ident: &str) -> String {
+    quote_with(ident, '`')
+}
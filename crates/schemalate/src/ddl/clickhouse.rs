@@ -0,0 +1,50 @@
+use super::{quote_table, quote_with, Column, ColumnType};
+
+pub fn create_table(table: &str, columns: &[Column]) -> String {
+    let lines = columns.iter().map(column).collect::<Vec<_>>();
+
+    // Rows are de-duplicated by key, as they're merged in the background.
+    let keys = columns
+        .iter()
+        .filter(|c| c.is_key)
+        .map(|c| quote(&c.field))
+        .collect::<Vec<_>>();
+
+    format!(
+        "CREATE TABLE IF NOT EXISTS {} (\n  {}\n)\nENGINE = ReplacingMergeTree\nORDER BY ({});\n",
+        quote_table(table, quote),
+        lines.join(",\n  "),
+        keys.join(", ")
+    )
+}
+
+// ClickHouse columns are not nullable unless wrapped in Nullable().
+pub fn column(column: &Column) -> String {
+    let type_ = type_name(column.type_);
+
+    if column.nullable {
+        format!("{} Nullable({type_})", quote(&column.field))
+    } else {
+        format!("{} {type_}", quote(&column.field))
+    }
+}
+
+fn type_name(type_: ColumnType) -> &'static str {
+    match type_ {
+        ColumnType::Boolean => "Bool",
+        ColumnType::Integer => "Int64",
+        ColumnType::Number => "Float64",
+        ColumnType::String => "String",
+        ColumnType::Date => "Date32",
+        ColumnType::DateTime => "DateTime64(6, 'UTC')",
+        ColumnType::Time => "String",
+        ColumnType::Uuid => "UUID",
+        ColumnType::IntegerString => "Int256",
+        ColumnType::NumberString => "Float64",
+        ColumnType::Json => "String",
+    }
+}
+
+pub fn quote(ident: &str) -> String {
+    quote_with(ident, '`')
+}
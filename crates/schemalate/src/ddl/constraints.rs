@@ -0,0 +1,197 @@
+use json::schema::types;
+use proto_flow::materialize::response::validated::{constraint, Constraint};
+
+/// Projection is a dialect-agnostic view of a projected field,
+/// having only the properties which influence its constraints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Projection<'a> {
+    /// Field name of the projection.
+    pub field: &'a str,
+    /// Document location of the projection.
+    pub ptr: &'a str,
+    /// Is this projection part of the collection key?
+    pub is_primary_key: bool,
+    /// Types which the projected location may take.
+    pub types: types::Set,
+    /// Must the projected location exist?
+    pub must_exist: bool,
+}
+
+/// Constraint of a projection which isn't part of an existing materialization.
+pub fn new_projection(projection: &Projection) -> Constraint {
+    let (r#type, reason) = if projection.is_primary_key {
+        (
+            constraint::Type::LocationRequired,
+            "All locations that are part of the collection key are required.",
+        )
+    } else if projection.ptr.is_empty() {
+        (
+            constraint::Type::FieldOptional,
+            "The root document is usually not necessary in delta-update connectors.",
+        )
+    } else if !projection.types.is_single_type() {
+        (
+            constraint::Type::FieldForbidden,
+            "Cannot materialize field with multiple or no types.",
+        )
+    } else if projection.types.is_single_scalar_type() {
+        (
+            constraint::Type::LocationRecommended,
+            "Scalar values are recommended to be materialized.",
+        )
+    } else if matches!(projection.types - types::NULL, types::OBJECT | types::ARRAY) {
+        (
+            constraint::Type::FieldOptional,
+            "Object and array fields can be materialized.",
+        )
+    } else {
+        unreachable!("Binding is malformed!")
+    };
+
+    Constraint {
+        r#type: r#type.into(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Constraint of a projection of an `existing` materialization, given its
+/// `proposed` projection of the same field, or None if the field is no longer projected.
+pub fn existing_projection(existing: &Projection, proposed: Option<&Projection>) -> Constraint {
+    let Some(proposed) = proposed else {
+        return Constraint {
+            r#type: constraint::Type::Unsatisfiable.into(),
+            reason: "The proposed materialization is missing the projection, which is required because it's included in the existing materialization".to_string(),
+        };
+    };
+    let diff = proposed.types - existing.types;
+
+    if diff != types::INVALID {
+        Constraint {
+            r#type: constraint::Type::Unsatisfiable.into(),
+            reason: format!(
+                "The proposed projection may contain types {}, which are not part of the original projection.",
+                diff.to_vec().join(", ")
+            ),
+        }
+    } else if existing.must_exist && !existing.types.overlaps(types::NULL) && !proposed.must_exist {
+        Constraint {
+            r#type: constraint::Type::Unsatisfiable.into(),
+            reason: "The existing projection must exist and be non-null, so the new projection must also exist."
+                .to_string(),
+        }
+    } else {
+        Constraint {
+            r#type: constraint::Type::FieldRequired.into(),
+            reason: "This field is part of the current materialization.".to_string(),
+        }
+    }
+}
+
+/// Constraint of a proposed projection which isn't part of an existing materialization.
+pub fn excluded_projection() -> Constraint {
+    Constraint {
+        r#type: constraint::Type::FieldForbidden.into(),
+        reason: "This field is not included in the existing materialization.".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn projection(types: types::Set, must_exist: bool) -> Projection<'static> {
+        Projection {
+            field: "field",
+            ptr: "/field",
+            is_primary_key: false,
+            types,
+            must_exist,
+        }
+    }
+
+    #[test]
+    fn test_new_projection() {
+        let cases = [
+            (
+                Projection {
+                    is_primary_key: true,
+                    ..projection(types::STRING, true)
+                },
+                constraint::Type::LocationRequired,
+            ),
+            (
+                Projection {
+                    ptr: "",
+                    ..projection(types::OBJECT, true)
+                },
+                constraint::Type::FieldOptional,
+            ),
+            (
+                projection(types::STRING | types::NULL, false),
+                constraint::Type::LocationRecommended,
+            ),
+            (
+                projection(types::ARRAY, true),
+                constraint::Type::FieldOptional,
+            ),
+            (
+                projection(types::STRING | types::INTEGER, true),
+                constraint::Type::FieldForbidden,
+            ),
+        ];
+
+        for (projection, expect) in cases {
+            assert_eq!(
+                new_projection(&projection).r#type,
+                i32::from(expect),
+                "{projection:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_existing_projection() {
+        let cases = [
+            (
+                projection(types::STRING, true),
+                Some(projection(types::STRING, true)),
+                constraint::Type::FieldRequired,
+            ),
+            (
+                projection(types::STRING, true),
+                None,
+                constraint::Type::Unsatisfiable,
+            ),
+            // Types may narrow, but not widen.
+            (
+                projection(types::INT_OR_FRAC, true),
+                Some(projection(types::INTEGER, true)),
+                constraint::Type::FieldRequired,
+            ),
+            (
+                projection(types::INTEGER, true),
+                Some(projection(types::INT_OR_FRAC, true)),
+                constraint::Type::Unsatisfiable,
+            ),
+            // A required, non-null location must remain required.
+            (
+                projection(types::STRING, true),
+                Some(projection(types::STRING, false)),
+                constraint::Type::Unsatisfiable,
+            ),
+            (
+                projection(types::STRING | types::NULL, true),
+                Some(projection(types::STRING, false)),
+                constraint::Type::FieldRequired,
+            ),
+        ];
+
+        for (existing, proposed, expect) in cases {
+            assert_eq!(
+                existing_projection(&existing, proposed.as_ref()).r#type,
+                i32::from(expect),
+                "{existing:?} {proposed:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,453 @@
+use anyhow::Context;
+use doc::shape::{location::Exists, Shape};
+use doc::Pointer;
+use json::schema::{formats::Format, types};
+use proto_flow::materialize::response::validated::constraint;
+use std::io::Read;
+
+pub mod constraints;
+
+mod bigquery;
+mod clickhouse;
+mod postgres;
+mod snowflake;
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// SQL dialect of the generated DDL.
+    #[clap(long, value_enum)]
+    pub dialect: Dialect,
+    /// Name of the table, which may be qualified as `schema.table`.
+    #[clap(long)]
+    pub table: String,
+    /// JSON pointer of a component of the collection key. May be repeated.
+    #[clap(long = "key", required = true)]
+    pub keys: Vec<String>,
+    /// Column of the root document. Pass `--document ''` to omit it.
+    #[clap(long, default_value = "flow_document")]
+    pub document: String,
+    /// Path to a previous version of the JSON schema. If given, generate
+    /// ALTER TABLE statements which migrate a table of the previous schema,
+    /// rather than a CREATE TABLE statement.
+    #[clap(long)]
+    pub previous: Option<std::path::PathBuf>,
+}
+
+/// SQL dialect of generated DDL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Dialect {
+    Postgres,
+    #[clap(name = "bigquery")]
+    BigQuery,
+    Snowflake,
+    #[clap(name = "clickhouse")]
+    ClickHouse,
+}
+
+pub fn run(args: Args) -> anyhow::Result<()> {
+    let mut bundle = String::new();
+    std::io::stdin().read_to_string(&mut bundle)?;
+
+    let proposed = columns(&bundle, &args.keys, &args.document)?;
+
+    let out = if let Some(previous) = &args.previous {
+        let previous = std::fs::read_to_string(previous)
+            .with_context(|| format!("failed to read {}", previous.display()))?;
+        let previous = columns(&previous, &args.keys, &args.document)
+            .context("failed to map previous schema into columns")?;
+
+        alter_table(args.dialect, &args.table, &previous, &proposed)?
+    } else {
+        create_table(args.dialect, &args.table, &proposed)
+    };
+    print!("{out}");
+
+    Ok(())
+}
+
+/// Column is a dialect-agnostic table column of a projected field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    /// Field (and column) name of the projection.
+    pub field: String,
+    /// Document location of the projection.
+    pub ptr: String,
+    /// Is this column part of the collection key?
+    pub is_key: bool,
+    /// Types which the projected location may take.
+    pub types: types::Set,
+    /// Must the projected location exist?
+    pub must_exist: bool,
+    /// Type of the column.
+    pub type_: ColumnType,
+    /// May the column be NULL?
+    pub nullable: bool,
+}
+
+/// ColumnType is a dialect-agnostic type of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Boolean,
+    Integer,
+    Number,
+    String,
+    /// String having `format: date`.
+    Date,
+    /// String having `format: date-time`.
+    DateTime,
+    /// String having `format: time`.
+    Time,
+    /// String having `format: uuid`.
+    Uuid,
+    /// Integer which may be encoded as a string, having `format: integer`.
+    IntegerString,
+    /// Number which may be encoded as a string, having `format: number`.
+    NumberString,
+    /// Objects, arrays, and locations having multiple types are stored as JSON.
+    Json,
+}
+
+impl Column {
+    pub fn new(field: &str, ptr: &str, is_key: bool, shape: &Shape, exists: Exists) -> Self {
+        Self {
+            field: field.to_string(),
+            ptr: ptr.to_string(),
+            is_key,
+            types: shape.type_,
+            must_exist: exists.must(),
+            type_: ColumnType::from_shape(shape),
+            nullable: !exists.must() || shape.type_.overlaps(types::NULL),
+        }
+    }
+
+    /// Dialect-agnostic Projection of this Column, for evaluating its constraints.
+    pub fn projection(&self) -> constraints::Projection<'_> {
+        constraints::Projection {
+            field: &self.field,
+            ptr: &self.ptr,
+            is_primary_key: self.is_key,
+            types: self.types,
+            must_exist: self.must_exist,
+        }
+    }
+}
+
+impl ColumnType {
+    /// Map a Shape into its ColumnType.
+    pub fn from_shape(shape: &Shape) -> Self {
+        let type_ = shape.type_ - types::NULL;
+
+        match (type_, &shape.string.format) {
+            (types::BOOLEAN, _) => Self::Boolean,
+            (types::INTEGER, _) => Self::Integer,
+            (types::FRACTIONAL | types::INT_OR_FRAC, _) => Self::Number,
+
+            (types::STRING, Some(Format::Date)) => Self::Date,
+            (types::STRING, Some(Format::DateTime)) => Self::DateTime,
+            (types::STRING, Some(Format::Time)) => Self::Time,
+            (types::STRING, Some(Format::Uuid)) => Self::Uuid,
+
+            // Numeric strings may also be represented as JSON numbers.
+            (type_, Some(Format::Integer))
+                if type_ == types::STRING || type_ == types::STRING | types::INTEGER =>
+            {
+                Self::IntegerString
+            }
+            (type_, Some(Format::Number))
+                if type_.overlaps(types::STRING)
+                    && types::STRING | types::INT_OR_FRAC == type_ | types::INT_OR_FRAC =>
+            {
+                Self::NumberString
+            }
+
+            (types::STRING, _) => Self::String,
+            _ => Self::Json,
+        }
+    }
+
+    /// Can values of this ColumnType be stored in an `existing` column?
+    pub fn is_compatible(&self, existing: &Self) -> bool {
+        match (self, existing) {
+            (a, b) if a == b => true,
+            (_, Self::Json) => true,
+            (Self::Integer, Self::Number) => true,
+            (
+                Self::Date
+                | Self::DateTime
+                | Self::Time
+                | Self::Uuid
+                | Self::IntegerString
+                | Self::NumberString,
+                Self::String,
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Map the JSON schema `bundle` into table columns.
+/// Columns are the collection `keys`, followed by each top-level property
+/// of the schema, followed by the root `document` unless it's empty.
+pub fn columns(bundle: &str, keys: &[String], document: &str) -> anyhow::Result<Vec<Column>> {
+    let schema = doc::validation::build_bundle(bundle).context("failed to build JSON schema")?;
+    let validator = doc::Validator::new(schema).context("failed to index JSON schema")?;
+    let shape = Shape::infer(&validator.schemas()[0], validator.schema_index());
+
+    let mut columns = Vec::new();
+
+    for key in keys {
+        let Some(field) = key.strip_prefix('/') else {
+            anyhow::bail!("collection key {key:?} must be a JSON pointer");
+        };
+        let (key_shape, exists) = shape.locate(&Pointer::from_str(key));
+        if exists.cannot() {
+            anyhow::bail!("collection key {key} cannot exist in the schema");
+        }
+        columns.push(Column::new(field, key, true, key_shape, exists));
+    }
+
+    for (ptr, pattern, prop_shape, exists) in shape.locations() {
+        let ptr = ptr.to_string();
+
+        // Skip the root, nested and pattern locations, keys, and locations which cannot exist.
+        if pattern
+            || ptr.is_empty()
+            || ptr[1..].contains('/')
+            || keys.contains(&ptr)
+            || exists.cannot()
+        {
+            continue;
+        }
+        columns.push(Column::new(&ptr[1..], &ptr, false, prop_shape, exists));
+    }
+
+    if !document.is_empty() {
+        columns.push(Column::new(document, "", false, &shape, Exists::Must));
+    }
+
+    Ok(columns)
+}
+
+/// Generate a CREATE TABLE statement of `table` having `columns`.
+pub fn create_table(dialect: Dialect, table: &str, columns: &[Column]) -> String {
+    match dialect {
+        Dialect::Postgres => postgres::create_table(table, columns),
+        Dialect::BigQuery => bigquery::create_table(table, columns),
+        Dialect::Snowflake => snowflake::create_table(table, columns),
+        Dialect::ClickHouse => clickhouse::create_table(table, columns),
+    }
+}
+
+/// Generate ALTER TABLE statements which migrate `table` from its `previous`
+/// columns to `proposed` columns, by adding new columns. It's an error if a
+/// previous column cannot be migrated, for example because its type has changed.
+/// Added columns are always nullable, as existing rows don't have values.
+pub fn alter_table(
+    dialect: Dialect,
+    table: &str,
+    previous: &[Column],
+    proposed: &[Column],
+) -> anyhow::Result<String> {
+    let mut added = Vec::new();
+    let mut errors = Vec::new();
+
+    for existing in previous {
+        let next = proposed.iter().find(|c| c.field == existing.field);
+
+        let constraint = constraints::existing_projection(
+            &existing.projection(),
+            next.map(Column::projection).as_ref(),
+        );
+        let next = match next {
+            Some(next) if constraint.r#type != i32::from(constraint::Type::Unsatisfiable) => next,
+            _ => {
+                errors.push(format!("{}: {}", existing.field, constraint.reason));
+                continue;
+            }
+        };
+
+        if next.is_key != existing.is_key {
+            errors.push(format!(
+                "{}: the collection key has changed, and the table must be re-created.",
+                existing.field
+            ));
+        } else if !next.type_.is_compatible(&existing.type_) {
+            errors.push(format!(
+                "{}: the column type has changed from {:?} to {:?}.",
+                existing.field, existing.type_, next.type_
+            ));
+        }
+    }
+
+    for column in proposed {
+        if previous.iter().any(|c| c.field == column.field) {
+            continue;
+        } else if column.is_key {
+            errors.push(format!(
+                "{}: the collection key has changed, and the table must be re-created.",
+                column.field
+            ));
+        } else {
+            added.push(Column {
+                nullable: true,
+                ..column.clone()
+            });
+        }
+    }
+
+    if !errors.is_empty() {
+        anyhow::bail!("cannot migrate table {table}:\n{}", errors.join("\n"));
+    }
+
+    let (quote, column): (fn(&str) -> String, fn(&Column) -> String) = match dialect {
+        Dialect::Postgres => (postgres::quote, postgres::column),
+        Dialect::BigQuery => (bigquery::quote, bigquery::column),
+        Dialect::Snowflake => (snowflake::quote, snowflake::column),
+        Dialect::ClickHouse => (clickhouse::quote, clickhouse::column),
+    };
+    let table = quote_table(table, quote);
+
+    Ok(added
+        .iter()
+        .map(|c| format!("ALTER TABLE {table} ADD COLUMN {};\n", column(c)))
+        .collect())
+}
+
+// Quote each dot-separated component of a possibly-qualified `table` name.
+fn quote_table(table: &str, quote: fn(&str) -> String) -> String {
+    table.split('.').map(quote).collect::<Vec<_>>().join(".")
+}
+
+// Quote an identifier with `q`, doubling any occurrences of `q` within it.
+// Generated DDL always quotes identifiers, as field names are arbitrary and
+// may be reserved words or differ in case.
+fn quote_with(ident: &str, q: char) -> String {
+    let mut out = String::with_capacity(ident.len() + 2);
+    out.push(q);
+    for c in ident.chars() {
+        if c == q {
+            out.push(q);
+        }
+        out.push(c);
+    }
+    out.push(q);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    const KEYS: &[&str] = &["/id", "/nested/key"];
+
+    fn fixture() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "nested": {
+                    "type": "object",
+                    "properties": {"key": {"type": "string"}},
+                    "required": ["key"],
+                },
+                "flag": {"type": "boolean"},
+                "price": {"type": ["number", "null"]},
+                "count": {"type": ["string", "integer"], "format": "integer"},
+                "ratio": {"type": ["string", "number"], "format": "number"},
+                "created": {"type": "string", "format": "date-time"},
+                "day": {"type": "string", "format": "date"},
+                "at": {"type": "string", "format": "time"},
+                "uuid": {"type": "string", "format": "uuid"},
+                "name": {"type": "string", "title": "A name"},
+                "select": {"type": "string"},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "multi": {"type": ["string", "integer"]},
+            },
+            "required": ["id", "nested", "flag", "created"],
+        })
+    }
+
+    fn fixture_columns(schema: serde_json::Value) -> Vec<Column> {
+        let keys = KEYS.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+        columns(&schema.to_string(), &keys, "flow_document").unwrap()
+    }
+
+    #[test]
+    fn test_column_types() {
+        let columns = fixture_columns(fixture());
+
+        let columns = columns
+            .iter()
+            .map(|c| (c.field.as_str(), c.type_, c.nullable, c.is_key))
+            .collect::<Vec<_>>();
+
+        insta::assert_debug_snapshot!(columns);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let columns = fixture_columns(fixture());
+        let mut out = String::new();
+
+        for dialect in [
+            Dialect::Postgres,
+            Dialect::BigQuery,
+            Dialect::Snowflake,
+            Dialect::ClickHouse,
+        ] {
+            out.push_str(&format!("-- {dialect:?}\n"));
+            out.push_str(&create_table(dialect, "my_schema.my_table", &columns));
+            out.push('\n');
+        }
+        insta::assert_snapshot!(out);
+    }
+
+    #[test]
+    fn test_alter_table() {
+        let previous = fixture_columns(fixture());
+
+        // Add columns, narrow a number to an integer,
+        // and add a format to a string.
+        let mut schema = fixture();
+        schema["properties"]["added"] = json!({"type": "string"});
+        schema["properties"]["also added"] = json!({"type": "object"});
+        schema["properties"]["price"] = json!({"type": "integer"});
+        schema["properties"]["name"]["format"] = json!("uuid");
+        let proposed = fixture_columns(schema);
+
+        let mut out = String::new();
+
+        for dialect in [
+            Dialect::Postgres,
+            Dialect::BigQuery,
+            Dialect::Snowflake,
+            Dialect::ClickHouse,
+        ] {
+            out.push_str(&format!("-- {dialect:?}\n"));
+            out.push_str(&alter_table(dialect, "my_table", &previous, &proposed).unwrap());
+            out.push('\n');
+        }
+        insta::assert_snapshot!(out);
+
+        // Tables cannot be migrated if a column type changes incompatibly,
+        // a location may take new types, or a required location is now optional.
+        let mut schema = fixture();
+        schema["properties"]["created"] = json!({"type": "string"});
+        schema["properties"]["multi"] = json!({"type": ["string", "integer", "null"]});
+        schema["required"] = json!(["id", "nested", "created"]);
+        let proposed = fixture_columns(schema);
+
+        insta::assert_snapshot!(
+            alter_table(Dialect::Postgres, "my_table", &previous, &proposed)
+                .unwrap_err()
+                .to_string(),
+            @r###"
+        cannot migrate table my_table:
+        created: the column type has changed from DateTime to String.
+        flag: The existing projection must exist and be non-null, so the new projection must also exist.
+        multi: The proposed projection may contain types null, which are not part of the original projection.
+        "###
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use super::{quote_table, quote_with, Column, ColumnType};
+
+pub fn create_table(table: &str, columns: &[Column]) -> String {
+    let mut lines = columns.iter().map(column).collect::<Vec<_>>();
+
+    let keys = columns
+        .iter()
+        .filter(|c| c.is_key)
+        .map(|c| quote(&c.field))
+        .collect::<Vec<_>>();
+    if !keys.is_empty() {
+        lines.push(format!("PRIMARY KEY ({})", keys.join(", ")));
+    }
+
+    format!(
+        "CREATE TABLE IF NOT EXISTS {} (\n  {}\n);\n",
+        quote_table(table, quote),
+        lines.join(",\n  ")
+    )
+}
+
+pub fn column(column: &Column) -> String {
+    format!(
+        "{} {}{}",
+        quote(&column.field),
+        type_name(column.type_),
+        if column.nullable { "" } else { " NOT NULL" }
+    )
+}
+
+fn type_name(type_: ColumnType) -> &'static str {
+    match type_ {
+        ColumnType::Boolean => "BOOLEAN",
+        ColumnType::Integer => "BIGINT",
+        ColumnType::Number => "DOUBLE PRECISION",
+        ColumnType::String => "TEXT",
+        ColumnType::Date => "DATE",
+        ColumnType::DateTime => "TIMESTAMPTZ",
+        ColumnType::Time => "TIMETZ",
+        ColumnType::Uuid => "UUID",
+        ColumnType::IntegerString => "NUMERIC",
+        // NUMERIC also represents "NaN" and "Infinity".
+        ColumnType::NumberString => "NUMERIC",
+        ColumnType::Json => "JSON",
+    }
+}
+
+pub fn quote(ident: &str) -> String {
+    quote_with(ident, '"')
+}
@@ -3,12 +3,13 @@ use std::iter::FromIterator;
 
 use json::schema::types;
 use proto_flow::{
-    flow::{inference::Exists, materialization_spec, FieldSelection},
+    flow::{inference::Exists, materialization_spec, FieldSelection, Projection},
     materialize::request::validate::Binding as ValidateBinding,
     materialize::response::validated::constraint,
     materialize::response::validated::Constraint,
 };
 
+use crate::ddl::constraints;
 use crate::firebolt::errors::BindingConstraintError;
 
 // Can we make this a method on FieldSelection itself?
@@ -87,46 +88,7 @@ pub fn validate_new_projection(proposed: ValidateBinding) -> BTreeMap<String, Co
         .projections
         .iter()
         .map(|projection| {
-            let infer = projection.inference.as_ref().unwrap();
-            let constraint = {
-                if projection.is_primary_key {
-                    Constraint {
-                        r#type: constraint::Type::LocationRequired.into(),
-                        reason: "All locations that are part of the collection key are required."
-                            .to_string(),
-                    }
-                } else if projection.ptr.len() == 0 {
-                    // root document
-                    Constraint {
-                        r#type: constraint::Type::FieldOptional.into(),
-                        reason:
-                            "The root document is usually not necessary in delta-update connectors."
-                                .to_string(),
-                    }
-                } else {
-                    let types = types::Set::from_iter(infer.types.iter());
-                    if !types.is_single_type() {
-                        Constraint {
-                            r#type: constraint::Type::FieldForbidden.into(),
-                            reason: "Cannot materialize field with multiple or no types."
-                                .to_string(),
-                        }
-                    } else if types.is_single_scalar_type() {
-                        Constraint {
-                            r#type: constraint::Type::LocationRecommended.into(),
-                            reason: "Scalar values are recommended to be materialized.".to_string(),
-                        }
-                    } else if matches!(types - types::NULL, types::OBJECT | types::ARRAY) {
-                        Constraint {
-                            r#type: constraint::Type::FieldOptional.into(),
-                            reason: "Object and array fields can be materialized.".to_string(),
-                        }
-                    } else {
-                        unreachable!("Binding is malformed!")
-                    }
-                }
-            };
-
+            let constraint = constraints::new_projection(&to_projection(projection));
             (projection.field.clone(), constraint)
         })
         .collect()
@@ -140,75 +102,44 @@ pub fn validate_existing_projection(
     let fields = all_fields(existing.field_selection.unwrap());
     let collection = proposed.collection.unwrap();
 
-    let mut constraints: BTreeMap<String, Constraint> = {
-        fields
-            .iter()
-            .filter_map({
-                |field| {
-                    let ep = existing_projections.iter().find(|p| &p.field == field).unwrap();
-                    let pp = match collection.projections.iter().find(|p| &p.field == field) {
-                        Some(p) => p,
-                        None => return Some((field.clone(), Constraint {
-                            r#type: constraint::Type::Unsatisfiable.into(),
-                            reason: "The proposed materialization is missing the projection, which is required because it's included in the existing materialization".to_string()
-                        }))
-                    };
-
-                    let ep_infer = ep.inference.as_ref().unwrap();
-                    let pp_infer = pp.inference.as_ref().unwrap();
-
-                    let ep_type_set = types::Set::from_iter(ep_infer.types.iter());
-                    let pp_type_set = types::Set::from_iter(pp_infer.types.iter());
-                    let diff = pp_type_set - ep_type_set;
-
-                    let constraint =
-                        if diff != types::INVALID {
-                            let new_types: String = diff.to_vec().join(", ");
-                            Constraint {
-                                r#type: constraint::Type::Unsatisfiable.into(),
-                                reason: format!("The proposed projection may contain types {}, which are not part of the original projection.", new_types)
-                                    .to_string(),
-                            }
-                        } else if ep_infer.exists == i32::from(Exists::Must) &&
-                                  !ep_type_set.overlaps(types::NULL) &&
-                                  pp_infer.exists != i32::from(Exists::Must) {
-                            Constraint {
-                                r#type: constraint::Type::Unsatisfiable.into(),
-                                reason: "The existing projection must exist and be non-null, so the new projection must also exist."
-                                    .to_string(),
-                            }
-                        } else {
-                            Constraint {
-                                r#type: constraint::Type::FieldRequired.into(),
-                                reason: "This field is part of the current materialization."
-                                    .to_string(),
-                            }
-                        };
-
-                    Some((field.clone(), constraint))
-                }
-            })
-            .collect()
-    };
-
-    collection.projections.iter().for_each({
-        |projection| {
-            if !constraints.contains_key(&projection.field) {
-                constraints.insert(
-                    projection.field.clone(),
-                    Constraint {
-                        r#type: constraint::Type::FieldForbidden.into(),
-                        reason: "This field is not included in the existing materialization."
-                            .to_string(),
-                    },
-                );
-            }
+    let mut constraints: BTreeMap<String, Constraint> = fields
+        .iter()
+        .map(|field| {
+            let ep = existing_projections
+                .iter()
+                .find(|p| &p.field == field)
+                .unwrap();
+            let pp = collection.projections.iter().find(|p| &p.field == field);
+
+            let constraint = constraints::existing_projection(
+                &to_projection(ep),
+                pp.map(to_projection).as_ref(),
+            );
+            (field.clone(), constraint)
+        })
+        .collect();
+
+    for projection in &collection.projections {
+        if !constraints.contains_key(&projection.field) {
+            constraints.insert(projection.field.clone(), constraints::excluded_projection());
         }
-    });
+    }
 
     constraints
 }
 
+fn to_projection(projection: &Projection) -> constraints::Projection<'_> {
+    let infer = projection.inference.as_ref().unwrap();
+
+    constraints::Projection {
+        field: &projection.field,
+        ptr: &projection.ptr,
+        is_primary_key: projection.is_primary_key,
+        types: types::Set::from_iter(infer.types.iter()),
+        must_exist: infer.exists == i32::from(Exists::Must),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proto_flow::flow::{CollectionSpec, FieldSelection, Inference, Projection};
@@ -4,5 +4,14 @@
 /// Generates Markdown documentation of the fields in a schema.
 pub mod markdown;
 
+/// Generates SQL DDL of tables which materialize a schema.
+pub mod ddl;
+
 // Generates Firebolt schemas.
 pub mod firebolt;
+
+/// Generates types of a schema in Rust, Go, Python, or Protobuf.
+pub mod typegen;
+
+/// Translates a schema into an Avro, Protobuf, or Arrow schema.
+pub mod translate;
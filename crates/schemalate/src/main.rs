@@ -1,5 +1,6 @@
 use clap::Parser;
 use flow_cli_common::{init_logging, LogArgs};
+use schemalate::translate::Format;
 
 /// Schemalate (Schema + Translate) generates things from JSON schemas.
 ///
@@ -19,8 +20,18 @@ struct Args {
 enum Subcommand {
     /// Generates Markdown documentation of the fields in a schema.
     Markdown(schemalate::markdown::Args),
+    /// Generates SQL DDL of a table which materializes a schema.
+    Ddl(schemalate::ddl::Args),
     // Generates a Firebolt table schema
     FireboltSchema(schemalate::firebolt::Args),
+    /// Generates types of a schema in Rust, Go, Python, or Protobuf.
+    Types(schemalate::typegen::Args),
+    /// Translates a schema into an Avro record schema.
+    Avro(schemalate::translate::Args),
+    /// Translates a schema into Protobuf message definitions.
+    Proto(schemalate::translate::Args),
+    /// Translates a schema into an Arrow schema.
+    Arrow(schemalate::translate::Args),
 }
 
 fn main() -> Result<(), anyhow::Error> {
@@ -35,7 +46,12 @@ fn main() -> Result<(), anyhow::Error> {
 fn run_subcommand(subcommand: Subcommand) -> Result<(), anyhow::Error> {
     let result = match subcommand {
         Subcommand::Markdown(md_args) => schemalate::markdown::run(md_args),
+        Subcommand::Ddl(ddl_args) => schemalate::ddl::run(ddl_args),
         Subcommand::FireboltSchema(fb_args) => schemalate::firebolt::run(fb_args),
+        Subcommand::Types(types_args) => schemalate::typegen::run(types_args),
+        Subcommand::Avro(args) => schemalate::translate::run(Format::Avro, args),
+        Subcommand::Proto(args) => schemalate::translate::run(Format::Protobuf, args),
+        Subcommand::Arrow(args) => schemalate::translate::run(Format::Arrow, args),
     };
 
     if let Err(err) = result.as_ref() {
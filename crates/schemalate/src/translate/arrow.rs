@@ -0,0 +1,185 @@
+use super::{doc, is_any, push_prop, Translation};
+use doc::shape::Shape;
+use json::schema::types;
+use serde_json::{json, Value};
+
+/// Translate `shape` into an Arrow schema, as pretty-printed JSON having the
+/// representation used by Arrow integration tests. The root must be an object
+/// having properties, which are the top-level fields of the schema.
+/// Locations having multiple types are dense unions, and annotations which
+/// Arrow can't model, like enumerations and reductions, are field metadata.
+pub fn translate(ctx: &mut Translation, shape: &Shape) -> anyhow::Result<String> {
+    if shape.type_ != types::OBJECT || shape.object.properties.is_empty() {
+        anyhow::bail!("the root of an Arrow schema must be an object having properties");
+    }
+    let (_, children) = struct_(ctx, shape, "");
+
+    let mut schema = json!({ "fields": children });
+    if let Some(metadata) = metadata(ctx, shape, "") {
+        schema["metadata"] = metadata;
+    }
+
+    Ok(serde_json::to_string_pretty(&schema).unwrap() + "\n")
+}
+
+fn field(ctx: &mut Translation, name: &str, shape: &Shape, ptr: &str, nullable: bool) -> Value {
+    let (type_, children) = if is_any(shape) {
+        ctx.lossy(
+            ptr,
+            "location may be any JSON value, and is represented as a JSON-encoded string",
+        );
+        (json!({"name": "utf8"}), Vec::new())
+    } else {
+        let mut branches = Vec::new();
+
+        for (type_, name) in [
+            (types::BOOLEAN, "boolean"),
+            (types::INTEGER, "integer"),
+            (types::FRACTIONAL, "number"),
+            (types::STRING, "string"),
+            (types::ARRAY, "array"),
+            (types::OBJECT, "object"),
+        ] {
+            // Integers are represented as doubles if the location may also be fractional.
+            if type_ == types::INTEGER && shape.type_.overlaps(types::FRACTIONAL) {
+                continue;
+            }
+            if shape.type_.overlaps(type_) {
+                branches.push((name, branch(ctx, type_, shape, ptr)));
+            }
+        }
+
+        match branches.len() {
+            0 => {
+                ctx.lossy(ptr, "location cannot exist, and is represented as null");
+                (json!({"name": "null"}), Vec::new())
+            }
+            1 => branches.pop().unwrap().1,
+            len => {
+                let children = branches
+                    .into_iter()
+                    .map(|(name, (branch, children))| {
+                        json!({
+                            "name": name,
+                            "nullable": false,
+                            "type": branch,
+                            "children": children,
+                        })
+                    })
+                    .collect();
+
+                let type_ = json!({
+                    "name": "union",
+                    "mode": "DENSE",
+                    "typeIds": (0..len).collect::<Vec<_>>(),
+                });
+                (type_, children)
+            }
+        }
+    };
+
+    let mut field = json!({
+        "name": name,
+        "nullable": nullable || shape.type_.overlaps(types::NULL),
+        "type": type_,
+        "children": children,
+    });
+    if let Some(metadata) = metadata(ctx, shape, ptr) {
+        field["metadata"] = metadata;
+    }
+    field
+}
+
+// Map a single JSON type of `shape` into its Arrow type and children.
+fn branch(
+    ctx: &mut Translation,
+    type_: types::Set,
+    shape: &Shape,
+    ptr: &str,
+) -> (Value, Vec<Value>) {
+    match type_ {
+        types::BOOLEAN => (json!({"name": "bool"}), Vec::new()),
+        types::INTEGER => (
+            json!({"name": "int", "bitWidth": 64, "isSigned": true}),
+            Vec::new(),
+        ),
+        types::FRACTIONAL => (
+            json!({"name": "floatingpoint", "precision": "DOUBLE"}),
+            Vec::new(),
+        ),
+        types::STRING => (json!({"name": "utf8"}), Vec::new()),
+        types::ARRAY => {
+            let ptr = format!("{ptr}/*");
+            let item = ctx.items(&ptr, &shape.array);
+
+            (
+                json!({"name": "list"}),
+                vec![field(ctx, "item", &item, &ptr, false)],
+            )
+        }
+        types::OBJECT => {
+            let Some(value) = ctx.map_values(ptr, &shape.object) else {
+                return struct_(ctx, shape, ptr);
+            };
+            let ptr = format!("{ptr}/*");
+
+            let entries = json!({
+                "name": "entries",
+                "nullable": false,
+                "type": {"name": "struct"},
+                "children": [
+                    {
+                        "name": "key",
+                        "nullable": false,
+                        "type": {"name": "utf8"},
+                        "children": [],
+                    },
+                    field(ctx, "value", &value, &ptr, false),
+                ],
+            });
+            (json!({"name": "map", "keysSorted": false}), vec![entries])
+        }
+        _ => unreachable!("not a single type"),
+    }
+}
+
+fn struct_(ctx: &mut Translation, shape: &Shape, ptr: &str) -> (Value, Vec<Value>) {
+    let children = shape
+        .object
+        .properties
+        .iter()
+        .map(|prop| {
+            let nullable = !prop.is_required || shape.type_ != types::OBJECT;
+            field(
+                ctx,
+                &prop.name,
+                &prop.shape,
+                &push_prop(ptr, &prop.name),
+                nullable,
+            )
+        })
+        .collect();
+
+    (json!({"name": "struct"}), children)
+}
+
+// Metadata of a field, having its documentation, enumerated values,
+// string format, and reduction strategy.
+fn metadata(ctx: &mut Translation, shape: &Shape, ptr: &str) -> Option<Value> {
+    let mut metadata = Vec::new();
+
+    if let Some(doc) = doc(shape) {
+        metadata.push(json!({"key": "description", "value": doc}));
+    }
+    if let Some(values) = &shape.enum_ {
+        metadata.push(json!({"key": "enum", "value": Value::from(values.clone()).to_string()}));
+    }
+    if let Some(format) = &shape.string.format {
+        metadata.push(json!({"key": "format", "value": format}));
+    }
+    if let Some(reduce) = ctx.reduce(ptr, shape) {
+        metadata.push(json!({"key": "reduce", "value": reduce.to_string()}));
+    }
+
+    (!metadata.is_empty()).then(|| Value::Array(metadata))
+}
@@ -0,0 +1,184 @@
+use super::{doc, enum_symbols, identifier, is_any, push_prop, Translation};
+use doc::shape::Shape;
+use json::schema::{formats::Format, types};
+use serde_json::{json, Map, Value};
+
+/// Translate `shape` into an Avro schema, as pretty-printed JSON.
+/// Objects having properties are records, and the root record is named for
+/// `name` within `namespace`. Nested records are named for their location.
+/// Locations which may be null are unions with "null", and locations
+/// having multiple types are unions of each type.
+pub fn translate(ctx: &mut Translation, shape: &Shape, name: &str, namespace: &str) -> String {
+    let mut schema = schema(ctx, shape, "", name);
+
+    if let Value::Object(record) = &mut schema {
+        if record.get("type") == Some(&json!("record")) {
+            record.insert("namespace".to_string(), json!(namespace));
+        }
+        if let Some(reduce) = ctx.reduce("", shape) {
+            record.insert("reduce".to_string(), reduce);
+        }
+    }
+
+    serde_json::to_string_pretty(&schema).unwrap() + "\n"
+}
+
+fn schema(ctx: &mut Translation, shape: &Shape, ptr: &str, hint: &str) -> Value {
+    if is_any(shape) {
+        ctx.lossy(
+            ptr,
+            "location may be any JSON value, and is represented as a JSON-encoded string",
+        );
+        return json!("string");
+    }
+
+    let mut branches = Vec::new();
+
+    if shape.type_.overlaps(types::NULL) {
+        branches.push(json!("null"));
+    }
+    if let Some(symbols) = shape.enum_.as_deref().and_then(|values| {
+        // Enumerated null values are modeled by the "null" branch.
+        let values = values
+            .iter()
+            .filter(|v| !v.is_null())
+            .cloned()
+            .collect::<Vec<_>>();
+        enum_symbols(&values).filter(|symbols| !symbols.is_empty())
+    }) {
+        branches.push(json!({
+            "type": "enum",
+            "name": ctx.name(hint),
+            "symbols": symbols,
+        }));
+        return union(branches);
+    } else if shape.enum_.is_some() {
+        ctx.lossy(
+            ptr,
+            "enumerated values aren't valid Avro enum symbols, and are represented by their types",
+        );
+    }
+
+    if shape.type_.overlaps(types::BOOLEAN) {
+        branches.push(json!("boolean"));
+    }
+    if shape.type_.overlaps(types::FRACTIONAL) {
+        branches.push(json!("double"));
+    } else if shape.type_.overlaps(types::INTEGER) {
+        branches.push(json!("long"));
+    }
+    if shape.type_.overlaps(types::STRING) {
+        if let Some(Format::Uuid) = shape.string.format {
+            branches.push(json!({"type": "string", "logicalType": "uuid"}));
+        } else {
+            branches.push(json!("string"));
+        }
+    }
+    if shape.type_.overlaps(types::ARRAY) {
+        let ptr = format!("{ptr}/*");
+        let item = ctx.items(&ptr, &shape.array);
+
+        let mut items = schema(ctx, &item, &ptr, &format!("{hint}Item"));
+        annotate(ctx, &mut items, &ptr, &item);
+
+        branches.push(json!({"type": "array", "items": items}));
+    }
+    if shape.type_.overlaps(types::OBJECT) {
+        if let Some(value) = ctx.map_values(ptr, &shape.object) {
+            let ptr = format!("{ptr}/*");
+
+            let mut values = schema(ctx, &value, &ptr, &format!("{hint}Value"));
+            annotate(ctx, &mut values, &ptr, &value);
+
+            branches.push(json!({"type": "map", "values": values}));
+        } else {
+            branches.push(record(ctx, shape, ptr, hint));
+        }
+    }
+
+    if branches.is_empty() {
+        ctx.lossy(ptr, "location cannot exist, and is represented as null");
+        branches.push(json!("null"));
+    }
+    union(branches)
+}
+
+fn record(ctx: &mut Translation, shape: &Shape, ptr: &str, hint: &str) -> Value {
+    let name = ctx.name(hint);
+    let mut fields = Vec::new();
+
+    for prop in &shape.object.properties {
+        let ptr = push_prop(ptr, &prop.name);
+        let field_name = identifier(&prop.name);
+
+        if field_name != *prop.name {
+            ctx.lossy(
+                &ptr,
+                format!("property isn't a valid Avro name, and is renamed to {field_name:?}"),
+            );
+        }
+
+        let mut type_ = schema(ctx, &prop.shape, &ptr, &super::hint(&name, &prop.name));
+        let mut field = Map::new();
+        field.insert("name".to_string(), json!(field_name));
+
+        // Optional fields default to null, which must be the first union branch.
+        if !prop.is_required || shape.type_ != types::OBJECT {
+            type_ = match type_ {
+                Value::Array(mut branches) => {
+                    branches.retain(|b| b != "null");
+                    branches.insert(0, json!("null"));
+                    Value::Array(branches)
+                }
+                type_ => json!(["null", type_]),
+            };
+            field.insert("type".to_string(), type_);
+            field.insert("default".to_string(), Value::Null);
+        } else {
+            field.insert("type".to_string(), type_);
+        }
+
+        if let Some(doc) = doc(&prop.shape) {
+            field.insert("doc".to_string(), json!(doc));
+        }
+        if let Some(reduce) = ctx.reduce(&ptr, &prop.shape) {
+            field.insert("reduce".to_string(), reduce);
+        }
+        fields.push(Value::Object(field));
+    }
+
+    let mut record = Map::new();
+    record.insert("type".to_string(), json!("record"));
+    record.insert("name".to_string(), json!(name));
+    if let Some(doc) = doc(shape) {
+        record.insert("doc".to_string(), json!(doc));
+    }
+    record.insert("fields".to_string(), Value::Array(fields));
+
+    Value::Object(record)
+}
+
+// Annotate the schema of an array item or map value with its reduction strategy.
+// Primitive types are expanded into their object form, but unions cannot be annotated.
+fn annotate(ctx: &mut Translation, schema: &mut Value, ptr: &str, shape: &Shape) {
+    let Some(reduce) = ctx.reduce(ptr, shape) else {
+        return;
+    };
+    match schema {
+        Value::Object(obj) => {
+            obj.insert("reduce".to_string(), reduce);
+        }
+        Value::String(primitive) => {
+            *schema = json!({"type": primitive, "reduce": reduce});
+        }
+        _ => ctx.lossy(ptr, "reduction strategy of a union cannot be represented"),
+    }
+}
+
+fn union(mut branches: Vec<Value>) -> Value {
+    if branches.len() == 1 {
+        branches.pop().unwrap()
+    } else {
+        Value::Array(branches)
+    }
+}
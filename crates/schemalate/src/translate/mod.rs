@@ -0,0 +1,300 @@
+use anyhow::Context;
+use doc::shape::{ArrayShape, ObjShape, Reduction, Shape};
+use json::schema::types;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::io::Read;
+
+mod arrow;
+mod avro;
+mod protobuf;
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// Name of the root record or message.
+    #[clap(long, default_value = "Document")]
+    pub name: String,
+    /// Avro namespace or Protobuf package of the translated schema.
+    #[clap(long, default_value = "flow")]
+    pub namespace: String,
+    /// Fail if any schema location cannot be translated losslessly.
+    #[clap(long)]
+    pub strict: bool,
+}
+
+/// Format of a translated schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Avro record schema, as JSON.
+    Avro,
+    /// Protobuf (proto3) message definitions.
+    Protobuf,
+    /// Arrow schema, in the JSON representation of the Arrow integration tests.
+    Arrow,
+}
+
+/// Lossy is a schema location which could not be translated losslessly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lossy {
+    /// JSON pointer of the location, which may include patterns.
+    pub ptr: String,
+    /// Reason why the location could not be losslessly translated.
+    pub reason: String,
+}
+
+impl std::fmt::Display for Lossy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ptr = if self.ptr.is_empty() {
+            "(root)"
+        } else {
+            &self.ptr
+        };
+        write!(f, "{ptr}: {}", self.reason)
+    }
+}
+
+pub fn run(format: Format, args: Args) -> anyhow::Result<()> {
+    let mut bundle = String::new();
+    std::io::stdin().read_to_string(&mut bundle)?;
+
+    let (out, lossy) = translate(format, &bundle, &args.name, &args.namespace)?;
+
+    for lossy in &lossy {
+        tracing::warn!(%lossy, "location cannot be translated losslessly");
+    }
+    if args.strict && !lossy.is_empty() {
+        anyhow::bail!("{} locations cannot be translated losslessly", lossy.len());
+    }
+    print!("{out}");
+
+    Ok(())
+}
+
+/// Translate the JSON schema `bundle` into `format`, returning the
+/// translated schema and its locations which couldn't be translated losslessly.
+/// `name` is the name of the root record or message, and `namespace`
+/// is its Avro namespace or Protobuf package.
+pub fn translate(
+    format: Format,
+    bundle: &str,
+    name: &str,
+    namespace: &str,
+) -> anyhow::Result<(String, Vec<Lossy>)> {
+    let schema = doc::validation::build_bundle(bundle).context("failed to build JSON schema")?;
+    let validator = doc::Validator::new(schema).context("failed to index JSON schema")?;
+    let shape = Shape::infer(&validator.schemas()[0], validator.schema_index());
+
+    let mut ctx = Translation::default();
+
+    let out = match format {
+        Format::Avro => avro::translate(&mut ctx, &shape, name, namespace),
+        Format::Protobuf => protobuf::translate(&mut ctx, &shape, name, namespace),
+        Format::Arrow => arrow::translate(&mut ctx, &shape)?,
+    };
+    Ok((out, ctx.lossy))
+}
+
+// Translation is the shared state of a translation into a format.
+#[derive(Default)]
+struct Translation {
+    lossy: Vec<Lossy>,
+    names: BTreeSet<String>,
+}
+
+impl Translation {
+    fn lossy(&mut self, ptr: &str, reason: impl Into<String>) {
+        self.lossy.push(Lossy {
+            ptr: ptr.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    // Claim a unique record or message name, derived from `hint`.
+    fn name(&mut self, hint: &str) -> String {
+        let hint = identifier(&crate::typegen::camel_case(hint));
+
+        let mut name = hint.clone();
+        for n in 2.. {
+            if self.names.insert(name.clone()) {
+                break;
+            }
+            name = format!("{hint}{n}");
+        }
+        name
+    }
+
+    // Reduction annotation of a location, as a JSON value.
+    fn reduce(&mut self, ptr: &str, shape: &Shape) -> Option<Value> {
+        match &shape.reduction {
+            Reduction::Unset => None,
+            Reduction::Strategy(strategy) => Some(serde_json::to_value(strategy).unwrap()),
+            Reduction::Multiple => {
+                self.lossy(ptr, "location has multiple reduction strategies");
+                None
+            }
+        }
+    }
+
+    // Merge array items into a single Shape.
+    // Tuple items are merged with additional items, which is lossy.
+    fn items(&mut self, ptr: &str, array: &ArrayShape) -> Shape {
+        if !array.tuple.is_empty() {
+            self.lossy(ptr, "tuple items are merged into a single item type");
+        }
+        let mut merged = Shape::nothing();
+
+        for item in &array.tuple {
+            merged = Shape::union(merged, item.clone());
+        }
+        match &array.additional_items {
+            Some(addl) => merged = Shape::union(merged, addl.as_ref().clone()),
+            None => merged = Shape::anything(),
+        }
+        merged
+    }
+
+    // Merge the additional and pattern properties of an object into a single
+    // Shape of map values, or return None if the object has explicit properties.
+    // Explicit additional properties of objects which also have explicit
+    // properties are dropped, which is lossy.
+    fn map_values(&mut self, ptr: &str, obj: &ObjShape) -> Option<Shape> {
+        let has_additional = !obj.pattern_properties.is_empty()
+            || matches!(&obj.additional_properties, Some(addl) if addl.type_ != types::INVALID);
+
+        if !obj.properties.is_empty() {
+            if has_additional {
+                self.lossy(ptr, "additional properties are not translated");
+            }
+            return None;
+        }
+        let mut merged = Shape::nothing();
+
+        for prop in &obj.pattern_properties {
+            merged = Shape::union(merged, prop.shape.clone());
+        }
+        match &obj.additional_properties {
+            Some(addl) => merged = Shape::union(merged, addl.as_ref().clone()),
+            None => merged = Shape::anything(),
+        }
+        Some(merged)
+    }
+}
+
+// Is `shape` able to take any JSON value, without further constraints?
+fn is_any(shape: &Shape) -> bool {
+    shape.type_ == types::ANY
+        && shape.enum_.is_none()
+        && shape.array.additional_items.is_none()
+        && shape.array.tuple.is_empty()
+        && shape.object.properties.is_empty()
+        && shape.object.additional_properties.is_none()
+}
+
+// Documentation of a Shape, from its `title` and `description`.
+fn doc(shape: &Shape) -> Option<String> {
+    match (&shape.title, &shape.description) {
+        (Some(title), Some(description)) => Some(format!("{title}\n{description}")),
+        (Some(s), None) | (None, Some(s)) => Some(s.to_string()),
+        (None, None) => None,
+    }
+}
+
+// Map `name` into an ASCII identifier, replacing other characters with '_'.
+fn identifier(name: &str) -> String {
+    let mut out = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    if !out.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        out.insert(0, '_');
+    }
+    out
+}
+
+// If all enumerated `values` are strings which are also identifiers, return them.
+fn enum_symbols(values: &[Value]) -> Option<Vec<String>> {
+    values
+        .iter()
+        .map(|value| match value {
+            Value::String(s) if identifier(s) == *s => Some(s.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+// Name hint of a property `prop` of a record or message `parent`.
+fn hint(parent: &str, prop: &str) -> String {
+    format!("{parent}{}", crate::typegen::camel_case(prop))
+}
+
+// Append a JSON pointer property token to `ptr`.
+fn push_prop(ptr: &str, prop: &str) -> String {
+    format!("{ptr}/{}", prop.replace('~', "~0").replace('/', "~1"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{translate, Format};
+
+    const FIXTURE: &str = r##"{
+        "type": "object",
+        "title": "A document",
+        "properties": {
+            "id": {"type": "integer", "description": "Identifier of the document."},
+            "name": {"type": ["string", "null"]},
+            "kind": {"enum": ["a", "b", "c"]},
+            "weird enum": {"enum": ["x-y", 1]},
+            "total": {"type": "number", "reduce": {"strategy": "sum"}},
+            "tags": {"type": "array", "items": {"type": "string"}},
+            "pair": {"type": "array", "items": [{"type": "integer"}, {"type": "string"}]},
+            "counts": {"type": "object", "additionalProperties": {"type": "integer"}},
+            "nested": {
+                "type": "object",
+                "properties": {
+                    "when": {"type": "string", "format": "date-time"},
+                    "uid": {"type": "string", "format": "uuid"}
+                },
+                "required": ["when"],
+                "additionalProperties": {"type": "string"}
+            },
+            "items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {"sku": {"type": "string"}},
+                    "required": ["sku"]
+                }
+            },
+            "multi": {"type": ["string", "integer", "object"]},
+            "any": {}
+        },
+        "required": ["id", "name", "total"],
+        "reduce": {"strategy": "merge"}
+    }"##;
+
+    fn check(format: Format) -> String {
+        let (out, lossy) = translate(format, FIXTURE, "Document", "acme.widgets").unwrap();
+        let lossy = lossy
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("{out}\n---\n{lossy}\n")
+    }
+
+    #[test]
+    fn test_avro() {
+        insta::assert_snapshot!(check(Format::Avro));
+    }
+
+    #[test]
+    fn test_protobuf() {
+        insta::assert_snapshot!(check(Format::Protobuf));
+    }
+
+    #[test]
+    fn test_arrow() {
+        insta::assert_snapshot!(check(Format::Arrow));
+    }
+}
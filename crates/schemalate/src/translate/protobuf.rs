@@ -0,0 +1,288 @@
+use super::{doc, enum_symbols, hint, identifier, is_any, push_prop, Translation};
+use crate::typegen::{push_doc, snake_case};
+use doc::shape::Shape;
+use json::schema::types;
+use std::collections::BTreeSet;
+
+/// Translate `shape` into Protobuf (proto3) messages within package `namespace`.
+/// Objects having properties are messages, and the root message is named for `name`.
+/// Locations which Protobuf can't otherwise model, such as those having
+/// multiple types, are `google.protobuf.Value`.
+pub fn translate(ctx: &mut Translation, shape: &Shape, name: &str, namespace: &str) -> String {
+    let mut out = Messages::default();
+    let name = ctx.name(name);
+
+    if is_record(shape) {
+        message(ctx, &mut out, shape, "", name);
+    } else {
+        // The root isn't an object, and is wrapped in a message having a single `value` field.
+        let mut root = Message::new(name, None);
+        let field = field(ctx, &mut out, &mut root, shape, "", "value", true);
+        root.fields.push(field);
+        out.messages.push(root.render());
+    }
+
+    let mut w = format!("syntax = \"proto3\";\n\npackage {namespace};\n");
+    if out.uses_value {
+        w.push_str("\nimport \"google/protobuf/struct.proto\";\n");
+    }
+    for message in out.messages {
+        w.push('\n');
+        w.push_str(&message);
+    }
+    w
+}
+
+#[derive(Default)]
+struct Messages {
+    // Rendered messages, ordered so that nested messages precede their uses.
+    messages: Vec<String>,
+    // Is google.protobuf.Value used?
+    uses_value: bool,
+}
+
+struct Message {
+    name: String,
+    doc: Option<String>,
+    enums: Vec<String>,
+    fields: Vec<String>,
+    // Identifiers of fields and enum values, which share the message scope.
+    scope: BTreeSet<String>,
+}
+
+impl Message {
+    fn new(name: String, doc: Option<String>) -> Self {
+        Self {
+            name,
+            doc,
+            enums: Vec::new(),
+            fields: Vec::new(),
+            scope: BTreeSet::new(),
+        }
+    }
+
+    fn render(self) -> String {
+        let mut w = String::new();
+        push_doc(&mut w, "", "//", &self.doc);
+        w.push_str(&format!("message {} {{\n", self.name));
+
+        for enum_ in &self.enums {
+            w.push_str(enum_);
+            w.push('\n');
+        }
+        for (index, field) in self.fields.iter().enumerate() {
+            w.push_str(&field.replace("{TAG}", &(index + 1).to_string()));
+        }
+        w.push_str("}\n");
+        w
+    }
+}
+
+fn is_record(shape: &Shape) -> bool {
+    shape.type_ - types::NULL == types::OBJECT && !shape.object.properties.is_empty()
+}
+
+fn message(ctx: &mut Translation, out: &mut Messages, shape: &Shape, ptr: &str, name: String) {
+    let mut msg = Message::new(name, doc(shape));
+
+    // Additional properties are not modeled, but are reported.
+    _ = ctx.map_values(ptr, &shape.object);
+
+    for prop in &shape.object.properties {
+        let ptr = push_prop(ptr, &prop.name);
+        let required = prop.is_required && shape.type_ == types::OBJECT;
+
+        let field = field(ctx, out, &mut msg, &prop.shape, &ptr, &prop.name, required);
+        msg.fields.push(field);
+    }
+    out.messages.push(msg.render());
+}
+
+// Render a field of `msg` having `shape`. The rendered field has a placeholder
+// {TAG} for its field number, which is filled when the message is rendered.
+fn field(
+    ctx: &mut Translation,
+    out: &mut Messages,
+    msg: &mut Message,
+    shape: &Shape,
+    ptr: &str,
+    name: &str,
+    required: bool,
+) -> String {
+    let mut ident = identifier(&snake_case(name));
+    for n in 2.. {
+        if msg.scope.insert(ident.clone()) {
+            break;
+        }
+        ident = format!("{}_{n}", identifier(&snake_case(name)));
+    }
+    let hint = hint(&msg.name, name);
+    let nullable = shape.type_.overlaps(types::NULL);
+
+    let map_value =
+        if shape.type_ - types::NULL == types::OBJECT && shape.object.properties.is_empty() {
+            ctx.map_values(ptr, &shape.object)
+        } else {
+            None
+        };
+
+    let (label, type_) = if shape.type_ - types::NULL == types::ARRAY {
+        if nullable {
+            ctx.lossy(
+                ptr,
+                "null is indistinguishable from an empty repeated field",
+            );
+        }
+        let item_ptr = format!("{ptr}/*");
+        let item = ctx.items(&item_ptr, &shape.array);
+
+        // Repeated items cannot be null, but google.protobuf.Value can.
+        let type_ = match item.type_.overlaps(types::NULL) {
+            false => element(ctx, out, msg, &item, &item_ptr, &format!("{hint}Item")),
+            true => None,
+        }
+        .unwrap_or_else(|| value(ctx, out, &item, &item_ptr));
+        ("repeated ", type_)
+    } else if let Some(value_shape) = map_value {
+        if nullable {
+            ctx.lossy(ptr, "null is indistinguishable from an empty map");
+        }
+        let value_ptr = format!("{ptr}/*");
+
+        // Map values cannot be null, but google.protobuf.Value can.
+        let type_ = match value_shape.type_.overlaps(types::NULL) {
+            false => element(
+                ctx,
+                out,
+                msg,
+                &value_shape,
+                &value_ptr,
+                &format!("{hint}Value"),
+            ),
+            true => None,
+        }
+        .unwrap_or_else(|| value(ctx, out, &value_shape, &value_ptr));
+        ("", format!("map<string, {type_}>"))
+    } else if let Some(type_) = element(ctx, out, msg, shape, ptr, &hint) {
+        if nullable && required {
+            ctx.lossy(ptr, "null is indistinguishable from an absent value");
+        }
+        (
+            if nullable || !required {
+                "optional "
+            } else {
+                ""
+            },
+            type_,
+        )
+    } else {
+        ("", value(ctx, out, shape, ptr))
+    };
+
+    let mut w = String::new();
+    push_doc(&mut w, "  ", "//", &doc(shape));
+    if let Some(reduce) = ctx.reduce(ptr, shape) {
+        w.push_str(&format!("  // reduce: {reduce}\n"));
+    }
+
+    let mut options = String::new();
+    if ident != name {
+        options = format!(
+            " [json_name = {}]",
+            serde_json::Value::String(name.to_string())
+        );
+    }
+    w.push_str(&format!("  {label}{type_} {ident} = {{TAG}}{options};\n"));
+    w
+}
+
+// Map a location having a single non-null type into a scalar, enum, or message type.
+// Returns None if the location cannot be modeled as a single type.
+fn element(
+    ctx: &mut Translation,
+    out: &mut Messages,
+    msg: &mut Message,
+    shape: &Shape,
+    ptr: &str,
+    hint: &str,
+) -> Option<String> {
+    if is_any(shape) {
+        return None;
+    }
+    let type_ = shape.type_ - types::NULL;
+
+    if let Some(values) = &shape.enum_ {
+        let values = values
+            .iter()
+            .filter(|v| !v.is_null())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if let Some(name) = enum_symbols(&values)
+            .filter(|symbols| !symbols.is_empty())
+            .and_then(|symbols| enum_(ctx, msg, hint, symbols))
+        {
+            return Some(name);
+        }
+        ctx.lossy(
+            ptr,
+            "enumerated values aren't valid and unique Protobuf enum values, and are represented by their types",
+        );
+    }
+
+    match type_ {
+        types::BOOLEAN => Some("bool".to_string()),
+        types::INTEGER => Some("int64".to_string()),
+        types::FRACTIONAL | types::INT_OR_FRAC => Some("double".to_string()),
+        types::STRING => Some("string".to_string()),
+        types::OBJECT if !shape.object.properties.is_empty() => {
+            let name = ctx.name(hint);
+            message(ctx, out, shape, ptr, name.clone());
+            Some(name)
+        }
+        _ => None,
+    }
+}
+
+// Add an enum of `symbols` to `msg`, returning its name,
+// or None if a symbol conflicts with another identifier of the message scope.
+fn enum_(
+    ctx: &mut Translation,
+    msg: &mut Message,
+    hint: &str,
+    symbols: Vec<String>,
+) -> Option<String> {
+    let name = ctx.name(hint);
+    let unspecified = format!("{}_UNSPECIFIED", snake_case(&name).to_uppercase());
+
+    if std::iter::once(&unspecified)
+        .chain(symbols.iter())
+        .any(|symbol| msg.scope.contains(symbol))
+        || symbols.contains(&unspecified)
+    {
+        return None;
+    }
+    msg.scope.insert(unspecified.clone());
+    msg.scope.extend(symbols.iter().cloned());
+
+    let mut w = format!("  enum {name} {{\n    {unspecified} = 0;\n");
+    for (index, symbol) in symbols.iter().enumerate() {
+        w.push_str(&format!("    {symbol} = {};\n", index + 1));
+    }
+    w.push_str("  }\n");
+    msg.enums.push(w);
+
+    Some(name)
+}
+
+// Represent a location as a google.protobuf.Value, which is lossy if it may be an integer.
+fn value(ctx: &mut Translation, out: &mut Messages, shape: &Shape, ptr: &str) -> String {
+    if shape.type_.overlaps(types::INTEGER) {
+        ctx.lossy(
+            ptr,
+            "location is represented as google.protobuf.Value, which represents integers as doubles",
+        );
+    }
+    out.uses_value = true;
+    "google.protobuf.Value".to_string()
+}
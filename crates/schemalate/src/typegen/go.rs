@@ -0,0 +1,132 @@
+use super::{camel_case, push_doc, Def, Field, Type};
+use std::collections::BTreeSet;
+
+/// Render Go types of `defs` within `package`, as structs having `encoding/json` tags.
+pub fn render(defs: &[Def], package: &str) -> String {
+    let mut w =
+        format!("// Code generated from a JSON schema. DO NOT EDIT.\n\npackage {package}\n");
+
+    for Def { name, doc, type_ } in defs {
+        w.push('\n');
+        push_doc(&mut w, "", "//", doc);
+
+        match type_ {
+            Type::Object(fields) => render_struct(&mut w, name, fields),
+            type_ => w.push_str(&format!("type {name} = {}\n", expr(type_))),
+        }
+    }
+    w
+}
+
+fn render_struct(w: &mut String, name: &str, fields: &[Field]) {
+    let mut idents = BTreeSet::new();
+
+    let fields = fields
+        .iter()
+        .map(
+            |Field {
+                 name,
+                 doc,
+                 type_,
+                 required,
+             }| {
+                let ident = unique(field_ident(name), &mut idents);
+
+                let mut type_ = expr(type_);
+                let mut tag = name.clone();
+
+                if !required {
+                    // Optional fields are omitted if empty, and use a pointer
+                    // to distinguish a missing value from the zero value.
+                    if !is_nilable(&type_) {
+                        type_ = format!("*{type_}");
+                    }
+                    tag.push_str(",omitempty");
+                }
+                let tag = format!("`json:{}`", serde_json::Value::String(tag));
+
+                (doc, ident, type_, tag)
+            },
+        )
+        .collect::<Vec<_>>();
+
+    w.push_str(&format!("type {name} struct {{\n"));
+
+    // Align field names, types, and tags into columns, as does gofmt.
+    // Like gofmt, a documented field begins a new block of aligned fields.
+    let mut fields = &fields[..];
+    while let Some(first) = fields.first() {
+        let len = 1 + fields[1..].iter().take_while(|f| f.0.is_none()).count();
+        let (block, rest) = fields.split_at(len);
+        fields = rest;
+
+        let ident_width = block.iter().map(|f| f.1.chars().count()).max().unwrap();
+        let type_width = block.iter().map(|f| f.2.chars().count()).max().unwrap();
+
+        push_doc(w, "\t", "//", first.0);
+        for (_, ident, type_, tag) in block {
+            w.push_str(&format!(
+                "\t{ident:ident_width$} {type_:type_width$} {tag}\n"
+            ));
+        }
+    }
+    w.push_str("}\n");
+}
+
+fn expr(type_: &Type) -> String {
+    if let Some(type_) = type_.nullable() {
+        let type_ = expr(type_);
+
+        return if is_nilable(&type_) {
+            type_
+        } else {
+            format!("*{type_}")
+        };
+    }
+
+    match type_ {
+        Type::Any | Type::Never | Type::Null | Type::Union(_) | Type::Object(_) => {
+            "any".to_string()
+        }
+        Type::Bool => "bool".to_string(),
+        Type::Int => "int64".to_string(),
+        Type::Float => "float64".to_string(),
+        Type::Str => "string".to_string(),
+        Type::Enum(values) => match Type::enum_type(values) {
+            Type::Enum(_) => "any".to_string(),
+            type_ => expr(&type_),
+        },
+        Type::Array(item) => format!("[]{}", expr(item)),
+        Type::Map(value) => format!("map[string]{}", expr(value)),
+        // Recursive references must be indirect.
+        Type::Ref(name) => format!("*{name}"),
+        Type::Named(name) => name.clone(),
+    }
+}
+
+// Is the Go type `type_` able to represent a nil value?
+fn is_nilable(type_: &str) -> bool {
+    type_ == "any" || ["*", "[]", "map["].iter().any(|p| type_.starts_with(p))
+}
+
+fn field_ident(name: &str) -> String {
+    let ident = camel_case(name);
+
+    // Exported field names must begin with an upper-case letter.
+    if ident.starts_with(|c: char| c.is_uppercase()) {
+        ident
+    } else {
+        format!("F{ident}")
+    }
+}
+
+fn unique(ident: String, idents: &mut BTreeSet<String>) -> String {
+    let mut out = ident.clone();
+    for n in 2.. {
+        if idents.insert(out.clone()) {
+            break;
+        }
+        out = format!("{ident}{n}");
+    }
+    out
+}
@@ -0,0 +1,267 @@
+use super::{Def, Field, Type};
+use anyhow::Context;
+use doc::shape::{ArrayShape, ObjShape, Provenance, Shape};
+use json::schema::{types, Keyword};
+use std::collections::BTreeMap;
+
+/// Mapper maps the Shapes of a JSON schema bundle into language-neutral Types.
+/// It's also used by derivation connectors which generate types of their own.
+pub struct Mapper {
+    top_level: BTreeMap<url::Url, String>,
+    validator: doc::Validator,
+    anchor_prefix: String,
+}
+
+impl Mapper {
+    /// Build a Mapper of the JSON schema `bundle`. Named schemas (having an
+    /// `$anchor`) are mapped to types named with `anchor_prefix`, or are
+    /// inlined where they're referenced if `anchor_prefix` is empty.
+    pub fn new(bundle: &str, anchor_prefix: &str) -> anyhow::Result<Self> {
+        let schema =
+            doc::validation::build_bundle(bundle).context("failed to build JSON schema")?;
+        let validator = doc::Validator::new(schema).context("failed to index JSON schema")?;
+
+        let mut top_level = BTreeMap::new();
+
+        let mut stack = Vec::new();
+        if !anchor_prefix.is_empty() {
+            stack.push(&validator.schemas()[0]);
+        }
+        while let Some(schema) = stack.pop() {
+            for kw in &schema.kw {
+                match kw {
+                    Keyword::Anchor(anchor_uri) => {
+                        // Does this anchor meet our definition of a named schema?
+                        if let Some((_, anchor)) = anchor_uri
+                            .as_str()
+                            .split_once('#')
+                            .filter(|(_, s)| NAMED_SCHEMA_RE.is_match(s))
+                        {
+                            top_level.insert(anchor_uri.clone(), anchor.to_owned());
+                        }
+                    }
+                    Keyword::Application(_, child) => {
+                        stack.push(child);
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(Mapper {
+            validator,
+            top_level,
+            anchor_prefix: anchor_prefix.to_string(),
+        })
+    }
+
+    /// Map the root schema and each named schema into definitions.
+    /// The root definition is named `name`.
+    pub fn defs(&self, name: &str) -> Vec<Def> {
+        let mut defs = vec![self.def(name.to_string(), self.root())];
+
+        for (anchor_url, anchor) in &self.top_level {
+            defs.push(self.def(format!("{}{anchor}", self.anchor_prefix), anchor_url));
+        }
+        defs
+    }
+
+    /// Map the schema having `url` into its documentation and Type.
+    /// Unlike `defs`, a schema which references a named schema maps to a Ref of it.
+    pub fn map(&self, url: &url::Url) -> (Option<String>, Type) {
+        let shape = self.shape(url);
+        let type_ = self.to_type(&shape);
+        (doc_of(&shape, &type_), type_)
+    }
+
+    /// URL of the root schema.
+    pub fn root(&self) -> &url::Url {
+        &self.validator.schemas()[0].curi
+    }
+
+    /// Named schemas, keyed on their `$anchor` URL, and their (unprefixed) anchor names.
+    pub fn anchors(&self) -> &BTreeMap<url::Url, String> {
+        &self.top_level
+    }
+
+    /// Infer the Shape of the schema having `url`.
+    pub fn shape(&self, url: &url::Url) -> Shape {
+        let index = self.validator.schema_index();
+
+        match index.fetch(url) {
+            Some(schema) => Shape::infer(schema, index),
+            None => Shape::anything(),
+        }
+    }
+
+    /// Prefixed name of the named schema which `shape` references, if any.
+    pub fn anchor_ref(&self, shape: &Shape) -> Option<String> {
+        let Provenance::Reference(uri) = &shape.provenance else {
+            return None;
+        };
+        self.top_level
+            .get(uri)
+            .map(|anchor| format!("{}{anchor}", &self.anchor_prefix))
+    }
+
+    fn def(&self, name: String, url: &url::Url) -> Def {
+        let shape = self.shape(url);
+
+        Def {
+            name,
+            doc: doc(&shape),
+            type_: self.to_type_inner(&shape),
+        }
+    }
+
+    fn to_type(&self, shape: &Shape) -> Type {
+        if let Some(name) = self.anchor_ref(shape) {
+            return Type::Ref(name);
+        }
+        self.to_type_inner(shape)
+    }
+
+    fn to_type_inner(&self, shape: &Shape) -> Type {
+        // Is this a trivial ANY type?
+        if shape.type_ == types::ANY
+            && shape.enum_.is_none()
+            && shape.array.additional_items.is_none()
+            && shape.array.tuple.is_empty()
+            && shape.object.properties.is_empty()
+            && shape.object.additional_properties.is_none()
+        {
+            return Type::Any;
+        }
+        // Is this an enum? Just emit the variants.
+        if let Some(enum_) = &shape.enum_ {
+            return Type::Enum(enum_.clone());
+        }
+
+        let mut disjunct = Vec::new();
+
+        if shape.type_.overlaps(types::OBJECT) {
+            disjunct.push(self.object_to_type(&shape.object));
+        }
+        if shape.type_.overlaps(types::ARRAY) {
+            disjunct.push(self.array_to_type(&shape.array));
+        }
+        if shape.type_.overlaps(types::BOOLEAN) {
+            disjunct.push(Type::Bool);
+        }
+        if shape.type_.overlaps(types::FRACTIONAL) {
+            disjunct.push(Type::Float);
+        } else if shape.type_.overlaps(types::INTEGER) {
+            disjunct.push(Type::Int);
+        }
+        if shape.type_.overlaps(types::STRING) {
+            disjunct.push(Type::Str);
+        }
+        if shape.type_.overlaps(types::NULL) {
+            disjunct.push(Type::Null);
+        }
+
+        if disjunct.is_empty() {
+            Type::Never
+        } else if disjunct.len() == 1 {
+            disjunct.pop().unwrap()
+        } else {
+            Type::Union(disjunct)
+        }
+    }
+
+    fn object_to_type(&self, obj: &ObjShape) -> Type {
+        let fields = obj
+            .properties
+            .iter()
+            .map(|prop| {
+                let type_ = self.to_type(&prop.shape);
+
+                Field {
+                    name: prop.name.to_string(),
+                    doc: doc_of(&prop.shape, &type_),
+                    type_,
+                    required: prop.is_required,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Generated types don't model additional properties of objects having
+        // explicit properties. If there are no explicit properties, then the object
+        // is instead a map of the union of its pattern and additional properties.
+        if !fields.is_empty() {
+            return Type::Object(fields);
+        }
+
+        let mut merged = Shape::nothing();
+
+        for prop in &obj.pattern_properties {
+            merged = Shape::union(merged, prop.shape.clone());
+        }
+        match &obj.additional_properties {
+            Some(addl) if addl.type_ != types::INVALID => {
+                merged = Shape::union(merged, addl.as_ref().clone());
+            }
+            Some(_) => (),
+            None => merged = Shape::anything(),
+        }
+
+        let value = if merged.type_ == types::INVALID {
+            Type::Any
+        } else {
+            self.to_type(&merged)
+        };
+        Type::Map(Box::new(value))
+    }
+
+    fn array_to_type(&self, arr: &ArrayShape) -> Type {
+        // Tuples are modeled as arrays of the union of each tuple item and additional items.
+        let mut merged = Shape::nothing();
+
+        for item in &arr.tuple {
+            merged = Shape::union(merged, item.clone());
+        }
+        match &arr.additional_items {
+            Some(addl) if addl.type_ != types::INVALID => {
+                merged = Shape::union(merged, addl.as_ref().clone());
+            }
+            Some(_) => (),
+            None => merged = Shape::anything(),
+        }
+
+        let item = if merged.type_ == types::INVALID {
+            Type::Any
+        } else {
+            self.to_type(&merged)
+        };
+        Type::Array(Box::new(item))
+    }
+}
+
+// Documentation of a Shape having the mapped `type_`.
+// References to named types are documented with a `title`, but not `description`.
+fn doc_of(shape: &Shape, type_: &Type) -> Option<String> {
+    if let Type::Ref(_) = type_ {
+        shape.title.as_ref().map(|title| title.to_string())
+    } else {
+        doc(shape)
+    }
+}
+
+// Documentation of a Shape, from its `title` and `description`.
+fn doc(shape: &Shape) -> Option<String> {
+    match (&shape.title, &shape.description) {
+        (Some(title), Some(description)) => Some(format!("{title}\n{description}")),
+        (Some(s), None) | (None, Some(s)) => Some(s.to_string()),
+        (None, None) => None,
+    }
+}
+
+lazy_static::lazy_static! {
+    // The set of allowed characters in a schema `$anchor` is quite limited,
+    // by Sec 8.2.3.
+    //
+    // To identify named schemas, we further restrict to anchors which start
+    // with a capital letter and include only '_' as punctuation.
+    // See: https://json-schema.org/draft/2019-09/json-schema-core.html#anchor
+    static ref NAMED_SCHEMA_RE: regex::Regex = regex::Regex::new("^[A-Z][\\w_]+$").unwrap();
+}
@@ -0,0 +1,474 @@
+use anyhow::Context;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::io::Read;
+
+mod go;
+mod mapper;
+mod protobuf;
+mod python;
+mod rust;
+
+pub use mapper::Mapper;
+
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// Language of the generated types.
+    #[clap(long, value_enum)]
+    pub lang: Lang,
+    /// Name of the generated root type.
+    /// Named schemas (having an `$anchor`) are also prefixed by this name.
+    #[clap(long, default_value = "Document")]
+    pub name: String,
+    /// Go or Protobuf package of the generated types.
+    #[clap(long, default_value = "flow")]
+    pub package: String,
+}
+
+/// Language of generated types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Lang {
+    /// Rust structs, which derive serde Serialize and Deserialize.
+    Rust,
+    /// Go structs, having `encoding/json` field tags.
+    Go,
+    /// Python TypedDicts.
+    Python,
+    /// Protobuf (proto3) messages.
+    Protobuf,
+}
+
+impl Lang {
+    /// File extension of generated sources in this language.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Lang::Rust => "rs",
+            Lang::Go => "go",
+            Lang::Python => "py",
+            Lang::Protobuf => "proto",
+        }
+    }
+
+    /// Name of this language, as used in paths of generated files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lang::Rust => "rust",
+            Lang::Go => "go",
+            Lang::Python => "python",
+            Lang::Protobuf => "protobuf",
+        }
+    }
+}
+
+pub fn run(args: Args) -> anyhow::Result<()> {
+    let mut bundle = String::new();
+    std::io::stdin().read_to_string(&mut bundle)?;
+
+    let out = generate(args.lang, &bundle, &args.name, &args.package)?;
+    print!("{out}");
+
+    Ok(())
+}
+
+/// Generate types of the JSON schema `bundle` in language `lang`.
+/// `name` is the name of the root type, and prefixes the names of `$anchor` types.
+/// `package` is the package of Go and Protobuf types, and is otherwise ignored.
+pub fn generate(lang: Lang, bundle: &str, name: &str, package: &str) -> anyhow::Result<String> {
+    let mapper = Mapper::new(bundle, name)?;
+    let defs = hoist(mapper.defs(name), lang == Lang::Python);
+
+    Ok(match lang {
+        Lang::Rust => rust::render(&defs),
+        Lang::Go => go::render(&defs, package),
+        Lang::Python => python::render(&defs),
+        Lang::Protobuf => protobuf::render(&defs, package),
+    })
+}
+
+/// Generate types of the named collection having JSON schema `bundle`.
+/// Returns the path of the generated file, relative to the project root, and its content.
+///
+/// The root type is named for the final component of the collection name,
+/// and Go and Protobuf packages are named for its preceding components.
+pub fn collection_types(
+    lang: Lang,
+    collection: &str,
+    bundle: &str,
+) -> anyhow::Result<(String, String)> {
+    let segments = collection
+        .split('/')
+        .map(|segment| {
+            let mut segment = segment
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect::<String>();
+
+            if segment.is_empty() || segment.starts_with(|c: char| c.is_numeric()) {
+                segment.insert(0, '_');
+            }
+            segment
+        })
+        .collect::<Vec<_>>();
+
+    let (last, prefix) = segments.split_last().unwrap();
+
+    let name = type_name(last);
+    let package = match lang {
+        Lang::Go => prefix.last().map(|s| s.to_lowercase()),
+        _ => (!prefix.is_empty()).then(|| prefix.join(".")),
+    }
+    .unwrap_or_else(|| "flow".to_string());
+
+    let content = generate(lang, bundle, &name, &package)
+        .with_context(|| format!("failed to generate {} types of {collection}", lang.name()))?;
+
+    Ok((
+        format!(
+            "flow_generated/{}/{}.{}",
+            lang.name(),
+            segments.join("/"),
+            lang.extension()
+        ),
+        content,
+    ))
+}
+
+/// Type is a language-neutral model of the type of a document location.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    /// Any JSON value.
+    Any,
+    /// No value is possible.
+    Never,
+    Null,
+    Bool,
+    Int,
+    Float,
+    Str,
+    /// One of an enumeration of literal values.
+    Enum(Vec<Value>),
+    Array(Box<Type>),
+    /// An object having only additional properties of the given type.
+    Map(Box<Type>),
+    /// An object having the given properties.
+    Object(Vec<Field>),
+    /// Any one of the given types.
+    Union(Vec<Type>),
+    /// Reference to a named `$anchor` type, which may be recursive.
+    Ref(String),
+    /// Reference to a named type which was hoisted from its original location.
+    Named(String),
+}
+
+/// Field is a property of an object type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub doc: Option<String>,
+    pub type_: Type,
+    pub required: bool,
+}
+
+/// Def is a named type definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Def {
+    pub name: String,
+    pub doc: Option<String>,
+    pub type_: Type,
+}
+
+impl Type {
+    /// If this is a union of a single type with Null, return that type.
+    pub fn nullable(&self) -> Option<&Type> {
+        match self {
+            Type::Union(variants) if variants.len() == 2 && variants.contains(&Type::Null) => {
+                variants.iter().find(|v| **v != Type::Null)
+            }
+            _ => None,
+        }
+    }
+
+    /// Map an enumeration to the common type of its values, for languages
+    /// which don't model literal types.
+    pub fn enum_type(values: &[Value]) -> Type {
+        let mut types = values
+            .iter()
+            .map(|value| match value {
+                Value::Null => Type::Null,
+                Value::Bool(_) => Type::Bool,
+                Value::Number(n) if n.is_f64() => Type::Float,
+                Value::Number(_) => Type::Int,
+                Value::String(_) => Type::Str,
+                _ => Type::Any,
+            })
+            .collect::<Vec<_>>();
+
+        types.sort_by_key(|t| format!("{t:?}"));
+        types.dedup();
+
+        match types.as_slice() {
+            [t] => t.clone(),
+            [Type::Float, Type::Int] => Type::Float,
+            [t, Type::Null] | [Type::Null, t] if *t != Type::Any => {
+                Type::Union(vec![t.clone(), Type::Null])
+            }
+            _ => Type::Any,
+        }
+    }
+}
+
+// Hoist object types which are nested within other types into definitions
+// of their own, having names derived from their location.
+// Hoisted definitions are ordered before the definitions which use them.
+// If `unions` is false, then unions other than nullable types are mapped to Any.
+fn hoist(defs: Vec<Def>, unions: bool) -> Vec<Def> {
+    let mut names: BTreeSet<String> = defs.iter().map(|def| def.name.clone()).collect();
+    let mut out = Vec::new();
+
+    for Def { name, doc, type_ } in defs {
+        let type_ = match type_ {
+            Type::Object(fields) => {
+                Type::Object(hoist_fields(fields, &name, &mut out, &mut names, unions))
+            }
+            type_ => hoist_type(type_, &name, &mut out, &mut names, unions),
+        };
+        out.push(Def { name, doc, type_ });
+    }
+    out
+}
+
+fn hoist_fields(
+    fields: Vec<Field>,
+    hint: &str,
+    out: &mut Vec<Def>,
+    names: &mut BTreeSet<String>,
+    unions: bool,
+) -> Vec<Field> {
+    fields
+        .into_iter()
+        .map(|field| {
+            let hint = format!("{hint}{}", camel_case(&field.name));
+            Field {
+                type_: hoist_type(field.type_, &hint, out, names, unions),
+                ..field
+            }
+        })
+        .collect()
+}
+
+fn hoist_type(
+    type_: Type,
+    hint: &str,
+    out: &mut Vec<Def>,
+    names: &mut BTreeSet<String>,
+    unions: bool,
+) -> Type {
+    match type_ {
+        Type::Object(fields) => {
+            let mut name = hint.to_string();
+            for n in 2.. {
+                if !names.contains(&name) {
+                    break;
+                }
+                name = format!("{hint}{n}");
+            }
+            names.insert(name.clone());
+
+            let fields = hoist_fields(fields, &name, out, names, unions);
+            out.push(Def {
+                name: name.clone(),
+                doc: None,
+                type_: Type::Object(fields),
+            });
+            Type::Named(name)
+        }
+        Type::Array(item) => Type::Array(Box::new(hoist_type(
+            *item,
+            &format!("{hint}Item"),
+            out,
+            names,
+            unions,
+        ))),
+        Type::Map(value) => Type::Map(Box::new(hoist_type(
+            *value,
+            &format!("{hint}Value"),
+            out,
+            names,
+            unions,
+        ))),
+        type_ @ Type::Union(_) if !unions && type_.nullable().is_none() => Type::Any,
+        Type::Union(variants) => Type::Union(
+            variants
+                .into_iter()
+                .map(|variant| hoist_type(variant, hint, out, names, unions))
+                .collect(),
+        ),
+        type_ => type_,
+    }
+}
+
+// Map `name` into a type name, which is an UpperCamelCase identifier.
+fn type_name(name: &str) -> String {
+    let name = camel_case(name);
+
+    if name.starts_with(|c: char| c.is_alphabetic()) {
+        name
+    } else {
+        format!("Document{name}")
+    }
+}
+
+// Map `name` into UpperCamelCase, removing non-alphanumeric characters.
+pub(crate) fn camel_case(name: &str) -> String {
+    let mut w = String::new();
+    let mut upper = true;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            upper = true
+        } else if upper {
+            w.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            w.push(c);
+        }
+    }
+    w
+}
+
+// Map `name` into snake_case, removing non-alphanumeric characters.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut w = String::new();
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !w.is_empty() && !w.ends_with('_') {
+                w.push('_');
+            }
+            prev_lower = false;
+        } else if c.is_uppercase() {
+            if prev_lower {
+                w.push('_');
+            }
+            w.extend(c.to_lowercase());
+            prev_lower = false;
+        } else {
+            w.push(c);
+            prev_lower = c.is_lowercase() || c.is_numeric();
+        }
+    }
+    let w = w.trim_end_matches('_').to_string();
+
+    if w.is_empty() || w.starts_with(|c: char| c.is_numeric()) {
+        format!("_{w}")
+    } else {
+        w
+    }
+}
+
+// Render `doc` as lines of comments having the given `prefix`.
+pub(crate) fn push_doc(w: &mut String, indent: &str, prefix: &str, doc: &Option<String>) {
+    for line in doc.iter().flat_map(|doc| doc.lines()) {
+        w.push_str(indent);
+        w.push_str(format!("{prefix} {line}").trim_end());
+        w.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{collection_types, generate, Lang};
+
+    const FIXTURE: &str = r##"{
+        "$id": "test://example/schema.json",
+        "title": "A thing which is counted.",
+        "type": "object",
+        "properties": {
+            "id": { "type": "string", "description": "Unique identifier\nof the thing." },
+            "count": { "type": "integer" },
+            "ratio": { "type": ["number", "null"] },
+            "flag": { "type": "boolean", "default": false },
+            "tags": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": { "name": { "type": "string" }, "weight": { "type": "number" } },
+                    "required": ["name"]
+                }
+            },
+            "counters": { "type": "object", "additionalProperties": { "type": "integer" } },
+            "kind": { "enum": ["big", "small"] },
+            "anything": {},
+            "mixed": { "type": ["string", "integer"] },
+            "tree": { "$ref": "#Tree" },
+            "weird-Name": { "type": "object", "properties": { "type": { "type": "string" } } }
+        },
+        "required": ["id", "count"],
+        "$defs": {
+            "tree": {
+                "$anchor": "Tree",
+                "type": "object",
+                "description": "A recursive tree.",
+                "properties": {
+                    "value": { "type": "string" },
+                    "children": { "type": "array", "items": { "$ref": "#Tree" } },
+                    "parent": { "$ref": "#Tree" }
+                },
+                "required": ["value"]
+            }
+        }
+    }"##;
+
+    #[test]
+    fn test_rust() {
+        insta::assert_snapshot!(generate(Lang::Rust, FIXTURE, "Thing", "acme").unwrap());
+    }
+
+    #[test]
+    fn test_go() {
+        insta::assert_snapshot!(generate(Lang::Go, FIXTURE, "Thing", "acme").unwrap());
+    }
+
+    #[test]
+    fn test_python() {
+        insta::assert_snapshot!(generate(Lang::Python, FIXTURE, "Thing", "acme").unwrap());
+    }
+
+    #[test]
+    fn test_protobuf() {
+        insta::assert_snapshot!(generate(Lang::Protobuf, FIXTURE, "Thing", "acme").unwrap());
+    }
+
+    #[test]
+    fn test_scalar_root() {
+        let bundle = r#"{"type": ["string", "null"]}"#;
+
+        insta::assert_snapshot!([Lang::Rust, Lang::Go, Lang::Python, Lang::Protobuf]
+            .iter()
+            .map(|lang| generate(*lang, bundle, "Scalar", "acme").unwrap())
+            .collect::<Vec<_>>()
+            .join("\n---\n"));
+    }
+
+    #[test]
+    fn test_collection_types() {
+        let bundle = r#"{"type": "object", "properties": {"a": {"type": "string"}}}"#;
+
+        let paths = [Lang::Rust, Lang::Go, Lang::Python, Lang::Protobuf]
+            .iter()
+            .map(|lang| {
+                let (path, content) =
+                    collection_types(*lang, "acmeCo/sub-dir/my-things", bundle).unwrap();
+                let first = content
+                    .lines()
+                    .find(|l| l.contains("MyThings"))
+                    .unwrap()
+                    .to_string();
+                (path, first)
+            })
+            .collect::<Vec<_>>();
+
+        insta::assert_debug_snapshot!(paths);
+    }
+}
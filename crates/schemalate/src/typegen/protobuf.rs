@@ -0,0 +1,125 @@
+use super::{push_doc, snake_case, Def, Field, Type};
+use std::collections::BTreeSet;
+
+/// Render Protobuf (proto3) messages of `defs` within `package`.
+///
+/// Each definition is a message. Definitions which aren't objects are
+/// messages having a single `value` field. Types which Protobuf can't
+/// model, such as unions or nested arrays, are `google.protobuf.Value`.
+pub fn render(defs: &[Def], package: &str) -> String {
+    let mut body = String::new();
+    let mut uses_value = false;
+
+    for Def { name, doc, type_ } in defs {
+        body.push('\n');
+        push_doc(&mut body, "", "//", doc);
+        body.push_str(&format!("message {name} {{\n"));
+
+        let fields = match type_ {
+            Type::Object(fields) => fields.clone(),
+            type_ => vec![Field {
+                name: "value".to_string(),
+                doc: None,
+                type_: type_.clone(),
+                required: true,
+            }],
+        };
+        let mut idents = BTreeSet::new();
+
+        for (
+            index,
+            Field {
+                name,
+                doc,
+                type_,
+                required,
+            },
+        ) in fields.iter().enumerate()
+        {
+            let ident = unique(snake_case(name), &mut idents);
+            let (label, type_) = field_type(type_, *required);
+            uses_value = uses_value || type_.contains(VALUE);
+
+            let mut options = String::new();
+            if &ident != name {
+                options = format!(" [json_name = {}]", serde_json::Value::String(name.clone()));
+            }
+
+            push_doc(&mut body, "  ", "//", doc);
+            body.push_str(&format!(
+                "  {label}{type_} {ident} = {}{options};\n",
+                index + 1
+            ));
+        }
+        body.push_str("}\n");
+    }
+
+    let mut w =
+        format!("// Generated types. Do not edit.\nsyntax = \"proto3\";\n\npackage {package};\n");
+    if uses_value {
+        w.push_str("\nimport \"google/protobuf/struct.proto\";\n");
+    }
+    w.push_str(&body);
+    w
+}
+
+// Map a field Type into its label (`optional` or `repeated`) and type.
+fn field_type(type_: &Type, required: bool) -> (&'static str, String) {
+    let (type_, nullable) = match type_.nullable() {
+        Some(type_) => (type_, true),
+        None => (type_, false),
+    };
+
+    match type_ {
+        // Array items must be scalars or messages.
+        Type::Array(item) => match scalar(item) {
+            Some(item) => ("repeated ", item),
+            None => ("repeated ", VALUE.to_string()),
+        },
+        // Map values must be scalars or messages, and cannot be null.
+        Type::Map(value) => match scalar(value) {
+            Some(value) if !nullable => ("", format!("map<string, {value}>")),
+            _ => ("", VALUE.to_string()),
+        },
+        type_ => match scalar(type_) {
+            Some(type_) if !required || nullable => ("optional ", type_),
+            Some(type_) => ("", type_),
+            None => ("", VALUE.to_string()),
+        },
+    }
+}
+
+// Map a Type into a scalar or message type, or None if it must be a google.protobuf.Value.
+fn scalar(type_: &Type) -> Option<String> {
+    match type_ {
+        Type::Bool => Some("bool".to_string()),
+        Type::Int => Some("int64".to_string()),
+        Type::Float => Some("double".to_string()),
+        Type::Str => Some("string".to_string()),
+        Type::Enum(values) => match Type::enum_type(values) {
+            Type::Enum(_) => None,
+            type_ => scalar(&type_),
+        },
+        Type::Ref(name) | Type::Named(name) => Some(name.clone()),
+        Type::Any
+        | Type::Never
+        | Type::Null
+        | Type::Array(_)
+        | Type::Map(_)
+        | Type::Object(_)
+        | Type::Union(_) => None,
+    }
+}
+
+fn unique(ident: String, idents: &mut BTreeSet<String>) -> String {
+    let mut out = ident.clone();
+    for n in 2.. {
+        if idents.insert(out.clone()) {
+            break;
+        }
+        out = format!("{ident}_{n}");
+    }
+    out
+}
+
+const VALUE: &str = "google.protobuf.Value";
@@ -0,0 +1,156 @@
+use super::{push_doc, Def, Field, Type};
+use serde_json::Value;
+
+/// Render Python types of `defs`, as TypedDicts and type aliases.
+pub fn render(defs: &[Def]) -> String {
+    let mut w = String::from(
+        "# Generated types. Do not edit.\n\
+         from __future__ import annotations\n\
+         \n\
+         import typing\n",
+    );
+
+    for Def { name, doc, type_ } in defs {
+        w.push_str("\n\n");
+        push_doc(&mut w, "", "#", doc);
+
+        match type_ {
+            Type::Object(fields) => render_typed_dict(&mut w, name, fields),
+            // Aliases are quoted, as they may reference types which are defined later.
+            type_ => w.push_str(&format!(
+                "{name}: typing.TypeAlias = {}\n",
+                quote(&expr(type_))
+            )),
+        }
+    }
+    w
+}
+
+fn render_typed_dict(w: &mut String, name: &str, fields: &[Field]) {
+    let is_class = fields
+        .iter()
+        .all(|field| PY_IDENTIFIER_RE.is_match(&field.name) && !is_keyword(&field.name));
+
+    if is_class {
+        w.push_str(&format!("class {name}(typing.TypedDict):\n"));
+    } else {
+        // Fields which aren't identifiers require the functional syntax.
+        w.push_str(&format!("{name} = typing.TypedDict({}, {{\n", quote(name)));
+    }
+
+    for Field {
+        name,
+        doc,
+        type_,
+        required,
+    } in fields
+    {
+        let mut type_ = expr(type_);
+        if !required {
+            type_ = format!("typing.NotRequired[{type_}]");
+        }
+
+        push_doc(w, "    ", "#", doc);
+        if is_class {
+            w.push_str(&format!("    {name}: {type_}\n"));
+        } else {
+            w.push_str(&format!("    {}: {},\n", quote(name), quote(&type_)));
+        }
+    }
+
+    if fields.is_empty() && is_class {
+        w.push_str("    pass\n");
+    } else if !is_class {
+        w.push_str("})\n");
+    }
+}
+
+fn expr(type_: &Type) -> String {
+    match type_ {
+        Type::Any | Type::Object(_) => "typing.Any".to_string(),
+        Type::Never => "typing.Never".to_string(),
+        Type::Null => "None".to_string(),
+        Type::Bool => "bool".to_string(),
+        Type::Int => "int".to_string(),
+        Type::Float => "float".to_string(),
+        Type::Str => "str".to_string(),
+        Type::Enum(values) => enum_expr(values),
+        Type::Array(item) => format!("list[{}]", expr(item)),
+        Type::Map(value) => format!("dict[str, {}]", expr(value)),
+        Type::Union(variants) => {
+            let mut parts = Vec::new();
+            for variant in variants {
+                let part = expr(variant);
+                if !parts.contains(&part) {
+                    parts.push(part);
+                }
+            }
+            parts.join(" | ")
+        }
+        Type::Ref(name) | Type::Named(name) => name.clone(),
+    }
+}
+
+// Python Literals may only be strings, integers, booleans, or None,
+// and other enumerated values are mapped to their type.
+fn enum_expr(values: &[Value]) -> String {
+    let mut literals = Vec::new();
+    let mut others = Vec::new();
+
+    for value in values {
+        let other = match value {
+            Value::Null => {
+                literals.push("None".to_string());
+                continue;
+            }
+            Value::Bool(b) => {
+                literals.push(if *b { "True" } else { "False" }.to_string());
+                continue;
+            }
+            Value::Number(n) if n.is_f64() => "float",
+            Value::Number(n) => {
+                literals.push(n.to_string());
+                continue;
+            }
+            // JSON strings are also valid Python string literals.
+            Value::String(_) => {
+                literals.push(value.to_string());
+                continue;
+            }
+            Value::Array(_) => "list[typing.Any]",
+            Value::Object(_) => "dict[str, typing.Any]",
+        };
+        if !others.contains(&other) {
+            others.push(other);
+        }
+    }
+
+    let mut parts = Vec::new();
+    if !literals.is_empty() {
+        parts.push(format!("typing.Literal[{}]", literals.join(", ")));
+    }
+    parts.extend(others.into_iter().map(str::to_string));
+    parts.join(" | ")
+}
+
+// Quote `s` as a Python string literal. JSON string escapes are also valid Python escapes.
+fn quote(s: &str) -> String {
+    Value::String(s.to_string()).to_string()
+}
+
+fn is_keyword(s: &str) -> bool {
+    PY_KEYWORDS.binary_search(&s).is_ok()
+}
+
+lazy_static::lazy_static! {
+    // The set of allowed characters in a bare Python identifier.
+    static ref PY_IDENTIFIER_RE: regex::Regex = regex::Regex::new(r"^[\pL_][\pL\pN_]*$").unwrap();
+}
+
+// Reserved keywords of Python, which cannot be used as identifiers.
+const PY_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
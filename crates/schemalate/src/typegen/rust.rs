@@ -0,0 +1,122 @@
+use super::{push_doc, snake_case, Def, Field, Type};
+use std::collections::BTreeSet;
+
+/// Render Rust types of `defs`, as structs which derive serde Serialize and Deserialize.
+pub fn render(defs: &[Def]) -> String {
+    let mut w = String::from("// Generated types. Do not edit.\n");
+
+    if defs.iter().any(|def| matches!(def.type_, Type::Object(_))) {
+        w.push_str("\nuse serde::{Deserialize, Serialize};\n");
+    }
+
+    for Def { name, doc, type_ } in defs {
+        w.push('\n');
+        push_doc(&mut w, "", "///", doc);
+
+        match type_ {
+            Type::Object(fields) => render_struct(&mut w, name, fields),
+            type_ => w.push_str(&format!("pub type {name} = {};\n", expr(type_, false))),
+        }
+    }
+    w
+}
+
+fn render_struct(w: &mut String, name: &str, fields: &[Field]) {
+    w.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+    w.push_str(&format!("pub struct {name} {{\n"));
+
+    let mut idents = BTreeSet::new();
+
+    for Field {
+        name,
+        doc,
+        type_,
+        required,
+    } in fields
+    {
+        let ident = unique(field_ident(name), &mut idents);
+
+        let mut attrs = Vec::new();
+        if ident.trim_start_matches("r#") != name {
+            attrs.push(format!(
+                "rename = {}",
+                serde_json::Value::String(name.clone())
+            ));
+        }
+
+        let mut type_ = expr(type_, true);
+        if !required {
+            if !type_.starts_with("Option<") {
+                type_ = format!("Option<{type_}>");
+            }
+            attrs.push("default".to_string());
+            attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
+        }
+
+        push_doc(w, "    ", "///", doc);
+        if !attrs.is_empty() {
+            w.push_str(&format!("    #[serde({})]\n", attrs.join(", ")));
+        }
+        w.push_str(&format!("    pub {ident}: {type_},\n"));
+    }
+    w.push_str("}\n");
+}
+
+// Render a type expression. Recursive references are boxed, unless `boxed` is false
+// because the expression is already within a heap-allocated container.
+fn expr(type_: &Type, boxed: bool) -> String {
+    if let Some(type_) = type_.nullable() {
+        return format!("Option<{}>", expr(type_, boxed));
+    }
+
+    match type_ {
+        Type::Any | Type::Never | Type::Union(_) | Type::Object(_) => {
+            "serde_json::Value".to_string()
+        }
+        Type::Null => "()".to_string(),
+        Type::Bool => "bool".to_string(),
+        Type::Int => "i64".to_string(),
+        Type::Float => "f64".to_string(),
+        Type::Str => "String".to_string(),
+        Type::Enum(values) => match Type::enum_type(values) {
+            Type::Enum(_) => "serde_json::Value".to_string(),
+            type_ => expr(&type_, boxed),
+        },
+        Type::Array(item) => format!("Vec<{}>", expr(item, false)),
+        Type::Map(value) => format!("std::collections::BTreeMap<String, {}>", expr(value, false)),
+        Type::Ref(name) if boxed => format!("Box<{name}>"),
+        Type::Ref(name) | Type::Named(name) => name.clone(),
+    }
+}
+
+fn field_ident(name: &str) -> String {
+    let ident = snake_case(name);
+
+    if matches!(ident.as_str(), "self" | "super" | "crate") {
+        format!("{ident}_")
+    } else if RUST_KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{ident}")
+    } else {
+        ident
+    }
+}
+
+fn unique(ident: String, idents: &mut BTreeSet<String>) -> String {
+    let mut out = ident.clone();
+    for n in 2.. {
+        if idents.insert(out.clone()) {
+            break;
+        }
+        out = format!("{ident}_{n}");
+    }
+    out
+}
+
+// Keywords of Rust, which are used as raw identifiers.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
@@ -291,7 +291,7 @@ fn indirect_derivation(
                 threshold,
             );
         }
-        models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations }) => {
+        models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations, .. }) => {
             is_sql = true;
 
             for (index, migration) in migrations.iter_mut().enumerate() {
@@ -324,6 +324,20 @@ fn indirect_derivation(
                 threshold,
             );
         }
+        models::DeriveUsing::Python(models::DeriveUsingPython { module }) => {
+            indirect_raw(
+                Scope::new(scope)
+                    .push_prop("derive")
+                    .push_prop("using")
+                    .push_prop("python")
+                    .push_prop("module"),
+                module,
+                format!("{base}.py"),
+                imports,
+                resources,
+                threshold,
+            );
+        }
     }
 
     for (
@@ -160,7 +160,7 @@ fn inline_derivation(
             imports,
             resources,
         ),
-        models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations }) => {
+        models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations, .. }) => {
             for (index, migration) in migrations.iter_mut().enumerate() {
                 inline_config(
                     Scope::new(scope)
@@ -187,6 +187,18 @@ fn inline_derivation(
                 resources,
             );
         }
+        models::DeriveUsing::Python(models::DeriveUsingPython { module }) => {
+            inline_config(
+                Scope::new(scope)
+                    .push_prop("derive")
+                    .push_prop("using")
+                    .push_prop("python")
+                    .push_prop("module"),
+                module,
+                imports,
+                resources,
+            );
+        }
     }
 
     for (
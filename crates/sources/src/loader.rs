@@ -502,7 +502,7 @@ impl<F: Fetcher> Loader<F> {
                     .boxed(),
                 );
             }
-            models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations }) => {
+            models::DeriveUsing::Sqlite(models::DeriveUsingSqlite { migrations, .. }) => {
                 for (index, migration) in migrations.iter().enumerate() {
                     tasks.push(
                         async move {
@@ -535,6 +535,21 @@ impl<F: Fetcher> Loader<F> {
                     .boxed(),
                 );
             }
+            models::DeriveUsing::Python(models::DeriveUsingPython { module, .. }) => {
+                tasks.push(
+                    async move {
+                        self.load_config(
+                            scope
+                                .push_prop("using")
+                                .push_prop("python")
+                                .push_prop("module"),
+                            module,
+                        )
+                        .await
+                    }
+                    .boxed(),
+                );
+            }
             models::DeriveUsing::Local(models::LocalConfig { config, .. }) => {
                 tasks.push(
                     async move {
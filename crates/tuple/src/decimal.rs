@@ -0,0 +1,435 @@
+use super::Element;
+use std::{cmp, fmt};
+
+/// Decimal is an arbitrary-precision decimal number, as might be represented
+/// by a JSON number or a numeric string. It's held in a normalized form of
+/// `0.d1 d2 ... dn * 10^exponent`, where `d1` and `dn` are non-zero,
+/// so that equal values always have an equal representation.
+///
+/// Decimals pack into an encoding whose byte order matches their numeric order:
+/// negative infinity sorts first, and NaN sorts last.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    class: Class,
+    exponent: i32,
+    digits: Vec<u8>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Class {
+    NegInfinity = 0x00,
+    Negative = 0x01,
+    Zero = 0x02,
+    Positive = 0x03,
+    Infinity = 0x04,
+    NaN = 0x05,
+}
+
+impl Class {
+    pub(crate) fn from_u8(b: u8) -> Option<Self> {
+        Some(match b {
+            0x00 => Class::NegInfinity,
+            0x01 => Class::Negative,
+            0x02 => Class::Zero,
+            0x03 => Class::Positive,
+            0x04 => Class::Infinity,
+            0x05 => Class::NaN,
+            _ => return None,
+        })
+    }
+}
+
+impl Decimal {
+    pub fn zero() -> Self {
+        Self::special(Class::Zero)
+    }
+    pub fn infinity() -> Self {
+        Self::special(Class::Infinity)
+    }
+    pub fn neg_infinity() -> Self {
+        Self::special(Class::NegInfinity)
+    }
+    pub fn nan() -> Self {
+        Self::special(Class::NaN)
+    }
+
+    fn special(class: Class) -> Self {
+        Self {
+            class,
+            exponent: 0,
+            digits: Vec::new(),
+        }
+    }
+
+    /// Parse a Decimal from its string representation.
+    ///
+    /// Accepted strings are an optional sign, integral and fractional digits
+    /// which may be separated by underscores, and an optional exponent.
+    /// "NaN", "Infinity", and "-Infinity" are also accepted.
+    /// None is returned if `s` isn't a decimal, or if its exponent is out of range.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "NaN" => return Some(Self::nan()),
+            "Infinity" => return Some(Self::infinity()),
+            "-Infinity" => return Some(Self::neg_infinity()),
+            _ => (),
+        }
+        let mut b = s.as_bytes();
+
+        let negative = match b.first() {
+            Some(b'-') => {
+                b = &b[1..];
+                true
+            }
+            Some(b'+') => {
+                b = &b[1..];
+                false
+            }
+            _ => false,
+        };
+
+        // Split off an exponent suffix.
+        let mut exponent: i64 = 0;
+        if let Some(ind) = b.iter().position(|c| *c == b'e' || *c == b'E') {
+            exponent = std::str::from_utf8(&b[ind + 1..]).ok()?.parse().ok()?;
+            b = &b[..ind];
+        }
+
+        let mut digits = Vec::new();
+        let mut integral = 0i64; // Number of integral digits within `digits`.
+        let mut seen_point = false;
+        let mut seen_digit = false;
+        let mut prev = None;
+
+        for &c in b {
+            match c {
+                b'0'..=b'9' => {
+                    seen_digit = true;
+                    if c == b'0' && digits.is_empty() {
+                        // Leading zeros of the integral part are insignificant,
+                        // while those of the fractional part shift the exponent.
+                        if seen_point {
+                            integral -= 1;
+                        }
+                    } else {
+                        digits.push(c - b'0');
+                        if !seen_point {
+                            integral += 1;
+                        }
+                    }
+                }
+                b'_' if matches!(prev, Some(b'0'..=b'9')) => {}
+                b'.' if !seen_point && prev != Some(b'_') => seen_point = true,
+                _ => return None,
+            }
+            prev = Some(c);
+        }
+        if !seen_digit || prev == Some(b'_') {
+            return None;
+        }
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            return Some(Self::zero());
+        }
+        let exponent = i32::try_from(exponent.checked_add(integral)?).ok()?;
+
+        Some(Self {
+            class: if negative {
+                Class::Negative
+            } else {
+                Class::Positive
+            },
+            exponent,
+            digits,
+        })
+    }
+
+    pub(crate) fn from_parts(class: Class, exponent: i32, digits: Vec<u8>) -> Self {
+        Self {
+            class,
+            exponent,
+            digits,
+        }
+    }
+
+    pub(crate) fn class(&self) -> Class {
+        self.class
+    }
+
+    /// Exponent of the normalized form `0.d1 d2 ... dn * 10^exponent`.
+    /// It's zero if the Decimal is not a finite, non-zero number.
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Significant digits of the normalized form, each in the range 0-9.
+    /// It's empty if the Decimal is not a finite, non-zero number.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    pub fn is_sign_negative(&self) -> bool {
+        matches!(self.class, Class::Negative | Class::NegInfinity)
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.class == Class::NaN
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(v: i64) -> Self {
+        Self::parse(&v.to_string()).unwrap()
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(v: u64) -> Self {
+        Self::parse(&v.to_string()).unwrap()
+    }
+}
+
+impl From<f64> for Decimal {
+    fn from(v: f64) -> Self {
+        if v.is_nan() {
+            Self::nan()
+        } else if v.is_infinite() {
+            if v.is_sign_negative() {
+                Self::neg_infinity()
+            } else {
+                Self::infinity()
+            }
+        } else {
+            // LowerExp renders the shortest digits which round-trip.
+            Self::parse(&format!("{:e}", v)).unwrap()
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.class.cmp(&other.class).then_with(|| {
+            // Normalization means a larger exponent is always a larger magnitude,
+            // and otherwise digits compare lexicographically.
+            let ord = (self.exponent, &self.digits).cmp(&(other.exponent, &other.digits));
+
+            match self.class {
+                Class::Negative => ord.reverse(),
+                Class::Positive => ord,
+                _ => cmp::Ordering::Equal,
+            }
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.class {
+            Class::NaN => return f.write_str("NaN"),
+            Class::Infinity => return f.write_str("Infinity"),
+            Class::NegInfinity => return f.write_str("-Infinity"),
+            Class::Zero => return f.write_str("0"),
+            Class::Negative => f.write_str("-")?,
+            Class::Positive => (),
+        }
+        let digits: String = self.digits.iter().map(|d| (b'0' + d) as char).collect();
+        let (e, n) = (self.exponent as i64, digits.len() as i64);
+
+        if !(-5..=21).contains(&e) {
+            // Use scientific notation for very large or small magnitudes.
+            let (head, tail) = digits.split_at(1);
+            f.write_str(head)?;
+            if !tail.is_empty() {
+                write!(f, ".{}", tail)?;
+            }
+            write!(f, "e{}", e - 1)
+        } else if e <= 0 {
+            write!(f, "0.{}{}", "0".repeat(-e as usize), digits)
+        } else if e < n {
+            let (head, tail) = digits.split_at(e as usize);
+            write!(f, "{}.{}", head, tail)
+        } else {
+            write!(f, "{}{}", digits, "0".repeat((e - n) as usize))
+        }
+    }
+}
+
+impl fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<'a> Element<'a> {
+    pub fn as_decimal(&self) -> Option<&Decimal> {
+        match self {
+            Element::Decimal(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{pack, unpack, Element};
+    use super::Decimal;
+    use quickcheck::{Arbitrary, Gen};
+    use quickcheck_macros::quickcheck;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_parse_and_display() {
+        for (input, expect) in [
+            ("0", Some("0")),
+            ("-0.000", Some("0")),
+            ("00012.3400", Some("12.34")),
+            ("+1_234.5", Some("1234.5")),
+            ("-0.00123", Some("-0.00123")),
+            ("1.5e3", Some("1500")),
+            ("1.5E-3", Some("0.0015")),
+            ("12e+20", Some("1.2e21")),
+            ("12e19", Some("120000000000000000000")),
+            ("12e21", Some("1.2e22")),
+            ("-7e-9", Some("-7e-9")),
+            (".5", Some("0.5")),
+            ("5.", Some("5")),
+            ("NaN", Some("NaN")),
+            ("-Infinity", Some("-Infinity")),
+            ("", None),
+            (".", None),
+            ("-", None),
+            ("1_", None),
+            ("_1", None),
+            ("1.2.3", None),
+            ("1_.5", None),
+            ("1e", None),
+            ("1e2.5", None),
+            ("1e+-2", None),
+            (" 1", None),
+            ("nan", None),
+            ("0x10", None),
+            ("1e9999999999", None),
+        ] {
+            assert_eq!(
+                Decimal::parse(input).map(|d| d.to_string()).as_deref(),
+                expect,
+                "input {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_packed_fixtures() {
+        for (input, expect) in [
+            ("-Infinity", &b"\x40\x00"[..]),
+            ("-12.5", b"\x40\x01\x7f\xff\xff\xfd\xdc\x9f"),
+            ("-1", b"\x40\x01\x7f\xff\xff\xfe\xdf"),
+            ("0", b"\x40\x02"),
+            ("1", b"\x40\x03\x80\x00\x00\x01\x20"),
+            ("12.5", b"\x40\x03\x80\x00\x00\x02\x23\x60"),
+            ("0.05", b"\x40\x03\x7f\xff\xff\xff\x60"),
+            ("Infinity", b"\x40\x04"),
+            ("NaN", b"\x40\x05"),
+        ] {
+            let d = Decimal::parse(input).unwrap();
+            assert_eq!(pack(&d), expect, "input {input}");
+            assert_eq!(unpack::<Decimal>(expect).unwrap(), d);
+        }
+        // Decimals round-trip within nested tuples.
+        let nested = (Element::Tuple(vec![
+            Element::Decimal(Decimal::parse("-3.25").unwrap()),
+            Element::Nil,
+            Element::Decimal(Decimal::zero()),
+        ]),);
+        assert_eq!(unpack::<(Element,)>(&pack(&nested)).unwrap(), nested);
+    }
+
+    /// Value is a decimal `mantissa * 10^shift` alongside a rendering of it,
+    /// which is compared exactly through i128 arithmetic.
+    #[derive(Clone, Debug)]
+    struct Value {
+        mantissa: i32,
+        shift: i8,
+        rendered: String,
+    }
+
+    impl Value {
+        fn scaled(&self, to: i8) -> i128 {
+            self.mantissa as i128 * 10i128.pow((self.shift - to) as u32)
+        }
+    }
+
+    impl Arbitrary for Value {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let mantissa = i16::arbitrary(g) as i32 * *g.choose(&[1, 10, 1000]).unwrap();
+            let shift = i8::arbitrary(g).rem_euclid(25) - 12;
+
+            // Render in plain or scientific form, with insignificant zeros.
+            let rendered = if bool::arbitrary(g) {
+                format!("{mantissa}e{shift}")
+            } else {
+                let abs = mantissa.unsigned_abs().to_string();
+                let sign = if mantissa < 0 { "-" } else { "" };
+                if shift >= 0 {
+                    format!("{sign}{abs}{}.00", "0".repeat(shift as usize))
+                } else {
+                    let padded = format!("{}{abs}", "0".repeat(-shift as usize));
+                    let (head, tail) = padded.split_at(padded.len() - (-shift as usize));
+                    format!("{sign}{head}.{tail}0")
+                }
+            };
+            Self {
+                mantissa,
+                shift,
+                rendered,
+            }
+        }
+    }
+
+    #[quickcheck]
+    fn test_packed_order_matches_decimal_order(a: Value, b: Value) -> bool {
+        let to = a.shift.min(b.shift);
+        let expect = a.scaled(to).cmp(&b.scaled(to));
+
+        let (da, db) = (
+            Decimal::parse(&a.rendered).unwrap(),
+            Decimal::parse(&b.rendered).unwrap(),
+        );
+        let (pa, pb) = (pack(&da), pack(&db));
+
+        // Also pack with a suffix, to verify the encoding is self-delimiting.
+        let (sa, sb) = (pack(&(&da, "suffix")), pack(&(&db, "suffix")));
+
+        da.cmp(&db) == expect
+            && pa.cmp(&pb) == expect
+            && sa.cmp(&sb) == expect
+            && unpack::<Decimal>(&pa).unwrap() == da
+            && Decimal::parse(&da.to_string()).unwrap() == da
+    }
+
+    #[quickcheck]
+    fn test_packed_order_matches_f64_order(a: f64, b: f64) -> bool {
+        let (da, db) = (Decimal::from(a), Decimal::from(b));
+        let (pa, pb) = (pack(&da), pack(&db));
+
+        let expect = match a.partial_cmp(&b) {
+            Some(ord) => ord,
+            None if a.is_nan() && b.is_nan() => Ordering::Equal,
+            None if a.is_nan() => Ordering::Greater,
+            None => Ordering::Less,
+        };
+        // Finite values also round-trip through their Display.
+        let round_trip = !a.is_finite() || da.to_string().parse::<f64>().unwrap() == a;
+
+        pa.cmp(&pb) == expect && round_trip
+    }
+}
@@ -1,5 +1,5 @@
 use super::pack::{f32_to_u32_be_bytes, f64_to_u64_be_bytes};
-use super::{Bytes, Versionstamp};
+use super::{Bytes, Decimal, Timestamp, Versionstamp};
 use std::{borrow::Cow, cmp};
 
 #[cfg(feature = "num-bigint")]
@@ -22,6 +22,8 @@ pub enum Element<'a> {
     #[cfg(feature = "uuid")]
     Uuid(uuid::Uuid),
     Versionstamp(Versionstamp),
+    Decimal(Decimal),
+    Timestamp(Timestamp),
 }
 
 struct CmpElement<'a, 'b>(&'a Element<'b>);
@@ -74,6 +76,8 @@ impl<'a, 'b> Ord for CmpElement<'a, 'b> {
                 #[cfg(feature = "uuid")]
                 (Element::Uuid(a), Element::Uuid(b)) => a.cmp(b),
                 (Element::Versionstamp(a), Element::Versionstamp(b)) => a.cmp(b),
+                (Element::Decimal(a), Element::Decimal(b)) => a.cmp(b),
+                (Element::Timestamp(a), Element::Timestamp(b)) => a.cmp(b),
                 _ => cmp::Ordering::Equal,
             })
     }
@@ -119,6 +123,8 @@ impl<'a> Element<'a> {
             #[cfg(feature = "uuid")]
             Element::Uuid(_) => super::UUID,
             Element::Versionstamp(_) => super::VERSIONSTAMP,
+            Element::Decimal(_) => super::DECIMAL,
+            Element::Timestamp(_) => super::TIMESTAMP,
         }
     }
 
@@ -151,6 +157,8 @@ impl<'a> Element<'a> {
             #[cfg(feature = "uuid")]
             Element::Uuid(v) => Element::Uuid(v),
             Element::Versionstamp(v) => Element::Versionstamp(v),
+            Element::Decimal(v) => Element::Decimal(v),
+            Element::Timestamp(v) => Element::Timestamp(v),
         }
     }
 
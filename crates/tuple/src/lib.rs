@@ -12,6 +12,9 @@ mod versionstamp;
 
 // NOTE(johnny): Added.
 mod value;
+// Added: order-preserving encodings of decimals and timestamps.
+mod decimal;
+mod timestamp;
 
 use std::borrow::Cow;
 use std::fmt::{self, Display};
@@ -22,9 +25,11 @@ use std::result;
 #[cfg(feature = "uuid")]
 pub use uuid::Uuid;
 
+pub use decimal::Decimal;
 pub use element::Element;
 pub use pack::{TuplePack, TupleUnpack, VersionstampOffset};
 // pub use subspace::Subspace;
+pub use timestamp::Timestamp;
 pub use versionstamp::Versionstamp;
 
 const NIL: u8 = 0x00;
@@ -43,6 +48,9 @@ const UUID: u8 = 0x30;
 // Not a single official binding is implementing 80 Bit versionstamp...
 // const VERSIONSTAMP_88: u8 = 0x32;
 const VERSIONSTAMP: u8 = 0x33;
+// Codes 0x40 through 0x4f are reserved by the specification for user types.
+const DECIMAL: u8 = 0x40;
+const TIMESTAMP: u8 = 0x41;
 
 const ESCAPE: u8 = 0xff;
 
@@ -1,3 +1,4 @@
+use super::decimal::Class as DecimalClass;
 use super::*;
 use memchr::memchr_iter;
 use std::convert::TryFrom;
@@ -943,6 +944,8 @@ impl<'a> TuplePack for Element<'a> {
             Element::String(ref c) => c.pack(w, tuple_depth),
             Element::Bytes(ref b) => b.pack(w, tuple_depth),
             Element::Versionstamp(ref b) => b.pack(w, tuple_depth),
+            Element::Decimal(ref d) => d.pack(w, tuple_depth),
+            Element::Timestamp(ref t) => t.pack(w, tuple_depth),
             Element::Tuple(ref v) => v.pack(w, tuple_depth),
             #[cfg(feature = "uuid")]
             Element::Uuid(v) => v.pack(w, tuple_depth),
@@ -1025,6 +1028,14 @@ impl<'de> TupleUnpack<'de> for Element<'de> {
                 let (input, v) = Versionstamp::unpack(input, tuple_depth)?;
                 (input, Element::Versionstamp(v))
             }
+            DECIMAL => {
+                let (input, v) = Decimal::unpack(input, tuple_depth)?;
+                (input, Element::Decimal(v))
+            }
+            TIMESTAMP => {
+                let (input, v) = Timestamp::unpack(input, tuple_depth)?;
+                (input, Element::Timestamp(v))
+            }
             #[cfg(feature = "uuid")]
             UUID => {
                 let (input, v) = uuid::Uuid::unpack(input, tuple_depth)?;
@@ -1079,6 +1090,121 @@ impl<'de> TupleUnpack<'de> for Versionstamp {
     }
 }
 
+// Decimals are encoded as a class byte which orders special values and signs,
+// followed for non-zero finite values by the big-endian exponent with its sign
+// bit flipped, and then by digits as nibbles offset by one, terminated by a
+// zero nibble and padded to a whole byte. The exponent and digits of negative
+// values are inverted, so that larger magnitudes order first.
+impl TuplePack for Decimal {
+    fn pack<W: io::Write>(
+        &self,
+        w: &mut W,
+        _tuple_depth: TupleDepth,
+    ) -> io::Result<VersionstampOffset> {
+        let class = self.class();
+        w.write_all(&[DECIMAL, class as u8])?;
+
+        if !matches!(class, DecimalClass::Positive | DecimalClass::Negative) {
+            return Ok(VersionstampOffset::None { size: 2 });
+        }
+
+        let mut bytes = Vec::with_capacity(4 + self.digits().len() / 2 + 1);
+        bytes.extend_from_slice(&(self.exponent() as u32 ^ sign_bit!(u32)).to_be_bytes());
+
+        for pair in self.digits().chunks(2) {
+            let hi = pair[0] + 1;
+            let lo = pair.get(1).map(|d| d + 1).unwrap_or(0);
+            bytes.push(hi << 4 | lo);
+        }
+        if self.digits().len() % 2 == 0 {
+            bytes.push(0);
+        }
+
+        if class == DecimalClass::Negative {
+            for b in bytes.iter_mut() {
+                *b = !*b;
+            }
+        }
+        w.write_all(&bytes)?;
+
+        Ok(VersionstampOffset::None {
+            size: 2 + bytes.len() as u32,
+        })
+    }
+}
+
+impl<'de> TupleUnpack<'de> for Decimal {
+    fn unpack(input: &[u8], _tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+        let input = parse_code(input, DECIMAL)?;
+        let (input, found) = parse_byte(input)?;
+
+        let class = DecimalClass::from_u8(found).ok_or_else(|| {
+            PackError::Message(format!("{} is not a valid decimal class", found).into_boxed_str())
+        })?;
+        let negative = match class {
+            DecimalClass::Positive => false,
+            DecimalClass::Negative => true,
+            _ => return Ok((input, Decimal::from_parts(class, 0, Vec::new()))),
+        };
+        let invert: u8 = if negative { !0 } else { 0 };
+
+        let (mut input, exponent) = parse_u32(input)?;
+        let exponent = if negative { !exponent } else { exponent };
+        let exponent = (exponent ^ sign_bit!(u32)) as i32;
+        let mut digits = Vec::new();
+
+        loop {
+            let (rem, b) = parse_byte(input)?;
+            input = rem;
+            let b = b ^ invert;
+
+            let (hi, lo) = (b >> 4, b & 0x0f);
+            for nibble in [hi, lo] {
+                match nibble {
+                    0 if digits.is_empty() => {
+                        return Err(PackError::Message("decimal has no digits".into()))
+                    }
+                    0 => return Ok((input, Decimal::from_parts(class, exponent, digits))),
+                    1..=10 => digits.push(nibble - 1),
+                    _ => {
+                        return Err(PackError::Message(
+                            format!("{} is not a valid decimal digit", nibble).into_boxed_str(),
+                        ))
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Timestamps are encoded as big-endian seconds since the epoch, with the sign
+// bit flipped, followed by big-endian nanoseconds.
+impl TuplePack for Timestamp {
+    fn pack<W: io::Write>(
+        &self,
+        w: &mut W,
+        _tuple_depth: TupleDepth,
+    ) -> io::Result<VersionstampOffset> {
+        w.write_all(&[TIMESTAMP])?;
+        w.write_all(&(self.seconds() as u64 ^ sign_bit!(u64)).to_be_bytes())?;
+        w.write_all(&self.nanos().to_be_bytes())?;
+        Ok(VersionstampOffset::None { size: 1 + 12 })
+    }
+}
+
+impl<'de> TupleUnpack<'de> for Timestamp {
+    fn unpack(input: &[u8], _tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+        let input = parse_code(input, TIMESTAMP)?;
+        let (input, seconds) = parse_u64(input)?;
+        let (input, nanos) = parse_u32(input)?;
+
+        let ts = Timestamp::new((seconds ^ sign_bit!(u64)) as i64, nanos).ok_or_else(|| {
+            PackError::Message(format!("{} is not a valid nanosecond", nanos).into_boxed_str())
+        })?;
+        Ok((input, ts))
+    }
+}
+
 #[cfg(feature = "uuid")]
 mod pack_uuid {
     use super::*;
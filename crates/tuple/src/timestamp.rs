@@ -0,0 +1,115 @@
+use super::Element;
+
+/// Timestamp is an instant in time, as seconds and nanoseconds since the
+/// Unix epoch. Timestamps pack into an encoding whose byte order matches
+/// their time order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    seconds: i64,
+    nanos: u32,
+}
+
+impl Timestamp {
+    /// Build a Timestamp from seconds since the Unix epoch, and a
+    /// non-negative sub-second offset in nanoseconds.
+    /// None is returned if `nanos` is not less than one second.
+    pub fn new(seconds: i64, nanos: u32) -> Option<Self> {
+        if nanos < 1_000_000_000 {
+            Some(Self { seconds, nanos })
+        } else {
+            None
+        }
+    }
+
+    /// Build a Timestamp from nanoseconds since the Unix epoch.
+    /// None is returned if it's outside of the representable range.
+    pub fn from_unix_nanos(nanos: i128) -> Option<Self> {
+        let seconds = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        Self::new(seconds, nanos.rem_euclid(1_000_000_000) as u32)
+    }
+
+    pub fn seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    pub fn nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    pub fn unix_nanos(&self) -> i128 {
+        self.seconds as i128 * 1_000_000_000 + self.nanos as i128
+    }
+}
+
+impl<'a> Element<'a> {
+    pub fn as_timestamp(&self) -> Option<&Timestamp> {
+        match self {
+            Element::Timestamp(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{pack, unpack, PackError};
+    use super::Timestamp;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_packed_fixtures() {
+        for (seconds, nanos, expect) in [
+            (
+                i64::MIN,
+                0,
+                &b"\x41\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"[..],
+            ),
+            (
+                -1,
+                999_999_999,
+                b"\x41\x7f\xff\xff\xff\xff\xff\xff\xff\x3b\x9a\xc9\xff",
+            ),
+            (
+                0,
+                0,
+                b"\x41\x80\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+            ),
+            (
+                1,
+                1,
+                b"\x41\x80\x00\x00\x00\x00\x00\x00\x01\x00\x00\x00\x01",
+            ),
+        ] {
+            let ts = Timestamp::new(seconds, nanos).unwrap();
+            assert_eq!(pack(&ts), expect);
+            assert_eq!(unpack::<Timestamp>(expect).unwrap(), ts);
+        }
+
+        assert!(matches!(
+            unpack::<Timestamp>(b"\x41\x80\x00\x00\x00\x00\x00\x00\x00\x3b\x9a\xca\x00"),
+            Err(PackError::Message(_))
+        ));
+        assert!(matches!(
+            unpack::<Timestamp>(b"\x41\x80\x00"),
+            Err(PackError::MissingBytes)
+        ));
+    }
+
+    #[quickcheck]
+    fn test_packed_order_matches_time_order(a: i64, b: i64, scale: u8) -> bool {
+        // Scale nanoseconds so that both nearby and distant instants are compared.
+        let scale = 10i128.pow((scale % 10) as u32);
+        let (a, b) = (a as i128 * scale, b as i128 * scale);
+
+        let (ta, tb) = (
+            Timestamp::from_unix_nanos(a).unwrap(),
+            Timestamp::from_unix_nanos(b).unwrap(),
+        );
+        let (pa, pb) = (pack(&(ta, "suffix")), pack(&(tb, "suffix")));
+
+        ta.unix_nanos() == a
+            && ta.cmp(&tb) == a.cmp(&b)
+            && pa.cmp(&pb) == a.cmp(&b)
+            && unpack::<(Timestamp, String)>(&pa).unwrap().0 == ta
+    }
+}
@@ -65,9 +65,13 @@ pub async fn walk_all_captures(
         let tables::Capture {
             scope,
             capture: _,
-            spec: models::CaptureDef {
-                interval, shards, ..
-            },
+            spec:
+                models::CaptureDef {
+                    bindings: model_bindings,
+                    interval,
+                    shards,
+                    ..
+                },
         } = capture;
         let scope = Scope::new(scope);
 
@@ -160,6 +164,23 @@ pub async fn walk_all_captures(
             errors,
         );
 
+        let mut shard_template = assemble::shard_template(
+            build_id,
+            &name,
+            labels::TASK_TYPE_CAPTURE,
+            &shards,
+            false, // Don't disable wait_for_ack.
+            &network_ports,
+        );
+        // Built bindings are the bindings of the model which are not disabled.
+        assemble::binding_rate_limit_labels(
+            &mut shard_template,
+            model_bindings
+                .iter()
+                .filter(|b| !b.disable)
+                .map(|b| b.rate_limit.as_ref()),
+        );
+
         let spec = flow::CaptureSpec {
             name: name.clone(),
             connector_type: *connector_type,
@@ -172,14 +193,7 @@ pub async fn walk_all_captures(
                 labels::TASK_TYPE_CAPTURE,
                 recovery_stores,
             )),
-            shard_template: Some(assemble::shard_template(
-                build_id,
-                &name,
-                labels::TASK_TYPE_CAPTURE,
-                &shards,
-                false, // Don't disable wait_for_ack.
-                &network_ports,
-            )),
+            shard_template: Some(shard_template),
             network_ports,
         };
         built_captures.insert_row(scope.flatten(), std::mem::take(name), validated, spec);
@@ -265,6 +279,7 @@ fn walk_capture_binding<'a>(
         target,
         disable: _,
         backfill,
+        rate_limit: _,
     } = binding;
 
     // We must resolve the target collection to continue.
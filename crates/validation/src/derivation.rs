@@ -330,9 +330,11 @@ fn walk_derive_request<'a>(
         using,
         transforms,
         shuffle_key_types: given_shuffle_types,
-        shards: _,
+        shards,
     } = derivation;
 
+    super::walk_capture_only_shard_fields(scope.push_prop("shards"), "derivation", shards, errors);
+
     let (connector_type, config_json) = match using {
         models::DeriveUsing::Connector(config) => (
             ConnectorType::Image as i32,
@@ -350,6 +352,10 @@ fn walk_derive_request<'a>(
             ConnectorType::Typescript as i32,
             serde_json::to_string(config).unwrap(),
         ),
+        models::DeriveUsing::Python(config) => (
+            ConnectorType::Python as i32,
+            serde_json::to_string(config).unwrap(),
+        ),
     };
 
     let scope = scope.push_prop("transforms");
@@ -183,6 +183,11 @@ pub enum Error {
         resource: String,
         rhs_scope: Url,
     },
+    #[error("{entity} shards set `{field}`, which is currently enforced only by captures")]
+    ShardFieldUnsupported {
+        entity: &'static str,
+        field: &'static str,
+    },
     #[error(transparent)]
     SchemaBuild(#[from] json::schema::build::Error),
     #[error(transparent)]
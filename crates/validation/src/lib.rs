@@ -258,3 +258,27 @@ pub async fn validate(
         errors,
     }
 }
+
+// Shard template fields which only captures currently enforce are rejected
+// for other task types, rather than being silently ignored.
+fn walk_capture_only_shard_fields(
+    scope: Scope,
+    entity: &'static str,
+    shards: &models::ShardTemplate,
+    errors: &mut tables::Errors,
+) {
+    if shards.rate_limit.is_some() {
+        Error::ShardFieldUnsupported {
+            entity,
+            field: "rateLimit",
+        }
+        .push(scope.push_prop("rateLimit"), errors);
+    }
+    if shards.max_combiner_bytes.is_some() {
+        Error::ShardFieldUnsupported {
+            entity,
+            field: "maxCombinerBytes",
+        }
+        .push(scope.push_prop("maxCombinerBytes"), errors);
+    }
+}
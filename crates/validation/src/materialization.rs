@@ -258,12 +258,23 @@ fn walk_materialization_request<'a>(
     let tables::Materialization {
         scope,
         materialization: name,
-        spec: models::MaterializationDef {
-            endpoint, bindings, ..
-        },
+        spec:
+            models::MaterializationDef {
+                endpoint,
+                bindings,
+                shards,
+                ..
+            },
     } = materialization;
     let scope = Scope::new(scope);
 
+    super::walk_capture_only_shard_fields(
+        scope.push_prop("shards"),
+        "materialization",
+        shards,
+        errors,
+    );
+
     // Require the materialization name is valid.
     indexed::walk_name(
         scope,
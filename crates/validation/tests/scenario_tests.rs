@@ -545,6 +545,27 @@ driver: {}
     insta::assert_debug_snapshot!(errors);
 }
 
+#[test]
+fn test_capture_only_shard_fields() {
+    let errors = run_test_errors(
+        &GOLDEN,
+        r#"
+test://example/int-halve:
+  collections:
+    testing/int-halve:
+      derive:
+        shards:
+          rateLimit: { docsPerSecond: 100 }
+test://example/webhook-deliveries:
+  materializations:
+    testing/webhook/deliveries:
+      shards:
+        maxCombinerBytes: 1048576
+"#,
+    );
+    insta::assert_debug_snapshot!(errors);
+}
+
 #[test]
 fn test_invalid_test_names_and_duplicates() {
     let errors = run_test_errors(
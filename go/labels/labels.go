@@ -68,6 +68,18 @@ const (
 	ExposePort       = "estuary.dev/expose-port"
 	PortProtoPrefix  = "estuary.dev/port-proto/"
 	PortPublicPrefix = "estuary.dev/port-public/"
+
+	// MaxCombinerBytes is the number of bytes of documents which a task shard
+	// may combine within a transaction before it stops reading from its connector.
+	MaxCombinerBytes = "estuary.dev/max-combiner-bytes"
+	// RateLimitDocs and RateLimitBytes are rate limits, in documents or bytes per
+	// second, of documents processed by the task shard. Rate limits of individual
+	// bindings are given by the respective label prefix, suffixed by the binding
+	// index. For example, `estuary.dev/rate-limit-docs/3=1000`.
+	RateLimitDocs        = "estuary.dev/rate-limit-docs"
+	RateLimitDocsPrefix  = "estuary.dev/rate-limit-docs/"
+	RateLimitBytes       = "estuary.dev/rate-limit-bytes"
+	RateLimitBytesPrefix = "estuary.dev/rate-limit-bytes/"
 )
 
 // A re-exported subset of Gazette labels, defined in go.gazette.dev/core/labels/labels.go.
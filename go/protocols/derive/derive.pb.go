@@ -433,10 +433,14 @@ var xxx_messageInfo_Request_Flush proto.InternalMessageInfo
 // The connector responds with StartedCommit.
 type Request_StartCommit struct {
 	// Flow runtime checkpoint associated with this transaction.
-	RuntimeCheckpoint    *protocol.Checkpoint `protobuf:"bytes,1,opt,name=runtime_checkpoint,json=runtimeCheckpoint,proto3" json:"runtime_checkpoint,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}             `json:"-"`
-	XXX_unrecognized     []byte               `json:"-"`
-	XXX_sizecache        int32                `json:"-"`
+	RuntimeCheckpoint *protocol.Checkpoint `protobuf:"bytes,1,opt,name=runtime_checkpoint,json=runtimeCheckpoint,proto3" json:"runtime_checkpoint,omitempty"`
+	// Sources and acknowledgement intents of `runtime_checkpoint` which were
+	// added or changed since the previous StartCommit or, for the first
+	// transaction of a session, since the checkpoint of Opened.
+	RuntimeCheckpointDelta *protocol.Checkpoint `protobuf:"bytes,2,opt,name=runtime_checkpoint_delta,json=runtimeCheckpointDelta,proto3" json:"runtime_checkpoint_delta,omitempty"`
+	XXX_NoUnkeyedLiteral   struct{}             `json:"-"`
+	XXX_unrecognized       []byte               `json:"-"`
+	XXX_sizecache          int32                `json:"-"`
 }
 
 func (m *Request_StartCommit) Reset()         { *m = Request_StartCommit{} }
@@ -714,9 +718,14 @@ var xxx_messageInfo_Response_Validated_Transform proto.InternalMessageInfo
 
 // Opened responds to Request.Open.
 type Response_Opened struct {
-	XXX_NoUnkeyedLiteral struct{} `json:"-"`
-	XXX_unrecognized     []byte   `json:"-"`
-	XXX_sizecache        int32    `json:"-"`
+	// Flow runtime checkpoint to begin processing from.
+	// If empty, the most recent checkpoint of the Flow recovery log is used.
+	// Connectors which transactionally persist the checkpoints of StartCommit
+	// may return their last-committed checkpoint to recover exactly-once.
+	RuntimeCheckpoint    *protocol.Checkpoint `protobuf:"bytes,1,opt,name=runtime_checkpoint,json=runtimeCheckpoint,proto3" json:"runtime_checkpoint,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}             `json:"-"`
+	XXX_unrecognized     []byte               `json:"-"`
+	XXX_sizecache        int32                `json:"-"`
 }
 
 func (m *Response_Opened) Reset()         { *m = Response_Opened{} }
@@ -902,85 +911,86 @@ func init() {
 func init() { proto.RegisterFile("go/protocols/derive/derive.proto", fileDescriptor_4410d076c75e1e4f) }
 
 var fileDescriptor_4410d076c75e1e4f = []byte{
-	// 1248 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xcc, 0x57, 0xdd, 0x6e, 0x1b, 0xc5,
-	0x17, 0xef, 0x3a, 0xfe, 0xda, 0x13, 0x27, 0x69, 0xa7, 0xf9, 0xff, 0x59, 0x6d, 0xa2, 0x34, 0x94,
-	0x22, 0x0c, 0x11, 0x0e, 0x72, 0x2b, 0x28, 0xa5, 0x20, 0x1a, 0xb7, 0x41, 0x15, 0x54, 0xa9, 0x26,
-	0x0d, 0x95, 0xb8, 0xb1, 0x26, 0xbb, 0x63, 0x7b, 0x9b, 0xdd, 0x9d, 0x65, 0x66, 0x36, 0x95, 0x9f,
-	0x81, 0xfb, 0xf6, 0x16, 0xf1, 0x10, 0x95, 0x78, 0x83, 0x5c, 0xf2, 0x04, 0x95, 0x28, 0x6f, 0xc1,
-	0x15, 0x9a, 0x8f, 0xdd, 0xd8, 0x89, 0x53, 0xdc, 0x3b, 0x6e, 0x92, 0x39, 0xe7, 0xfc, 0xce, 0x99,
-	0xd9, 0x73, 0xce, 0x9c, 0xdf, 0x18, 0x36, 0x87, 0x6c, 0x3b, 0xe3, 0x4c, 0xb2, 0x80, 0xc5, 0x62,
-	0x3b, 0xa4, 0x3c, 0x3a, 0xa6, 0xf6, 0x5f, 0x47, 0xeb, 0x51, 0xdd, 0x48, 0xfe, 0x66, 0xc0, 0x52,
-	0x91, 0x27, 0x94, 0x97, 0xf8, 0x72, 0x61, 0x90, 0xfe, 0xfa, 0x54, 0xac, 0x41, 0xcc, 0x9e, 0xeb,
-	0x3f, 0xd6, 0xba, 0x3a, 0x64, 0x43, 0xa6, 0x97, 0xdb, 0x6a, 0x65, 0xb4, 0xd7, 0x7f, 0x59, 0x81,
-	0x06, 0xa6, 0x3f, 0xe7, 0x54, 0x48, 0xd4, 0x86, 0xaa, 0xc8, 0x68, 0xe0, 0x39, 0x9b, 0x4e, 0x7b,
-	0xb1, 0xbb, 0xda, 0xb1, 0xc7, 0xb0, 0xe6, 0xce, 0x7e, 0x46, 0x03, 0xac, 0x11, 0xe8, 0x16, 0x34,
-	0x8f, 0x49, 0x1c, 0x85, 0x44, 0x52, 0xaf, 0xa2, 0xd1, 0xde, 0x59, 0xf4, 0x8f, 0xd6, 0x8e, 0x4b,
-	0xa4, 0x8a, 0xcf, 0x32, 0x9a, 0x7a, 0x0b, 0xb3, 0xe3, 0xef, 0x65, 0x34, 0xc5, 0x1a, 0xa1, 0x90,
-	0x9c, 0x92, 0xd0, 0xab, 0xce, 0x46, 0x62, 0x4a, 0x42, 0xac, 0x11, 0x68, 0x0b, 0x6a, 0x83, 0x38,
-	0x17, 0x23, 0xaf, 0xa6, 0xa1, 0xff, 0x3b, 0x0b, 0xdd, 0x55, 0x46, 0x6c, 0x30, 0xe8, 0x1b, 0x68,
-	0x09, 0x49, 0xb8, 0xec, 0x07, 0x2c, 0x49, 0x22, 0xe9, 0xd5, 0xb5, 0xcf, 0xda, 0xb9, 0x0f, 0x55,
-	0x98, 0x9e, 0x86, 0xe0, 0x45, 0x71, 0x2a, 0xa8, 0xcd, 0x38, 0x15, 0x54, 0x7a, 0x8d, 0xd9, 0x9b,
-	0x61, 0x65, 0xc4, 0x06, 0x83, 0xd6, 0xa0, 0x19, 0xa5, 0x92, 0xf2, 0x94, 0xc4, 0x5e, 0xb8, 0xe9,
-	0xb4, 0x5b, 0xd8, 0xbd, 0x51, 0x28, 0xfc, 0x97, 0x0e, 0x54, 0x55, 0x3e, 0xd1, 0x13, 0x58, 0x0e,
-	0x58, 0x9a, 0xd2, 0x40, 0x32, 0xde, 0x97, 0xe3, 0x8c, 0xea, 0xec, 0x2f, 0x77, 0x3f, 0xed, 0xe8,
-	0xd2, 0xf5, 0x58, 0x1c, 0xd3, 0x40, 0x46, 0x2c, 0x55, 0xe8, 0xce, 0x7d, 0xb5, 0x1f, 0x51, 0x62,
-	0xa7, 0x57, 0x78, 0x3d, 0x19, 0x67, 0x14, 0x2f, 0x05, 0x93, 0x22, 0xfa, 0x12, 0x16, 0x03, 0x96,
-	0x0e, 0xa2, 0x61, 0xff, 0x99, 0x60, 0xa9, 0x2e, 0x91, 0xbb, 0xb3, 0xfe, 0xf7, 0xeb, 0x6b, 0x1e,
-	0x4d, 0x03, 0x16, 0x46, 0xe9, 0x70, 0x5b, 0x19, 0x3a, 0x98, 0x3c, 0x7f, 0x44, 0x85, 0x20, 0x43,
-	0x8a, 0xeb, 0xc6, 0xc1, 0x7f, 0x55, 0x87, 0x66, 0x51, 0xbb, 0xff, 0xdc, 0xe9, 0xd0, 0x1d, 0x80,
-	0xa0, 0xdc, 0xb4, 0x6c, 0xa4, 0x19, 0x87, 0xd9, 0xa9, 0x9e, 0xbc, 0xbe, 0x76, 0x09, 0x4f, 0xa0,
-	0xd1, 0x0e, 0x80, 0xe4, 0x24, 0x15, 0x03, 0xc6, 0x13, 0xe1, 0x55, 0x37, 0x17, 0xda, 0x8b, 0xdd,
-	0xeb, 0x17, 0xb5, 0x6d, 0xe7, 0x49, 0x01, 0xc5, 0x13, 0x5e, 0xe8, 0x29, 0x5c, 0x11, 0xa3, 0x7c,
-	0x30, 0x88, 0x69, 0xff, 0x88, 0x8e, 0x75, 0x4a, 0x84, 0x57, 0xdb, 0x5c, 0x68, 0x2f, 0x77, 0xb7,
-	0xfe, 0x2d, 0x27, 0xfb, 0xc6, 0x51, 0x67, 0x64, 0xc5, 0x46, 0xf9, 0x9e, 0x8e, 0x95, 0x2c, 0xd0,
-	0xfb, 0xd0, 0xca, 0x38, 0x7b, 0x46, 0x03, 0xd9, 0xe7, 0x8c, 0x99, 0xd6, 0x74, 0xf1, 0xa2, 0xd5,
-	0x61, 0xc6, 0x24, 0xda, 0x05, 0x88, 0x92, 0x8c, 0x71, 0xd9, 0x4f, 0x48, 0xe6, 0x35, 0xf4, 0xf9,
-	0x3f, 0xba, 0xf0, 0xfc, 0x0f, 0x35, 0xf4, 0x11, 0xc9, 0x1e, 0xa4, 0x92, 0x8f, 0xb1, 0x1b, 0x15,
-	0xb2, 0xff, 0xa2, 0x02, 0x6e, 0xf9, 0x75, 0x08, 0x41, 0x35, 0x25, 0x89, 0x29, 0xac, 0x8b, 0xf5,
-	0xfa, 0x4c, 0x96, 0x2b, 0xef, 0x94, 0xe5, 0x03, 0xf0, 0x8b, 0x0c, 0xc5, 0x24, 0x39, 0x0c, 0x49,
-	0x7f, 0xb2, 0xd6, 0x0b, 0x73, 0xd4, 0xfa, 0xaa, 0xf5, 0xff, 0x41, 0xbb, 0xf7, 0x4c, 0xe1, 0x77,
-	0x01, 0xcd, 0x08, 0x57, 0x9d, 0x23, 0x5c, 0x2b, 0x9e, 0x8c, 0xe3, 0x43, 0xf3, 0x90, 0x04, 0x47,
-	0x83, 0x28, 0x8e, 0xf5, 0xc8, 0x58, 0xc2, 0xa5, 0xec, 0xdf, 0x85, 0xe5, 0xe9, 0xac, 0xa1, 0xcb,
-	0xb0, 0x70, 0x44, 0xc7, 0x36, 0x37, 0x6a, 0x89, 0x56, 0xa1, 0x76, 0x4c, 0xe2, 0xdc, 0x8c, 0x3d,
-	0x17, 0x1b, 0xe1, 0x4e, 0xe5, 0xb6, 0xe3, 0xbf, 0x72, 0xa0, 0xaa, 0x46, 0x18, 0xba, 0x35, 0x95,
-	0x3d, 0xe7, 0xe2, 0xec, 0x4d, 0xe5, 0xcd, 0x83, 0xc6, 0x31, 0xe5, 0xa2, 0x48, 0xb8, 0x8b, 0x0b,
-	0x11, 0x7d, 0x08, 0x35, 0x4e, 0xd2, 0x21, 0xb5, 0xed, 0xbe, 0x62, 0x42, 0x61, 0xa5, 0xd2, 0x51,
-	0x8c, 0x15, 0x7d, 0x01, 0x20, 0x24, 0x91, 0xd4, 0x64, 0xa6, 0x36, 0x47, 0x66, 0x6a, 0x1a, 0xef,
-	0xff, 0x56, 0x81, 0xaa, 0x9a, 0xa8, 0x68, 0x1d, 0xdc, 0xb2, 0xd5, 0xf5, 0xb9, 0x97, 0xf0, 0xa9,
-	0x02, 0x7d, 0x00, 0xd5, 0x3c, 0x8f, 0x42, 0xdb, 0x0e, 0xf6, 0x14, 0x07, 0x07, 0x0f, 0xef, 0x3f,
-	0x26, 0x5c, 0x0a, 0xac, 0x8d, 0xe8, 0x73, 0x68, 0xd8, 0xea, 0xd9, 0xd3, 0xae, 0xcf, 0x9a, 0xdd,
-	0xc5, 0x55, 0xc0, 0x05, 0x18, 0xdd, 0x84, 0x66, 0xc8, 0x82, 0xf9, 0x8b, 0xba, 0x10, 0xb2, 0xc0,
-	0x7f, 0x06, 0x8d, 0xfd, 0x53, 0x7f, 0x75, 0x1f, 0xb5, 0xbf, 0x33, 0x8f, 0xbf, 0xaa, 0xe5, 0xff,
-	0xa1, 0x9e, 0x91, 0xe0, 0x88, 0x9a, 0x6f, 0x6a, 0x61, 0x2b, 0xa9, 0x2b, 0x31, 0x22, 0x62, 0xa4,
-	0xbf, 0x60, 0x09, 0xeb, 0xb5, 0xdf, 0x80, 0x9a, 0xa6, 0x12, 0x1f, 0xc3, 0xe2, 0x04, 0x3f, 0xa0,
-	0x1e, 0x20, 0x9e, 0xa7, 0x32, 0x4a, 0x68, 0x3f, 0x18, 0xd1, 0xe0, 0x28, 0x63, 0x51, 0x2a, 0xcb,
-	0xa2, 0x17, 0x94, 0xdd, 0xe9, 0x95, 0x36, 0x7c, 0xc5, 0xe2, 0x4f, 0x55, 0x2a, 0xb8, 0xa6, 0x8e,
-	0xeb, 0xbf, 0x37, 0xa1, 0x89, 0xa9, 0xc8, 0x58, 0x2a, 0x28, 0xfa, 0x78, 0x8a, 0x8e, 0x27, 0xc8,
-	0xc6, 0xd8, 0x27, 0xf9, 0xf8, 0x36, 0xb8, 0x05, 0xcb, 0x16, 0x05, 0xf2, 0xcf, 0xe1, 0x8b, 0xd1,
-	0x10, 0xe2, 0x53, 0x30, 0xda, 0x86, 0xba, 0x62, 0x5c, 0x1a, 0xda, 0x7a, 0xbd, 0x77, 0xce, 0x6d,
-	0x4f, 0x9b, 0xb1, 0x85, 0xa9, 0xad, 0xb2, 0xfc, 0x30, 0x8e, 0xc4, 0x88, 0x16, 0xfc, 0x7c, 0x7e,
-	0xab, 0xc7, 0x05, 0x02, 0x9f, 0x82, 0x51, 0x17, 0x1a, 0x9a, 0x86, 0x69, 0x68, 0xc9, 0xda, 0x3b,
-	0xe7, 0xb7, 0x6b, 0xec, 0xb8, 0x00, 0xa2, 0x07, 0xb0, 0xac, 0x09, 0x98, 0x86, 0xd3, 0x9c, 0xbd,
-	0x71, 0x3e, 0x1b, 0x06, 0x66, 0x69, 0x7b, 0x49, 0x4c, 0x8a, 0x6f, 0xe7, 0xe2, 0x17, 0x15, 0xcb,
-	0xc5, 0x3e, 0x34, 0x8b, 0xe7, 0x93, 0x6d, 0xff, 0x52, 0x56, 0xf3, 0xc7, 0x0e, 0x1e, 0x11, 0x8c,
-	0x68, 0x42, 0xe6, 0xa7, 0xae, 0x96, 0xf1, 0xdb, 0xd7, 0x6e, 0xe8, 0x29, 0xac, 0x71, 0x2a, 0x58,
-	0xce, 0x03, 0xda, 0x9f, 0x11, 0x70, 0x9e, 0xf9, 0xb8, 0x5a, 0x04, 0xe8, 0x4d, 0x06, 0xde, 0x82,
-	0x2b, 0x21, 0x0b, 0xf2, 0x84, 0xa6, 0x52, 0xd3, 0x4d, 0x3f, 0xe7, 0xb1, 0xb9, 0x4a, 0xf8, 0xf2,
-	0x94, 0xe1, 0x80, 0xc7, 0xe8, 0x06, 0xd4, 0x19, 0xc9, 0xe5, 0xa8, 0x6b, 0x2b, 0xd1, 0x32, 0xb7,
-	0x79, 0xef, 0x9e, 0xd2, 0x61, 0x6b, 0xf3, 0x5f, 0x56, 0xc0, 0x2d, 0x9b, 0x06, 0xdd, 0x9f, 0xa2,
-	0x4f, 0x47, 0xd3, 0xcf, 0x8d, 0x8b, 0x9b, 0xec, 0x42, 0x02, 0x5d, 0x19, 0xd2, 0x94, 0x72, 0x05,
-	0xe9, 0x0f, 0xa2, 0x98, 0x0a, 0xaf, 0xa2, 0x43, 0x75, 0xde, 0x12, 0xea, 0xbb, 0xc2, 0x63, 0x57,
-	0x39, 0x18, 0x42, 0x5b, 0x1e, 0x4e, 0x29, 0xfd, 0xf6, 0x24, 0xa9, 0xad, 0x81, 0xab, 0x5e, 0x87,
-	0x7d, 0x96, 0xc6, 0x66, 0x7a, 0x37, 0x71, 0x53, 0x29, 0xf6, 0xd2, 0x78, 0xec, 0xdf, 0x83, 0xab,
-	0x33, 0x02, 0xbe, 0xd3, 0xac, 0x6f, 0x42, 0xdd, 0x5c, 0x0b, 0xff, 0x5b, 0x70, 0xcb, 0x66, 0x9f,
-	0x9a, 0x62, 0xce, 0xbc, 0x53, 0xcc, 0x85, 0x86, 0x6d, 0x7b, 0xff, 0x2b, 0x58, 0x9a, 0x6a, 0x63,
-	0xf4, 0x09, 0x98, 0x19, 0x7d, 0x96, 0x45, 0xec, 0x6b, 0x6a, 0x5f, 0xd9, 0xec, 0x18, 0xef, 0xde,
-	0x05, 0xb7, 0x34, 0xa8, 0x6b, 0xad, 0x5f, 0x1e, 0x14, 0xad, 0x9c, 0x19, 0xc0, 0xfe, 0xe5, 0xb3,
-	0x89, 0x6e, 0x3b, 0x9f, 0x39, 0x3b, 0x5f, 0x9f, 0xfc, 0xb9, 0x71, 0xe9, 0xe4, 0xcd, 0x86, 0xf3,
-	0xc7, 0x9b, 0x0d, 0xe7, 0xd7, 0xbf, 0x36, 0x9c, 0x9f, 0xb6, 0x86, 0x91, 0x1c, 0xe5, 0x87, 0x9d,
-	0x80, 0x25, 0xdb, 0x54, 0xc8, 0x9c, 0xf0, 0xb1, 0xf9, 0x4d, 0x31, 0xe3, 0x17, 0xcb, 0x61, 0x5d,
-	0x6b, 0x6e, 0xfe, 0x13, 0x00, 0x00, 0xff, 0xff, 0x83, 0x3a, 0xb3, 0x5b, 0xcf, 0x0c, 0x00, 0x00,
+	// 1258 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xcd, 0x57, 0x5b, 0x6f, 0x1b, 0x45,
+	0x14, 0xee, 0xfa, 0xbe, 0x27, 0xce, 0x6d, 0x1a, 0xca, 0x6a, 0x13, 0xa5, 0x25, 0x04, 0x11, 0x88,
+	0xb0, 0x51, 0x8a, 0xa0, 0x94, 0x82, 0x68, 0x9c, 0xa6, 0xaa, 0x20, 0xa4, 0x9a, 0x24, 0x20, 0xf1,
+	0xb2, 0xda, 0xac, 0xc7, 0xf6, 0x26, 0xeb, 0x5d, 0xb3, 0x97, 0x54, 0xf9, 0x23, 0xf4, 0x15, 0xf5,
+	0x47, 0xf4, 0x37, 0xe4, 0x91, 0x1f, 0x80, 0x2a, 0x01, 0xff, 0x82, 0x07, 0xc4, 0x99, 0xcb, 0xae,
+	0xd7, 0x97, 0x14, 0x57, 0xe2, 0x81, 0x87, 0x38, 0x73, 0xe6, 0x7c, 0xe7, 0xcc, 0xf8, 0xdc, 0xbe,
+	0x31, 0xdc, 0xe9, 0x06, 0xcd, 0x41, 0x18, 0xc4, 0x81, 0x13, 0x78, 0x51, 0xb3, 0xcd, 0x42, 0xf7,
+	0x82, 0xa9, 0x7f, 0x0d, 0xb1, 0x4f, 0x2a, 0x52, 0x32, 0xef, 0x38, 0x81, 0x1f, 0x25, 0x7d, 0x16,
+	0x66, 0xf8, 0x6c, 0x21, 0x91, 0xe6, 0xda, 0x88, 0xaf, 0x8e, 0x17, 0x3c, 0x13, 0x1f, 0x4a, 0xbb,
+	0xd2, 0x0d, 0xba, 0x81, 0x58, 0x36, 0xf9, 0x4a, 0xee, 0x6e, 0xfc, 0xb6, 0x08, 0x55, 0xca, 0x7e,
+	0x4a, 0x58, 0x14, 0x93, 0x2d, 0x28, 0x45, 0x03, 0xe6, 0x18, 0xda, 0x1d, 0x6d, 0x6b, 0x6e, 0x67,
+	0xa5, 0xa1, 0xae, 0xa1, 0xd4, 0x8d, 0x23, 0xd4, 0x51, 0x81, 0x20, 0x9f, 0x40, 0xed, 0xc2, 0xf6,
+	0xdc, 0xb6, 0x1d, 0x33, 0xa3, 0x20, 0xd0, 0xc6, 0x38, 0xfa, 0x7b, 0xa5, 0xa7, 0x19, 0x92, 0xfb,
+	0x0f, 0x06, 0xcc, 0x37, 0x8a, 0xd3, 0xfd, 0x1f, 0xa2, 0x8e, 0x0a, 0x04, 0x47, 0x86, 0xcc, 0x6e,
+	0x1b, 0xa5, 0xe9, 0x48, 0x8a, 0x3a, 0x2a, 0x10, 0x64, 0x1b, 0xca, 0x1d, 0x2f, 0x89, 0x7a, 0x46,
+	0x59, 0x40, 0xdf, 0x1a, 0x87, 0xee, 0x73, 0x25, 0x95, 0x18, 0xf2, 0x15, 0xd4, 0xa3, 0xd8, 0x0e,
+	0x63, 0xcb, 0x09, 0xfa, 0x7d, 0x37, 0x36, 0x2a, 0xc2, 0x66, 0x75, 0xe2, 0x8b, 0x72, 0x4c, 0x4b,
+	0x40, 0xe8, 0x5c, 0x34, 0x14, 0xf8, 0x61, 0x21, 0x8b, 0x58, 0x6c, 0x54, 0xa7, 0x1f, 0x46, 0xb9,
+	0x92, 0x4a, 0x0c, 0x59, 0x85, 0x9a, 0xeb, 0xc7, 0x2c, 0xf4, 0x6d, 0xcf, 0x68, 0x23, 0xbe, 0x4e,
+	0xf5, 0xcd, 0x74, 0xc3, 0x7c, 0xae, 0x41, 0x89, 0xc7, 0x93, 0x1c, 0xc3, 0x02, 0xe6, 0xd5, 0x67,
+	0x4e, 0x1c, 0x84, 0x56, 0x7c, 0x39, 0x60, 0x22, 0xfa, 0x0b, 0x3b, 0x1f, 0x35, 0x44, 0xea, 0x5a,
+	0x81, 0xe7, 0xa1, 0xce, 0x0d, 0x7c, 0x8e, 0x6e, 0xec, 0xf1, 0xf3, 0x6c, 0x2e, 0xa2, 0x46, 0x59,
+	0x1d, 0xa3, 0x11, 0x9d, 0x77, 0xf2, 0x22, 0xf9, 0x1c, 0xe6, 0x70, 0xa3, 0xe3, 0x76, 0xad, 0xb3,
+	0x28, 0xf0, 0x45, 0x8a, 0xf4, 0xdd, 0xb5, 0xbf, 0x5e, 0xdd, 0x36, 0x98, 0xef, 0x04, 0x6d, 0xd7,
+	0xef, 0x36, 0xb9, 0xa2, 0x41, 0xed, 0x67, 0x07, 0x2c, 0x8a, 0xec, 0x2e, 0xa3, 0x15, 0x69, 0x60,
+	0xbe, 0xac, 0x40, 0x2d, 0xcd, 0xdd, 0xff, 0xee, 0x76, 0xe4, 0x3e, 0x80, 0x93, 0x1d, 0x9a, 0x15,
+	0xd2, 0x94, 0xcb, 0xec, 0x96, 0xae, 0x5e, 0xdd, 0xbe, 0x41, 0x73, 0x68, 0xb2, 0x0b, 0x10, 0x87,
+	0xb6, 0x1f, 0x75, 0x82, 0xb0, 0x1f, 0x61, 0x69, 0x15, 0xd1, 0x76, 0xe3, 0xba, 0xb2, 0x6d, 0x1c,
+	0xa7, 0x50, 0x9a, 0xb3, 0x22, 0x3f, 0xc0, 0x72, 0xd4, 0x4b, 0x3a, 0x1d, 0x8f, 0x59, 0xe7, 0xec,
+	0x52, 0x84, 0x24, 0xc2, 0xd2, 0x2b, 0x62, 0x4c, 0xb6, 0xff, 0x2d, 0x26, 0x47, 0xd2, 0x50, 0x44,
+	0x64, 0x51, 0x79, 0xf9, 0x86, 0x5d, 0x72, 0x39, 0x22, 0xef, 0x40, 0x1d, 0x1b, 0xf2, 0x0c, 0x2d,
+	0xad, 0x30, 0x08, 0x64, 0x69, 0xea, 0x74, 0x4e, 0xed, 0x51, 0xdc, 0x22, 0xfb, 0x00, 0x6e, 0x7f,
+	0x10, 0x60, 0xf9, 0xf6, 0xed, 0x01, 0x96, 0x20, 0xbf, 0xff, 0xfb, 0xd7, 0xde, 0xff, 0x89, 0x80,
+	0x1e, 0xd8, 0x83, 0x47, 0x7e, 0x1c, 0x5e, 0x52, 0xdd, 0x4d, 0x65, 0xf3, 0xe7, 0x02, 0xe8, 0xd9,
+	0xb7, 0x23, 0x04, 0x4a, 0xbe, 0xdd, 0x97, 0x89, 0xd5, 0xa9, 0x58, 0x8f, 0x45, 0xb9, 0xf0, 0x46,
+	0x51, 0x3e, 0x01, 0x33, 0x8d, 0x90, 0x67, 0xf7, 0x4f, 0xdb, 0xb6, 0x95, 0xcf, 0x75, 0x71, 0x86,
+	0x5c, 0xdf, 0x54, 0xf6, 0xdf, 0x0a, 0xf3, 0x96, 0x4c, 0xfc, 0x3e, 0x90, 0x29, 0xee, 0x4a, 0x33,
+	0xb8, 0xab, 0x7b, 0x79, 0x3f, 0x26, 0xd4, 0x4e, 0x6d, 0xe7, 0xbc, 0xe3, 0x7a, 0x9e, 0x18, 0x19,
+	0xf3, 0x34, 0x93, 0xcd, 0x07, 0xb0, 0x30, 0x1a, 0x35, 0xb2, 0x04, 0x45, 0x4c, 0xb3, 0x8a, 0x0d,
+	0x5f, 0x92, 0x15, 0x28, 0xe3, 0x3c, 0x4b, 0xe4, 0xd8, 0xd3, 0xa9, 0x14, 0xee, 0x17, 0xee, 0x69,
+	0xe6, 0x4b, 0x6c, 0x69, 0x3e, 0xc2, 0x70, 0x38, 0xe6, 0xa3, 0xa7, 0x5d, 0x1f, 0xbd, 0x91, 0xb8,
+	0x19, 0x50, 0xbd, 0x60, 0x61, 0x94, 0x06, 0x5c, 0xa7, 0xa9, 0x48, 0xde, 0xc3, 0xa9, 0x63, 0xfb,
+	0x5d, 0xa6, 0xca, 0x7d, 0x51, 0xba, 0xa2, 0x7c, 0x4b, 0x78, 0x91, 0x5a, 0xf2, 0x19, 0x00, 0xce,
+	0xaa, 0x98, 0xc9, 0xc8, 0x94, 0x67, 0x88, 0x4c, 0x59, 0xe0, 0xcd, 0x17, 0x05, 0x28, 0xf1, 0x89,
+	0x4a, 0xd6, 0x40, 0xcf, 0x4a, 0x5d, 0xdc, 0x7b, 0x9e, 0x0e, 0x37, 0xc8, 0xbb, 0x50, 0x4a, 0x12,
+	0xb7, 0xad, 0xca, 0x41, 0xdd, 0xe2, 0xe4, 0xe4, 0xc9, 0xde, 0x53, 0x1c, 0x90, 0x11, 0x15, 0x4a,
+	0xf2, 0x29, 0x54, 0x55, 0xf6, 0xd4, 0x6d, 0xd7, 0xa6, 0xcd, 0xee, 0xb4, 0x15, 0x68, 0x0a, 0x26,
+	0x77, 0xa1, 0xd6, 0x0e, 0x9c, 0xd9, 0x93, 0x5a, 0x44, 0xb4, 0x79, 0x06, 0xd5, 0xa3, 0xa1, 0x3d,
+	0xef, 0x47, 0x61, 0xaf, 0xcd, 0x62, 0xcf, 0x73, 0x79, 0x0b, 0x2a, 0x03, 0xcc, 0x3d, 0x93, 0xdf,
+	0xa9, 0x4e, 0x95, 0xc4, 0x5b, 0xa2, 0x67, 0x23, 0xa5, 0x14, 0x45, 0x08, 0xc4, 0xda, 0xac, 0x42,
+	0x59, 0x50, 0x89, 0xf9, 0x42, 0x83, 0xb9, 0x1c, 0x41, 0x90, 0x16, 0x90, 0x30, 0xf1, 0x63, 0xb7,
+	0xcf, 0x2c, 0xa7, 0xc7, 0x9c, 0xf3, 0x41, 0x80, 0x43, 0x3e, 0xcb, 0x7a, 0xca, 0xd9, 0x8d, 0x56,
+	0xa6, 0xa3, 0xcb, 0x0a, 0x3f, 0xdc, 0x22, 0xdf, 0x81, 0x31, 0xe9, 0xc4, 0x6a, 0x33, 0x2f, 0xb6,
+	0xb3, 0xf6, 0x9b, 0xe6, 0xea, 0xd6, 0x84, 0xab, 0x3d, 0x6e, 0xc3, 0x6f, 0x2b, 0xb8, 0x68, 0xe3,
+	0xef, 0x1a, 0xd4, 0x70, 0x35, 0x40, 0x5b, 0x46, 0x3e, 0x18, 0xe1, 0xf7, 0x1c, 0x7b, 0x49, 0x7d,
+	0x9e, 0xe0, 0xef, 0x81, 0x9e, 0xd2, 0x76, 0x9a, 0x71, 0x73, 0x02, 0x9f, 0xce, 0x9a, 0x36, 0x1d,
+	0x82, 0x49, 0x13, 0x2a, 0x9c, 0xc2, 0xd1, 0x4c, 0x16, 0xc0, 0xdb, 0x13, 0x66, 0x87, 0x42, 0x4d,
+	0x15, 0x8c, 0x1f, 0x35, 0x48, 0x4e, 0x3d, 0x37, 0xea, 0xb1, 0x94, 0xf0, 0x27, 0x8f, 0x7a, 0x9a,
+	0x22, 0xe8, 0x10, 0x4c, 0x76, 0xa0, 0x2a, 0x78, 0x1d, 0xed, 0xca, 0xe3, 0x8f, 0x10, 0x65, 0xb7,
+	0x2f, 0xf5, 0x34, 0x05, 0x92, 0x47, 0xb0, 0x20, 0x18, 0x9d, 0xb5, 0x47, 0x1f, 0x01, 0xeb, 0x93,
+	0xd1, 0x90, 0x30, 0xf5, 0x0e, 0x98, 0x8f, 0xf2, 0xe2, 0xeb, 0xc9, 0x1d, 0x07, 0xac, 0x24, 0x77,
+	0x1c, 0x36, 0xe9, 0x7b, 0x4c, 0xf5, 0x53, 0x26, 0xf3, 0x81, 0xa6, 0x26, 0x59, 0x84, 0x29, 0xef,
+	0xdb, 0xb3, 0x73, 0x61, 0x5d, 0xda, 0x1d, 0x09, 0x33, 0x64, 0xa4, 0x55, 0x7c, 0x6f, 0x04, 0x49,
+	0xe8, 0x30, 0x6b, 0x8a, 0xc3, 0x59, 0x06, 0xee, 0x4a, 0xea, 0xa0, 0x95, 0x77, 0xbc, 0x0d, 0xcb,
+	0xd8, 0x64, 0x58, 0x71, 0x7e, 0x2c, 0xf8, 0xcb, 0x4a, 0x42, 0x4f, 0xf6, 0x26, 0x5d, 0x1a, 0x51,
+	0x9c, 0x84, 0x1e, 0xd9, 0xc4, 0xac, 0xdb, 0x49, 0xdc, 0xdb, 0x51, 0x99, 0xa8, 0xcb, 0xf1, 0x70,
+	0xf8, 0x90, 0xef, 0x51, 0xa5, 0x33, 0x9f, 0x23, 0xf3, 0x64, 0x45, 0x43, 0xf6, 0x46, 0xf8, 0x58,
+	0x13, 0x7c, 0xb6, 0x79, 0x7d, 0x91, 0x5d, 0xcb, 0xc8, 0x8b, 0x5d, 0xac, 0xa3, 0x90, 0x43, 0x2c,
+	0x1c, 0xe3, 0xc8, 0xc7, 0x05, 0xe1, 0xaa, 0xf1, 0x1a, 0x57, 0x8f, 0x53, 0x8b, 0x7d, 0x6e, 0x20,
+	0x19, 0x72, 0xa1, 0x3b, 0xb2, 0x69, 0x6e, 0xe5, 0x59, 0x72, 0x15, 0x74, 0xfe, 0xdc, 0xb4, 0x02,
+	0xdf, 0x93, 0x74, 0x50, 0xa3, 0x35, 0xbe, 0x71, 0x88, 0xb2, 0xf9, 0x10, 0x6e, 0x4e, 0x71, 0xf8,
+	0x46, 0xe4, 0x71, 0x00, 0x15, 0xd9, 0x16, 0xff, 0xc9, 0x3c, 0x31, 0xbf, 0x06, 0x3d, 0xeb, 0x98,
+	0x91, 0xd9, 0xaa, 0xcd, 0x3a, 0x5b, 0x75, 0xa8, 0xaa, 0xde, 0x31, 0xbf, 0x80, 0xf9, 0x91, 0x5e,
+	0x20, 0x1f, 0x82, 0x64, 0x8e, 0x71, 0x6e, 0x53, 0x6f, 0xbc, 0x23, 0xae, 0x53, 0xe4, 0xb2, 0xf3,
+	0x00, 0xf4, 0x4c, 0xc1, 0x67, 0x83, 0x78, 0x0f, 0x31, 0xb2, 0x38, 0x46, 0x0b, 0xe6, 0xd2, 0x78,
+	0xb6, 0xb6, 0xb4, 0x8f, 0xb5, 0xdd, 0x2f, 0xaf, 0x7e, 0x5f, 0xbf, 0x71, 0xf5, 0xc7, 0xba, 0xf6,
+	0x2b, 0xfe, 0xfd, 0xf2, 0xe7, 0xba, 0xf6, 0xe3, 0x76, 0xd7, 0x8d, 0x7b, 0xc9, 0x29, 0x06, 0xa2,
+	0xdf, 0x44, 0xbb, 0xc4, 0x0e, 0x2f, 0xe5, 0x2f, 0x9d, 0x29, 0xbf, 0xa3, 0x4e, 0x2b, 0x62, 0xe7,
+	0xee, 0x3f, 0x32, 0x84, 0xe6, 0x2c, 0x65, 0x0d, 0x00, 0x00,
 }
 
 // Reference imports to suppress errors if they are not otherwise used.
@@ -1649,6 +1659,18 @@ func (m *Request_StartCommit) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.RuntimeCheckpointDelta != nil {
+		{
+			size, err := m.RuntimeCheckpointDelta.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintDerive(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
 	if m.RuntimeCheckpoint != nil {
 		{
 			size, err := m.RuntimeCheckpoint.MarshalToSizedBuffer(dAtA[:i])
@@ -1985,6 +2007,18 @@ func (m *Response_Opened) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.RuntimeCheckpoint != nil {
+		{
+			size, err := m.RuntimeCheckpoint.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintDerive(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
 	return len(dAtA) - i, nil
 }
 
@@ -2337,6 +2371,10 @@ func (m *Request_StartCommit) ProtoSize() (n int) {
 		l = m.RuntimeCheckpoint.ProtoSize()
 		n += 1 + l + sovDerive(uint64(l))
 	}
+	if m.RuntimeCheckpointDelta != nil {
+		l = m.RuntimeCheckpointDelta.ProtoSize()
+		n += 1 + l + sovDerive(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -2473,6 +2511,10 @@ func (m *Response_Opened) ProtoSize() (n int) {
 	}
 	var l int
 	_ = l
+	if m.RuntimeCheckpoint != nil {
+		l = m.RuntimeCheckpoint.ProtoSize()
+		n += 1 + l + sovDerive(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -4177,6 +4219,42 @@ func (m *Request_StartCommit) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RuntimeCheckpointDelta", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowDerive
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthDerive
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthDerive
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.RuntimeCheckpointDelta == nil {
+				m.RuntimeCheckpointDelta = &protocol.Checkpoint{}
+			}
+			if err := m.RuntimeCheckpointDelta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipDerive(dAtA[iNdEx:])
@@ -5065,6 +5143,42 @@ func (m *Response_Opened) Unmarshal(dAtA []byte) error {
 			return fmt.Errorf("proto: Opened: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RuntimeCheckpoint", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowDerive
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthDerive
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthDerive
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.RuntimeCheckpoint == nil {
+				m.RuntimeCheckpoint = &protocol.Checkpoint{}
+			}
+			if err := m.RuntimeCheckpoint.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipDerive(dAtA[iNdEx:])
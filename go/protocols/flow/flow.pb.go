@@ -120,6 +120,7 @@ const (
 	CollectionSpec_Derivation_TYPESCRIPT             CollectionSpec_Derivation_ConnectorType = 2
 	CollectionSpec_Derivation_IMAGE                  CollectionSpec_Derivation_ConnectorType = 3
 	CollectionSpec_Derivation_LOCAL                  CollectionSpec_Derivation_ConnectorType = 4
+	CollectionSpec_Derivation_PYTHON                 CollectionSpec_Derivation_ConnectorType = 5
 )
 
 var CollectionSpec_Derivation_ConnectorType_name = map[int32]string{
@@ -128,6 +129,7 @@ var CollectionSpec_Derivation_ConnectorType_name = map[int32]string{
 	2: "TYPESCRIPT",
 	3: "IMAGE",
 	4: "LOCAL",
+	5: "PYTHON",
 }
 
 var CollectionSpec_Derivation_ConnectorType_value = map[string]int32{
@@ -136,6 +138,7 @@ var CollectionSpec_Derivation_ConnectorType_value = map[string]int32{
 	"TYPESCRIPT":             2,
 	"IMAGE":                  3,
 	"LOCAL":                  4,
+	"PYTHON":                 5,
 }
 
 func (x CollectionSpec_Derivation_ConnectorType) String() string {
@@ -2406,245 +2409,242 @@ func init() {
 func init() { proto.RegisterFile("go/protocols/flow/flow.proto", fileDescriptor_d0677502142fec31) }
 
 var fileDescriptor_d0677502142fec31 = []byte{
-	// 3795 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd4, 0x5a, 0xcd, 0x8f, 0x1b, 0xc9,
-	0x75, 0x9f, 0xe6, 0x37, 0x1f, 0x3f, 0xa6, 0xa7, 0x66, 0x24, 0x51, 0xf4, 0x6a, 0x38, 0xa2, 0xed,
-	0xec, 0xec, 0xae, 0x97, 0x72, 0x66, 0x61, 0x67, 0x25, 0x41, 0x31, 0xf8, 0x35, 0x12, 0x25, 0x0e,
-	0xc9, 0x34, 0x39, 0x5e, 0xcb, 0x80, 0xd1, 0x68, 0x76, 0x17, 0x39, 0xbd, 0xd3, 0xec, 0x66, 0xba,
-	0x8b, 0x92, 0xe8, 0x4b, 0x82, 0x20, 0x40, 0x80, 0x45, 0x02, 0xf8, 0x12, 0xd8, 0x37, 0x6f, 0x0c,
-	0x04, 0xc8, 0xbf, 0x90, 0xe4, 0x12, 0x20, 0x97, 0x4d, 0xe2, 0x04, 0x8b, 0x04, 0xc9, 0x21, 0x07,
-	0x2d, 0xe2, 0x1c, 0x6c, 0x20, 0x40, 0x72, 0x09, 0x82, 0x40, 0xa7, 0xa0, 0x3e, 0xba, 0xd9, 0x1c,
-	0x8e, 0x66, 0x38, 0x6b, 0xe7, 0xb0, 0x17, 0xa2, 0xeb, 0xd5, 0x7b, 0xaf, 0xab, 0xde, 0xc7, 0xef,
-	0xbd, 0xae, 0x22, 0xbc, 0x31, 0x76, 0xee, 0x4c, 0x5d, 0x87, 0x38, 0xba, 0x63, 0x79, 0x77, 0x46,
-	0x96, 0xf3, 0x9c, 0xfd, 0x54, 0x18, 0x0d, 0xc5, 0xe8, 0x73, 0x71, 0x77, 0xe8, 0x3a, 0xa7, 0xd8,
-	0x0d, 0xf8, 0x82, 0x07, 0xce, 0x55, 0xdc, 0xd3, 0x1d, 0xdb, 0x9b, 0x4d, 0x2e, 0xe0, 0xd8, 0x19,
-	0x3b, 0x63, 0x87, 0x3d, 0xde, 0xa1, 0x4f, 0x82, 0x5a, 0x1a, 0x3b, 0xce, 0xd8, 0xc2, 0x9c, 0x79,
-	0x38, 0x1b, 0xdd, 0x21, 0xe6, 0x04, 0x7b, 0x44, 0x9b, 0x4c, 0x39, 0x43, 0xf9, 0x01, 0xc4, 0xfb,
-	0x96, 0xa9, 0x63, 0xb4, 0x03, 0xf1, 0x21, 0x1e, 0x9b, 0x76, 0x41, 0xda, 0x93, 0xf6, 0x73, 0x0a,
-	0x1f, 0x20, 0x19, 0xa2, 0xd8, 0x36, 0x0a, 0x11, 0x46, 0xa3, 0x8f, 0xf7, 0xb2, 0x9f, 0xfe, 0x49,
-	0x69, 0xe3, 0x07, 0x3f, 0x29, 0x6d, 0xfc, 0xe8, 0x27, 0xa5, 0x8d, 0xb2, 0x0d, 0xe9, 0xe3, 0xe3,
-	0x56, 0xa3, 0xa7, 0xb9, 0xc4, 0x43, 0x08, 0x62, 0xb6, 0x63, 0x60, 0xa6, 0x21, 0xa1, 0xb0, 0x67,
-	0x74, 0x1f, 0xe2, 0xba, 0xe5, 0xe8, 0xa7, 0x4c, 0x45, 0xa2, 0xf6, 0xd5, 0x57, 0x2f, 0x4b, 0xb7,
-	0xc7, 0x4e, 0x65, 0xac, 0x7d, 0x1f, 0x13, 0x82, 0x2b, 0x06, 0x7e, 0x76, 0x47, 0x77, 0x5c, 0x7c,
-	0x67, 0x82, 0x3d, 0x4f, 0x1b, 0xe3, 0x4a, 0x9d, 0x32, 0x2b, 0x5c, 0xe6, 0x9e, 0xfc, 0x8b, 0x8f,
-	0x4b, 0xd2, 0xd2, 0xfb, 0xfe, 0x59, 0x02, 0xe8, 0xb9, 0xce, 0x87, 0x58, 0x27, 0xa6, 0xc3, 0x96,
-	0x37, 0x25, 0x2e, 0x7b, 0x61, 0x5a, 0xa1, 0x8f, 0x74, 0x1b, 0x23, 0x13, 0x5b, 0x7c, 0xc9, 0x69,
-	0x85, 0x0f, 0x50, 0x11, 0x52, 0xf8, 0xc5, 0xd4, 0x32, 0x75, 0x93, 0x14, 0xa2, 0x7b, 0xd2, 0x7e,
-	0x4a, 0x09, 0xc6, 0x68, 0x1f, 0x64, 0xd3, 0x53, 0xa7, 0x9a, 0x4b, 0x4c, 0xaa, 0x53, 0x3d, 0xc5,
-	0xf3, 0x42, 0x8c, 0xf1, 0xe4, 0x4d, 0xaf, 0xe7, 0x93, 0x9f, 0xe0, 0x39, 0xfa, 0x0a, 0xe4, 0x29,
-	0xa7, 0x6b, 0x4e, 0x34, 0x77, 0xce, 0xf8, 0xe2, 0x8c, 0x2f, 0x6b, 0x7a, 0x3d, 0x4e, 0xa4, 0x5c,
-	0xef, 0x41, 0xda, 0xb4, 0x47, 0xd8, 0xc5, 0xb6, 0x8e, 0x0b, 0x89, 0x3d, 0x69, 0x3f, 0x73, 0xb0,
-	0x59, 0x61, 0x0e, 0x6f, 0xf9, 0xe4, 0x5a, 0xec, 0x93, 0x97, 0xa5, 0x0d, 0x65, 0xc1, 0x57, 0xfe,
-	0xef, 0x28, 0xa4, 0x83, 0x69, 0xba, 0x09, 0x32, 0x9f, 0x62, 0xaf, 0x20, 0xed, 0x45, 0xe9, 0x26,
-	0xd8, 0x00, 0x55, 0x20, 0xe1, 0x11, 0xd7, 0xb4, 0xc7, 0x6c, 0x0b, 0x99, 0x83, 0xeb, 0x67, 0xb4,
-	0x56, 0xfa, 0x6c, 0x56, 0x11, 0x5c, 0x4c, 0x8b, 0x49, 0x2c, 0xcc, 0x76, 0x43, 0xb5, 0xd0, 0x01,
-	0xda, 0x83, 0x8c, 0x81, 0x3d, 0xdd, 0x35, 0xa7, 0x74, 0x5b, 0x6c, 0x07, 0x69, 0x25, 0x4c, 0x42,
-	0xf7, 0x21, 0x6b, 0xe0, 0x91, 0x36, 0xb3, 0x88, 0xfa, 0xa1, 0xe7, 0xd8, 0x6c, 0x0f, 0xe9, 0xda,
-	0x1b, 0xaf, 0x5e, 0x96, 0x0a, 0xd8, 0xd6, 0x1d, 0xc3, 0xb4, 0xc7, 0x77, 0xe8, 0x44, 0x45, 0xd1,
-	0x9e, 0x1f, 0x71, 0xb7, 0x29, 0x49, 0x21, 0x81, 0xae, 0x43, 0xc2, 0xc3, 0xba, 0x8b, 0x49, 0x21,
-	0xc9, 0x6c, 0x23, 0x46, 0x74, 0xf1, 0xf8, 0x85, 0xe9, 0x11, 0xaf, 0x90, 0xda, 0x93, 0xf6, 0xf3,
-	0xab, 0x8b, 0x6f, 0xb2, 0x59, 0x45, 0x70, 0x15, 0x7f, 0x2c, 0x41, 0x82, 0xef, 0x07, 0xdd, 0x86,
-	0xac, 0xee, 0xd8, 0x04, 0xdb, 0x44, 0xa5, 0x86, 0x60, 0xbb, 0x4f, 0x2b, 0x19, 0x41, 0x1b, 0xcc,
-	0xa7, 0x98, 0xbe, 0x75, 0xe4, 0xb8, 0x13, 0x8d, 0x88, 0xbd, 0x8a, 0x11, 0x7a, 0x0b, 0x64, 0x5f,
-	0xd4, 0x5f, 0x3a, 0x5b, 0x57, 0x5a, 0xd9, 0x14, 0xf4, 0xa6, 0x20, 0xa3, 0x5b, 0x00, 0x13, 0xed,
-	0x85, 0x6a, 0x61, 0x7b, 0x4c, 0x4e, 0xd8, 0x9e, 0x73, 0x4a, 0x7a, 0xa2, 0xbd, 0x68, 0x33, 0xc2,
-	0xe3, 0x58, 0x4a, 0x92, 0x23, 0x8f, 0x63, 0xa9, 0x88, 0x1c, 0x7d, 0x1c, 0x4b, 0xc5, 0xe5, 0x44,
-	0xb9, 0x06, 0x09, 0xbe, 0x66, 0x94, 0x81, 0x64, 0xab, 0xf3, 0xed, 0x6a, 0xbb, 0xd5, 0x90, 0x37,
-	0x50, 0x0a, 0x62, 0x47, 0xc7, 0xfd, 0x81, 0x2c, 0xa1, 0x24, 0x44, 0x8f, 0xaa, 0x4f, 0xe5, 0x08,
-	0xca, 0x42, 0xaa, 0x75, 0xd4, 0x6b, 0xb7, 0xea, 0xad, 0x81, 0x1c, 0x45, 0x00, 0x89, 0x7a, 0xb5,
-	0xd3, 0xe9, 0x0e, 0xe4, 0x58, 0xf9, 0x29, 0x64, 0x3a, 0x98, 0x3c, 0x77, 0xdc, 0xd3, 0x9e, 0xe3,
-	0x32, 0xe3, 0xd9, 0xb3, 0xc9, 0x10, 0xbb, 0x22, 0x09, 0xc5, 0x88, 0x86, 0xaf, 0x9f, 0xed, 0x22,
-	0xae, 0x83, 0x31, 0x95, 0x99, 0xce, 0x86, 0x96, 0xa9, 0x8b, 0xc0, 0x16, 0xa3, 0xf2, 0xff, 0xe4,
-	0x21, 0x5f, 0x77, 0x2c, 0x8b, 0x67, 0x4a, 0x7f, 0x8a, 0x75, 0x54, 0x86, 0x98, 0xad, 0x4d, 0x78,
-	0x7e, 0xa6, 0x6b, 0xf9, 0x57, 0x2f, 0x4b, 0xb0, 0xe0, 0x50, 0xd8, 0x1c, 0x6a, 0xc0, 0xd6, 0x73,
-	0xd7, 0x24, 0x58, 0xf5, 0xf4, 0x13, 0x3c, 0xd1, 0x78, 0x04, 0xa4, 0xd6, 0x88, 0x80, 0x0c, 0x13,
-	0xeb, 0x33, 0x29, 0x54, 0x03, 0xd9, 0xc5, 0x9a, 0xb1, 0xa4, 0x24, 0xb3, 0x86, 0x12, 0xa0, 0x52,
-	0x42, 0x87, 0x0c, 0x51, 0x9a, 0x62, 0x51, 0x96, 0x02, 0xf4, 0x11, 0xdd, 0x84, 0xd4, 0x6c, 0x66,
-	0x1a, 0x2a, 0x4d, 0x79, 0xee, 0xe7, 0x24, 0x1d, 0xf7, 0x88, 0x4b, 0x1d, 0xbd, 0xc8, 0x60, 0x96,
-	0xf3, 0x5e, 0x21, 0xce, 0x24, 0x37, 0x03, 0xfa, 0x21, 0x23, 0xa3, 0xf7, 0x21, 0x33, 0x0d, 0x10,
-	0xc4, 0x2b, 0x24, 0xf6, 0xa2, 0xfb, 0x99, 0x03, 0x99, 0x87, 0xe3, 0x02, 0x5a, 0x44, 0x8a, 0x86,
-	0x59, 0xa9, 0x6d, 0x34, 0xfd, 0x54, 0x25, 0x78, 0x32, 0xb5, 0x34, 0x82, 0xf9, 0xb6, 0x92, 0xeb,
-	0xd8, 0x46, 0xd3, 0x4f, 0x07, 0x42, 0x0a, 0x35, 0x00, 0x2d, 0x96, 0xea, 0xeb, 0x2a, 0xa4, 0x59,
-	0x4a, 0x5f, 0xab, 0x04, 0xa8, 0xfe, 0xd8, 0x99, 0xb9, 0xb6, 0x66, 0x51, 0xc7, 0x29, 0x5b, 0x81,
-	0x40, 0xa0, 0xe5, 0x5b, 0x00, 0x06, 0x76, 0xcd, 0x67, 0x1a, 0xcb, 0xe2, 0x2c, 0x93, 0x2e, 0xf1,
-	0x4d, 0x2c, 0x7b, 0xbd, 0xd2, 0x08, 0xd8, 0x94, 0x90, 0x48, 0xf1, 0x5f, 0x00, 0x60, 0x31, 0x85,
-	0x06, 0x90, 0xd7, 0x1d, 0xdb, 0xc6, 0x3a, 0x71, 0x5c, 0x9e, 0x66, 0x12, 0xcb, 0xd3, 0x77, 0x2f,
-	0xd1, 0x59, 0xa9, 0xfb, 0x52, 0x34, 0x11, 0x95, 0x9c, 0x1e, 0x1e, 0xa2, 0xbb, 0x40, 0xd3, 0x74,
-	0x64, 0x8e, 0xb9, 0xad, 0x22, 0x6b, 0xd8, 0x2a, 0xc1, 0x05, 0x50, 0x17, 0x80, 0xb8, 0x9a, 0xed,
-	0xd1, 0x4c, 0xf6, 0x58, 0x14, 0x64, 0x0e, 0xde, 0xba, 0x6c, 0x31, 0x03, 0x5f, 0x42, 0xb8, 0x2f,
-	0xa4, 0x02, 0x7d, 0x00, 0x5b, 0xde, 0xc9, 0x6c, 0x34, 0xb2, 0x30, 0x85, 0x6e, 0x95, 0x03, 0x6c,
-	0x6c, 0x2f, 0xba, 0x9f, 0x3f, 0x78, 0xe7, 0x32, 0xbd, 0x7d, 0x2e, 0xc8, 0xb6, 0xb8, 0x29, 0xb4,
-	0x3c, 0xc1, 0xf3, 0x01, 0xc3, 0xe5, 0x7b, 0x90, 0xf7, 0x4e, 0x34, 0xd7, 0x58, 0x38, 0x33, 0xce,
-	0xdc, 0xb1, 0x5d, 0xf1, 0x8b, 0x76, 0xa5, 0x4f, 0xe7, 0x99, 0x2b, 0x73, 0x8c, 0x35, 0x70, 0x63,
-	0x0b, 0xae, 0xb9, 0x58, 0x77, 0x9e, 0x61, 0x77, 0xae, 0x5a, 0xce, 0x78, 0xa1, 0x22, 0x71, 0x51,
-	0x3c, 0x6c, 0xfb, 0x32, 0x6d, 0x67, 0x1c, 0xa8, 0xfa, 0x26, 0xe4, 0x6c, 0x8e, 0x25, 0xea, 0xd4,
-	0x71, 0x89, 0x57, 0x48, 0x32, 0x9b, 0x6d, 0xf1, 0xbd, 0x85, 0x60, 0x46, 0xc9, 0xda, 0x8b, 0x81,
-	0x57, 0xfc, 0x71, 0x1c, 0xd2, 0x81, 0xdd, 0xd0, 0xed, 0x25, 0x8c, 0xc8, 0xbd, 0x7a, 0x59, 0x5a,
-	0x4c, 0x0a, 0x88, 0xb8, 0x07, 0xa0, 0x07, 0x96, 0x62, 0x3e, 0xcd, 0x1c, 0xec, 0x9c, 0x67, 0x41,
-	0xdf, 0x09, 0x0b, 0x6e, 0xd4, 0x0e, 0x07, 0xbf, 0x87, 0x2d, 0x16, 0x2a, 0xa2, 0x9e, 0xdd, 0x58,
-	0x6c, 0xb6, 0xad, 0x0d, 0xb1, 0xd5, 0x17, 0xd3, 0x42, 0xcd, 0x22, 0x09, 0xfc, 0x09, 0x8e, 0x8b,
-	0xa6, 0xe3, 0x9a, 0x84, 0x97, 0xec, 0x9c, 0x12, 0x8c, 0xd1, 0xd7, 0x00, 0x31, 0x08, 0x32, 0xb0,
-	0xa5, 0xcd, 0x55, 0x0f, 0xeb, 0x8e, 0xcd, 0x30, 0x81, 0x72, 0x31, 0x70, 0x6a, 0xd0, 0x89, 0x3e,
-	0xa7, 0xa3, 0x12, 0x64, 0x42, 0xc1, 0xc1, 0x40, 0x21, 0xad, 0xc0, 0xc2, 0xd3, 0xe8, 0x18, 0x8a,
-	0x3e, 0x83, 0xa5, 0x4d, 0x86, 0x86, 0xa6, 0x86, 0x03, 0x7b, 0x1d, 0x10, 0xd8, 0x16, 0xf2, 0x6d,
-	0x26, 0x5e, 0xe7, 0x51, 0x7e, 0x08, 0xe8, 0x1c, 0x75, 0xeb, 0xe0, 0x6d, 0xd6, 0x0a, 0xeb, 0xf9,
-	0x12, 0xa4, 0xd9, 0x6e, 0x1d, 0xdb, 0x9a, 0x33, 0x2c, 0x49, 0x29, 0x29, 0x4a, 0xe8, 0xda, 0xd6,
-	0x1c, 0x55, 0x60, 0xfb, 0x43, 0x1e, 0x3d, 0x2a, 0x47, 0xe5, 0xd9, 0x68, 0x64, 0xbe, 0x28, 0x00,
-	0x83, 0xd0, 0x2d, 0x31, 0xa5, 0x50, 0xe4, 0x65, 0x13, 0xe8, 0x2e, 0x80, 0xed, 0x10, 0x75, 0x88,
-	0x47, 0x8e, 0x8b, 0x19, 0x6e, 0x67, 0x0e, 0x8a, 0x15, 0xde, 0x49, 0x56, 0xfc, 0x4e, 0xb2, 0x32,
-	0xf0, 0x3b, 0x49, 0x25, 0x6d, 0x3b, 0xa4, 0xc6, 0x98, 0xd1, 0x6f, 0x00, 0x1d, 0xa8, 0xda, 0x88,
-	0x60, 0x57, 0xa0, 0xd2, 0x45, 0x92, 0x29, 0xdb, 0x21, 0x55, 0xca, 0x4b, 0x5d, 0x39, 0xd4, 0xf4,
-	0xd3, 0x91, 0x69, 0x59, 0x85, 0x1c, 0x77, 0xa5, 0x3f, 0x2e, 0x7f, 0x0f, 0x72, 0x4b, 0x28, 0x83,
-	0x8a, 0x70, 0x5d, 0x14, 0x5c, 0xb5, 0xde, 0xed, 0x74, 0x9a, 0xf5, 0x41, 0x57, 0x51, 0x07, 0x4f,
-	0x7b, 0x4d, 0x79, 0x83, 0x96, 0xd7, 0xfe, 0x6f, 0xb5, 0x5b, 0x83, 0xa6, 0x2c, 0xa1, 0x3c, 0x00,
-	0xa5, 0xf6, 0xeb, 0x4a, 0xab, 0x37, 0x90, 0x23, 0x28, 0x0d, 0xf1, 0xd6, 0x51, 0xf5, 0x61, 0x53,
-	0x8e, 0xd2, 0xc7, 0x76, 0xb7, 0x5e, 0x6d, 0xcb, 0xb1, 0xf2, 0x11, 0x64, 0x42, 0xf9, 0x8d, 0x0a,
-	0xb0, 0xe3, 0x2b, 0xef, 0x3f, 0x3a, 0x3e, 0x3c, 0x6c, 0x37, 0x7d, 0xd5, 0x19, 0x48, 0xd6, 0xba,
-	0xdd, 0x76, 0xb3, 0xda, 0x91, 0x25, 0x5e, 0xf4, 0x07, 0xcd, 0x87, 0x4d, 0x45, 0x8e, 0xb0, 0x97,
-	0x0e, 0x94, 0x56, 0xe7, 0xa1, 0x1c, 0x2d, 0xff, 0x30, 0x02, 0x79, 0x56, 0x6a, 0x78, 0x98, 0xd2,
-	0xa8, 0x47, 0x10, 0x3b, 0xc5, 0x73, 0xbf, 0x9d, 0x63, 0xcf, 0xb4, 0x6e, 0x3f, 0xd3, 0xac, 0x19,
-	0xf6, 0x0a, 0x11, 0x46, 0x15, 0x23, 0x6a, 0x08, 0xc3, 0xd1, 0x67, 0x13, 0x6c, 0x13, 0xd1, 0xe9,
-	0x04, 0x63, 0xf4, 0x0c, 0xae, 0xb1, 0xda, 0x16, 0x0e, 0x16, 0x75, 0xa2, 0x4d, 0x19, 0x8c, 0x65,
-	0x0e, 0xbe, 0xc6, 0x93, 0x70, 0xf9, 0xe5, 0x7c, 0xc8, 0xc3, 0xe4, 0xb1, 0xe7, 0xd8, 0x47, 0xda,
-	0xb4, 0x69, 0x13, 0x77, 0x5e, 0x7b, 0xe3, 0xa3, 0xcf, 0x2e, 0x2a, 0x59, 0xa3, 0x85, 0x58, 0xb1,
-	0x09, 0x37, 0x5e, 0xa3, 0xc5, 0xaf, 0xd2, 0xa2, 0x03, 0xa7, 0x55, 0x7a, 0x07, 0xe2, 0x6c, 0x2b,
-	0x7e, 0x07, 0xce, 0x06, 0xf7, 0x22, 0xef, 0x4b, 0xe5, 0xff, 0x8d, 0x43, 0xa6, 0xae, 0x4d, 0xc9,
-	0xcc, 0xc5, 0xac, 0x1f, 0x29, 0x2d, 0x61, 0x4d, 0xe6, 0xd5, 0xcb, 0x52, 0x52, 0x4c, 0x0b, 0xa4,
-	0x39, 0x5c, 0x29, 0x4a, 0x11, 0x56, 0x94, 0xfc, 0x42, 0xb7, 0xd0, 0x75, 0xa5, 0x32, 0x14, 0xbd,
-	0x42, 0x19, 0xfa, 0x06, 0xa4, 0x86, 0xa6, 0x4d, 0x59, 0x3c, 0x61, 0xe5, 0x9b, 0xab, 0x2f, 0xaf,
-	0x71, 0x0e, 0x25, 0x60, 0xa5, 0xfd, 0x88, 0x69, 0x13, 0xec, 0x3e, 0xd3, 0xac, 0x33, 0xd8, 0xb3,
-	0xe9, 0xd3, 0x7d, 0xe8, 0x59, 0x2d, 0x1f, 0x89, 0x5f, 0xbe, 0x7c, 0x24, 0x7f, 0xf9, 0xf2, 0x91,
-	0x5a, 0xaf, 0x7c, 0xfc, 0x97, 0x04, 0x49, 0xb1, 0x7f, 0xf4, 0x18, 0x76, 0x5c, 0xec, 0x39, 0x33,
-	0x57, 0xc7, 0x4b, 0x78, 0x26, 0xad, 0x61, 0xf0, 0xbc, 0x2f, 0x29, 0x10, 0xed, 0xcb, 0x90, 0x0b,
-	0x74, 0x4d, 0x35, 0x72, 0x22, 0xd2, 0x24, 0xeb, 0x13, 0x7b, 0x1a, 0x39, 0x39, 0x53, 0x8a, 0xa2,
-	0x57, 0x2a, 0x45, 0x61, 0xc4, 0x89, 0x2d, 0x23, 0x0e, 0x85, 0x53, 0x8f, 0xd0, 0x16, 0xcf, 0xff,
-	0xc8, 0x4b, 0x2b, 0x29, 0x46, 0x78, 0x82, 0xe7, 0xe5, 0x83, 0xb3, 0x70, 0xb4, 0xd4, 0xff, 0x07,
-	0x18, 0x93, 0x5c, 0x60, 0x4c, 0xaa, 0xfc, 0x53, 0x80, 0xed, 0x23, 0x8d, 0x60, 0xd7, 0xd4, 0x2c,
-	0xf3, 0xfb, 0x5a, 0xd0, 0x92, 0xbf, 0xb9, 0x94, 0x02, 0xdb, 0xaf, 0x5e, 0x96, 0x36, 0xcf, 0xb0,
-	0x89, 0x54, 0xe8, 0xbc, 0x26, 0x15, 0xde, 0xe4, 0xbb, 0x3d, 0x47, 0xf7, 0xff, 0x5b, 0x4a, 0x3c,
-	0x58, 0x49, 0x89, 0xdb, 0xaf, 0x5f, 0xc4, 0x6a, 0x6a, 0x7c, 0xc1, 0xdb, 0xa5, 0x7f, 0x48, 0x7c,
-	0x41, 0xe3, 0xfd, 0xfc, 0xd6, 0x2b, 0xf9, 0x2b, 0x68, 0xbd, 0xd2, 0x67, 0x5a, 0xaf, 0x3a, 0x6c,
-	0xf2, 0x32, 0xe5, 0xf9, 0x45, 0x88, 0x25, 0x58, 0xb0, 0xd4, 0xe5, 0x02, 0x25, 0xde, 0x91, 0x1f,
-	0x2d, 0xd7, 0xcc, 0x2f, 0x43, 0xce, 0xc0, 0x16, 0xd1, 0xd4, 0xd9, 0xd4, 0xd0, 0x08, 0xf6, 0xfc,
-	0xb3, 0x16, 0x46, 0x3c, 0xe6, 0x34, 0x34, 0x04, 0x64, 0xe0, 0xa9, 0x8b, 0x75, 0x8d, 0x60, 0x43,
-	0x15, 0x0d, 0x96, 0x08, 0x86, 0xf7, 0x2e, 0x0d, 0xca, 0x4a, 0x23, 0x90, 0x15, 0x0d, 0x80, 0xb2,
-	0x65, 0x9c, 0x25, 0xbd, 0xae, 0x7b, 0x4a, 0xad, 0xd7, 0x3d, 0xc1, 0xe7, 0xee, 0x9e, 0x32, 0x9f,
-	0xb3, 0x7b, 0xca, 0x5e, 0x84, 0x65, 0xb9, 0x65, 0x2c, 0x2b, 0xfe, 0xae, 0x04, 0x5b, 0x2b, 0x56,
-	0x40, 0xb7, 0x00, 0xc6, 0xae, 0x33, 0x9b, 0xaa, 0x0b, 0x6c, 0x52, 0xd2, 0x8c, 0xd2, 0xa1, 0x58,
-	0xf4, 0x2b, 0x6d, 0xe2, 0xd7, 0x85, 0xd3, 0xd4, 0x02, 0x4e, 0xd3, 0xe5, 0xff, 0x4c, 0x43, 0xa2,
-	0x5b, 0x9d, 0x91, 0x93, 0x03, 0x71, 0x36, 0xf2, 0xcc, 0x34, 0xb0, 0x7f, 0x0e, 0x18, 0x8c, 0xd1,
-	0xdb, 0xb0, 0xa5, 0xcd, 0xc8, 0x89, 0x3a, 0x73, 0xad, 0x05, 0x54, 0xf0, 0xb6, 0x64, 0x93, 0x4e,
-	0x1c, 0xbb, 0x56, 0x80, 0x07, 0x77, 0xe1, 0xa6, 0xa6, 0xeb, 0xd8, 0xf3, 0x54, 0xe2, 0x9c, 0x62,
-	0x7b, 0x59, 0x86, 0x37, 0x62, 0xd7, 0x39, 0xc3, 0x80, 0xce, 0x87, 0x45, 0x2b, 0xb0, 0xbd, 0x24,
-	0x3a, 0xc1, 0xe4, 0xc4, 0x31, 0xf8, 0x81, 0x87, 0xb2, 0x15, 0x12, 0x3a, 0x62, 0x13, 0x6c, 0x59,
-	0x61, 0xfe, 0xa1, 0x63, 0xcc, 0xc5, 0x81, 0xc6, 0x66, 0x88, 0xbb, 0xe6, 0x18, 0x73, 0xf4, 0xfb,
-	0x12, 0xdc, 0x5a, 0x62, 0x3e, 0xc1, 0x9a, 0x81, 0x5d, 0x6f, 0xd1, 0xfb, 0xc5, 0x19, 0x6e, 0x89,
-	0x4f, 0x58, 0x6e, 0x94, 0x4a, 0x75, 0xa1, 0xe5, 0x11, 0xe7, 0xbf, 0x42, 0xeb, 0x87, 0xb4, 0x15,
-	0x69, 0xf4, 0x91, 0x04, 0xbb, 0x4b, 0xcb, 0x70, 0xb1, 0x37, 0x75, 0x6c, 0x0f, 0x2f, 0xd6, 0x91,
-	0x08, 0xf7, 0xa0, 0xab, 0xeb, 0x50, 0x84, 0xc0, 0x15, 0x16, 0x12, 0xb6, 0xb7, 0x2f, 0x7e, 0xa4,
-	0x4d, 0xd1, 0x7d, 0x28, 0xba, 0x78, 0xe4, 0x62, 0xef, 0xe4, 0x3c, 0x5f, 0xf1, 0xf3, 0xbd, 0x1b,
-	0x82, 0x63, 0xc5, 0x59, 0x5f, 0xa7, 0x98, 0x1d, 0x16, 0x16, 0xde, 0xca, 0x32, 0x31, 0x14, 0x16,
-	0x13, 0xee, 0x62, 0x5f, 0x92, 0x61, 0x09, 0xe6, 0x2f, 0x9e, 0xff, 0x72, 0x98, 0x9f, 0x39, 0xec,
-	0x0f, 0x24, 0xd8, 0x5d, 0x66, 0x5f, 0xf1, 0x58, 0xfa, 0x1c, 0x4b, 0x29, 0x21, 0x3d, 0x57, 0x77,
-	0xd9, 0xb6, 0xbb, 0x2a, 0x8e, 0xfe, 0x48, 0x82, 0xd2, 0xf2, 0x4a, 0x56, 0x9d, 0x06, 0x6c, 0x29,
-	0xef, 0xbe, 0x76, 0x29, 0x9f, 0xc3, 0x6b, 0x4b, 0x96, 0x0f, 0xb9, 0xad, 0xd8, 0x86, 0xdd, 0x8b,
-	0xe3, 0xf2, 0x2a, 0x1f, 0x13, 0xc5, 0x23, 0x28, 0x5d, 0x12, 0x5d, 0x57, 0x55, 0x77, 0x89, 0x0b,
-	0xae, 0xa4, 0xae, 0x03, 0x7b, 0x97, 0x99, 0xf1, 0x4a, 0x9f, 0x4e, 0x7f, 0x1e, 0x85, 0xd4, 0x00,
-	0x7b, 0x84, 0x35, 0x8d, 0x28, 0xdc, 0x34, 0x8a, 0xfe, 0xf0, 0x0e, 0xc4, 0x3d, 0x82, 0xa7, 0xfc,
-	0x6b, 0x92, 0x36, 0x53, 0xcc, 0xa3, 0xbe, 0x48, 0xa5, 0x4f, 0xf0, 0x54, 0x40, 0x30, 0xe7, 0x2b,
-	0xfe, 0x3c, 0x02, 0x31, 0x4a, 0x45, 0xdf, 0xa0, 0xf5, 0x01, 0x4f, 0xc3, 0x87, 0x7e, 0x85, 0x73,
-	0xa4, 0x2b, 0xac, 0x8b, 0x4c, 0x51, 0x56, 0x86, 0xd2, 0xb7, 0x00, 0x98, 0x98, 0x69, 0x1b, 0xf8,
-	0x85, 0xb8, 0x20, 0x62, 0x8a, 0x5a, 0x94, 0x70, 0xf6, 0x9a, 0x21, 0xba, 0x7a, 0xcd, 0xe0, 0x2b,
-	0xf0, 0x74, 0x67, 0xea, 0xdf, 0x51, 0x30, 0x05, 0x7d, 0x4a, 0x40, 0x95, 0xa5, 0x56, 0x27, 0x7e,
-	0xee, 0x91, 0x75, 0xb8, 0xbd, 0xb9, 0x0b, 0x39, 0xc3, 0xd1, 0x45, 0x96, 0x3d, 0xc3, 0x3a, 0x3f,
-	0xc3, 0xb9, 0xa4, 0x09, 0x8b, 0x51, 0x11, 0xf4, 0x00, 0x20, 0x28, 0x4b, 0xde, 0x7a, 0x1d, 0x51,
-	0x48, 0xa0, 0xbc, 0x0b, 0x31, 0x66, 0x11, 0x80, 0x44, 0xab, 0xf3, 0xb0, 0xd9, 0x1f, 0xf0, 0x53,
-	0x88, 0x6f, 0x37, 0x95, 0xd6, 0xe1, 0x53, 0x59, 0x2a, 0xff, 0x50, 0x82, 0xb4, 0xa2, 0xd9, 0x63,
-	0xfe, 0xd1, 0xfb, 0x25, 0x48, 0x9f, 0xe2, 0xb9, 0xca, 0xef, 0xda, 0xa8, 0xd9, 0x92, 0x4a, 0xea,
-	0x14, 0xcf, 0x6b, 0xec, 0xba, 0xed, 0x06, 0x24, 0xe9, 0x24, 0xb6, 0x0d, 0x66, 0xb1, 0xa4, 0x92,
-	0x38, 0xc5, 0xf3, 0xa6, 0x6d, 0xa0, 0x32, 0xe4, 0x5c, 0x95, 0x5d, 0x8a, 0x09, 0xc9, 0x18, 0x9b,
-	0xce, 0xb8, 0xec, 0xbe, 0x8c, 0x0b, 0xef, 0x42, 0xc6, 0xe7, 0xa1, 0x0a, 0xe2, 0x8c, 0x23, 0xcd,
-	0x39, 0x9a, 0xb6, 0x71, 0x4f, 0xfe, 0xd1, 0xc7, 0xa5, 0x8d, 0x33, 0xb7, 0x77, 0xf9, 0xa0, 0xf4,
-	0xf6, 0x69, 0x4b, 0x80, 0xee, 0x43, 0x96, 0xf7, 0x5b, 0xc6, 0xfa, 0x9d, 0x6c, 0x52, 0x48, 0xa0,
-	0x12, 0x64, 0x26, 0xd8, 0x1d, 0xb3, 0xfe, 0x55, 0x3f, 0x61, 0x9b, 0x4b, 0x29, 0xc0, 0x48, 0x3d,
-	0x4a, 0x29, 0xdf, 0x85, 0x74, 0x1f, 0xbb, 0x3d, 0xc7, 0x32, 0x75, 0x76, 0x40, 0xe7, 0x11, 0x57,
-	0x25, 0xee, 0xcc, 0xa6, 0xcd, 0x87, 0xe8, 0x7a, 0xf8, 0xc5, 0x87, 0xec, 0x11, 0x77, 0x20, 0x26,
-	0x58, 0x87, 0x53, 0xfe, 0xd3, 0x08, 0x40, 0xf3, 0x05, 0x71, 0x35, 0x9d, 0x54, 0x7b, 0xad, 0xe2,
-	0x5f, 0x4a, 0x90, 0x10, 0x8d, 0x73, 0xf8, 0x56, 0x40, 0x5a, 0xbe, 0x15, 0x78, 0x00, 0x99, 0xf0,
-	0x0d, 0xc4, 0x3a, 0xc7, 0xcf, 0xc0, 0x05, 0x68, 0xa2, 0xd2, 0x08, 0xe5, 0x7d, 0xec, 0x94, 0xb8,
-	0x9e, 0xb8, 0x88, 0x48, 0x33, 0x4a, 0x8f, 0xb8, 0x2b, 0x17, 0x09, 0xb1, 0xb5, 0x2f, 0x12, 0xca,
-	0x4f, 0x21, 0x56, 0x77, 0x8c, 0x33, 0x9d, 0x4e, 0x0e, 0xd2, 0xf5, 0x6e, 0xe7, 0xb0, 0xf5, 0xf0,
-	0x58, 0x69, 0xf2, 0xf3, 0xa5, 0xe6, 0x77, 0x06, 0x4a, 0xb5, 0x3e, 0x90, 0x23, 0x08, 0x41, 0x5e,
-	0x0c, 0x9a, 0x0d, 0xf5, 0xf8, 0xb8, 0xd5, 0x90, 0xa3, 0x68, 0x07, 0xe4, 0x05, 0xed, 0xb0, 0xd5,
-	0x6c, 0x37, 0xfa, 0x72, 0xac, 0xfc, 0x69, 0x1c, 0xa0, 0xee, 0x4c, 0x86, 0xa6, 0x8d, 0xa9, 0x9d,
-	0xfe, 0x23, 0x12, 0xd8, 0xe9, 0x8c, 0x31, 0xa4, 0x2b, 0x1a, 0xe3, 0x26, 0xd0, 0x30, 0xe5, 0xa6,
-	0xe0, 0x9f, 0x26, 0x34, 0x54, 0x99, 0x21, 0xae, 0x43, 0x42, 0x5c, 0xb9, 0x70, 0x1b, 0x89, 0x11,
-	0x2d, 0xb5, 0xdc, 0x33, 0x96, 0xa6, 0xe3, 0x13, 0xc7, 0x32, 0xb0, 0x1b, 0xba, 0xbb, 0x41, 0xcc,
-	0x4b, 0x8b, 0x29, 0xea, 0xb0, 0x33, 0x26, 0x8d, 0xaf, 0x7f, 0x37, 0xf3, 0x26, 0x6c, 0x2e, 0xc0,
-	0x80, 0xf7, 0xad, 0xec, 0xde, 0x52, 0xc9, 0x2f, 0xc8, 0xac, 0x79, 0x7d, 0x1b, 0xb6, 0xd8, 0xb5,
-	0xeb, 0xd2, 0xdd, 0x94, 0xb8, 0x13, 0x64, 0x13, 0xfd, 0xc5, 0x9e, 0x2b, 0x00, 0x1e, 0x5d, 0x33,
-	0x0b, 0x58, 0x56, 0xf1, 0x83, 0xbb, 0xdc, 0x20, 0x8e, 0x95, 0xb4, 0xe7, 0x3f, 0x16, 0x7f, 0x07,
-	0xe2, 0x34, 0x8d, 0x3c, 0xf4, 0x6b, 0x10, 0xb3, 0xf0, 0x88, 0x30, 0x23, 0x67, 0x0e, 0x10, 0x17,
-	0x69, 0x38, 0xba, 0x57, 0xb5, 0x8d, 0xda, 0x9c, 0x60, 0x4f, 0x61, 0xf3, 0x68, 0x1f, 0xe2, 0xae,
-	0x39, 0x3e, 0x21, 0xe2, 0x14, 0xfd, 0x3c, 0x46, 0xce, 0x80, 0xbe, 0x02, 0x51, 0x67, 0x46, 0x44,
-	0x93, 0x7d, 0x1e, 0x1f, 0x9d, 0x2e, 0xff, 0xa3, 0xb4, 0x46, 0x64, 0x6d, 0x42, 0x46, 0x69, 0x36,
-	0x8e, 0xeb, 0x4d, 0xb5, 0xdd, 0x3c, 0xa4, 0xd1, 0xb5, 0x05, 0xb9, 0x7a, 0xf7, 0xa8, 0xd6, 0xea,
-	0x34, 0x55, 0xa5, 0xf5, 0xf0, 0xd1, 0x40, 0x8e, 0x22, 0x19, 0x32, 0x0d, 0xa5, 0xda, 0xea, 0xa8,
-	0xf5, 0x47, 0xc7, 0x9d, 0x27, 0xf2, 0x27, 0x12, 0xda, 0x85, 0x9b, 0x8c, 0xd2, 0x6c, 0xa8, 0x82,
-	0xb9, 0xa1, 0x36, 0xba, 0xf5, 0xe3, 0xa3, 0x66, 0x67, 0x20, 0xff, 0x8d, 0x84, 0x6e, 0x41, 0xc1,
-	0x9f, 0xe7, 0xda, 0x43, 0xd3, 0x7f, 0x2b, 0x05, 0x0a, 0x9b, 0x0d, 0xf5, 0x49, 0xf3, 0xa9, 0xfc,
-	0x77, 0x12, 0xda, 0x86, 0xbc, 0x4f, 0x11, 0xd1, 0xfb, 0x53, 0x09, 0x21, 0xc8, 0xf9, 0xc4, 0xfe,
-	0xa0, 0x3a, 0xe8, 0xcb, 0x7f, 0x2f, 0x95, 0x7f, 0x2e, 0x41, 0xaa, 0x36, 0x33, 0x2d, 0x83, 0x06,
-	0xf4, 0xbf, 0x2e, 0x25, 0xfe, 0x90, 0x92, 0x55, 0xd3, 0xf0, 0x13, 0x9f, 0x8d, 0x5b, 0xc6, 0x62,
-	0xca, 0x18, 0x8a, 0x5a, 0xca, 0xa7, 0x1a, 0x43, 0x76, 0x41, 0xcd, 0x3e, 0xa8, 0x45, 0x4d, 0x12,
-	0x23, 0x74, 0x00, 0x19, 0xf1, 0xf5, 0xcd, 0x0a, 0x61, 0x8c, 0x15, 0xc2, 0x2d, 0xff, 0xdb, 0x3a,
-	0xb8, 0x6a, 0x56, 0x80, 0x73, 0x31, 0xc4, 0x7f, 0x07, 0xb6, 0x16, 0x87, 0x32, 0xe2, 0x94, 0x40,
-	0x1c, 0x17, 0xc9, 0xc1, 0x84, 0x38, 0x4a, 0x40, 0xb7, 0x21, 0x2b, 0x02, 0x56, 0x75, 0x1d, 0x87,
-	0x88, 0xf0, 0xf4, 0x83, 0x58, 0x71, 0x1c, 0x52, 0xde, 0x86, 0x2d, 0x05, 0x7b, 0x98, 0x30, 0x2c,
-	0x56, 0xf0, 0x6f, 0xcf, 0xb0, 0x47, 0xca, 0x3b, 0x80, 0xc2, 0x44, 0xde, 0x48, 0x94, 0x1f, 0x00,
-	0xaa, 0x1a, 0xcf, 0x34, 0x5b, 0xc7, 0xf4, 0x7b, 0x50, 0xf0, 0xd2, 0x2c, 0xd0, 0x38, 0x35, 0x38,
-	0x75, 0xa4, 0x96, 0x89, 0x29, 0x79, 0x41, 0x16, 0x87, 0x8e, 0xe5, 0x6b, 0xb0, 0xbd, 0x24, 0x2e,
-	0xb4, 0xbe, 0x0f, 0xd9, 0x70, 0x50, 0xd1, 0x4e, 0x83, 0x56, 0x48, 0x81, 0xca, 0xbc, 0x5a, 0xee,
-	0x40, 0x7c, 0x48, 0x27, 0x99, 0x61, 0x63, 0x0a, 0x1f, 0x94, 0xff, 0x58, 0x82, 0x5c, 0xcb, 0x1e,
-	0x63, 0x8f, 0xf8, 0x6b, 0x59, 0x2e, 0xe0, 0xd2, 0xa5, 0x05, 0x3c, 0xec, 0xce, 0xc8, 0xb2, 0x3b,
-	0x57, 0x6a, 0x7b, 0x74, 0xdd, 0xda, 0x5e, 0xfe, 0xa7, 0x08, 0xe4, 0xfd, 0x75, 0xf1, 0x4d, 0xa2,
-	0xbf, 0x90, 0x16, 0x5f, 0xf4, 0xfc, 0xae, 0x9b, 0x76, 0xe8, 0xfc, 0x74, 0x3e, 0xf8, 0x90, 0x5a,
-	0x96, 0xf1, 0x8f, 0x93, 0x3e, 0xa0, 0xec, 0xb4, 0x37, 0xf4, 0x78, 0x27, 0xfc, 0xbd, 0xdf, 0xfb,
-	0xac, 0xf4, 0xce, 0x79, 0x7f, 0x67, 0x39, 0xf3, 0x77, 0x1e, 0x5f, 0xfe, 0xa3, 0xcf, 0x4a, 0x6f,
-	0xaf, 0xc3, 0xde, 0x1d, 0x8d, 0x3c, 0x4c, 0x82, 0xd3, 0x85, 0xc5, 0x6b, 0xd1, 0x6f, 0x42, 0xd6,
-	0x5f, 0x3b, 0x26, 0xba, 0x21, 0x80, 0x23, 0x74, 0xf0, 0xc5, 0x3b, 0xd7, 0x4a, 0x93, 0xe8, 0x86,
-	0x8f, 0x93, 0x42, 0x80, 0x92, 0x8a, 0x0d, 0xb8, 0x7e, 0xfe, 0x5e, 0x2e, 0x6b, 0x47, 0xa3, 0xe1,
-	0x76, 0xf4, 0xcf, 0x22, 0x70, 0x63, 0xa0, 0x79, 0xa7, 0xfe, 0x31, 0x99, 0xeb, 0xbc, 0x98, 0xfb,
-	0x7e, 0xff, 0x26, 0xc4, 0x9c, 0x29, 0xb6, 0x05, 0xf6, 0x95, 0x45, 0x2b, 0x79, 0x3e, 0x73, 0xa5,
-	0x3b, 0xc5, 0xb6, 0xc2, 0xf8, 0x59, 0xac, 0x69, 0x44, 0x63, 0x2f, 0xcb, 0x2a, 0xec, 0xb9, 0xf8,
-	0x57, 0x12, 0xc4, 0x28, 0x0b, 0xda, 0x87, 0x04, 0xff, 0x8c, 0x12, 0x6a, 0xe5, 0xb3, 0x1b, 0x56,
-	0xc4, 0x3c, 0x6a, 0x43, 0x8a, 0x1f, 0x2f, 0xfa, 0x61, 0x54, 0xfb, 0xf5, 0x57, 0x2f, 0x4b, 0xef,
-	0x9e, 0x67, 0xf5, 0x95, 0xff, 0x54, 0xf1, 0x83, 0xc7, 0x56, 0x43, 0x49, 0x32, 0x15, 0x2d, 0xd6,
-	0xd2, 0x10, 0xcd, 0x1d, 0x63, 0xc2, 0x0e, 0x09, 0x19, 0x64, 0xe4, 0x14, 0xe0, 0x24, 0xf6, 0x97,
-	0x8d, 0x12, 0x64, 0x74, 0xcb, 0xc4, 0x36, 0x51, 0x35, 0xc3, 0xf0, 0x4b, 0x1b, 0x70, 0x52, 0xd5,
-	0x30, 0xdc, 0xf2, 0x1f, 0x46, 0xa1, 0xb0, 0xba, 0x7b, 0x11, 0x8a, 0x3d, 0xc8, 0xd1, 0xbd, 0x07,
-	0x1f, 0x66, 0x62, 0x77, 0xef, 0xbc, 0xce, 0x68, 0x22, 0x1a, 0x99, 0xd5, 0xc4, 0x40, 0xc9, 0x3a,
-	0xa1, 0xd1, 0xb9, 0x56, 0x7c, 0x0e, 0xd9, 0xb0, 0x04, 0x7a, 0x00, 0x09, 0x8f, 0x68, 0x64, 0xe6,
-	0x89, 0x76, 0xff, 0xab, 0x97, 0xbc, 0xae, 0xcf, 0x98, 0x15, 0x21, 0x14, 0xf2, 0x45, 0xe4, 0x62,
-	0x5f, 0x94, 0x7f, 0xc0, 0xfe, 0xc4, 0xc3, 0x84, 0x12, 0x10, 0xe9, 0x3e, 0x91, 0x37, 0xd0, 0x36,
-	0x6c, 0xf6, 0x1f, 0x55, 0x95, 0x86, 0xda, 0xe9, 0x0e, 0xd4, 0xc3, 0xee, 0x71, 0xa7, 0x21, 0x4b,
-	0xb4, 0x95, 0xe9, 0x74, 0x55, 0x4e, 0xef, 0x29, 0xad, 0xa3, 0xaa, 0xf2, 0x54, 0x8e, 0xa0, 0x6b,
-	0xb0, 0x45, 0x99, 0x96, 0xc9, 0x51, 0xda, 0x0b, 0xb5, 0x3a, 0x83, 0xa6, 0xd2, 0xa9, 0xb6, 0xd5,
-	0xa6, 0xa2, 0x74, 0x15, 0x39, 0x46, 0x2b, 0x18, 0x67, 0xeb, 0x0f, 0xba, 0xbd, 0x5e, 0xb3, 0x21,
-	0xc7, 0xd1, 0x35, 0x90, 0x7b, 0x5d, 0x65, 0xc0, 0xde, 0x53, 0x6d, 0xb7, 0xbb, 0x1f, 0x34, 0x1b,
-	0xf2, 0x2f, 0x92, 0x6f, 0x77, 0x20, 0x13, 0x42, 0x73, 0x5a, 0x27, 0xeb, 0xd5, 0x41, 0xb5, 0xdd,
-	0x7d, 0x28, 0x6f, 0xd0, 0xc2, 0xf8, 0xb8, 0xdf, 0xed, 0xa8, 0xfd, 0xfa, 0xa3, 0xe6, 0x51, 0x55,
-	0x96, 0xd8, 0x5f, 0x75, 0x58, 0xe1, 0x94, 0x63, 0x74, 0x35, 0x7e, 0x3d, 0xeb, 0xab, 0x87, 0xad,
-	0xef, 0x0c, 0x68, 0x31, 0x8d, 0x1f, 0xfc, 0xb5, 0x04, 0x49, 0xfa, 0x9d, 0x64, 0xda, 0x63, 0xf4,
-	0x2d, 0x80, 0x05, 0x52, 0xa3, 0x1b, 0xdc, 0xaa, 0x2b, 0x80, 0x5e, 0x2c, 0xac, 0x4e, 0x08, 0xc7,
-	0xd4, 0x20, 0x13, 0x42, 0x65, 0x24, 0x18, 0x57, 0x71, 0xbe, 0x78, 0xf3, 0x9c, 0x19, 0xa1, 0xe3,
-	0x3d, 0x48, 0x70, 0xec, 0x42, 0xdb, 0xcb, 0x48, 0xc6, 0x25, 0x77, 0xce, 0x83, 0xb7, 0x83, 0xef,
-	0x42, 0x36, 0xec, 0x79, 0xf4, 0x18, 0xe2, 0xfc, 0xe1, 0xd6, 0x85, 0xe9, 0x5b, 0xdc, 0xbd, 0x38,
-	0x72, 0xf6, 0xa5, 0xaf, 0x4b, 0xb5, 0xfb, 0x9f, 0xfc, 0xdb, 0xee, 0xc6, 0x27, 0x3f, 0xdb, 0x95,
-	0x3e, 0xfd, 0xd9, 0xae, 0xf4, 0xf1, 0xbf, 0xef, 0x4a, 0xdf, 0x7d, 0x6b, 0x6c, 0x92, 0x93, 0xd9,
-	0xb0, 0xa2, 0x3b, 0x93, 0x3b, 0xd8, 0x23, 0x33, 0xcd, 0x9d, 0xf3, 0xbf, 0x45, 0xae, 0xfc, 0x51,
-	0x72, 0x98, 0x60, 0xe3, 0xf7, 0xfe, 0x2f, 0x00, 0x00, 0xff, 0xff, 0xd8, 0x8c, 0x72, 0x9d, 0x44,
-	0x29, 0x00, 0x00,
+	// 3760 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xd5, 0x5a, 0xcd, 0x6f, 0x23, 0xd9,
+	0x71, 0x9f, 0xe6, 0x97, 0xc8, 0x22, 0x45, 0xb5, 0x9e, 0xe6, 0x43, 0xc3, 0xec, 0x4a, 0xbb, 0x8c,
+	0x9d, 0xfd, 0xf2, 0x52, 0x8e, 0x16, 0x71, 0x76, 0x67, 0xb1, 0x31, 0xf8, 0xa5, 0x19, 0xce, 0x52,
+	0x24, 0xd3, 0xa4, 0xbc, 0x1e, 0x03, 0x41, 0xa3, 0x45, 0x36, 0xa9, 0xde, 0x69, 0xb1, 0x99, 0xee,
+	0xe6, 0xcc, 0xc8, 0x97, 0x04, 0x46, 0x80, 0x00, 0x46, 0x02, 0xf8, 0x12, 0xd8, 0xb7, 0x6c, 0x02,
+	0x04, 0xc8, 0xbf, 0x10, 0xfb, 0x12, 0x20, 0x97, 0x4d, 0xe2, 0x04, 0x8b, 0x04, 0xb9, 0xe4, 0x60,
+	0x23, 0xce, 0xc1, 0x06, 0x02, 0x24, 0x97, 0x1c, 0x82, 0x9c, 0x52, 0xaf, 0xde, 0xeb, 0x0f, 0x8a,
+	0x9c, 0x11, 0xb5, 0x76, 0x0e, 0x3e, 0x48, 0xe8, 0x57, 0xaf, 0xaa, 0xde, 0x47, 0x55, 0xfd, 0xaa,
+	0xde, 0x7b, 0x84, 0x97, 0x26, 0xce, 0xc1, 0xcc, 0x75, 0x7c, 0x67, 0xe8, 0xd8, 0xde, 0xc1, 0xd8,
+	0x76, 0x9e, 0xd2, 0xbf, 0x0a, 0xd1, 0x58, 0x8a, 0x7f, 0x97, 0xf6, 0x4e, 0x5d, 0xe7, 0xb1, 0xe9,
+	0x86, 0x7c, 0xe1, 0x87, 0xe0, 0x2a, 0xbd, 0x32, 0x74, 0xa6, 0xde, 0xfc, 0xfc, 0x05, 0x1c, 0x37,
+	0x27, 0xce, 0xc4, 0xa1, 0xcf, 0x03, 0xfe, 0x25, 0xa9, 0xfb, 0x13, 0xc7, 0x99, 0xd8, 0xa6, 0x60,
+	0x3e, 0x9d, 0x8f, 0x0f, 0x7c, 0xeb, 0xdc, 0xf4, 0x7c, 0xe3, 0x7c, 0x26, 0x18, 0xca, 0x1f, 0x40,
+	0xba, 0x6f, 0x5b, 0x43, 0x93, 0xdd, 0x84, 0xf4, 0xa9, 0x39, 0xb1, 0xa6, 0xbb, 0xca, 0x2b, 0xca,
+	0xeb, 0x9b, 0x9a, 0x68, 0x30, 0x15, 0x92, 0xe6, 0x74, 0xb4, 0x9b, 0x20, 0x1a, 0xff, 0xbc, 0x57,
+	0xf8, 0xec, 0xcf, 0xf6, 0x6f, 0x7c, 0xe7, 0xcf, 0xf7, 0x6f, 0x7c, 0x0f, 0xff, 0xca, 0x53, 0xc8,
+	0x9d, 0x9c, 0xb4, 0x1a, 0x3d, 0xc3, 0xf5, 0x3d, 0xc6, 0x20, 0x35, 0x75, 0x46, 0x26, 0x69, 0xc8,
+	0x68, 0xf4, 0xcd, 0xde, 0x87, 0xf4, 0xd0, 0x76, 0x86, 0x8f, 0x49, 0x45, 0xa6, 0xf6, 0xc5, 0xff,
+	0xfd, 0xd1, 0xfe, 0xab, 0x38, 0xbb, 0x89, 0xf1, 0x4d, 0xd3, 0xf7, 0xcd, 0xca, 0xc8, 0x7c, 0x72,
+	0x30, 0x74, 0x5c, 0xf3, 0x00, 0x67, 0xe5, 0x19, 0x13, 0xb3, 0x52, 0xe7, 0xcc, 0x9a, 0x90, 0xb9,
+	0xa7, 0xfe, 0xec, 0x93, 0x7d, 0x65, 0x61, 0xbc, 0x7f, 0x51, 0x00, 0x7a, 0xae, 0xf3, 0xb1, 0x39,
+	0xf4, 0x2d, 0x87, 0xa6, 0x37, 0xf3, 0x5d, 0x1a, 0x30, 0xa7, 0xf1, 0x4f, 0xbe, 0x8c, 0xb1, 0x65,
+	0xda, 0x62, 0xca, 0x39, 0x4d, 0x34, 0x58, 0x09, 0xb2, 0xe6, 0xb3, 0x19, 0xae, 0xd3, 0xf2, 0x77,
+	0x93, 0xd8, 0x91, 0xd5, 0xc2, 0x36, 0x7b, 0x1d, 0x54, 0xcb, 0xd3, 0x67, 0xb8, 0x02, 0x8b, 0xeb,
+	0xd4, 0x1f, 0x9b, 0x17, 0xbb, 0x29, 0xe2, 0x29, 0x5a, 0x5e, 0x2f, 0x20, 0x7f, 0x68, 0x5e, 0xb0,
+	0x2f, 0x40, 0x91, 0x73, 0xba, 0xd6, 0xb9, 0xe1, 0x5e, 0x10, 0x5f, 0x9a, 0xf8, 0x0a, 0xc8, 0x27,
+	0x88, 0x9c, 0xeb, 0x1d, 0xc8, 0x59, 0xd3, 0xb1, 0xe9, 0x9a, 0xd3, 0xa1, 0xb9, 0x9b, 0x41, 0x86,
+	0xfc, 0xe1, 0x56, 0x85, 0x0c, 0xde, 0x0a, 0xc8, 0xb5, 0xd4, 0xa7, 0x3f, 0xda, 0xbf, 0xa1, 0x45,
+	0x7c, 0xe5, 0xff, 0x4e, 0x42, 0x2e, 0xec, 0xe6, 0x8b, 0xf0, 0x2f, 0x66, 0xa6, 0x87, 0x0b, 0x4b,
+	0xf2, 0x45, 0x50, 0x83, 0x55, 0x20, 0xe3, 0xf9, 0xae, 0x35, 0x9d, 0xd0, 0x12, 0xf2, 0x87, 0xb7,
+	0x2f, 0x69, 0xad, 0xf4, 0xa9, 0x57, 0x93, 0x5c, 0xa4, 0xc5, 0xf2, 0x6d, 0x93, 0x56, 0xc3, 0xb5,
+	0xf0, 0x06, 0x7b, 0x05, 0xf2, 0x23, 0xd3, 0x1b, 0xba, 0xd6, 0x8c, 0x2f, 0x8b, 0x56, 0x90, 0xd3,
+	0xe2, 0x24, 0x34, 0x59, 0x61, 0x64, 0x8e, 0x8d, 0xb9, 0xed, 0xeb, 0x1f, 0x7b, 0xc8, 0xc2, 0xd7,
+	0x90, 0xab, 0xbd, 0x84, 0x96, 0xdb, 0xc5, 0x61, 0x9c, 0x11, 0xea, 0x3e, 0xe0, 0x1d, 0x15, 0xcd,
+	0x78, 0x7a, 0x2c, 0xcc, 0xa6, 0x6d, 0x48, 0x09, 0x76, 0x1b, 0x27, 0x69, 0x0e, 0x5d, 0xd3, 0xdf,
+	0xdd, 0xa0, 0xbd, 0x91, 0x2d, 0x3e, 0x79, 0xf3, 0x99, 0xe5, 0xf9, 0xde, 0x6e, 0x16, 0xe9, 0xc5,
+	0xe5, 0xc9, 0x37, 0xa9, 0x57, 0x93, 0x5c, 0xa5, 0x3f, 0x55, 0x20, 0x23, 0xd6, 0xc3, 0x5e, 0x85,
+	0x02, 0x7a, 0xbf, 0x6f, 0x4e, 0x7d, 0x9d, 0x6f, 0x04, 0xad, 0x1e, 0xa7, 0x2c, 0x69, 0x03, 0x24,
+	0xf1, 0x51, 0xc7, 0x8e, 0x7b, 0x6e, 0xf8, 0x72, 0xad, 0xb2, 0xc5, 0xde, 0x00, 0x35, 0x10, 0x0d,
+	0xa6, 0x4e, 0xf3, 0xca, 0x69, 0x5b, 0x92, 0xde, 0x94, 0x64, 0xf6, 0x32, 0xc0, 0xb9, 0xf1, 0x4c,
+	0xb7, 0xcd, 0xe9, 0xc4, 0x3f, 0xa3, 0x35, 0x6f, 0x6a, 0x39, 0xa4, 0xb4, 0x89, 0xf0, 0x30, 0x95,
+	0x55, 0xd4, 0x04, 0xfe, 0x4f, 0xa8, 0x49, 0xfc, 0x9f, 0x56, 0x33, 0xe5, 0x1a, 0x64, 0xc4, 0x9c,
+	0x59, 0x1e, 0x36, 0x5a, 0x9d, 0xaf, 0x55, 0xdb, 0xad, 0x86, 0x7a, 0x83, 0x65, 0x21, 0x75, 0x7c,
+	0xd2, 0x1f, 0xa8, 0x0a, 0xdb, 0x80, 0xe4, 0x71, 0xf5, 0x91, 0x9a, 0x60, 0x05, 0xc8, 0xb6, 0x8e,
+	0x7b, 0xed, 0x56, 0xbd, 0x35, 0x50, 0x93, 0x0c, 0x20, 0x53, 0xaf, 0x76, 0x3a, 0xdd, 0x81, 0x9a,
+	0x2a, 0x3f, 0x82, 0x7c, 0xc7, 0xf4, 0x9f, 0x3a, 0xee, 0xe3, 0x9e, 0xe3, 0xd2, 0xe6, 0x4d, 0xe7,
+	0xe7, 0xa7, 0xa6, 0x2b, 0x83, 0x50, 0xb6, 0xb8, 0xfb, 0x06, 0xd1, 0x2e, 0xfd, 0x3a, 0x6c, 0x73,
+	0x99, 0xd9, 0xfc, 0x14, 0x5d, 0x59, 0x3a, 0xb6, 0x6c, 0x95, 0xbf, 0xb5, 0x05, 0xc5, 0xba, 0x63,
+	0xdb, 0x22, 0x52, 0xfa, 0x33, 0x73, 0xc8, 0xca, 0x18, 0x9f, 0xc6, 0xb9, 0x88, 0xcf, 0x5c, 0xad,
+	0x88, 0x06, 0x85, 0x88, 0x43, 0xa3, 0x3e, 0xd6, 0x80, 0xed, 0xa7, 0xae, 0xe5, 0x9b, 0xba, 0x37,
+	0x3c, 0x33, 0xcf, 0x0d, 0xe1, 0x01, 0xd9, 0x35, 0x3c, 0x20, 0x4f, 0x62, 0x7d, 0x92, 0x62, 0x35,
+	0x50, 0x5d, 0xd3, 0x18, 0x2d, 0x28, 0xc9, 0xaf, 0xa1, 0x04, 0xb8, 0x94, 0xd4, 0x81, 0xb1, 0xcd,
+	0x43, 0x2c, 0x49, 0x21, 0xc0, 0x3f, 0xd9, 0x5d, 0xc8, 0xce, 0xe7, 0xd6, 0x48, 0xe7, 0x21, 0x2f,
+	0xec, 0xbc, 0xc1, 0xdb, 0x3d, 0x0c, 0x7b, 0x34, 0x74, 0x14, 0xc1, 0x14, 0xf3, 0x1e, 0xba, 0x36,
+	0x97, 0xdc, 0x0a, 0xe9, 0x47, 0x44, 0x66, 0xef, 0x42, 0x7e, 0x16, 0x22, 0x88, 0x87, 0x96, 0x4e,
+	0x62, 0x2c, 0xa9, 0xc2, 0x1d, 0x23, 0x68, 0x91, 0x21, 0x1a, 0x67, 0xe5, 0x7b, 0x63, 0x0c, 0x1f,
+	0xeb, 0xbe, 0x79, 0x3e, 0xb3, 0x0d, 0xdc, 0x22, 0x5a, 0xd6, 0xc6, 0x3a, 0x7b, 0x83, 0x62, 0x03,
+	0x29, 0x85, 0x5a, 0x58, 0x34, 0xd5, 0x40, 0xd7, 0x6e, 0x8e, 0x42, 0xfa, 0x56, 0x25, 0x44, 0xf5,
+	0x87, 0xce, 0xdc, 0x9d, 0x1a, 0x36, 0x37, 0x9c, 0xb6, 0x1d, 0x0a, 0x84, 0x5a, 0xbe, 0x0a, 0x30,
+	0x32, 0x5d, 0xeb, 0x89, 0x41, 0x51, 0x5c, 0x20, 0xe9, 0x7d, 0xb1, 0x88, 0x45, 0xab, 0x57, 0x1a,
+	0x21, 0x9b, 0x16, 0x13, 0x29, 0xfd, 0x04, 0x00, 0xa2, 0x2e, 0x36, 0x80, 0x22, 0x46, 0xc4, 0x14,
+	0xe5, 0x1c, 0x57, 0x84, 0x99, 0x42, 0x71, 0xfa, 0xf6, 0x15, 0x3a, 0xb1, 0x47, 0x4a, 0xf1, 0x40,
+	0xd4, 0x36, 0x87, 0xf1, 0x26, 0x7b, 0x0f, 0x78, 0x98, 0x8e, 0xad, 0x89, 0xd8, 0xab, 0xc4, 0x1a,
+	0x7b, 0x95, 0x11, 0x02, 0xac, 0x0b, 0xe0, 0xbb, 0xc6, 0xd4, 0xe3, 0x91, 0xec, 0x91, 0x17, 0xe4,
+	0x0f, 0xdf, 0xb8, 0x6a, 0x32, 0x83, 0x40, 0x42, 0x9a, 0x2f, 0xa6, 0x82, 0x7d, 0x04, 0xdb, 0xde,
+	0xd9, 0x7c, 0x3c, 0xb6, 0x4d, 0x0e, 0xdd, 0xba, 0x00, 0xd8, 0x14, 0xea, 0x2d, 0x1e, 0xbe, 0x75,
+	0x95, 0xde, 0xbe, 0x10, 0xa4, 0x25, 0x6e, 0x49, 0x2d, 0x88, 0xf5, 0x03, 0xc2, 0xe5, 0x7b, 0x50,
+	0xf4, 0xce, 0x0c, 0x77, 0x14, 0x19, 0x33, 0x4d, 0xe6, 0xd8, 0xa9, 0x04, 0x49, 0x1b, 0xe5, 0xb1,
+	0x9f, 0x4c, 0xb9, 0x49, 0xac, 0xa1, 0x19, 0x5b, 0x70, 0xcb, 0x35, 0x87, 0xce, 0x13, 0x13, 0x13,
+	0x8a, 0xed, 0x4c, 0x22, 0x15, 0x99, 0x17, 0xf9, 0xc3, 0x4e, 0x20, 0xd3, 0x76, 0x26, 0xa1, 0xaa,
+	0xaf, 0xc0, 0xe6, 0x54, 0x60, 0x89, 0x3e, 0x43, 0x30, 0xf1, 0xd0, 0x33, 0xf9, 0x9e, 0x6d, 0x8b,
+	0xb5, 0xc5, 0x60, 0x46, 0x2b, 0x4c, 0xa3, 0x06, 0x22, 0x6d, 0x1a, 0x72, 0xe1, 0xbe, 0x21, 0xd8,
+	0xc6, 0x31, 0x62, 0x13, 0x4d, 0x15, 0x75, 0x4a, 0x88, 0xb8, 0x07, 0x30, 0x0c, 0x77, 0x8a, 0x6c,
+	0x9a, 0x3f, 0xbc, 0xb9, 0x6a, 0x07, 0x03, 0x23, 0x44, 0xdc, 0xac, 0x1d, 0x77, 0x7e, 0xcf, 0xb4,
+	0xc9, 0x55, 0x64, 0x3e, 0xbb, 0x13, 0x2d, 0xb6, 0x6d, 0x9c, 0x9a, 0x76, 0x5f, 0x76, 0x4b, 0x35,
+	0x51, 0x10, 0x04, 0x1d, 0x02, 0x17, 0x2d, 0x07, 0x81, 0x47, 0xa4, 0xec, 0x4d, 0x2d, 0x6c, 0xb3,
+	0x2f, 0x01, 0x23, 0x08, 0x1a, 0x99, 0xb6, 0x71, 0x81, 0x43, 0xa1, 0x2d, 0x08, 0x13, 0x38, 0x17,
+	0x81, 0x53, 0x83, 0x77, 0xf4, 0x05, 0x9d, 0xed, 0x43, 0x3e, 0xe6, 0x1c, 0x04, 0x0a, 0x39, 0x0d,
+	0x22, 0x4b, 0xb3, 0x13, 0x28, 0x05, 0x0c, 0xb6, 0x71, 0x7e, 0x3a, 0x32, 0xf4, 0xb8, 0x63, 0xaf,
+	0x03, 0x02, 0x3b, 0x52, 0xbe, 0x4d, 0xe2, 0x75, 0xe1, 0xe5, 0x47, 0xc0, 0x56, 0xa8, 0x5b, 0x07,
+	0x6f, 0x0b, 0x76, 0x5c, 0xcf, 0xaf, 0x40, 0x8e, 0x56, 0xeb, 0x4c, 0xed, 0x0b, 0xc2, 0x12, 0xac,
+	0x70, 0x38, 0xa1, 0x8b, 0x6d, 0xcc, 0xbd, 0x3b, 0x1f, 0x0b, 0xef, 0xd1, 0x05, 0x2a, 0xe3, 0x3c,
+	0xac, 0x67, 0xbb, 0x40, 0x10, 0xba, 0x2d, 0xbb, 0x34, 0x8e, 0xbc, 0xd4, 0x81, 0x51, 0x0b, 0x53,
+	0xc7, 0xd7, 0x4f, 0x4d, 0x34, 0xba, 0x49, 0xb8, 0x9d, 0x3f, 0x2c, 0x55, 0x44, 0x25, 0x59, 0x09,
+	0x2a, 0xc9, 0xca, 0x20, 0xa8, 0x24, 0xb5, 0x1c, 0x72, 0xd7, 0x88, 0x99, 0xfd, 0x26, 0xf0, 0x86,
+	0x6e, 0x8c, 0x7d, 0x4c, 0x62, 0x85, 0x2b, 0x25, 0xb3, 0xc8, 0x5c, 0xe5, 0xbc, 0xdc, 0x94, 0xa7,
+	0x88, 0x92, 0x63, 0xcb, 0xb6, 0x77, 0x37, 0x85, 0x29, 0x83, 0x76, 0xd9, 0x82, 0xcd, 0x05, 0x94,
+	0x41, 0xe6, 0xdb, 0x32, 0xe1, 0xea, 0xf5, 0x6e, 0xa7, 0xd3, 0xac, 0x0f, 0xba, 0x9a, 0x3e, 0x78,
+	0xd4, 0x6b, 0x62, 0xfe, 0xc5, 0xf4, 0xda, 0xff, 0xed, 0x76, 0x6b, 0xd0, 0xc4, 0x0c, 0x5c, 0x04,
+	0xe0, 0xd4, 0x7e, 0x5d, 0x6b, 0xf5, 0x06, 0x98, 0x88, 0x73, 0x90, 0x6e, 0x1d, 0x57, 0xef, 0x37,
+	0x31, 0x0b, 0xe3, 0x67, 0xbb, 0x5b, 0xaf, 0xb6, 0xd5, 0x14, 0x97, 0xe8, 0x3d, 0x1a, 0x3c, 0xe8,
+	0x76, 0xd4, 0x74, 0xf9, 0x18, 0xf2, 0xb1, 0x58, 0x67, 0xbb, 0x70, 0x33, 0x18, 0xa8, 0xff, 0xe0,
+	0xe4, 0xe8, 0xa8, 0xdd, 0x0c, 0x86, 0xc1, 0x9c, 0x5f, 0xeb, 0x76, 0xdb, 0xcd, 0x6a, 0x07, 0xc7,
+	0xa1, 0x02, 0x60, 0xd0, 0xbc, 0xdf, 0xd4, 0x70, 0x10, 0x3e, 0x81, 0x81, 0xd6, 0xea, 0xdc, 0x57,
+	0x93, 0xe5, 0xef, 0x26, 0xa0, 0x48, 0x69, 0x47, 0xb8, 0x2c, 0x8f, 0x00, 0x2c, 0x92, 0xd1, 0xc3,
+	0x82, 0xd2, 0x8e, 0xbe, 0x79, 0x0e, 0x7f, 0x62, 0xd8, 0x73, 0xc4, 0xa3, 0x04, 0x51, 0x65, 0x8b,
+	0x6f, 0xca, 0xc8, 0x19, 0x22, 0x82, 0x4c, 0x7d, 0x59, 0xf5, 0x84, 0x6d, 0xf6, 0x04, 0x6e, 0x51,
+	0x9e, 0x8b, 0x3b, 0x8e, 0x7e, 0x6e, 0xcc, 0x08, 0xd2, 0xf2, 0x87, 0x5f, 0x12, 0x01, 0xb9, 0x38,
+	0xb8, 0x68, 0x0a, 0x97, 0x79, 0x88, 0xfc, 0xc7, 0xc6, 0xac, 0x39, 0xf5, 0xdd, 0x8b, 0xda, 0x4b,
+	0xdf, 0xfe, 0xf1, 0x8b, 0xd2, 0xd7, 0x38, 0x12, 0x2b, 0x35, 0xe1, 0xce, 0x73, 0xb4, 0x04, 0x19,
+	0x5b, 0x56, 0xe3, 0x3c, 0x63, 0x63, 0x09, 0x4a, 0x4b, 0x09, 0xaa, 0x71, 0x6a, 0xdc, 0x4b, 0xbc,
+	0xab, 0x94, 0xff, 0x27, 0x0d, 0xf9, 0xba, 0x31, 0xf3, 0xe7, 0xae, 0x49, 0xb5, 0xc9, 0xfe, 0x02,
+	0xee, 0xe4, 0xd1, 0xf5, 0x37, 0x64, 0xb7, 0x44, 0x9d, 0xa3, 0xa5, 0x04, 0x95, 0xa0, 0x04, 0x15,
+	0x24, 0xbd, 0x48, 0xd7, 0xb5, 0x52, 0x52, 0xf2, 0x1a, 0x29, 0xe9, 0x37, 0xd0, 0x47, 0xad, 0x29,
+	0x67, 0xf1, 0xe4, 0x2e, 0xdf, 0x5d, 0x1e, 0xbc, 0x26, 0x38, 0xb4, 0x90, 0x95, 0xd7, 0x26, 0x16,
+	0xd6, 0x9a, 0x2e, 0x2e, 0xfe, 0x12, 0x0e, 0x6d, 0x05, 0xf4, 0x00, 0x86, 0x96, 0x53, 0x49, 0xe6,
+	0xe7, 0x4f, 0x25, 0x1b, 0x3f, 0x7f, 0x2a, 0xc9, 0xae, 0x97, 0x4a, 0xfe, 0x4b, 0xc1, 0xa8, 0x10,
+	0xcb, 0x66, 0x0f, 0xe1, 0xa6, 0x6b, 0x7a, 0x38, 0xd2, 0xd0, 0x5c, 0xc0, 0x36, 0x65, 0x8d, 0x0d,
+	0x2f, 0x06, 0x92, 0x12, 0xdd, 0x7e, 0x15, 0x36, 0x43, 0x5d, 0x33, 0x03, 0xcb, 0x73, 0x11, 0x26,
+	0x85, 0x80, 0xd8, 0x43, 0xda, 0xa5, 0xb4, 0x94, 0xbc, 0x56, 0x5a, 0x8a, 0xa3, 0x4f, 0x6a, 0x11,
+	0x7d, 0x38, 0xb4, 0x22, 0x58, 0xf9, 0x66, 0x78, 0xe0, 0xc3, 0x28, 0x24, 0x02, 0xa6, 0x85, 0xf2,
+	0xe1, 0x65, 0x68, 0x5a, 0x38, 0x0b, 0x84, 0x78, 0xb3, 0x11, 0xe1, 0x4d, 0xb6, 0xfc, 0x43, 0x80,
+	0x9d, 0x63, 0x94, 0x77, 0x2d, 0xc3, 0xb6, 0xbe, 0x69, 0x84, 0xe5, 0xf9, 0x6b, 0x0b, 0x21, 0xb0,
+	0x83, 0x3b, 0xb4, 0x75, 0x89, 0x4d, 0x86, 0x42, 0xe7, 0x39, 0xa1, 0xf0, 0x9a, 0x58, 0xed, 0x0a,
+	0xdd, 0xff, 0x6f, 0x21, 0xf1, 0xc1, 0x52, 0x48, 0xbc, 0xfa, 0xfc, 0x49, 0x2c, 0x87, 0xc6, 0x2f,
+	0x79, 0xe9, 0xf4, 0x8f, 0x99, 0x5f, 0x52, 0x7f, 0x5f, 0x5d, 0x86, 0x6d, 0xfc, 0x02, 0xca, 0xb0,
+	0xdc, 0xa5, 0x32, 0xac, 0x0e, 0x5b, 0x22, 0x4d, 0x79, 0x41, 0x12, 0xa2, 0x00, 0x0b, 0xa7, 0xba,
+	0x98, 0xa0, 0xe4, 0x18, 0xc5, 0xf1, 0x62, 0xce, 0xc4, 0xfd, 0xc0, 0x32, 0xce, 0x37, 0xf4, 0xf9,
+	0x6c, 0x84, 0xf6, 0xf2, 0x82, 0x7b, 0x17, 0x22, 0x9e, 0x08, 0x1a, 0x3b, 0x05, 0x36, 0x32, 0x67,
+	0x68, 0x5e, 0x6c, 0xe1, 0x70, 0x22, 0x8b, 0x4b, 0x67, 0x78, 0xe7, 0x4a, 0xa7, 0xc4, 0x6a, 0x3f,
+	0x90, 0x95, 0x05, 0x80, 0xb6, 0x3d, 0xba, 0x4c, 0x7a, 0x5e, 0x25, 0x95, 0x5d, 0xaf, 0x92, 0x82,
+	0xcf, 0x5d, 0x49, 0xe5, 0x3f, 0x67, 0x25, 0x55, 0x78, 0x11, 0x96, 0x6d, 0x2e, 0x62, 0x59, 0xe9,
+	0xf7, 0x15, 0xd8, 0x5e, 0xda, 0x05, 0x7e, 0x2f, 0x32, 0x71, 0x9d, 0xf9, 0x4c, 0x8f, 0xb0, 0x49,
+	0xcb, 0x11, 0xa5, 0xc3, 0xb1, 0xe8, 0x17, 0x5a, 0xd0, 0xaf, 0x0b, 0xa7, 0xd9, 0x08, 0x4e, 0x73,
+	0xe5, 0xff, 0xcc, 0x41, 0xa6, 0x5b, 0x9d, 0xfb, 0x67, 0x87, 0xf2, 0x9e, 0xe4, 0x89, 0x35, 0x32,
+	0x83, 0x3b, 0xc1, 0xb0, 0xcd, 0xde, 0xc4, 0xc3, 0x3b, 0x32, 0xe9, 0x73, 0xd7, 0x8e, 0xa0, 0x42,
+	0x94, 0x25, 0x5b, 0xbc, 0xe3, 0xc4, 0xb5, 0x43, 0x3c, 0x78, 0x0f, 0xee, 0x1a, 0xc3, 0x21, 0x06,
+	0xa7, 0xee, 0x3b, 0x8f, 0xcd, 0xe9, 0xa2, 0x8c, 0x28, 0xc4, 0x6e, 0x0b, 0x86, 0x01, 0xef, 0x8f,
+	0x8b, 0xa2, 0x87, 0x2c, 0x88, 0x9e, 0x9b, 0xfe, 0x99, 0x33, 0x12, 0x97, 0x1f, 0xda, 0x76, 0x4c,
+	0xe8, 0x98, 0x3a, 0x68, 0x5a, 0x71, 0xfe, 0x53, 0x67, 0x74, 0x21, 0x2f, 0x37, 0xb6, 0x62, 0xdc,
+	0x35, 0x24, 0xb3, 0x3f, 0x50, 0xe0, 0xe5, 0x05, 0xe6, 0x33, 0xf4, 0x34, 0xd3, 0xf5, 0xa2, 0xda,
+	0x2f, 0x4d, 0xb8, 0x25, 0x8f, 0xb3, 0x62, 0x53, 0x2a, 0xd5, 0x48, 0xcb, 0x03, 0xc1, 0x7f, 0x8d,
+	0xd2, 0x8f, 0x19, 0x4b, 0xd2, 0xec, 0xdb, 0x0a, 0xec, 0x2d, 0x4c, 0x03, 0x61, 0x69, 0x86, 0x70,
+	0x6d, 0x46, 0xf3, 0xc8, 0xc4, 0x6b, 0xd0, 0xe5, 0x79, 0x68, 0x52, 0xe0, 0x1a, 0x13, 0x89, 0xef,
+	0x77, 0x20, 0x8e, 0xa2, 0xec, 0x7d, 0x28, 0xb9, 0xe6, 0x18, 0x67, 0x70, 0xb6, 0xca, 0x56, 0xe2,
+	0xae, 0xef, 0x8e, 0xe4, 0x58, 0x32, 0xd6, 0x97, 0x39, 0x66, 0xc7, 0x85, 0xa5, 0xb5, 0x0a, 0x24,
+	0xc6, 0xe2, 0x62, 0xd2, 0x5c, 0x74, 0xaa, 0x8c, 0x4b, 0x90, 0xbd, 0x44, 0xfc, 0xab, 0x71, 0x7e,
+	0x32, 0xd8, 0x1f, 0xe2, 0x4e, 0x2d, 0xb2, 0x2f, 0x59, 0x2c, 0xb7, 0x62, 0xa7, 0xb4, 0x98, 0x9e,
+	0xeb, 0x9b, 0x6c, 0xc7, 0x5d, 0x16, 0x67, 0x7f, 0xac, 0xc0, 0xfe, 0xe2, 0x4c, 0x96, 0x8d, 0x06,
+	0x34, 0x95, 0xb7, 0x9f, 0x3b, 0x95, 0xcf, 0x61, 0xb5, 0x85, 0x9d, 0x8f, 0x99, 0xad, 0xd4, 0x86,
+	0xbd, 0x17, 0xfb, 0xe5, 0x75, 0x0e, 0x13, 0xa5, 0x63, 0xd8, 0xbf, 0xc2, 0xbb, 0xae, 0xab, 0xee,
+	0x0a, 0x13, 0x5c, 0x4b, 0x5d, 0x07, 0x5e, 0xb9, 0x6a, 0x1b, 0xaf, 0x75, 0x74, 0xfa, 0xab, 0x24,
+	0x64, 0x07, 0x08, 0xfb, 0x54, 0x34, 0xb2, 0x78, 0xd1, 0x28, 0xeb, 0xc3, 0x03, 0x48, 0x7b, 0xbe,
+	0x39, 0x13, 0xa7, 0x49, 0x5e, 0x4c, 0x91, 0x45, 0x03, 0x91, 0x4a, 0x1f, 0xfb, 0x24, 0x04, 0x0b,
+	0xbe, 0xd2, 0x4f, 0x13, 0x90, 0xe2, 0x54, 0x3c, 0xe1, 0xe4, 0x38, 0x25, 0x7e, 0x01, 0xb8, 0xbb,
+	0x42, 0xba, 0x42, 0x55, 0x64, 0x96, 0xb3, 0x12, 0x4a, 0x63, 0x8e, 0x20, 0x31, 0x4c, 0xa5, 0xe6,
+	0x33, 0xf9, 0x58, 0x44, 0x8a, 0x5a, 0x9c, 0x70, 0xf9, 0xc9, 0x21, 0xb9, 0xfc, 0xe4, 0x10, 0x28,
+	0xf0, 0x86, 0xce, 0x2c, 0x78, 0xaf, 0x20, 0x05, 0x7d, 0x4e, 0x40, 0x50, 0x8d, 0x97, 0x3a, 0xe9,
+	0x95, 0xd7, 0xd7, 0xf1, 0xf2, 0xe6, 0x3d, 0xac, 0x17, 0x9c, 0xa1, 0x8c, 0xb2, 0x27, 0xe6, 0x50,
+	0xdc, 0xe7, 0x5c, 0x51, 0x84, 0xa5, 0xb8, 0x08, 0x16, 0xb4, 0x10, 0xa6, 0x25, 0x6f, 0xbd, 0x8a,
+	0x28, 0x26, 0x50, 0xde, 0x83, 0x14, 0xed, 0x08, 0x40, 0xa6, 0xd5, 0xb9, 0xdf, 0xec, 0x0f, 0xc4,
+	0x8d, 0xc4, 0xd7, 0x9a, 0x5a, 0xeb, 0xe8, 0x91, 0xaa, 0x94, 0xbf, 0xab, 0x40, 0x4e, 0x33, 0xa6,
+	0x13, 0x71, 0xe8, 0xc5, 0x74, 0xcc, 0xaf, 0x22, 0xc5, 0xbb, 0x1b, 0xdf, 0xb6, 0x0d, 0x2d, 0x8b,
+	0x84, 0x1a, 0x3d, 0xbd, 0xdd, 0x81, 0x0d, 0xde, 0xc9, 0x9f, 0xdf, 0x92, 0xd4, 0x95, 0xc1, 0x66,
+	0x73, 0x3a, 0x62, 0x65, 0xac, 0x0e, 0x75, 0x7a, 0x20, 0x93, 0x92, 0x29, 0xea, 0xce, 0xbb, 0xf4,
+	0x76, 0x26, 0x84, 0xf7, 0x20, 0x1f, 0xf0, 0x70, 0x05, 0x69, 0xe2, 0xc8, 0x09, 0x0e, 0xd4, 0x71,
+	0x4f, 0xfd, 0xde, 0x27, 0xfb, 0x37, 0x2e, 0xbd, 0xe4, 0x15, 0xc3, 0xd4, 0xdb, 0xe7, 0x25, 0x01,
+	0x7f, 0x07, 0x12, 0xf5, 0xd6, 0x68, 0xfd, 0x4a, 0x76, 0x43, 0x4a, 0xf0, 0x0b, 0x35, 0xac, 0xde,
+	0x27, 0x54, 0xbf, 0x0e, 0xcf, 0x68, 0x71, 0x59, 0x0d, 0x88, 0xd4, 0xe3, 0x94, 0xf2, 0x7b, 0x90,
+	0xeb, 0x9b, 0x6e, 0xcf, 0xb1, 0xad, 0x21, 0x5d, 0xd6, 0x79, 0x3e, 0x9e, 0x65, 0xdc, 0xf9, 0x94,
+	0x17, 0x1f, 0xb2, 0xea, 0x11, 0x8f, 0x20, 0x2a, 0xf6, 0x0c, 0x64, 0x07, 0x55, 0x38, 0xe5, 0xbf,
+	0x48, 0x00, 0x34, 0x9f, 0xf9, 0xae, 0x31, 0xf4, 0xab, 0xbd, 0x56, 0xe9, 0x07, 0x0a, 0x64, 0x64,
+	0xe1, 0x1c, 0x7f, 0x21, 0x50, 0x16, 0x5f, 0x08, 0x3e, 0x80, 0x7c, 0xfc, 0x35, 0x62, 0x9d, 0xab,
+	0x68, 0x10, 0x02, 0x3c, 0x50, 0xb9, 0x87, 0x8a, 0x3a, 0x16, 0x55, 0x7b, 0xf2, 0x51, 0x22, 0x47,
+	0x14, 0x54, 0xbe, 0xf4, 0xa8, 0x90, 0x5a, 0xfb, 0x51, 0xa1, 0xfc, 0x08, 0x52, 0x75, 0xfe, 0x50,
+	0xba, 0x50, 0xe9, 0x6c, 0x42, 0xae, 0xde, 0xed, 0x1c, 0xb5, 0xee, 0x9f, 0x68, 0x4d, 0x71, 0xbf,
+	0xd4, 0xfc, 0xfa, 0x40, 0xab, 0xd6, 0xf9, 0x25, 0x16, 0x83, 0xa2, 0x6c, 0x34, 0x1b, 0x3a, 0x7f,
+	0x7c, 0x55, 0x93, 0x08, 0x16, 0x6a, 0x44, 0x3b, 0x6a, 0x35, 0xdb, 0x8d, 0xbe, 0x9a, 0x2a, 0x7f,
+	0x96, 0x06, 0x8c, 0x90, 0x73, 0x3c, 0x6d, 0x99, 0x7c, 0x9f, 0xfe, 0x23, 0x11, 0xee, 0xd3, 0xa5,
+	0xcd, 0x50, 0xae, 0xb9, 0x19, 0xb8, 0xcd, 0xdc, 0x35, 0x69, 0x2b, 0xc4, 0xd1, 0x84, 0xbb, 0x2a,
+	0x6d, 0x04, 0x7f, 0x89, 0x13, 0xcf, 0x2f, 0x62, 0x8f, 0x64, 0x8b, 0xa7, 0x5a, 0x61, 0x19, 0xdb,
+	0x18, 0x9a, 0x67, 0x8e, 0x8d, 0x88, 0x1a, 0x7b, 0xc7, 0x61, 0x64, 0xa5, 0xa8, 0x8b, 0x1b, 0xec,
+	0xd2, 0x96, 0xa6, 0xd7, 0x7f, 0xa7, 0x79, 0x0d, 0xb6, 0x22, 0x30, 0x10, 0x75, 0x2b, 0xbd, 0x61,
+	0x6a, 0xc5, 0x88, 0x4c, 0xc5, 0x2b, 0x16, 0x5f, 0xf4, 0x04, 0xbb, 0xf0, 0x4e, 0x25, 0xdf, 0x07,
+	0xa9, 0xa3, 0x1f, 0xad, 0x19, 0x31, 0xc8, 0xe3, 0x73, 0x26, 0x87, 0xa5, 0x8c, 0x1f, 0xbe, 0xeb,
+	0x86, 0x7e, 0x8c, 0x98, 0x15, 0x7c, 0x96, 0x7e, 0x0f, 0xd2, 0x3c, 0x8c, 0x3c, 0xf6, 0x6b, 0x90,
+	0xb2, 0xcd, 0xb1, 0x4f, 0x9b, 0x9c, 0x3f, 0x64, 0x42, 0xa4, 0x81, 0x58, 0x53, 0x9d, 0x8e, 0x6a,
+	0x17, 0x78, 0x72, 0xd1, 0xa8, 0x9f, 0xbd, 0x0e, 0x69, 0xd7, 0x9a, 0x9c, 0xf9, 0xf2, 0x46, 0x7d,
+	0x15, 0xa3, 0x60, 0x60, 0x5f, 0x80, 0xa4, 0x33, 0xf7, 0x65, 0x91, 0xbd, 0x8a, 0x8f, 0x77, 0x97,
+	0xff, 0x49, 0x59, 0xc3, 0xb3, 0xb6, 0x20, 0xaf, 0x35, 0x1b, 0x27, 0xf5, 0xa6, 0xde, 0x6e, 0x1e,
+	0x71, 0xef, 0xda, 0xc6, 0x0a, 0xbc, 0x7b, 0x5c, 0x6b, 0x75, 0x9a, 0xba, 0xd6, 0xba, 0xff, 0x80,
+	0x3f, 0x58, 0xaa, 0x90, 0x6f, 0x68, 0xd5, 0x56, 0x47, 0xaf, 0x3f, 0x38, 0xe9, 0x7c, 0xa8, 0x7e,
+	0xaa, 0x20, 0xba, 0xdc, 0x25, 0x4a, 0x93, 0xdf, 0xbf, 0x12, 0x73, 0x43, 0x6f, 0x74, 0xeb, 0x27,
+	0xc7, 0xcd, 0xce, 0x40, 0xfd, 0x5b, 0x05, 0x83, 0x65, 0x37, 0xe8, 0x17, 0xda, 0x63, 0xdd, 0x7f,
+	0xa7, 0x84, 0x0a, 0x91, 0xfc, 0x61, 0xf3, 0x91, 0xfa, 0xf7, 0x0a, 0xdb, 0x81, 0x62, 0x40, 0x91,
+	0xde, 0xfb, 0x43, 0x05, 0x1d, 0x7d, 0x33, 0x20, 0xf6, 0x07, 0xd5, 0x41, 0x5f, 0xfd, 0x07, 0xa5,
+	0xfc, 0x53, 0x05, 0xb2, 0xb5, 0xb9, 0x65, 0x8f, 0xb8, 0x43, 0xff, 0xeb, 0x42, 0xe0, 0x9f, 0x72,
+	0xb2, 0x6e, 0x8d, 0x82, 0xc0, 0xa7, 0x76, 0x6b, 0x14, 0x75, 0x8d, 0x4e, 0x65, 0x2e, 0x15, 0x5d,
+	0x8d, 0x53, 0x7a, 0xac, 0xa6, 0x03, 0xb5, 0xcc, 0x49, 0xb2, 0xc5, 0x0e, 0x31, 0x3c, 0xc4, 0xe9,
+	0x9b, 0x12, 0x61, 0x8a, 0x12, 0xe1, 0x76, 0x70, 0xb6, 0x0e, 0x9f, 0x9d, 0x31, 0x26, 0x88, 0x8b,
+	0x10, 0xff, 0x2d, 0xd8, 0x8e, 0x2e, 0x65, 0xe4, 0x2d, 0x81, 0xbc, 0x2e, 0x52, 0xc3, 0x0e, 0x79,
+	0x95, 0xc0, 0x9f, 0xb4, 0xa5, 0xc3, 0xea, 0xae, 0xe3, 0xf8, 0xd2, 0x3d, 0x03, 0x27, 0xd6, 0x90,
+	0x54, 0xde, 0x81, 0x6d, 0xac, 0x14, 0x4c, 0x9f, 0xb0, 0x58, 0x33, 0x7f, 0x77, 0x8e, 0x09, 0xb8,
+	0x7c, 0x13, 0x58, 0x9c, 0x28, 0x0a, 0x89, 0xf2, 0x07, 0xc0, 0xaa, 0xa3, 0x27, 0xc6, 0x14, 0x67,
+	0x82, 0xe7, 0x41, 0xc9, 0xcb, 0xa3, 0xc0, 0x10, 0xd4, 0xf0, 0xd6, 0x91, 0xef, 0x4c, 0x4a, 0x2b,
+	0x4a, 0xb2, 0xbc, 0x74, 0x2c, 0xdf, 0x82, 0x9d, 0x05, 0x71, 0xa9, 0xf5, 0x5d, 0x28, 0xc4, 0x9d,
+	0x8a, 0x57, 0x1a, 0x3c, 0x43, 0x4a, 0x54, 0x16, 0xd9, 0x92, 0xff, 0x68, 0x84, 0x77, 0xd2, 0xc6,
+	0xa6, 0x34, 0xd1, 0x28, 0xff, 0x89, 0x02, 0x9b, 0x2d, 0xcc, 0x71, 0x9e, 0x1f, 0xcc, 0x65, 0x31,
+	0x81, 0x2b, 0x57, 0x26, 0xf0, 0xb8, 0x39, 0x13, 0x8b, 0xe6, 0x5c, 0xca, 0xed, 0xc9, 0x75, 0x73,
+	0x7b, 0xf9, 0x9f, 0x13, 0x50, 0x0c, 0xe6, 0x25, 0x16, 0xc9, 0xbe, 0xaf, 0x44, 0x27, 0x7a, 0xf1,
+	0xee, 0xcd, 0x2b, 0x74, 0x71, 0x3b, 0x1f, 0x1e, 0xa4, 0x16, 0x65, 0x82, 0xeb, 0xa4, 0x8f, 0x38,
+	0x3b, 0xaf, 0x0d, 0x3d, 0x51, 0x09, 0xff, 0xce, 0xb7, 0x7e, 0xbc, 0xff, 0xd6, 0xaa, 0x9f, 0xb6,
+	0x5c, 0xfa, 0x69, 0x4f, 0x20, 0x8f, 0x85, 0xf3, 0x9b, 0xeb, 0xb0, 0x77, 0xc7, 0x63, 0xb4, 0x78,
+	0x78, 0xbb, 0x10, 0x0d, 0xcb, 0x7e, 0x0b, 0x0a, 0xc1, 0xdc, 0x4d, 0x7f, 0x38, 0x92, 0xc0, 0x11,
+	0xbb, 0xf8, 0x12, 0x95, 0x6b, 0xa5, 0x89, 0x9d, 0x01, 0x4e, 0x4a, 0x01, 0x4e, 0x2a, 0x35, 0xe0,
+	0xf6, 0xea, 0xb5, 0x5c, 0x55, 0x8e, 0x26, 0xe3, 0xe5, 0xe8, 0x5f, 0x26, 0xe0, 0xce, 0xc0, 0xf0,
+	0x1e, 0x07, 0xd7, 0x64, 0xae, 0xf3, 0xec, 0x22, 0xb0, 0xfb, 0x57, 0x20, 0x85, 0xf5, 0xdb, 0x54,
+	0x62, 0x5f, 0x59, 0x96, 0x92, 0xab, 0x99, 0x2b, 0x5d, 0xe4, 0xd4, 0x88, 0x9f, 0x7c, 0xcd, 0xf0,
+	0x0d, 0x1a, 0xac, 0xa0, 0xd1, 0x77, 0xe9, 0xaf, 0x11, 0xcf, 0x38, 0x0b, 0x02, 0x65, 0x46, 0x1c,
+	0xa3, 0xa4, 0x5a, 0xf5, 0xf2, 0x82, 0x35, 0xd9, 0xcf, 0xda, 0x90, 0x15, 0xd7, 0x8b, 0x81, 0x1b,
+	0xd5, 0x7e, 0x1d, 0xdd, 0xe4, 0xed, 0x55, 0xbb, 0xbe, 0xf4, 0xfb, 0x2a, 0x71, 0xf1, 0xd8, 0x6a,
+	0x68, 0x1b, 0xa4, 0xa2, 0x45, 0x25, 0x8d, 0x6f, 0x60, 0xfd, 0xe2, 0xd3, 0x25, 0x21, 0x41, 0xc6,
+	0xa6, 0x06, 0x82, 0x44, 0x3f, 0xdf, 0x40, 0x86, 0xa1, 0x6d, 0xf1, 0x1f, 0x9b, 0x18, 0xa3, 0x51,
+	0x90, 0xda, 0x40, 0x90, 0xaa, 0x48, 0x29, 0xff, 0x51, 0x12, 0x76, 0x97, 0x57, 0x2f, 0x5d, 0xb1,
+	0x07, 0x9b, 0x7c, 0xed, 0xe1, 0xc1, 0x4c, 0xae, 0xee, 0xad, 0xe7, 0x6d, 0x9a, 0xf4, 0x46, 0xda,
+	0x35, 0xd9, 0xd0, 0x0a, 0x4e, 0xac, 0xb5, 0x72, 0x17, 0x9f, 0x42, 0x21, 0x2e, 0x81, 0x95, 0x40,
+	0x86, 0x5f, 0x00, 0xcd, 0x3d, 0x59, 0xee, 0x7f, 0xf1, 0x8a, 0xe1, 0xfa, 0xc4, 0xac, 0x49, 0xa1,
+	0x98, 0x2d, 0x12, 0x2f, 0xb6, 0x45, 0xf9, 0x3b, 0xf4, 0x83, 0x1e, 0x12, 0xca, 0x40, 0xa2, 0xfb,
+	0x21, 0xe6, 0xa2, 0x1d, 0xd8, 0xea, 0x3f, 0xa8, 0x6a, 0x0d, 0xbd, 0xd3, 0x1d, 0xe8, 0x47, 0xdd,
+	0x93, 0x4e, 0x03, 0x33, 0x12, 0x96, 0x32, 0x9d, 0xae, 0x2e, 0xe8, 0x3d, 0xad, 0x75, 0x5c, 0xd5,
+	0xf8, 0x4f, 0x68, 0x6e, 0xc1, 0x36, 0x67, 0x5a, 0x24, 0x27, 0x79, 0x2d, 0xc4, 0x1f, 0xde, 0xb4,
+	0x4e, 0xb5, 0xad, 0x37, 0x35, 0xad, 0xab, 0xa9, 0x29, 0x9e, 0xc1, 0x04, 0x5b, 0x7f, 0xd0, 0xed,
+	0xf5, 0x9a, 0x0d, 0x35, 0x8d, 0xd2, 0x6a, 0xaf, 0xab, 0x0d, 0x68, 0x9c, 0x6a, 0xbb, 0xdd, 0xfd,
+	0x08, 0xa9, 0x3f, 0xdb, 0x78, 0xb3, 0x03, 0xf9, 0x18, 0x9a, 0xf3, 0x3c, 0x59, 0xaf, 0x0e, 0xaa,
+	0xed, 0xee, 0x7d, 0x9c, 0x1b, 0x26, 0xc6, 0x87, 0xfd, 0x6e, 0x47, 0xef, 0xd7, 0x1f, 0x34, 0x8f,
+	0xab, 0x38, 0x2f, 0xfe, 0xb3, 0x1d, 0x4a, 0x9c, 0x38, 0x04, 0xce, 0x26, 0xc8, 0x67, 0x7d, 0x4c,
+	0x58, 0x5f, 0x1f, 0xf0, 0x64, 0x9a, 0x3e, 0xfc, 0x1b, 0x05, 0x36, 0xf8, 0x39, 0x89, 0x5f, 0x07,
+	0x7f, 0x15, 0x20, 0x42, 0x6a, 0x76, 0x47, 0xec, 0xea, 0x12, 0xa0, 0x97, 0x76, 0x97, 0x3b, 0xa4,
+	0x61, 0x6a, 0x90, 0x8f, 0xa1, 0x32, 0x93, 0x8c, 0xcb, 0x38, 0x5f, 0xba, 0xbb, 0xa2, 0x47, 0xea,
+	0x78, 0x07, 0x4f, 0x21, 0x84, 0x5d, 0x6c, 0x67, 0x11, 0xc9, 0x84, 0xe4, 0xcd, 0x55, 0xf0, 0x76,
+	0xf8, 0x0d, 0x28, 0xc4, 0x2d, 0xcf, 0x1e, 0x42, 0x5a, 0x7c, 0xbc, 0xfc, 0xc2, 0xf0, 0x2d, 0xed,
+	0xbd, 0xd8, 0x73, 0x5e, 0x57, 0xbe, 0xac, 0xd4, 0xde, 0xff, 0xf4, 0xdf, 0xf6, 0x6e, 0x7c, 0xfa,
+	0x93, 0x3d, 0xe5, 0x33, 0xfc, 0xfb, 0xe4, 0xdf, 0xf7, 0x94, 0x6f, 0xbc, 0x31, 0xb1, 0xfc, 0xb3,
+	0xf9, 0x69, 0x65, 0xe8, 0x9c, 0x1f, 0xa0, 0x96, 0xb9, 0xe1, 0x5e, 0x88, 0x9f, 0x48, 0x2e, 0xfd,
+	0x68, 0xf2, 0x34, 0x43, 0xed, 0x77, 0xfe, 0x0f, 0x67, 0xfc, 0x1e, 0x32, 0x50, 0x29, 0x00, 0x00,
 }
 
 func (this *UUIDParts) Equal(that interface{}) bool {
@@ -546,10 +546,14 @@ var xxx_messageInfo_Request_Store proto.InternalMessageInfo
 // instruct it to start committing its transaction.
 type Request_StartCommit struct {
 	// Flow runtime checkpoint to commit with this transaction.
-	RuntimeCheckpoint    *protocol.Checkpoint `protobuf:"bytes,1,opt,name=runtime_checkpoint,json=runtimeCheckpoint,proto3" json:"runtime_checkpoint,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}             `json:"-"`
-	XXX_unrecognized     []byte               `json:"-"`
-	XXX_sizecache        int32                `json:"-"`
+	RuntimeCheckpoint *protocol.Checkpoint `protobuf:"bytes,1,opt,name=runtime_checkpoint,json=runtimeCheckpoint,proto3" json:"runtime_checkpoint,omitempty"`
+	// Sources and acknowledgement intents of `runtime_checkpoint` which were
+	// added or changed since the previous StartCommit or, for the first
+	// transaction of a session, since the checkpoint of Opened.
+	RuntimeCheckpointDelta *protocol.Checkpoint `protobuf:"bytes,2,opt,name=runtime_checkpoint_delta,json=runtimeCheckpointDelta,proto3" json:"runtime_checkpoint_delta,omitempty"`
+	XXX_NoUnkeyedLiteral   struct{}             `json:"-"`
+	XXX_unrecognized       []byte               `json:"-"`
+	XXX_sizecache          int32                `json:"-"`
 }
 
 func (m *Request_StartCommit) Reset()         { *m = Request_StartCommit{} }
@@ -1314,110 +1318,110 @@ func init() {
 }
 
 var fileDescriptor_3e8b62b327f34bc6 = []byte{
-	// 1648 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xbc, 0x57, 0x4f, 0x6f, 0xdb, 0xc8,
-	0x15, 0x0f, 0x65, 0xfd, 0x7d, 0x92, 0x6c, 0x79, 0xaa, 0x74, 0x59, 0xae, 0xd7, 0xf6, 0x7a, 0x77,
-	0xb1, 0x46, 0x8a, 0x95, 0x03, 0xa7, 0xc0, 0x26, 0xbb, 0x48, 0x51, 0xfd, 0x05, 0x94, 0xca, 0x96,
-	0x77, 0x1c, 0xef, 0x02, 0xb9, 0x08, 0x63, 0x72, 0x2c, 0x33, 0xa6, 0x38, 0x2c, 0x49, 0x25, 0x51,
-	0x2f, 0xed, 0xa5, 0x28, 0xd0, 0x5b, 0x81, 0xa2, 0x97, 0x5e, 0x82, 0x9e, 0x7b, 0xed, 0x27, 0x28,
-	0x0a, 0xe4, 0xd6, 0x7e, 0x82, 0x14, 0x4d, 0x3f, 0x41, 0xaf, 0x39, 0x14, 0xc5, 0xfc, 0x21, 0x45,
-	0x39, 0x92, 0xa2, 0x43, 0x36, 0x17, 0x81, 0xf3, 0xe6, 0xf7, 0x7b, 0x7a, 0xf3, 0xf8, 0xe6, 0xbd,
-	0x1f, 0xe1, 0xd6, 0x90, 0x1d, 0x78, 0x3e, 0x0b, 0x99, 0xc9, 0x9c, 0xe0, 0x60, 0x44, 0x42, 0xea,
-	0xdb, 0xc4, 0xb1, 0x7f, 0x49, 0x93, 0xcf, 0x35, 0x81, 0x40, 0xc5, 0x84, 0xc9, 0xd8, 0x35, 0x99,
-	0x1b, 0x8c, 0x47, 0xd4, 0x8f, 0xe9, 0xf1, 0x83, 0x84, 0x1b, 0x5b, 0x33, 0xae, 0x2f, 0x1c, 0xf6,
-	0x54, 0xfc, 0xa8, 0xdd, 0xea, 0x90, 0x0d, 0x99, 0x78, 0x3c, 0xe0, 0x4f, 0xd2, 0xba, 0xf7, 0x7c,
-	0x13, 0x72, 0x98, 0xfe, 0x62, 0x4c, 0x83, 0x10, 0x7d, 0x01, 0xe9, 0xc0, 0xa3, 0xa6, 0xae, 0xed,
-	0x6a, 0xfb, 0xc5, 0xc3, 0x1f, 0xd5, 0x92, 0x01, 0x29, 0x4c, 0xed, 0xd4, 0xa3, 0x26, 0x16, 0x30,
-	0x74, 0x0f, 0xf2, 0x4f, 0x88, 0x63, 0x5b, 0x24, 0xa4, 0x7a, 0x4a, 0x50, 0x3e, 0x9a, 0x4b, 0xf9,
-	0x56, 0x81, 0x70, 0x0c, 0x47, 0xb7, 0x21, 0x43, 0x3c, 0xcf, 0x99, 0xe8, 0x6b, 0x82, 0x67, 0xcc,
-	0xe5, 0xd5, 0x39, 0x02, 0x4b, 0x20, 0x8f, 0x8d, 0x79, 0xd4, 0xd5, 0xd3, 0x4b, 0x62, 0xeb, 0x7b,
-	0xd4, 0xc5, 0x02, 0xc6, 0xe1, 0x0e, 0x23, 0x96, 0x9e, 0x59, 0x02, 0xef, 0x31, 0x62, 0x61, 0x01,
-	0xe3, 0xf1, 0x5c, 0x38, 0xe3, 0xe0, 0x52, 0xcf, 0x2e, 0x89, 0xa7, 0xc3, 0x11, 0x58, 0x02, 0x39,
-	0x23, 0x08, 0x99, 0x4f, 0xf5, 0xdc, 0x12, 0xc6, 0x29, 0x47, 0x60, 0x09, 0x44, 0x4d, 0x28, 0x05,
-	0x21, 0xf1, 0xc3, 0x81, 0xc9, 0x46, 0x23, 0x3b, 0xd4, 0xf3, 0x82, 0xb8, 0xbb, 0x80, 0x48, 0xfc,
-	0xb0, 0x29, 0x70, 0xb8, 0x18, 0x4c, 0x17, 0xa8, 0x01, 0x45, 0x62, 0x5e, 0xb9, 0xec, 0xa9, 0x43,
-	0xad, 0x21, 0xd5, 0x0b, 0x4b, 0x7c, 0xd4, 0xa7, 0x38, 0x9c, 0x24, 0xa1, 0x0f, 0x21, 0x6f, 0xbb,
-	0x21, 0xf5, 0x5d, 0xe2, 0xe8, 0xd6, 0xae, 0xb6, 0x5f, 0xc2, 0x85, 0x4f, 0x23, 0x83, 0xf1, 0x7b,
-	0x0d, 0xd2, 0xfc, 0x1d, 0xa3, 0x63, 0x58, 0x37, 0x99, 0xeb, 0x52, 0x33, 0x64, 0xfe, 0x20, 0x9c,
-	0x78, 0x54, 0x94, 0xc5, 0xfa, 0xe1, 0xe7, 0x35, 0x51, 0x53, 0x47, 0xf1, 0x3f, 0x92, 0xd0, 0x66,
-	0x2e, 0xa7, 0xd4, 0x9a, 0x11, 0xfe, 0xe1, 0xc4, 0xa3, 0xb8, 0x6c, 0x26, 0x97, 0xe8, 0x1e, 0x14,
-	0x4d, 0xe6, 0x5e, 0xd8, 0xc3, 0xc1, 0xe3, 0x80, 0xb9, 0xa2, 0x60, 0x0a, 0x8d, 0xad, 0xd7, 0x2f,
-	0x77, 0x74, 0xea, 0x9a, 0xcc, 0xb2, 0xdd, 0xe1, 0x01, 0xdf, 0xa8, 0x61, 0xf2, 0xf4, 0x88, 0x06,
-	0x01, 0x19, 0x52, 0x9c, 0x95, 0x04, 0xe3, 0x2f, 0x19, 0xc8, 0x47, 0x45, 0x84, 0xbe, 0x81, 0xb4,
-	0x4b, 0x46, 0x32, 0x9a, 0x42, 0xe3, 0xfe, 0xeb, 0x97, 0x3b, 0xf7, 0x86, 0x76, 0x78, 0x39, 0x3e,
-	0xaf, 0x99, 0x6c, 0x74, 0x40, 0x83, 0x70, 0x4c, 0xfc, 0x89, 0x2c, 0xfe, 0x37, 0xae, 0xc3, 0xf5,
-	0xa8, 0xb1, 0x70, 0x35, 0xe7, 0xa8, 0xa9, 0x77, 0x79, 0xd4, 0xb5, 0xd5, 0x8f, 0x8a, 0xea, 0x90,
-	0x3f, 0xb7, 0x5d, 0x0e, 0x09, 0xf4, 0xf4, 0xee, 0xda, 0x7e, 0xf1, 0xf0, 0xb3, 0xa5, 0x77, 0xaa,
-	0xd6, 0x90, 0x68, 0x1c, 0xd3, 0x8c, 0xff, 0xa5, 0x20, 0xa7, 0xac, 0xe8, 0x01, 0x54, 0x7d, 0x1a,
-	0xb0, 0xb1, 0x6f, 0xd2, 0x41, 0x32, 0x24, 0x6d, 0x85, 0x90, 0xd6, 0x23, 0x66, 0x53, 0x86, 0xf6,
-	0x15, 0x80, 0xc9, 0x1c, 0x87, 0x9a, 0x3c, 0x09, 0xea, 0xc2, 0x57, 0x65, 0x86, 0x9a, 0xb1, 0x9d,
-	0x27, 0xa7, 0x91, 0x7e, 0xf1, 0x72, 0xe7, 0x06, 0x4e, 0xa0, 0xd1, 0x6f, 0x35, 0xb8, 0x79, 0x61,
-	0x53, 0xc7, 0x4a, 0x46, 0x31, 0x18, 0x11, 0x4f, 0x5f, 0x13, 0x87, 0xbc, 0xbf, 0xd2, 0x21, 0x6b,
-	0x1d, 0xee, 0x42, 0x86, 0xf3, 0x20, 0x60, 0xee, 0x11, 0xf1, 0xda, 0x6e, 0xe8, 0x4f, 0x1a, 0x5b,
-	0xbf, 0xfb, 0xd7, 0x92, 0x83, 0x14, 0x2f, 0xa6, 0x34, 0x64, 0x40, 0xfe, 0x9c, 0x98, 0x57, 0x17,
-	0xb6, 0xe3, 0x88, 0x5e, 0x52, 0xc6, 0xf1, 0xda, 0x68, 0xc3, 0x07, 0x0b, 0xfe, 0x01, 0x55, 0x60,
-	0xed, 0x8a, 0x4e, 0x64, 0xde, 0x30, 0x7f, 0x44, 0x55, 0xc8, 0x3c, 0x21, 0xce, 0x58, 0xd6, 0x4a,
-	0x01, 0xcb, 0xc5, 0x57, 0xa9, 0xbb, 0x9a, 0xf1, 0x2b, 0xc8, 0x88, 0xd6, 0x85, 0x9a, 0xb0, 0x31,
-	0x9a, 0xad, 0x9d, 0xb8, 0xb5, 0x2e, 0x2a, 0x2c, 0x7c, 0x9d, 0x81, 0x74, 0xc8, 0x3d, 0xa1, 0x7e,
-	0x10, 0xe5, 0xbc, 0x80, 0xa3, 0x25, 0xfa, 0x00, 0x72, 0x96, 0x3f, 0x19, 0xf8, 0x63, 0x59, 0x62,
-	0x79, 0x9c, 0xb5, 0xfc, 0x09, 0x1e, 0xbb, 0xc6, 0xdf, 0x35, 0x48, 0xf3, 0x5e, 0xf8, 0x7d, 0x07,
-	0xf0, 0x19, 0x64, 0x7c, 0xe2, 0x0e, 0xa9, 0xea, 0xe2, 0x1b, 0xd2, 0x29, 0xe6, 0x26, 0xe1, 0x4a,
-	0xee, 0xa2, 0x2f, 0x01, 0x82, 0x90, 0x84, 0x54, 0x96, 0x5e, 0x7a, 0x85, 0xd2, 0xcb, 0x08, 0xbc,
-	0x11, 0x42, 0x9a, 0xf7, 0x68, 0x1e, 0x81, 0xaa, 0x6e, 0x11, 0x7e, 0x19, 0x47, 0x4b, 0x74, 0x07,
-	0xf2, 0x57, 0x74, 0xb2, 0x7a, 0x47, 0x11, 0x6f, 0xee, 0x23, 0x00, 0x4e, 0xf2, 0x88, 0x79, 0x45,
-	0x2d, 0x11, 0x7b, 0x09, 0x17, 0xae, 0xe8, 0xe4, 0x44, 0x18, 0x8c, 0x1c, 0x64, 0x44, 0xa7, 0x37,
-	0xfe, 0x9c, 0x82, 0x8c, 0xe8, 0xe0, 0xef, 0x37, 0x00, 0xde, 0x3e, 0x44, 0x31, 0x05, 0xab, 0x27,
-	0x2c, 0x2b, 0x09, 0xe8, 0x13, 0x28, 0x2b, 0xaa, 0x72, 0x9e, 0x11, 0xce, 0x4b, 0xd2, 0xa8, 0xfc,
-	0xdf, 0x81, 0xbc, 0xc5, 0x4c, 0xe9, 0x3c, 0xbb, 0x4a, 0xcc, 0x16, 0x33, 0xd1, 0x0f, 0x21, 0x4b,
-	0x9f, 0xd9, 0x41, 0x18, 0x88, 0x81, 0x97, 0xc7, 0x6a, 0x65, 0x60, 0x28, 0x26, 0x86, 0x15, 0x6a,
-	0x02, 0xf2, 0xc7, 0x6e, 0x68, 0x8f, 0xe8, 0xc0, 0xbc, 0xa4, 0xe6, 0x95, 0xc7, 0x6c, 0x37, 0x54,
-	0x45, 0x57, 0xad, 0x45, 0x0a, 0xa6, 0xd6, 0x8c, 0xf7, 0xf0, 0xa6, 0xc2, 0x4f, 0x4d, 0x46, 0x19,
-	0x8a, 0x89, 0xe1, 0xb5, 0xf7, 0x8f, 0x32, 0xe4, 0x31, 0x0d, 0x3c, 0xe6, 0x06, 0x14, 0xd5, 0x66,
-	0x34, 0xca, 0xf5, 0xb1, 0x2b, 0x41, 0x49, 0x91, 0x72, 0x1f, 0x0a, 0x91, 0xea, 0xb0, 0x54, 0xd3,
-	0xda, 0x99, 0x4f, 0x8a, 0xba, 0x8d, 0x85, 0xa7, 0x0c, 0xf4, 0x25, 0xe4, 0xb8, 0xfe, 0xb0, 0xd5,
-	0x7b, 0x7a, 0x53, 0xe2, 0x28, 0x72, 0x5d, 0x82, 0x70, 0x84, 0x46, 0x3f, 0x81, 0x2c, 0x17, 0x22,
-	0xd4, 0x52, 0x8a, 0x65, 0x6b, 0x3e, 0xaf, 0x2f, 0x30, 0x58, 0x61, 0x39, 0x8b, 0xeb, 0x11, 0x1a,
-	0x09, 0x97, 0x05, 0xac, 0x9e, 0xc0, 0x60, 0x85, 0xe5, 0x41, 0x0a, 0x51, 0x42, 0x2d, 0xa5, 0x5f,
-	0x16, 0x04, 0xd9, 0x91, 0x20, 0x1c, 0xa1, 0xd1, 0x03, 0x58, 0x17, 0xe2, 0x82, 0x5a, 0x91, 0x28,
-	0x91, 0x6a, 0xe6, 0x93, 0x05, 0x69, 0x95, 0x58, 0xa5, 0x4b, 0xca, 0x41, 0x72, 0x89, 0x3a, 0x50,
-	0x4a, 0x88, 0x0c, 0x4b, 0xc9, 0x9b, 0xbd, 0x05, 0xe9, 0x4a, 0x20, 0xf1, 0x0c, 0x6f, 0xb9, 0x3a,
-	0xf9, 0x63, 0x4a, 0xa9, 0x13, 0x03, 0xf2, 0xd1, 0x68, 0x57, 0x57, 0x32, 0x5e, 0xa3, 0x0e, 0x20,
-	0x35, 0x65, 0x02, 0xf3, 0x92, 0x8e, 0xc8, 0xea, 0xb7, 0xb3, 0x24, 0x79, 0xa7, 0x82, 0x86, 0xbe,
-	0x83, 0x0f, 0xaf, 0x0f, 0xcf, 0xa4, 0xc3, 0x55, 0xc6, 0x7a, 0x75, 0x76, 0x86, 0x2a, 0xc7, 0x3f,
-	0x86, 0x4d, 0x8b, 0x99, 0xe3, 0x11, 0x75, 0x43, 0xd1, 0x62, 0x07, 0x63, 0x5f, 0x0e, 0xa3, 0x02,
-	0xae, 0xcc, 0x6c, 0x9c, 0xf9, 0x0e, 0xfa, 0x14, 0xb2, 0x8c, 0x8c, 0xc3, 0xcb, 0x43, 0x55, 0x12,
-	0x25, 0xd9, 0x65, 0xfb, 0x75, 0x6e, 0xc3, 0x6a, 0xcf, 0xf8, 0x6f, 0x1a, 0x0a, 0x71, 0x01, 0xa3,
-	0x66, 0x42, 0x45, 0x68, 0x62, 0xc0, 0x7e, 0xfe, 0x96, 0x9a, 0x9f, 0xa3, 0x23, 0x7e, 0x9d, 0x02,
-	0x68, 0x32, 0x37, 0x08, 0x7d, 0x62, 0xbb, 0xfc, 0x66, 0xa7, 0x13, 0xd2, 0xe8, 0xe0, 0x6d, 0xfe,
-	0xa6, 0xcc, 0x9a, 0x90, 0x48, 0x82, 0xcc, 0xbb, 0x88, 0x4f, 0x49, 0x9c, 0x3d, 0xac, 0x56, 0x7b,
-	0x7f, 0xd0, 0x20, 0x2d, 0xa4, 0x53, 0x11, 0x72, 0xdd, 0xe3, 0x6f, 0xeb, 0xbd, 0x6e, 0xab, 0x72,
-	0x03, 0x21, 0x58, 0xef, 0x74, 0xdb, 0xbd, 0xd6, 0x00, 0xb7, 0xbf, 0x39, 0xeb, 0xe2, 0x76, 0xab,
-	0xa2, 0xa1, 0x9b, 0xb0, 0xd9, 0xeb, 0x37, 0xeb, 0x0f, 0xbb, 0xfd, 0xe3, 0xa9, 0x39, 0x85, 0x74,
-	0xa8, 0x26, 0xcc, 0xcd, 0xfe, 0xd1, 0x51, 0xfb, 0xb8, 0xd5, 0x6e, 0x55, 0xd6, 0xa6, 0x4e, 0xfa,
-	0x27, 0x7c, 0xb7, 0xde, 0xab, 0xa4, 0xd1, 0x0f, 0x60, 0x43, 0xda, 0x3a, 0x7d, 0xdc, 0xe8, 0xb6,
-	0x5a, 0xed, 0xe3, 0x4a, 0x06, 0x6d, 0x42, 0xf9, 0xec, 0xf8, 0xb4, 0xfe, 0xb0, 0x7b, 0xda, 0xe9,
-	0xd6, 0x1b, 0xbd, 0x76, 0x25, 0x6b, 0xfc, 0x29, 0x21, 0xa5, 0x1e, 0x09, 0x51, 0xa7, 0xce, 0x14,
-	0xa5, 0xf5, 0xee, 0x8a, 0x69, 0x4d, 0xa4, 0x23, 0x10, 0x82, 0x02, 0x27, 0x9d, 0xf1, 0xb6, 0x1d,
-	0x57, 0x9a, 0x47, 0xc2, 0x4b, 0x3d, 0xb5, 0xbb, 0xb6, 0x5f, 0xc0, 0xa5, 0xc8, 0x78, 0x42, 0xc2,
-	0x4b, 0x0e, 0xb2, 0xa8, 0x13, 0x92, 0xc1, 0xd8, 0xe3, 0xbe, 0x03, 0x35, 0xf4, 0x4b, 0xc2, 0x78,
-	0x26, 0x6d, 0xc6, 0x63, 0xa8, 0x5c, 0xff, 0xab, 0x39, 0xda, 0xe5, 0x67, 0x49, 0xed, 0x52, 0x3c,
-	0xbc, 0xb5, 0xfa, 0xcb, 0x4c, 0xea, 0x9c, 0xbb, 0x90, 0x53, 0x6d, 0x0f, 0x7d, 0x01, 0x88, 0x08,
-	0xa5, 0x37, 0xb0, 0x68, 0x60, 0xfa, 0xb6, 0x17, 0x6b, 0x8d, 0x02, 0xde, 0x94, 0x3b, 0xad, 0xe9,
-	0x86, 0x71, 0x04, 0x59, 0xd9, 0xf8, 0xde, 0xcd, 0xbc, 0xf8, 0x0e, 0xb2, 0xb2, 0x23, 0x2e, 0x1f,
-	0xd4, 0xf1, 0xd0, 0x4b, 0xad, 0x38, 0xf4, 0x8c, 0x02, 0xe4, 0x54, 0xcf, 0x34, 0xbe, 0x86, 0xf2,
-	0x4c, 0xfb, 0x43, 0xb7, 0x40, 0xaa, 0x94, 0x38, 0x58, 0xa5, 0x84, 0xd5, 0x87, 0xc0, 0x29, 0xdf,
-	0x8b, 0x84, 0xcc, 0x3a, 0x94, 0x92, 0x1d, 0x6f, 0xef, 0x37, 0x69, 0xc8, 0xb4, 0x9f, 0x85, 0x3e,
-	0x31, 0xfe, 0xa6, 0xc1, 0xc7, 0x51, 0x9e, 0xdb, 0x7c, 0xa2, 0xda, 0xee, 0xf0, 0xc4, 0x67, 0x8f,
-	0xa5, 0x6e, 0x8e, 0x3e, 0xcc, 0x7b, 0x50, 0xa1, 0x6a, 0x73, 0x90, 0x3c, 0x5f, 0xf1, 0xf0, 0xe3,
-	0xc5, 0x9f, 0x28, 0xd1, 0x8d, 0xde, 0x88, 0xa8, 0x51, 0x25, 0x9f, 0x40, 0xc5, 0xf3, 0x99, 0xc7,
-	0x02, 0x6a, 0xc5, 0xde, 0x64, 0x21, 0xac, 0xf8, 0xad, 0xb1, 0x11, 0xd1, 0x95, 0xc1, 0xf8, 0x6b,
-	0x6a, 0x7a, 0x0a, 0x65, 0xab, 0x0f, 0x89, 0xed, 0x06, 0x61, 0xa2, 0x18, 0xd1, 0xd7, 0xb3, 0x2f,
-	0x67, 0xa5, 0xe0, 0xe3, 0xf7, 0x37, 0x9c, 0xbd, 0x7e, 0x29, 0x71, 0xfd, 0xda, 0x33, 0xf1, 0x8a,
-	0x8c, 0xd6, 0xde, 0x1a, 0xc7, 0xf2, 0xbb, 0xf8, 0x3e, 0x6f, 0xd0, 0xe1, 0xcf, 0xa1, 0x10, 0x17,
-	0x0c, 0xfa, 0x29, 0x14, 0xa7, 0x99, 0xa0, 0xa8, 0x3a, 0xef, 0x5d, 0x18, 0x37, 0xe7, 0xfe, 0xd1,
-	0xbe, 0x76, 0x5b, 0x6b, 0x34, 0x5e, 0xfc, 0x7b, 0xfb, 0xc6, 0x8b, 0x57, 0xdb, 0xda, 0x3f, 0x5f,
-	0x6d, 0x6b, 0xcf, 0xff, 0xb3, 0xad, 0x3d, 0xba, 0xbd, 0xd2, 0x87, 0x71, 0xc2, 0xe1, 0x79, 0x56,
-	0x98, 0xef, 0xfc, 0x3f, 0x00, 0x00, 0xff, 0xff, 0x8b, 0x76, 0x9c, 0xc0, 0xa5, 0x12, 0x00, 0x00,
+	// 1644 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xbd, 0x57, 0x4b, 0x6f, 0x1b, 0x55,
+	0x14, 0xae, 0x1d, 0x3f, 0x8f, 0xed, 0xc4, 0xb9, 0xb8, 0xad, 0x99, 0xa6, 0xaf, 0xb4, 0x55, 0xab,
+	0x22, 0x9c, 0x2a, 0x45, 0xa2, 0x0f, 0x15, 0xe1, 0x57, 0xa4, 0x14, 0x27, 0x4e, 0x6f, 0xfa, 0x90,
+	0xba, 0xb1, 0xa6, 0xf6, 0x8d, 0x33, 0xad, 0x3d, 0x33, 0xcc, 0x8c, 0xdb, 0x86, 0x0d, 0x6c, 0x10,
+	0x12, 0x3b, 0x24, 0xc4, 0x86, 0x0d, 0xea, 0x9a, 0x2d, 0xbf, 0x00, 0x21, 0x75, 0x07, 0xbf, 0x00,
+	0x04, 0xfc, 0x82, 0x6e, 0x59, 0x20, 0xce, 0x7d, 0xcc, 0x78, 0x9c, 0x38, 0xee, 0x2c, 0x4a, 0x17,
+	0x89, 0xe6, 0x9e, 0xfb, 0x7d, 0xc7, 0xe7, 0x9e, 0x39, 0xf7, 0x9c, 0x6f, 0xe0, 0x72, 0xdf, 0x5a,
+	0xb1, 0x1d, 0xcb, 0xb3, 0xba, 0xd6, 0xc0, 0x5d, 0x19, 0xea, 0x1e, 0x73, 0x0c, 0x7d, 0x60, 0x7c,
+	0xc6, 0xc2, 0xcf, 0x15, 0x81, 0x20, 0xb9, 0x90, 0x49, 0x3b, 0xd3, 0xb5, 0x4c, 0x77, 0x34, 0x64,
+	0x4e, 0x40, 0x0f, 0x1e, 0x24, 0x5c, 0x5b, 0x9a, 0x70, 0xbd, 0x33, 0xb0, 0x9e, 0x89, 0x7f, 0x6a,
+	0xb7, 0xd4, 0xb7, 0xfa, 0x96, 0x78, 0x5c, 0xe1, 0x4f, 0xd2, 0xba, 0xfc, 0x6a, 0x11, 0xd2, 0x94,
+	0x7d, 0x3a, 0x62, 0xae, 0x47, 0xde, 0x87, 0x84, 0x6b, 0xb3, 0x6e, 0x39, 0x76, 0x26, 0x76, 0x29,
+	0xb7, 0xfa, 0x6e, 0x25, 0x1c, 0x90, 0xc2, 0x54, 0xb6, 0x11, 0x40, 0x05, 0x8c, 0x5c, 0x87, 0xcc,
+	0x53, 0xdc, 0xec, 0x21, 0xaa, 0x1c, 0x17, 0x94, 0x93, 0x53, 0x29, 0xf7, 0x15, 0x88, 0x06, 0x70,
+	0x72, 0x05, 0x92, 0xba, 0x6d, 0x0f, 0xf6, 0xca, 0x73, 0x82, 0xa7, 0x4d, 0xe5, 0x55, 0x39, 0x82,
+	0x4a, 0x20, 0x8f, 0xcd, 0xb2, 0x99, 0x59, 0x4e, 0xcc, 0x88, 0xad, 0x8d, 0x00, 0x2a, 0x60, 0x1c,
+	0x3e, 0xb0, 0xf4, 0x5e, 0x39, 0x39, 0x03, 0xde, 0x42, 0x00, 0x15, 0x30, 0x1e, 0xcf, 0xce, 0x60,
+	0xe4, 0xee, 0x96, 0x53, 0x33, 0xe2, 0x59, 0xe3, 0x08, 0x2a, 0x81, 0x9c, 0xe1, 0x7a, 0x96, 0xc3,
+	0xca, 0xe9, 0x19, 0x8c, 0x6d, 0x8e, 0xa0, 0x12, 0x48, 0xea, 0x90, 0x77, 0x3d, 0xdd, 0xf1, 0x3a,
+	0x5d, 0x6b, 0x38, 0x34, 0xbc, 0x72, 0x46, 0x10, 0xcf, 0x1c, 0x42, 0x44, 0x60, 0x5d, 0xe0, 0x68,
+	0xce, 0x1d, 0x2f, 0x48, 0x0d, 0x72, 0x7a, 0xf7, 0x89, 0x69, 0x3d, 0x1b, 0xb0, 0x5e, 0x9f, 0x95,
+	0xb3, 0x33, 0x7c, 0x54, 0xc7, 0x38, 0x1a, 0x26, 0x91, 0x13, 0x90, 0x31, 0x4c, 0xc4, 0x9b, 0xfa,
+	0xa0, 0xdc, 0x43, 0x07, 0x79, 0x9a, 0x3d, 0xef, 0x1b, 0xb4, 0x6f, 0x62, 0x90, 0xe0, 0xef, 0x98,
+	0x6c, 0xc2, 0x3c, 0x16, 0x9c, 0xc9, 0xba, 0x18, 0x7c, 0xc7, 0xdb, 0xb3, 0x99, 0x28, 0x8b, 0xf9,
+	0xd5, 0x8b, 0x15, 0x51, 0x53, 0x1b, 0xc1, 0x2f, 0xea, 0x9e, 0x61, 0x99, 0x9c, 0x52, 0xa9, 0xfb,
+	0xf8, 0xbb, 0x08, 0xa7, 0x85, 0x6e, 0x78, 0x89, 0xd5, 0x92, 0x43, 0xc3, 0x8e, 0xd1, 0xef, 0x3c,
+	0x76, 0x2d, 0x53, 0x14, 0x4c, 0xb6, 0xb6, 0xf4, 0xcf, 0xef, 0xa7, 0xcb, 0xcc, 0xec, 0x5a, 0x3d,
+	0xc3, 0xec, 0xaf, 0xf0, 0x8d, 0x0a, 0xd5, 0x9f, 0x6d, 0x30, 0xd7, 0xd5, 0x31, 0xea, 0x94, 0x24,
+	0x68, 0x3f, 0x26, 0x21, 0xe3, 0x17, 0x11, 0xb9, 0x03, 0x09, 0x53, 0x1f, 0xca, 0x68, 0xb2, 0xb5,
+	0x5b, 0xe8, 0xe0, 0x7a, 0xdf, 0xf0, 0x76, 0x47, 0x8f, 0x2a, 0x98, 0xd7, 0x15, 0x3c, 0xf8, 0x48,
+	0x77, 0xf6, 0x64, 0xf1, 0x1f, 0xb8, 0x0e, 0xfb, 0xa3, 0xa6, 0xc2, 0xd5, 0x94, 0xa3, 0xc6, 0xdf,
+	0xe4, 0x51, 0xe7, 0xa2, 0x1f, 0x95, 0x54, 0x21, 0xf3, 0xc8, 0x30, 0x39, 0xc4, 0xc5, 0x52, 0x9f,
+	0xc3, 0x97, 0x7b, 0x61, 0xe6, 0x9d, 0xaa, 0xd4, 0x24, 0x9a, 0x06, 0x34, 0xed, 0xdf, 0x38, 0xa4,
+	0x95, 0x95, 0xdc, 0x86, 0x92, 0xc3, 0x5c, 0x6b, 0xe4, 0x74, 0x59, 0x27, 0x1c, 0x52, 0x2c, 0x42,
+	0x48, 0xf3, 0x3e, 0xb3, 0x2e, 0x43, 0xbb, 0x01, 0x80, 0x89, 0x1c, 0xe0, 0x31, 0x0d, 0xf5, 0xfe,
+	0x72, 0xab, 0x25, 0x99, 0xa1, 0x7a, 0x60, 0xe7, 0xc9, 0xa9, 0x25, 0x5e, 0xfe, 0x7e, 0xfa, 0x08,
+	0x0d, 0xa1, 0xc9, 0x57, 0x31, 0x38, 0xba, 0x63, 0xb0, 0x41, 0x2f, 0x1c, 0x45, 0x67, 0xa8, 0xdb,
+	0x98, 0x1c, 0x7e, 0xc8, 0x5b, 0x91, 0x0e, 0x59, 0x59, 0xe3, 0x2e, 0x64, 0x38, 0xb7, 0xd1, 0xc1,
+	0x86, 0x6e, 0x37, 0x4d, 0xcf, 0xd9, 0xab, 0x2d, 0x7d, 0xfd, 0xc7, 0x8c, 0x83, 0xe4, 0x76, 0xc6,
+	0x34, 0xa2, 0x61, 0x82, 0xf1, 0x32, 0xec, 0x18, 0x83, 0x81, 0xe8, 0x25, 0x05, 0x1a, 0xac, 0xb5,
+	0x26, 0x1c, 0x3f, 0xe4, 0x17, 0x48, 0x11, 0xe6, 0x9e, 0xb0, 0x3d, 0x99, 0x37, 0xca, 0x1f, 0x49,
+	0x09, 0x92, 0xd8, 0xce, 0x46, 0xb2, 0x56, 0xb2, 0x54, 0x2e, 0x6e, 0xc4, 0xaf, 0xc5, 0xb4, 0xcf,
+	0x21, 0x29, 0x5a, 0x17, 0xde, 0xf8, 0x85, 0xe1, 0x64, 0xed, 0x04, 0xad, 0xf5, 0xb0, 0xc2, 0xa2,
+	0xfb, 0x19, 0xa4, 0x0c, 0xe9, 0xa7, 0xcc, 0x71, 0xfd, 0x9c, 0x67, 0xa9, 0xbf, 0x24, 0xc7, 0x21,
+	0xdd, 0x73, 0xf6, 0x3a, 0xce, 0x48, 0x96, 0x58, 0x86, 0xa6, 0x70, 0x49, 0x47, 0xa6, 0xf6, 0x0b,
+	0xde, 0x61, 0xde, 0x0b, 0xff, 0xef, 0x00, 0x2e, 0x40, 0xd2, 0xd1, 0x4d, 0x6c, 0x43, 0xb2, 0x8b,
+	0x2f, 0x48, 0xa7, 0x94, 0x9b, 0x84, 0x2b, 0xb9, 0x4b, 0x3e, 0x04, 0xc0, 0x16, 0xe6, 0x31, 0x59,
+	0x7a, 0x89, 0x08, 0xa5, 0x97, 0x14, 0x78, 0xcd, 0x83, 0x04, 0xef, 0xd1, 0x3c, 0x02, 0x55, 0xdd,
+	0x22, 0xfc, 0x02, 0xf5, 0x97, 0xe4, 0x2a, 0x64, 0xf0, 0x5d, 0x44, 0xef, 0x28, 0xe2, 0xcd, 0x9d,
+	0x04, 0xe0, 0x24, 0x1b, 0x5f, 0x3b, 0xeb, 0x89, 0xd8, 0xb1, 0x03, 0xa2, 0x65, 0x4b, 0x18, 0xb4,
+	0x34, 0x24, 0x45, 0xa7, 0xd7, 0x5e, 0xc4, 0x21, 0x29, 0x3a, 0xf8, 0xdb, 0x0d, 0x80, 0xb7, 0x0f,
+	0x51, 0x4c, 0x6e, 0xf4, 0x84, 0xa5, 0x24, 0x81, 0x9c, 0x83, 0x82, 0xa2, 0x2a, 0xe7, 0x49, 0xe1,
+	0x3c, 0x2f, 0x8d, 0xca, 0x3f, 0xc6, 0xdc, 0xb3, 0xba, 0xd2, 0x79, 0x2a, 0x4a, 0xcc, 0x88, 0x26,
+	0xc7, 0x20, 0xc5, 0x9e, 0x1b, 0xae, 0xe7, 0x8a, 0x81, 0x87, 0xb5, 0x26, 0x57, 0xda, 0x8b, 0x18,
+	0xe4, 0x42, 0xd3, 0x0a, 0x4b, 0x8e, 0x60, 0x41, 0x7a, 0xc6, 0x10, 0x1b, 0xce, 0x2e, 0xeb, 0x3e,
+	0xb1, 0x2d, 0x1c, 0x2d, 0xaa, 0xea, 0x4a, 0x15, 0x5f, 0xc2, 0x54, 0xea, 0xc1, 0x1e, 0x5d, 0x54,
+	0xf8, 0xb1, 0x09, 0x1b, 0x72, 0xf9, 0xa0, 0x93, 0x4e, 0x8f, 0x0d, 0x3c, 0x3d, 0x68, 0x3c, 0xd3,
+	0x5c, 0x1d, 0x3b, 0xe0, 0xaa, 0xc1, 0x39, 0x5a, 0x01, 0x72, 0xa1, 0x69, 0xb8, 0xfc, 0x6b, 0x01,
+	0x32, 0x94, 0xb9, 0x36, 0x7a, 0x60, 0xa4, 0x32, 0x21, 0x7a, 0xf6, 0xcf, 0x71, 0x09, 0x0a, 0xab,
+	0x9e, 0x5b, 0x90, 0xf5, 0x65, 0x4c, 0x4f, 0x05, 0x73, 0x7a, 0x3a, 0xc9, 0x6f, 0x5f, 0x3d, 0x3a,
+	0x66, 0xe0, 0x65, 0x48, 0x73, 0x41, 0x63, 0xa8, 0x17, 0x7f, 0x50, 0x33, 0x29, 0x72, 0x55, 0x82,
+	0xa8, 0x8f, 0x26, 0x1f, 0x40, 0x8a, 0x2b, 0x1b, 0xe4, 0x49, 0x09, 0xb4, 0x34, 0x9d, 0xd7, 0x16,
+	0x18, 0xaa, 0xb0, 0x9c, 0xc5, 0x05, 0x0e, 0xf3, 0x95, 0xd0, 0x21, 0xac, 0x96, 0xc0, 0x50, 0x85,
+	0xe5, 0x41, 0x0a, 0x95, 0x83, 0xb4, 0xd4, 0xac, 0x20, 0xd7, 0x24, 0x88, 0xfa, 0x68, 0x9c, 0x37,
+	0xf3, 0x42, 0xad, 0xb0, 0x9e, 0xaf, 0x72, 0xa4, 0x3c, 0x3a, 0x77, 0x48, 0x5a, 0x25, 0x56, 0x09,
+	0x9d, 0x82, 0x1b, 0x5e, 0x92, 0x35, 0xc8, 0x87, 0x54, 0x4b, 0x4f, 0xe9, 0xa5, 0xe5, 0x43, 0xd2,
+	0x15, 0x42, 0xd2, 0x09, 0xde, 0x6c, 0xb9, 0xf3, 0x5d, 0x5c, 0xc9, 0x1d, 0x9c, 0x0b, 0xbe, 0x56,
+	0x50, 0x77, 0x3c, 0x58, 0x63, 0x24, 0x44, 0x8d, 0x2d, 0x17, 0xcb, 0x71, 0xa8, 0x47, 0xbf, 0xee,
+	0x79, 0xc9, 0xdb, 0x16, 0x34, 0xf2, 0x00, 0x4e, 0xec, 0x9f, 0xc6, 0x61, 0x87, 0x51, 0x74, 0x42,
+	0x69, 0x72, 0x28, 0x2b, 0xc7, 0xef, 0xc1, 0x22, 0xde, 0x51, 0xbc, 0x0d, 0xa6, 0x27, 0x7a, 0x76,
+	0x67, 0xe4, 0xc8, 0xe9, 0x96, 0xa5, 0xc5, 0x89, 0x8d, 0x7b, 0xce, 0x80, 0x9c, 0xc7, 0x42, 0xd2,
+	0x47, 0xde, 0xee, 0xaa, 0x2a, 0x89, 0xbc, 0x6c, 0xdb, 0xed, 0x2a, 0xb7, 0x51, 0xb5, 0xa7, 0xbd,
+	0x4a, 0x40, 0x36, 0x28, 0x60, 0xbc, 0xd5, 0x63, 0x59, 0x12, 0x13, 0x13, 0xfb, 0xe2, 0x6b, 0x6a,
+	0x7e, 0x8a, 0x30, 0xf9, 0x22, 0x0e, 0x80, 0x61, 0xbb, 0x9e, 0xa3, 0xf3, 0x4b, 0x5e, 0x87, 0x44,
+	0x48, 0x6b, 0xad, 0xbc, 0xce, 0xdf, 0x98, 0x59, 0x11, 0x9a, 0x4b, 0x90, 0x79, 0x5b, 0x72, 0x98,
+	0x1e, 0x64, 0x8f, 0xaa, 0xd5, 0xf2, 0xb7, 0x38, 0x02, 0x85, 0x16, 0xcb, 0x41, 0x7a, 0x7d, 0xf3,
+	0x7e, 0xb5, 0xb5, 0xde, 0x28, 0x1e, 0x21, 0x04, 0xe6, 0xd7, 0xd6, 0x9b, 0xad, 0x46, 0x87, 0x36,
+	0xef, 0xdc, 0x5b, 0xa7, 0xcd, 0x46, 0x31, 0x46, 0x8e, 0xc2, 0x62, 0xab, 0x5d, 0xaf, 0xde, 0x5d,
+	0x6f, 0x6f, 0x8e, 0xcd, 0x71, 0x6c, 0xf9, 0xa5, 0x90, 0xb9, 0xde, 0xde, 0xd8, 0x68, 0x6e, 0x36,
+	0x70, 0x67, 0x6e, 0xec, 0xa4, 0xbd, 0xc5, 0x77, 0xab, 0xad, 0x62, 0x82, 0xbc, 0x03, 0x0b, 0xd2,
+	0xb6, 0xd6, 0xa6, 0xb5, 0xf5, 0x46, 0xa3, 0xb9, 0x59, 0x4c, 0x92, 0x45, 0x28, 0xdc, 0xdb, 0xdc,
+	0x46, 0x17, 0xdb, 0x6b, 0xeb, 0xd5, 0x5a, 0xab, 0x59, 0x4c, 0x69, 0xdf, 0x87, 0xb4, 0xd9, 0x43,
+	0xa1, 0x12, 0xd5, 0x99, 0xfc, 0xb4, 0x5e, 0x8b, 0x98, 0xd6, 0x50, 0x3a, 0x5c, 0xa1, 0x50, 0x68,
+	0xd8, 0x19, 0x9f, 0x03, 0x41, 0xa5, 0xd9, 0xba, 0xb7, 0x8b, 0x49, 0x9e, 0xc3, 0xec, 0xe4, 0x7d,
+	0xe3, 0x16, 0xda, 0x38, 0x48, 0xb4, 0xd4, 0xce, 0xc8, 0xe6, 0xbe, 0x5d, 0xa5, 0x22, 0xf2, 0xc2,
+	0x78, 0x4f, 0xda, 0xb4, 0xc7, 0x50, 0xdc, 0xff, 0x53, 0x53, 0xc4, 0xd0, 0xc7, 0x61, 0x31, 0x94,
+	0x5b, 0xbd, 0x1c, 0xfd, 0x65, 0x86, 0x85, 0xd3, 0x35, 0x48, 0xab, 0xb6, 0x87, 0xdf, 0x6f, 0x44,
+	0x17, 0xd2, 0x11, 0xbb, 0xbe, 0xdb, 0x75, 0x0c, 0x3b, 0x10, 0x2f, 0x59, 0xba, 0x28, 0x77, 0x1a,
+	0xe3, 0x0d, 0x6d, 0x03, 0x52, 0xb2, 0xf1, 0xbd, 0x91, 0xf9, 0xa3, 0x3d, 0x80, 0x94, 0xec, 0x88,
+	0xb3, 0x27, 0x7f, 0x30, 0x45, 0xe3, 0x11, 0xa7, 0xa8, 0x96, 0x85, 0xb4, 0xea, 0x99, 0xda, 0x4d,
+	0x28, 0x4c, 0xb4, 0x3f, 0x72, 0x19, 0xa4, 0xec, 0x09, 0x82, 0x55, 0xd2, 0x5a, 0x7d, 0x59, 0x6c,
+	0xf3, 0x3d, 0x5f, 0x19, 0xcd, 0x43, 0x3e, 0xdc, 0xf1, 0x96, 0xbf, 0x4c, 0x40, 0xb2, 0xf9, 0x1c,
+	0x33, 0xaa, 0xfd, 0x1c, 0x83, 0xb3, 0x7e, 0x9e, 0x9b, 0x7c, 0x44, 0x63, 0x2c, 0x5b, 0x8e, 0xf5,
+	0x58, 0x0a, 0x71, 0xff, 0x4b, 0xbf, 0x05, 0x45, 0xa6, 0x36, 0x3b, 0xe1, 0xf3, 0xe5, 0x56, 0xcf,
+	0x1e, 0xfe, 0xcd, 0xe3, 0xdf, 0xe8, 0x05, 0x9f, 0xea, 0x57, 0xf2, 0x16, 0x14, 0xb1, 0x57, 0xda,
+	0x96, 0x8b, 0x6d, 0xdf, 0xf7, 0x26, 0x0b, 0x21, 0xe2, 0xc7, 0xcb, 0x82, 0x4f, 0x57, 0x06, 0xed,
+	0xa7, 0xf8, 0xf8, 0x14, 0xca, 0x56, 0xed, 0x63, 0xb9, 0xb8, 0x5e, 0xa8, 0x18, 0xc9, 0xcd, 0xc9,
+	0x97, 0x13, 0x29, 0xf8, 0xe0, 0xfd, 0xf5, 0x27, 0xaf, 0x5f, 0x5c, 0x5c, 0xbf, 0xe6, 0x44, 0xbc,
+	0x22, 0xa3, 0x95, 0xd7, 0xc6, 0x31, 0xfb, 0x2e, 0xbe, 0xcd, 0x1b, 0xb4, 0xfa, 0x09, 0x64, 0x83,
+	0x82, 0x21, 0x1f, 0x41, 0x6e, 0x9c, 0x09, 0x46, 0x4a, 0xd3, 0xde, 0x85, 0x76, 0x74, 0xea, 0x0f,
+	0x5d, 0x8a, 0x5d, 0x89, 0xd5, 0x6a, 0x2f, 0xff, 0x3c, 0x75, 0xe4, 0xe5, 0x5f, 0xa7, 0x62, 0xbf,
+	0xe1, 0xdf, 0x0f, 0x7f, 0x9f, 0x8a, 0x3d, 0xbc, 0x12, 0xe9, 0x4b, 0x3b, 0xe4, 0xf0, 0x51, 0x4a,
+	0x98, 0xaf, 0xfe, 0x07, 0xf1, 0x8b, 0x5d, 0x25, 0xf6, 0x12, 0x00, 0x00,
 }
 
 // Reference imports to suppress errors if they are not otherwise used.
@@ -2138,6 +2142,18 @@ func (m *Request_StartCommit) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.RuntimeCheckpointDelta != nil {
+		{
+			size, err := m.RuntimeCheckpointDelta.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintMaterialize(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
 	if m.RuntimeCheckpoint != nil {
 		{
 			size, err := m.RuntimeCheckpoint.MarshalToSizedBuffer(dAtA[:i])
@@ -3151,6 +3167,10 @@ func (m *Request_StartCommit) ProtoSize() (n int) {
 		l = m.RuntimeCheckpoint.ProtoSize()
 		n += 1 + l + sovMaterialize(uint64(l))
 	}
+	if m.RuntimeCheckpointDelta != nil {
+		l = m.RuntimeCheckpointDelta.ProtoSize()
+		n += 1 + l + sovMaterialize(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -5250,6 +5270,42 @@ func (m *Request_StartCommit) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field RuntimeCheckpointDelta", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowMaterialize
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthMaterialize
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthMaterialize
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.RuntimeCheckpointDelta == nil {
+				m.RuntimeCheckpointDelta = &protocol.Checkpoint{}
+			}
+			if err := m.RuntimeCheckpointDelta.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipMaterialize(dAtA[iNdEx:])
@@ -324,11 +324,16 @@ type Stats struct {
 	Capture map[string]*Stats_Binding `protobuf:"bytes,6,rep,name=capture,proto3" json:"capture,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
 	Derive  *Stats_Derive             `protobuf:"bytes,7,opt,name=derive,proto3" json:"derive,omitempty"`
 	// Materialization metrics.
-	Materialize          map[string]*Stats_Binding `protobuf:"bytes,8,rep,name=materialize,proto3" json:"materialize,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
-	Interval             *Stats_Interval           `protobuf:"bytes,9,opt,name=interval,proto3" json:"interval,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}                  `json:"-"`
-	XXX_unrecognized     []byte                    `json:"-"`
-	XXX_sizecache        int32                     `json:"-"`
+	Materialize map[string]*Stats_Binding `protobuf:"bytes,8,rep,name=materialize,proto3" json:"materialize,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	Interval    *Stats_Interval           `protobuf:"bytes,9,opt,name=interval,proto3" json:"interval,omitempty"`
+	// Duration of time that the transaction was throttled by rate limits,
+	// during which the task shard paused reading from its connector.
+	// When aggregating, this is total time throttled across all transactions
+	// within the interval.
+	ThrottledSecondsTotal float64  `protobuf:"fixed64,10,opt,name=throttled_seconds_total,json=throttledSecondsTotal,proto3" json:"throttled_seconds_total,omitempty"`
+	XXX_NoUnkeyedLiteral  struct{} `json:"-"`
+	XXX_unrecognized      []byte   `json:"-"`
+	XXX_sizecache         int32    `json:"-"`
 }
 
 func (m *Stats) Reset()         { *m = Stats{} }
@@ -615,78 +620,79 @@ func init() {
 func init() { proto.RegisterFile("go/protocols/ops/ops.proto", fileDescriptor_37de94a5cb9d0036) }
 
 var fileDescriptor_37de94a5cb9d0036 = []byte{
-	// 1134 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xc4, 0x56, 0x4f, 0x8f, 0xdb, 0xc4,
-	0x1b, 0x5e, 0x27, 0x76, 0x36, 0x7e, 0xd3, 0xdd, 0xf5, 0x6f, 0x5a, 0xfd, 0xb0, 0xd2, 0xb2, 0xbb,
-	0x0d, 0x20, 0x6d, 0x5b, 0x48, 0xd4, 0x20, 0x24, 0x54, 0x09, 0x89, 0xa6, 0x05, 0x09, 0xb4, 0xbb,
-	0x2a, 0xb3, 0x39, 0xf5, 0x62, 0x4d, 0xec, 0x89, 0xd7, 0xc4, 0x99, 0xb1, 0x66, 0xc6, 0xbb, 0x0d,
-	0x47, 0x8e, 0x9c, 0xf8, 0x08, 0x7c, 0x9c, 0x9e, 0x10, 0x1c, 0x39, 0x00, 0xa2, 0x7c, 0x11, 0x34,
-	0x33, 0x76, 0xe2, 0xfe, 0xa3, 0x42, 0x1c, 0x38, 0x24, 0x9a, 0x79, 0xde, 0x67, 0xc6, 0xef, 0xf3,
-	0xfe, 0xb3, 0xa1, 0x9f, 0xf2, 0x51, 0x21, 0xb8, 0xe2, 0x31, 0xcf, 0xe5, 0x88, 0x17, 0xe6, 0x37,
-	0x34, 0x08, 0x6a, 0xf3, 0x42, 0xf6, 0x6f, 0x3c, 0x47, 0x98, 0xe7, 0xfc, 0xd2, 0xfc, 0x59, 0x4a,
-	0xff, 0x5a, 0xca, 0x53, 0x6e, 0x96, 0x23, 0xbd, 0xaa, 0xd0, 0x83, 0x94, 0xf3, 0x34, 0xa7, 0xf6,
-	0xdc, 0xac, 0x9c, 0x8f, 0x54, 0xb6, 0xa4, 0x52, 0x91, 0x65, 0x61, 0x09, 0x83, 0x5f, 0x5a, 0xb0,
-	0x73, 0x76, 0x4e, 0x44, 0x72, 0x4c, 0x66, 0x34, 0xcf, 0x58, 0x8a, 0xae, 0x81, 0x37, 0x2b, 0xb3,
-	0x3c, 0x09, 0x9d, 0x43, 0xe7, 0xc8, 0xc7, 0x76, 0x83, 0xfa, 0xd0, 0x3d, 0xe7, 0x52, 0x31, 0xb2,
-	0xa4, 0x61, 0xcb, 0x18, 0xd6, 0x7b, 0x74, 0x07, 0xfc, 0x9c, 0xa7, 0x51, 0x4e, 0x2f, 0x68, 0x1e,
-	0xb6, 0x0f, 0x9d, 0xa3, 0xdd, 0xf1, 0xee, 0x50, 0x3b, 0x7f, 0xcc, 0xd3, 0xe1, 0xb1, 0x46, 0x71,
-	0x37, 0xe7, 0xa9, 0x59, 0xa1, 0x0f, 0xc0, 0x2b, 0xb8, 0x50, 0x32, 0x74, 0x0f, 0xdb, 0x47, 0xbd,
-	0xf1, 0xff, 0x86, 0x46, 0xc3, 0x29, 0x55, 0x97, 0x5c, 0x2c, 0x1e, 0x71, 0xa1, 0x26, 0xee, 0xd3,
-	0xdf, 0x0e, 0xb6, 0xb0, 0x65, 0xa1, 0x3b, 0xe0, 0x09, 0xc2, 0x52, 0x1a, 0x7a, 0x87, 0xce, 0x51,
-	0x6f, 0xbc, 0x67, 0xe9, 0x58, 0x43, 0x67, 0x05, 0x8d, 0x6b, 0xb2, 0xe1, 0xa0, 0x9b, 0x70, 0x45,
-	0x16, 0x79, 0xa6, 0x22, 0xc9, 0x4b, 0x11, 0xd3, 0xb0, 0x63, 0x1c, 0xed, 0x19, 0xec, 0xcc, 0x40,
-	0x1b, 0x8a, 0x22, 0x22, 0xa5, 0x2a, 0xdc, 0x6e, 0x50, 0xa6, 0x06, 0x42, 0xd7, 0xc1, 0x57, 0x44,
-	0x2e, 0x22, 0xa3, 0xb5, 0x6b, 0xb5, 0x6a, 0xe0, 0x54, 0x6b, 0xbd, 0x5d, 0x19, 0xd5, 0xaa, 0xa0,
-	0xa1, 0x6f, 0xb4, 0xee, 0x18, 0xad, 0x53, 0x22, 0x17, 0xd3, 0x55, 0x41, 0x2d, 0x57, 0xaf, 0x06,
-	0xdf, 0x3a, 0xd0, 0x35, 0xb1, 0xc5, 0x74, 0x8e, 0x6e, 0x82, 0xbb, 0xc8, 0x98, 0x8d, 0xea, 0x4b,
-	0x67, 0x8c, 0x09, 0x21, 0x70, 0x1b, 0xf1, 0x35, 0x6b, 0xed, 0xcc, 0x82, 0xae, 0xa2, 0x19, 0x4d,
-	0x33, 0x66, 0x62, 0xeb, 0xe3, 0xee, 0x82, 0xae, 0x26, 0x7a, 0x8f, 0x06, 0xb0, 0x23, 0xa2, 0x38,
-	0xe7, 0xf1, 0xa2, 0x22, 0xb8, 0x56, 0x8d, 0x78, 0xa0, 0x31, 0xc3, 0x19, 0xf4, 0xc1, 0x3d, 0xa1,
-	0x8a, 0xe8, 0xcb, 0xcb, 0x32, 0xab, 0xb3, 0x6a, 0xd6, 0x83, 0x1f, 0xdb, 0xd0, 0x3e, 0xe6, 0x29,
-	0xda, 0x07, 0x77, 0x49, 0x15, 0x31, 0xb6, 0xde, 0xd8, 0x37, 0xbe, 0xe9, 0x43, 0xd8, 0x8b, 0x34,
-	0x8e, 0xde, 0x01, 0x4f, 0x6a, 0x1d, 0xc6, 0xb3, 0x5e, 0xe5, 0x7c, 0xad, 0x0c, 0x5b, 0x1b, 0xba,
-	0x0b, 0xfe, 0xba, 0xb8, 0x8c, 0xa7, 0xbd, 0x71, 0x7f, 0x68, 0xcb, 0x6f, 0x58, 0x97, 0xdf, 0x70,
-	0x5a, 0x33, 0x70, 0x4b, 0x49, 0xf4, 0x2e, 0x78, 0xb6, 0x68, 0xdc, 0x57, 0x16, 0x8d, 0x35, 0xa2,
-	0x10, 0xb6, 0x97, 0x54, 0x4a, 0x52, 0x15, 0x81, 0x8f, 0xeb, 0x2d, 0x7a, 0x0c, 0x7b, 0xf3, 0x8c,
-	0xe6, 0x89, 0x8c, 0xbe, 0x96, 0x9c, 0x45, 0x4b, 0x52, 0x84, 0x1d, 0x53, 0x55, 0xd7, 0xd7, 0x37,
-	0x7d, 0x6e, 0xec, 0x5f, 0x4a, 0xce, 0x4e, 0x48, 0xf1, 0x19, 0x53, 0x62, 0x35, 0xb9, 0xf1, 0xdd,
-	0xef, 0x07, 0x21, 0x65, 0x31, 0x4f, 0x32, 0x96, 0x8e, 0xf4, 0xc9, 0x21, 0x26, 0x97, 0x27, 0xf6,
-	0x4e, 0xdc, 0xb1, 0x37, 0xa2, 0x7d, 0xf0, 0x64, 0x41, 0x98, 0x0c, 0xb7, 0xcd, 0x8d, 0xdd, 0xfa,
-	0x46, 0x6c, 0xe1, 0xfe, 0xa7, 0x80, 0x5e, 0xbe, 0x1b, 0x05, 0xd0, 0x5e, 0xd0, 0x55, 0x15, 0x64,
-	0xbd, 0xd4, 0xed, 0x74, 0x41, 0xf2, 0xb2, 0xce, 0xaa, 0xdd, 0xdc, 0x6b, 0x7d, 0xec, 0x0c, 0xbe,
-	0x02, 0xcf, 0xb6, 0xc4, 0x55, 0xd8, 0x2b, 0x59, 0x42, 0xe7, 0x19, 0xa3, 0x89, 0xed, 0xa2, 0x60,
-	0x0b, 0xf9, 0xe0, 0x51, 0x21, 0xb8, 0x08, 0x1c, 0xd4, 0x05, 0xf7, 0x92, 0x08, 0x16, 0xb4, 0xf4,
-	0x2a, 0x63, 0x73, 0x1e, 0xb4, 0xb5, 0x39, 0xa1, 0xb3, 0x32, 0x0d, 0x5c, 0xbd, 0x54, 0x82, 0xc4,
-	0x34, 0xf0, 0x06, 0x3f, 0xfb, 0xe0, 0x9d, 0x29, 0xa2, 0xe4, 0x7f, 0x96, 0xd2, 0xf7, 0x01, 0xf1,
-	0x82, 0xb2, 0x48, 0xd2, 0x98, 0xb3, 0x44, 0x46, 0x8a, 0x2b, 0x62, 0xf3, 0xeb, 0xe0, 0x40, 0x5b,
-	0xce, 0xac, 0x61, 0xaa, 0x71, 0xd3, 0x6a, 0x4f, 0x58, 0x14, 0xf3, 0x92, 0x29, 0x93, 0xdc, 0x1d,
-	0xdc, 0x55, 0x4f, 0xd8, 0x03, 0xbd, 0x47, 0x77, 0x61, 0x3b, 0x26, 0x85, 0x2a, 0x05, 0xad, 0xb2,
-	0xfa, 0x96, 0x75, 0x52, 0xeb, 0x1b, 0x3e, 0xb0, 0x16, 0x13, 0x75, 0x5c, 0xf3, 0xd0, 0x2d, 0xe8,
-	0x24, 0x54, 0x64, 0x17, 0xd4, 0xf4, 0xb5, 0x9e, 0x2e, 0x9b, 0x13, 0x0f, 0x8d, 0x01, 0x57, 0x04,
-	0xf4, 0x09, 0xf4, 0x96, 0x44, 0x51, 0x91, 0x91, 0x3c, 0xfb, 0x46, 0xf7, 0xf9, 0xa6, 0x6e, 0x2c,
-	0xff, 0x64, 0x63, 0xb5, 0x4f, 0x69, 0xf2, 0xd1, 0x08, 0xba, 0x19, 0x53, 0x54, 0x5c, 0x90, 0xdc,
-	0x8c, 0x81, 0xde, 0xf8, 0x6a, 0xe3, 0xec, 0x17, 0x95, 0x09, 0xaf, 0x49, 0xfd, 0x53, 0xb8, 0xf2,
-	0x90, 0xc7, 0xf2, 0x3e, 0x4b, 0x26, 0x2b, 0x45, 0x25, 0x7a, 0x1b, 0x20, 0xe1, 0x71, 0x1d, 0x20,
-	0xc7, 0x68, 0xf7, 0x35, 0x62, 0x23, 0x73, 0x00, 0xbd, 0x99, 0xe6, 0x55, 0x76, 0x9d, 0x25, 0x17,
-	0x83, 0x81, 0x0c, 0xa1, 0xff, 0xbd, 0x03, 0xdb, 0x93, 0x8c, 0xe9, 0x1a, 0x46, 0x77, 0xc0, 0xcd,
-	0xe9, 0x5c, 0x55, 0xc9, 0x6e, 0x86, 0xa9, 0xf9, 0x48, 0x6c, 0x48, 0x7a, 0x00, 0x8b, 0x2c, 0x3d,
-	0x57, 0x55, 0xe6, 0x5f, 0xcb, 0xb6, 0x2c, 0x74, 0x0b, 0xda, 0xbc, 0x54, 0x55, 0xf6, 0x5f, 0x4b,
-	0xd6, 0x1c, 0x2d, 0xb1, 0x99, 0x96, 0x57, 0x34, 0xc3, 0x51, 0xb3, 0x19, 0x7a, 0x63, 0xd4, 0xb8,
-	0xae, 0xd2, 0xd2, 0x68, 0x90, 0xfe, 0xaf, 0x2d, 0xe8, 0xd8, 0xac, 0xa1, 0xfb, 0x00, 0x4a, 0x10,
-	0x26, 0xe7, 0x5c, 0x2c, 0x65, 0xe8, 0x98, 0x64, 0xdd, 0x7c, 0x29, 0xb9, 0xc3, 0xe9, 0x9a, 0x63,
-	0x53, 0xd6, 0x38, 0x84, 0x3e, 0x02, 0xbf, 0x28, 0x67, 0x79, 0x26, 0xcf, 0x69, 0xf2, 0x26, 0xed,
-	0x1b, 0xe6, 0x3f, 0xd1, 0x8f, 0xc1, 0x5f, 0x3b, 0x80, 0xfe, 0x0f, 0x9d, 0xea, 0x2d, 0x64, 0xf5,
-	0x57, 0x3b, 0x1d, 0xfe, 0x8c, 0x15, 0xe5, 0x9b, 0xc3, 0x6f, 0x58, 0xfd, 0xc7, 0xb0, 0xf7, 0x82,
-	0xa8, 0x57, 0x84, 0xf5, 0xee, 0xf3, 0x61, 0xbd, 0xfe, 0x37, 0x81, 0x69, 0xc6, 0x17, 0x43, 0xf0,
-	0x62, 0x91, 0xff, 0xeb, 0x9c, 0x3d, 0x82, 0x6e, 0x5d, 0xfc, 0xe8, 0x3d, 0xd8, 0x2d, 0x0b, 0x3d,
-	0x40, 0xea, 0x69, 0x50, 0x95, 0xf9, 0x8e, 0x45, 0xab, 0x49, 0xa0, 0x3b, 0xa1, 0xd4, 0xa3, 0x37,
-	0x12, 0x44, 0xd9, 0xa7, 0xb4, 0xb0, 0x6f, 0x10, 0x4c, 0x14, 0xbd, 0x7d, 0x0a, 0xdd, 0xfa, 0x3d,
-	0x89, 0x02, 0xb8, 0x92, 0xb1, 0x0b, 0x92, 0x67, 0x89, 0x79, 0x01, 0x07, 0x5b, 0xa8, 0xb7, 0x1e,
-	0x12, 0x81, 0x83, 0x76, 0x01, 0x4c, 0x77, 0x13, 0x95, 0x71, 0x3d, 0x2e, 0xaf, 0xc2, 0xde, 0xa6,
-	0x67, 0x2d, 0xd8, 0x9e, 0xdc, 0x7b, 0xfa, 0xc7, 0xfe, 0xd6, 0xd3, 0x67, 0xfb, 0xce, 0x4f, 0xcf,
-	0xf6, 0x9d, 0x1f, 0xfe, 0xdc, 0x77, 0x1e, 0x1f, 0xa5, 0x99, 0x3a, 0x2f, 0x67, 0xc3, 0x98, 0x2f,
-	0x47, 0x54, 0xaa, 0x92, 0x88, 0x95, 0xfd, 0xbc, 0x7a, 0xf1, 0x8b, 0x6c, 0xd6, 0x31, 0xdb, 0x0f,
-	0xff, 0x0a, 0x00, 0x00, 0xff, 0xff, 0x62, 0x52, 0xbc, 0x06, 0xac, 0x09, 0x00, 0x00,
+	// 1142 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xc5, 0x56, 0x4b, 0x8f, 0xe3, 0x44,
+	0x10, 0x9e, 0x24, 0x76, 0x26, 0x2e, 0xcf, 0x23, 0xf4, 0x2e, 0x6c, 0x94, 0x59, 0x66, 0x76, 0x03,
+	0x48, 0xc3, 0x2e, 0x24, 0xda, 0x20, 0x10, 0x5a, 0x09, 0x89, 0xcd, 0x2c, 0x48, 0xa0, 0x99, 0xd1,
+	0xe2, 0xe4, 0xb4, 0x17, 0xcb, 0xb1, 0x3b, 0x8e, 0x19, 0xa7, 0xdb, 0xb2, 0xdb, 0x33, 0x3b, 0x1c,
+	0xb9, 0xc1, 0x89, 0x9f, 0xc0, 0xcf, 0x99, 0x13, 0xe2, 0xca, 0x01, 0x10, 0xf0, 0x47, 0xa8, 0xee,
+	0xb6, 0x13, 0xcf, 0x63, 0x59, 0x21, 0x0e, 0x1c, 0x12, 0x55, 0x57, 0x7d, 0x5d, 0xae, 0xfa, 0xea,
+	0x61, 0x43, 0x37, 0xe4, 0x83, 0x24, 0xe5, 0x82, 0xfb, 0x3c, 0xce, 0x06, 0x3c, 0x51, 0xbf, 0xbe,
+	0xd2, 0x90, 0x06, 0x8a, 0xdd, 0xbb, 0x97, 0x00, 0xb3, 0x98, 0x9f, 0xa9, 0x3f, 0x0d, 0xe9, 0xde,
+	0x0e, 0x79, 0xc8, 0x95, 0x38, 0x90, 0x52, 0xa1, 0xdd, 0x0b, 0x39, 0x0f, 0x63, 0xaa, 0xef, 0x4d,
+	0xf3, 0xd9, 0x40, 0x44, 0x0b, 0x9a, 0x09, 0x6f, 0x91, 0x68, 0x40, 0xef, 0x97, 0x3a, 0x6c, 0x8e,
+	0xe7, 0x5e, 0x1a, 0x1c, 0x7a, 0x53, 0x1a, 0x47, 0x2c, 0x24, 0xb7, 0xc1, 0x9c, 0xe6, 0x51, 0x1c,
+	0x74, 0x6a, 0xf7, 0x6a, 0xfb, 0x96, 0xa3, 0x0f, 0xa4, 0x0b, 0xad, 0x39, 0xcf, 0x04, 0xf3, 0x16,
+	0xb4, 0x53, 0x57, 0x86, 0xe5, 0x99, 0x3c, 0x04, 0x2b, 0xe6, 0xa1, 0x1b, 0xd3, 0x53, 0x1a, 0x77,
+	0x1a, 0x68, 0xdc, 0x1a, 0x6e, 0xf5, 0x65, 0xf0, 0x87, 0x3c, 0xec, 0x1f, 0x4a, 0xad, 0xd3, 0x42,
+	0x80, 0x92, 0xc8, 0xfb, 0x60, 0x26, 0x3c, 0x15, 0x59, 0xc7, 0xb8, 0xd7, 0xd8, 0xb7, 0x87, 0xaf,
+	0xf5, 0x55, 0x0e, 0xc7, 0x54, 0x9c, 0xf1, 0xf4, 0xe4, 0x19, 0x5a, 0x46, 0xc6, 0xc5, 0x6f, 0x7b,
+	0x6b, 0x8e, 0x46, 0xa1, 0x6f, 0x33, 0xf5, 0x58, 0x48, 0x3b, 0x26, 0xfa, 0xb5, 0x87, 0xdb, 0x1a,
+	0xee, 0x48, 0xd5, 0x38, 0xa1, 0x7e, 0x09, 0x56, 0x18, 0x72, 0x1f, 0x36, 0xb2, 0x24, 0x8e, 0x84,
+	0x9b, 0xf1, 0x3c, 0xf5, 0x69, 0xa7, 0xa9, 0x02, 0xb5, 0x95, 0x6e, 0xac, 0x54, 0x2b, 0x88, 0xf0,
+	0xd2, 0x90, 0x8a, 0xce, 0x7a, 0x05, 0x32, 0x51, 0x2a, 0xb2, 0x03, 0x96, 0xf0, 0xb2, 0x13, 0x57,
+	0xe5, 0xda, 0xd2, 0xb9, 0x4a, 0xc5, 0xb1, 0xcc, 0xf5, 0x41, 0x61, 0x14, 0xe7, 0x09, 0xed, 0x58,
+	0x2a, 0xd7, 0x4d, 0x95, 0xeb, 0x04, 0xb5, 0x13, 0x54, 0x6a, 0xac, 0x94, 0x7a, 0xdf, 0xd6, 0xa0,
+	0xa5, 0xb8, 0x75, 0xe8, 0x0c, 0x1f, 0x6c, 0x9c, 0x44, 0x4c, 0xb3, 0x7a, 0xed, 0x8e, 0x32, 0x11,
+	0x02, 0x46, 0x85, 0x5f, 0x25, 0xcb, 0x60, 0x4e, 0xe8, 0xb9, 0x3b, 0xa5, 0x61, 0xc4, 0x14, 0xb7,
+	0x18, 0x0c, 0x2a, 0x46, 0xf2, 0x4c, 0x7a, 0xb0, 0x99, 0xba, 0x7e, 0xcc, 0xfd, 0x93, 0x02, 0x60,
+	0xe8, 0x6c, 0xd2, 0x03, 0xa9, 0x53, 0x98, 0x5e, 0x17, 0x8c, 0x23, 0x2a, 0x3c, 0xe9, 0x3c, 0xcf,
+	0xa3, 0xb2, 0xaa, 0x4a, 0xee, 0xfd, 0xd4, 0x80, 0x06, 0xd6, 0x88, 0xec, 0x82, 0xb1, 0x40, 0x8c,
+	0xb2, 0xd9, 0x43, 0x4b, 0xc5, 0x26, 0x2f, 0x39, 0xa6, 0x2b, 0xf5, 0xe4, 0x2d, 0x30, 0x33, 0x99,
+	0x87, 0x8a, 0xcc, 0x2e, 0x82, 0x2f, 0x33, 0x73, 0xb4, 0x8d, 0x3c, 0x42, 0x66, 0xca, 0xe6, 0x52,
+	0x91, 0xda, 0xc3, 0x6e, 0x5f, 0xb7, 0x5f, 0xbf, 0x6c, 0xbf, 0xfe, 0xa4, 0x44, 0x38, 0x75, 0x2c,
+	0xee, 0xdb, 0x60, 0xea, 0xa6, 0x31, 0x6e, 0x6c, 0x1a, 0x6d, 0x24, 0x1d, 0x58, 0xc7, 0x4b, 0x99,
+	0x57, 0x34, 0x81, 0xe5, 0x94, 0x47, 0xf2, 0x1c, 0xb6, 0x67, 0x11, 0x8d, 0x83, 0xcc, 0xfd, 0x3a,
+	0xe3, 0xcc, 0x5d, 0x78, 0x09, 0x96, 0x5c, 0x76, 0xd5, 0xce, 0xd2, 0xd3, 0xe7, 0xca, 0xfe, 0x25,
+	0x9a, 0x8f, 0xbc, 0xe4, 0x33, 0x26, 0xd2, 0xf3, 0xd1, 0xdd, 0xef, 0x7f, 0xdf, 0xeb, 0x50, 0xe6,
+	0xf3, 0x00, 0xdb, 0x7d, 0x20, 0x6f, 0x62, 0x3f, 0x9d, 0x1d, 0x69, 0x9f, 0x4e, 0x53, 0x7b, 0x44,
+	0x4e, 0xcc, 0x2c, 0xf1, 0x58, 0x86, 0x1d, 0x22, 0x3d, 0xb6, 0x4a, 0x8f, 0x8e, 0x56, 0x77, 0x3f,
+	0x05, 0x72, 0xdd, 0x37, 0x69, 0x43, 0x03, 0xab, 0x53, 0x90, 0x2c, 0x45, 0x39, 0x4e, 0xa7, 0x5e,
+	0x9c, 0x97, 0x55, 0xd5, 0x87, 0xc7, 0xf5, 0x8f, 0x6b, 0xbd, 0xaf, 0xc0, 0xd4, 0x23, 0x71, 0x0b,
+	0xb6, 0x73, 0x16, 0xd0, 0x59, 0xc4, 0x68, 0xa0, 0xa7, 0xa8, 0xbd, 0x46, 0x2c, 0x30, 0x69, 0x9a,
+	0xf2, 0xb4, 0x5d, 0x23, 0x2d, 0x30, 0xce, 0xbc, 0x94, 0xb5, 0xeb, 0x52, 0x8a, 0xd8, 0x8c, 0xb7,
+	0x1b, 0xd2, 0x1c, 0xd0, 0x69, 0x1e, 0xb6, 0x0d, 0x29, 0x8a, 0xd4, 0xf3, 0x69, 0xdb, 0xec, 0x7d,
+	0x07, 0x60, 0x8e, 0x85, 0x27, 0xb2, 0xff, 0xad, 0xa4, 0xef, 0x01, 0xe1, 0x09, 0x65, 0x6e, 0x46,
+	0x7d, 0xce, 0xb0, 0x30, 0x82, 0x0b, 0x4f, 0xd7, 0xb7, 0xe6, 0xb4, 0xa5, 0x65, 0xac, 0x0d, 0x13,
+	0xa9, 0x57, 0xa3, 0xf6, 0x82, 0xb9, 0x3e, 0xcf, 0x99, 0x50, 0xc5, 0xdd, 0xc4, 0xf1, 0x79, 0xc1,
+	0x0e, 0xe4, 0x19, 0x9f, 0xbe, 0xee, 0x7b, 0x89, 0xc8, 0x53, 0x5a, 0x54, 0xf5, 0x8e, 0x0e, 0x52,
+	0xe6, 0xd7, 0x3f, 0xd0, 0x16, 0xc5, 0xba, 0x53, 0xe2, 0xc8, 0xbb, 0xd0, 0x0c, 0x68, 0x1a, 0x9d,
+	0x52, 0x35, 0xd7, 0x72, 0xbb, 0xac, 0x6e, 0x3c, 0x55, 0x06, 0xa7, 0x00, 0x90, 0x4f, 0xc0, 0x5e,
+	0x78, 0x02, 0x65, 0x2f, 0x8e, 0xbe, 0x91, 0x73, 0xbe, 0xea, 0x1b, 0x8d, 0x3f, 0x5a, 0x59, 0xf5,
+	0x53, 0xaa, 0x78, 0x32, 0x80, 0x56, 0xc4, 0xf0, 0x88, 0xe5, 0x54, 0x6b, 0xc0, 0x1e, 0xde, 0xaa,
+	0xdc, 0xfd, 0xa2, 0x30, 0x39, 0x4b, 0x10, 0xf9, 0x08, 0xee, 0x88, 0x39, 0x72, 0x26, 0x62, 0x2c,
+	0xf2, 0x65, 0x76, 0x40, 0xb1, 0xf3, 0xfa, 0xd2, 0x5c, 0xa5, 0xa8, 0x7b, 0x0c, 0x1b, 0x4f, 0xb9,
+	0x9f, 0x3d, 0x61, 0xc1, 0xe8, 0x5c, 0xd0, 0x8c, 0xbc, 0x09, 0x10, 0xe0, 0xb9, 0xb8, 0x5a, 0x53,
+	0x9c, 0x59, 0x52, 0xa3, 0x19, 0xdd, 0x03, 0x7b, 0x2a, 0x71, 0x85, 0x5d, 0x56, 0xd7, 0x70, 0x40,
+	0xa9, 0xb4, 0xbf, 0x1f, 0x6a, 0xb0, 0x3e, 0xc2, 0x6d, 0x23, 0x57, 0xfd, 0x43, 0x30, 0x62, 0x3a,
+	0x13, 0x45, 0x93, 0x54, 0xe9, 0xad, 0x3e, 0xd2, 0x51, 0x20, 0xb9, 0xb8, 0xd3, 0x28, 0x9c, 0x8b,
+	0xa2, 0x63, 0x5e, 0x8a, 0xd6, 0x28, 0x2c, 0x45, 0x83, 0xe7, 0xa2, 0xe8, 0x9a, 0x97, 0x82, 0x25,
+	0x46, 0xa6, 0x58, 0x2d, 0xe7, 0x0d, 0x43, 0xb4, 0x5f, 0x1d, 0x22, 0x7b, 0x48, 0x2a, 0xee, 0x8a,
+	0x5c, 0x2a, 0x83, 0xd5, 0xfd, 0xb5, 0x0e, 0x4d, 0x5d, 0x6d, 0xf2, 0x04, 0x00, 0x67, 0x83, 0x65,
+	0x33, 0x9e, 0x2e, 0x32, 0xf4, 0x28, 0x8b, 0x7c, 0xff, 0x5a, 0x53, 0xf4, 0x27, 0x4b, 0x8c, 0x2e,
+	0x75, 0xe5, 0x12, 0xf9, 0x10, 0xac, 0x24, 0x9f, 0xc6, 0x51, 0x36, 0xa7, 0xc1, 0xab, 0x72, 0x5f,
+	0x21, 0xff, 0x4d, 0xfe, 0x0e, 0x58, 0xcb, 0x00, 0xc8, 0x1b, 0xd0, 0x2c, 0xde, 0x5e, 0x3a, 0xff,
+	0xe2, 0x24, 0xe9, 0x8f, 0x58, 0x92, 0xbf, 0x9a, 0x7e, 0x85, 0xea, 0xe2, 0x6a, 0xbc, 0x92, 0xd4,
+	0x0d, 0xb4, 0x3e, 0xba, 0x4c, 0xeb, 0xce, 0x3f, 0x10, 0x53, 0xe5, 0xd7, 0x81, 0xf6, 0xd5, 0xe1,
+	0xf8, 0xcf, 0x35, 0x7b, 0x06, 0xad, 0x72, 0x68, 0xc8, 0x3b, 0xb0, 0x95, 0x27, 0x72, 0xf1, 0x94,
+	0x73, 0x52, 0xb4, 0xf9, 0xa6, 0xd6, 0x16, 0xe3, 0x21, 0x27, 0x21, 0x97, 0x2b, 0xdb, 0x4d, 0x31,
+	0x18, 0xf5, 0x94, 0xba, 0x63, 0x29, 0x8d, 0x83, 0x8a, 0x07, 0xc7, 0xd0, 0x2a, 0xdf, 0xaf, 0x18,
+	0xdd, 0x46, 0xc4, 0xd0, 0x75, 0x14, 0xa8, 0x17, 0x37, 0xae, 0x57, 0x7b, 0xb9, 0x5c, 0x70, 0xc1,
+	0x6e, 0xe1, 0x4c, 0xc9, 0x7c, 0x3d, 0x11, 0x71, 0xb9, 0x66, 0x71, 0x21, 0xaf, 0x66, 0x5d, 0x2b,
+	0x1b, 0xa3, 0xc7, 0x17, 0x7f, 0xec, 0xae, 0x5d, 0xfc, 0xb9, 0x5b, 0xfb, 0x19, 0x7f, 0x3f, 0xfe,
+	0xb5, 0x5b, 0x7b, 0xbe, 0x1f, 0x46, 0x62, 0x9e, 0x4f, 0xfb, 0x3e, 0x5f, 0x0c, 0x70, 0x05, 0xe6,
+	0x5e, 0x7a, 0xae, 0x3f, 0xcb, 0xae, 0x7e, 0xc9, 0x4d, 0x9b, 0xea, 0xf8, 0xc1, 0xdf, 0x59, 0xae,
+	0x71, 0xf3, 0xe4, 0x09, 0x00, 0x00,
 }
 
 func (m *ShardLabeling) Marshal() (dAtA []byte, err error) {
@@ -1004,6 +1010,12 @@ func (m *Stats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.ThrottledSecondsTotal != 0 {
+		i -= 8
+		encoding_binary.LittleEndian.PutUint64(dAtA[i:], uint64(math.Float64bits(float64(m.ThrottledSecondsTotal))))
+		i--
+		dAtA[i] = 0x51
+	}
 	if m.Interval != nil {
 		{
 			size, err := m.Interval.MarshalToSizedBuffer(dAtA[:i])
@@ -1594,6 +1606,9 @@ func (m *Stats) ProtoSize() (n int) {
 		l = m.Interval.ProtoSize()
 		n += 1 + l + sovOps(uint64(l))
 	}
+	if m.ThrottledSecondsTotal != 0 {
+		n += 9
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -3152,6 +3167,17 @@ func (m *Stats) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 10:
+			if wireType != 1 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ThrottledSecondsTotal", wireType)
+			}
+			var v uint64
+			if (iNdEx + 8) > l {
+				return io.ErrUnexpectedEOF
+			}
+			v = uint64(encoding_binary.LittleEndian.Uint64(dAtA[iNdEx:]))
+			iNdEx += 8
+			m.ThrottledSecondsTotal = float64(math.Float64frombits(v))
 		default:
 			iNdEx = preIndex
 			skippy, err := skipOps(dAtA[iNdEx:])
@@ -164,11 +164,13 @@ func (d *Derive) RestoreCheckpoint(shard consumer.Shard) (cp pf.Checkpoint, err
 	if err != nil {
 		return pf.Checkpoint{}, err
 	}
-	var openedExt = pr.FromInternal[pr.DeriveResponseExt](opened.Internal)
+	if opened.Opened == nil || opened.Opened.RuntimeCheckpoint == nil {
+		return pf.Checkpoint{}, fmt.Errorf("expected Opened with RuntimeCheckpoint")
+	}
 
-	removeOldOpsJournalAckIntents(openedExt.Opened.RuntimeCheckpoint.AckIntents)
+	removeOldOpsJournalAckIntents(opened.Opened.RuntimeCheckpoint.AckIntents)
 
-	return *openedExt.Opened.RuntimeCheckpoint, nil
+	return *opened.Opened.RuntimeCheckpoint, nil
 }
 
 // Destroy releases the API binding delegate, which also cleans up the associated
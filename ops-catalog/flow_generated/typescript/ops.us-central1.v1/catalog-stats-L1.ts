@@ -157,6 +157,7 @@ export type SourceStats = /* Flow task stats Statistics related to the processin
         name: /* The name of the catalog task (without the task type prefix) */ string;
         rClockBegin: /* The inclusive beginning of the shard's assigned rClock range */ string;
     };
+    throttledSecondsTotal?: /* Total time that the transaction was throttled by rate limits */ number;
     ts: /* Timestamp corresponding to the start of the transaction */ string;
     txnCount?: /* Total number of transactions represented by this stats document */ number;
 };